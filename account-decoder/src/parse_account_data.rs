@@ -14,10 +14,6 @@ use solana_sdk::{instruction::InstructionError, pubkey::Pubkey, system_program,
 use std::collections::HashMap;
 use thiserror::Error;
 
-mod velas_account {
-    solana_sdk::declare_id!("VAcccHVjpknkW5N5R9sfRppQxYJrJYVV7QJGKchkQj5");
-}
-
 lazy_static! {
     static ref BPF_UPGRADEABLE_LOADER_PROGRAM_ID: Pubkey = solana_sdk::bpf_loader_upgradeable::id();
     static ref CONFIG_PROGRAM_ID: Pubkey = solana_config_program::id();
@@ -26,7 +22,7 @@ lazy_static! {
     static ref SYSVAR_PROGRAM_ID: Pubkey = sysvar::id();
     static ref TOKEN_PROGRAM_ID: Pubkey = spl_token_id_v2_0();
     static ref VOTE_PROGRAM_ID: Pubkey = solana_vote_program::id();
-    static ref VELAS_ACCOUNT_PROGRAM_ID: Pubkey = velas_account::id();
+    static ref VELAS_ACCOUNT_PROGRAM_ID: Pubkey = solana_velas_account_program::id();
     pub static ref PARSABLE_PROGRAM_IDS: HashMap<Pubkey, ParsableAccount> = {
         let mut m = HashMap::new();
         m.insert(
@@ -1,10 +1,11 @@
 use crate::{
     parse_account_data::{ParsableAccount, ParseAccountError},
-    validator_info,
+    validator_info, StringAmount,
 };
 use bincode::deserialize;
 use serde_json::Value;
 use solana_config_program::{get_config_data, ConfigKeys};
+use solana_evm_loader_program::evm_params::{self, EvmParams};
 use solana_sdk::pubkey::Pubkey;
 use solana_stake_program::config::Config as StakeConfig;
 
@@ -14,6 +15,15 @@ pub fn parse_config(data: &[u8], pubkey: &Pubkey) -> Result<ConfigAccountType, P
             .ok()
             .and_then(|data| deserialize::<StakeConfig>(data).ok())
             .map(|config| ConfigAccountType::StakeConfig(config.into()))
+    } else if pubkey == &evm_params::id() || pubkey == &evm_params::active::id() {
+        deserialize::<ConfigKeys>(data).ok().and_then(|key_list| {
+            parse_config_data::<EvmParams>(data, key_list.keys).map(|params| {
+                ConfigAccountType::EvmParams(UiConfig {
+                    keys: params.keys,
+                    config_data: params.config_data.into(),
+                })
+            })
+        })
     } else {
         deserialize::<ConfigKeys>(data).ok().and_then(|key_list| {
             if !key_list.keys.is_empty() && key_list.keys[0].0 == validator_info::id() {
@@ -53,6 +63,7 @@ where
 pub enum ConfigAccountType {
     StakeConfig(UiStakeConfig),
     ValidatorInfo(UiConfig<Value>),
+    EvmParams(UiConfig<UiEvmParams>),
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -78,6 +89,26 @@ impl From<StakeConfig> for UiStakeConfig {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UiEvmParams {
+    pub min_gas_price: StringAmount,
+    pub block_gas_limit: StringAmount,
+    pub fee_burn_percent: u8,
+    pub fee_leader_percent: u8,
+}
+
+impl From<EvmParams> for UiEvmParams {
+    fn from(params: EvmParams) -> Self {
+        Self {
+            min_gas_price: params.min_gas_price.to_string(),
+            block_gas_limit: params.block_gas_limit.to_string(),
+            fee_burn_percent: params.fee_burn_percent,
+            fee_leader_percent: params.fee_leader_percent,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct UiConfig<T> {
@@ -140,6 +171,31 @@ mod test {
             }),
         );
 
+        let evm_params = EvmParams {
+            min_gas_price: 2_000_000_000,
+            block_gas_limit: 100_000_000,
+            fee_burn_percent: 50,
+            fee_leader_percent: 25,
+        };
+        let governance_pubkey = solana_sdk::pubkey::new_rand();
+        let evm_params_account =
+            create_config_account(vec![(governance_pubkey, true)], &evm_params, 10);
+        assert_eq!(
+            parse_config(&evm_params_account.data, &evm_params::id()).unwrap(),
+            ConfigAccountType::EvmParams(UiConfig {
+                keys: vec![UiConfigKey {
+                    pubkey: governance_pubkey.to_string(),
+                    signer: true,
+                }],
+                config_data: UiEvmParams {
+                    min_gas_price: "2000000000".to_string(),
+                    block_gas_limit: "100000000".to_string(),
+                    fee_burn_percent: 50,
+                    fee_leader_percent: 25,
+                },
+            }),
+        );
+
         let bad_data = vec![0; 4];
         assert!(parse_config(&bad_data, &info_pubkey).is_err());
     }
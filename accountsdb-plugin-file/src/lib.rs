@@ -0,0 +1,335 @@
+//! Reference AccountsDb plugin: appends every notification to a local file as
+//! one JSON object per line, which makes it easy to tail or to bulk-load into a
+//! database.
+//!
+//! The plugin config file looks like:
+//! ```json
+//! {
+//!     "libpath": "/path/to/libsolana_accountsdb_plugin_file.so",
+//!     "path": "/var/log/accountsdb-plugin.jsonl",
+//!     "owners": ["Vote111111111111111111111111111111111111111"],
+//!     "startup_accounts": false,
+//!     "transactions": true
+//! }
+//! ```
+#[macro_use]
+extern crate serde_derive;
+
+use {
+    log::*,
+    serde_json::json,
+    solana_accountsdb_plugin_interface::accountsdb_plugin_interface::{
+        AccountsDbPlugin, AccountsDbPluginError, ReplicaAccountInfoVersions,
+        ReplicaEvmReceiptInfoVersions, ReplicaTransactionInfoVersions, Result, SlotStatus,
+    },
+    solana_transaction_status::UiTransactionStatusMeta,
+    std::{
+        collections::HashSet,
+        fs::{File, OpenOptions},
+        io::{BufWriter, Write},
+        path::PathBuf,
+    },
+};
+
+#[derive(Debug, Deserialize)]
+struct AccountsDbPluginFileConfig {
+    /// The file notifications are appended to
+    path: PathBuf,
+
+    /// Only accounts owned by these programs are written, all accounts when empty
+    #[serde(default)]
+    owners: Vec<String>,
+
+    /// Write the accounts restored from the snapshot when the validator starts
+    #[serde(default)]
+    startup_accounts: bool,
+
+    /// Write committed transactions and EVM receipts
+    #[serde(default)]
+    transactions: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct AccountsDbPluginFile {
+    writer: Option<BufWriter<File>>,
+    owners: HashSet<Vec<u8>>,
+    startup_accounts: bool,
+    transactions: bool,
+}
+
+impl AccountsDbPluginFile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn write_line(&mut self, value: serde_json::Value) -> Result<()> {
+        let writer = self.writer.as_mut().ok_or_else(|| {
+            AccountsDbPluginError::Custom("plugin is not loaded".to_string().into())
+        })?;
+        serde_json::to_writer(&mut *writer, &value)
+            .map_err(|err| AccountsDbPluginError::Custom(Box::new(err)))?;
+        writer.write_all(b"\n")?;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        if let Some(writer) = self.writer.as_mut() {
+            writer.flush()?;
+        }
+        Ok(())
+    }
+}
+
+impl AccountsDbPlugin for AccountsDbPluginFile {
+    fn name(&self) -> &'static str {
+        "AccountsDbPluginFile"
+    }
+
+    fn on_load(&mut self, config_file: &str) -> Result<()> {
+        info!("Loading plugin {} from config {}", self.name(), config_file);
+        let config = std::fs::read_to_string(config_file)?;
+        let config: AccountsDbPluginFileConfig = serde_json::from_str(&config).map_err(|err| {
+            AccountsDbPluginError::ConfigFileReadError {
+                msg: format!("The config file is not in the expected format: {}", err),
+            }
+        })?;
+
+        self.owners = config
+            .owners
+            .iter()
+            .map(|owner| {
+                bs58::decode(owner).into_vec().map_err(|err| {
+                    AccountsDbPluginError::ConfigFileReadError {
+                        msg: format!("Invalid owner {}: {}", owner, err),
+                    }
+                })
+            })
+            .collect::<Result<_>>()?;
+        self.startup_accounts = config.startup_accounts;
+        self.transactions = config.transactions;
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&config.path)?;
+        self.writer = Some(BufWriter::new(file));
+        Ok(())
+    }
+
+    fn on_unload(&mut self) {
+        info!("Unloading plugin: {}", self.name());
+        if let Err(err) = self.flush() {
+            error!("Failed to flush the plugin output: {}", err);
+        }
+        self.writer = None;
+    }
+
+    fn update_account(
+        &mut self,
+        account: ReplicaAccountInfoVersions,
+        slot: u64,
+        is_startup: bool,
+    ) -> Result<()> {
+        let ReplicaAccountInfoVersions::V0_0_1(account) = account;
+        if is_startup && !self.startup_accounts {
+            return Ok(());
+        }
+        if !self.owners.is_empty() && !self.owners.contains(account.owner) {
+            return Ok(());
+        }
+        self.write_line(json!({
+            "type": "account",
+            "slot": slot,
+            "isStartup": is_startup,
+            "pubkey": bs58::encode(account.pubkey).into_string(),
+            "lamports": account.lamports,
+            "owner": bs58::encode(account.owner).into_string(),
+            "executable": account.executable,
+            "rentEpoch": account.rent_epoch,
+            "data": base64::encode(account.data),
+        }))
+    }
+
+    fn notify_end_of_startup(&mut self) -> Result<()> {
+        self.flush()
+    }
+
+    fn update_slot_status(
+        &mut self,
+        slot: u64,
+        parent: Option<u64>,
+        status: SlotStatus,
+    ) -> Result<()> {
+        self.write_line(json!({
+            "type": "slot",
+            "slot": slot,
+            "parent": parent,
+            "status": status.as_str(),
+        }))?;
+        // Rooted slots are final, make them visible to the readers of the file
+        if status == SlotStatus::Rooted {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn notify_transaction(
+        &mut self,
+        transaction: ReplicaTransactionInfoVersions,
+        slot: u64,
+    ) -> Result<()> {
+        let ReplicaTransactionInfoVersions::V0_0_1(transaction) = transaction;
+        let meta = UiTransactionStatusMeta::from(transaction.transaction_status_meta.clone());
+        self.write_line(json!({
+            "type": "transaction",
+            "slot": slot,
+            "signature": transaction.signature.to_string(),
+            "isVote": transaction.is_vote,
+            "transaction": transaction.transaction,
+            "meta": meta,
+        }))
+    }
+
+    fn notify_evm_receipt(
+        &mut self,
+        receipt: ReplicaEvmReceiptInfoVersions,
+        slot: u64,
+    ) -> Result<()> {
+        let ReplicaEvmReceiptInfoVersions::V0_0_1(receipt) = receipt;
+        self.write_line(json!({
+            "type": "evmReceipt",
+            "slot": slot,
+            "transactionHash": receipt.transaction_hash,
+            "receipt": receipt.receipt,
+        }))
+    }
+
+    fn account_data_notifications_enabled(&self) -> bool {
+        true
+    }
+
+    fn transaction_notifications_enabled(&self) -> bool {
+        self.transactions
+    }
+}
+
+#[no_mangle]
+#[allow(improper_ctypes_definitions)]
+/// # Safety
+///
+/// This function returns the AccountsDbPluginFile pointer as trait AccountsDbPlugin.
+pub unsafe extern "C" fn _create_plugin() -> *mut dyn AccountsDbPlugin {
+    let plugin = AccountsDbPluginFile::new();
+    let plugin: Box<dyn AccountsDbPlugin> = Box::new(plugin);
+    Box::into_raw(plugin)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_accountsdb_plugin_interface::accountsdb_plugin_interface::{
+            ReplicaAccountInfo, ReplicaTransactionInfo,
+        },
+        solana_sdk::{pubkey::Pubkey, signature::Signature, transaction::Transaction},
+        solana_transaction_status::TransactionStatusMeta,
+        std::path::Path,
+    };
+
+    fn load_plugin(
+        config: serde_json::Value,
+    ) -> (AccountsDbPluginFile, PathBuf, tempfile::TempDir) {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output_path = temp_dir.path().join("output.jsonl");
+        let mut config = config;
+        config["path"] = json!(output_path);
+        let config_path = temp_dir.path().join("config.json");
+        std::fs::write(&config_path, config.to_string()).unwrap();
+
+        let mut plugin = AccountsDbPluginFile::new();
+        plugin.on_load(config_path.to_str().unwrap()).unwrap();
+        (plugin, output_path, temp_dir)
+    }
+
+    fn read_lines(path: &Path) -> Vec<serde_json::Value> {
+        std::fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_write_accounts_and_slots() {
+        let owner = Pubkey::new_unique();
+        let (mut plugin, output_path, _temp_dir) =
+            load_plugin(json!({ "owners": [owner.to_string()] }));
+        assert!(!plugin.transaction_notifications_enabled());
+
+        let pubkey = Pubkey::new_unique();
+        let other_owner = Pubkey::new_unique();
+        for (owner, is_startup) in &[(owner, false), (other_owner, false), (owner, true)] {
+            let account = ReplicaAccountInfo {
+                pubkey: pubkey.as_ref(),
+                lamports: 42,
+                owner: owner.as_ref(),
+                executable: false,
+                rent_epoch: 1,
+                data: &[1, 2, 3],
+            };
+            plugin
+                .update_account(ReplicaAccountInfoVersions::V0_0_1(&account), 5, *is_startup)
+                .unwrap();
+        }
+        plugin
+            .update_slot_status(5, Some(4), SlotStatus::Rooted)
+            .unwrap();
+
+        let lines = read_lines(&output_path);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["type"], "account");
+        assert_eq!(lines[0]["pubkey"], pubkey.to_string());
+        assert_eq!(lines[0]["lamports"], 42);
+        assert_eq!(lines[0]["data"], base64::encode(&[1u8, 2, 3]));
+        assert_eq!(
+            lines[1],
+            json!({"type": "slot", "slot": 5, "parent": 4, "status": "rooted"})
+        );
+    }
+
+    #[test]
+    fn test_write_transactions() {
+        let (mut plugin, output_path, _temp_dir) = load_plugin(json!({ "transactions": true }));
+        assert!(plugin.transaction_notifications_enabled());
+
+        let signature = Signature::new(&[7; 64]);
+        let transaction = Transaction::default();
+        let transaction_status_meta = TransactionStatusMeta {
+            status: Ok(()),
+            fee: 5000,
+            pre_balances: vec![10],
+            post_balances: vec![5],
+            inner_instructions: None,
+            log_messages: None,
+            pre_token_balances: None,
+            post_token_balances: None,
+            return_data: None,
+        };
+        let transaction_info = ReplicaTransactionInfo {
+            signature: &signature,
+            is_vote: false,
+            transaction: &transaction,
+            transaction_status_meta: &transaction_status_meta,
+        };
+        plugin
+            .notify_transaction(ReplicaTransactionInfoVersions::V0_0_1(&transaction_info), 3)
+            .unwrap();
+        plugin.on_unload();
+
+        let lines = read_lines(&output_path);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0]["type"], "transaction");
+        assert_eq!(lines[0]["signature"], signature.to_string());
+        assert_eq!(lines[0]["meta"]["fee"], 5000);
+    }
+}
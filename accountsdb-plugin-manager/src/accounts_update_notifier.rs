@@ -0,0 +1,107 @@
+/// Module responsible for notifying plugins of account updates
+use {
+    crate::accountsdb_plugin_manager::AccountsDbPluginManager,
+    log::*,
+    solana_accountsdb_plugin_interface::accountsdb_plugin_interface::{
+        ReplicaAccountInfo, ReplicaAccountInfoVersions,
+    },
+    solana_measure::measure::Measure,
+    solana_metrics::*,
+    solana_runtime::accounts_update_notifier_interface::AccountsUpdateNotifierInterface,
+    solana_sdk::{account::Account, clock::Slot, pubkey::Pubkey},
+    std::sync::{Arc, RwLock},
+};
+
+#[derive(Debug)]
+pub(crate) struct AccountsUpdateNotifierImpl {
+    plugin_manager: Arc<RwLock<AccountsDbPluginManager>>,
+}
+
+impl AccountsUpdateNotifierInterface for AccountsUpdateNotifierImpl {
+    fn notify_account_update(&self, slot: Slot, pubkey: &Pubkey, account: &Account) {
+        let account = Self::accountinfo_from_account(pubkey, account);
+        self.notify_plugins_of_account_update(account, slot, false);
+    }
+
+    fn notify_account_restore_from_snapshot(&self, slot: Slot, pubkey: &Pubkey, account: &Account) {
+        let account = Self::accountinfo_from_account(pubkey, account);
+        self.notify_plugins_of_account_update(account, slot, true);
+    }
+}
+
+impl AccountsUpdateNotifierImpl {
+    pub fn new(plugin_manager: Arc<RwLock<AccountsDbPluginManager>>) -> Self {
+        AccountsUpdateNotifierImpl { plugin_manager }
+    }
+
+    fn accountinfo_from_account<'a>(
+        pubkey: &'a Pubkey,
+        account: &'a Account,
+    ) -> ReplicaAccountInfo<'a> {
+        ReplicaAccountInfo {
+            pubkey: pubkey.as_ref(),
+            lamports: account.lamports,
+            owner: account.owner.as_ref(),
+            executable: account.executable,
+            rent_epoch: account.rent_epoch,
+            data: &account.data,
+        }
+    }
+
+    fn notify_plugins_of_account_update(
+        &self,
+        account: ReplicaAccountInfo,
+        slot: Slot,
+        is_startup: bool,
+    ) {
+        let mut measure2 = Measure::start("accountsdb-plugin-notify_plugins_of_account_update");
+        let mut plugin_manager = self.plugin_manager.write().unwrap();
+
+        if plugin_manager.plugins.is_empty() {
+            return;
+        }
+        for plugin in plugin_manager.plugins.iter_mut() {
+            if !plugin.account_data_notifications_enabled() {
+                continue;
+            }
+            let mut measure = Measure::start("accountsdb-plugin-update-account");
+            match plugin.update_account(
+                ReplicaAccountInfoVersions::V0_0_1(&account),
+                slot,
+                is_startup,
+            ) {
+                Err(err) => {
+                    error!(
+                        "Failed to update account {} at slot {}, error: {} to plugin {}",
+                        bs58::encode(account.pubkey).into_string(),
+                        slot,
+                        err,
+                        plugin.name()
+                    )
+                }
+                Ok(_) => {
+                    trace!(
+                        "Successfully updated account {} at slot {} to plugin {}",
+                        bs58::encode(account.pubkey).into_string(),
+                        slot,
+                        plugin.name()
+                    );
+                }
+            }
+            measure.stop();
+            inc_new_counter_debug!(
+                "accountsdb-plugin-update-account-us",
+                measure.as_us() as usize,
+                100000,
+                100000
+            );
+        }
+        measure2.stop();
+        inc_new_counter_debug!(
+            "accountsdb-plugin-notify_plugins_of_account_update-us",
+            measure2.as_us() as usize,
+            100000,
+            100000
+        );
+    }
+}
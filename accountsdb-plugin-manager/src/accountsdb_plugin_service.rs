@@ -0,0 +1,238 @@
+use {
+    crate::{
+        accounts_update_notifier::AccountsUpdateNotifierImpl,
+        accountsdb_plugin_manager::AccountsDbPluginManager,
+        slot_status_notifier::{SlotStatusNotifier, SlotStatusNotifierImpl},
+        transaction_notifier::{TransactionNotifierImpl, TransactionNotifierLock},
+    },
+    log::*,
+    solana_runtime::accounts_update_notifier_interface::AccountsUpdateNotifier,
+    std::{
+        fs::File,
+        io::Read,
+        path::{Path, PathBuf},
+        sync::{Arc, RwLock},
+        thread,
+    },
+    thiserror::Error,
+};
+
+#[derive(Error, Debug)]
+pub enum AccountsdbPluginServiceError {
+    #[error("Cannot open the the plugin config file")]
+    CannotOpenConfigFile(String),
+
+    #[error("Cannot read the the plugin config file")]
+    CannotReadConfigFile(String),
+
+    #[error("The config file is not in a valid Json format")]
+    InvalidConfigFileFormat(String),
+
+    #[error("Plugin library path is not specified in the config file")]
+    LibPathNotSet,
+
+    #[error("Invalid plugin path")]
+    InvalidPluginPath,
+
+    #[error("Cannot load plugin shared library")]
+    PluginLoadError(String),
+}
+
+/// The service managing the AccountsDb plugin workflow.
+pub struct AccountsDbPluginService {
+    plugin_manager: Arc<RwLock<AccountsDbPluginManager>>,
+    accounts_update_notifier: Option<AccountsUpdateNotifier>,
+    transaction_notifier: Option<TransactionNotifierLock>,
+    slot_status_notifier: SlotStatusNotifier,
+}
+
+impl AccountsDbPluginService {
+    /// Creates and returns the AccountsDbPluginService.
+    /// # Arguments
+    /// * `accountsdb_plugin_config_files` - the list of paths to the plugin config files.
+    ///    The config file is in JSON format and must contain the field "libpath", the full
+    ///    path name of the shared library implementing the AccountsDbPlugin interface.
+    ///    The rest of the file is handed over to the plugin in its `on_load` callback.
+    pub fn new(
+        accountsdb_plugin_config_files: &[PathBuf],
+    ) -> Result<Self, AccountsdbPluginServiceError> {
+        info!(
+            "Starting AccountsDbPluginService from config files: {:?}",
+            accountsdb_plugin_config_files
+        );
+        let mut plugin_manager = AccountsDbPluginManager::new();
+
+        for accountsdb_plugin_config_file in accountsdb_plugin_config_files {
+            Self::load_plugin(&mut plugin_manager, accountsdb_plugin_config_file)?;
+        }
+        let account_data_notifications_enabled =
+            plugin_manager.account_data_notifications_enabled();
+        let transaction_notifications_enabled = plugin_manager.transaction_notifications_enabled();
+
+        let plugin_manager = Arc::new(RwLock::new(plugin_manager));
+
+        let accounts_update_notifier: Option<AccountsUpdateNotifier> =
+            if account_data_notifications_enabled {
+                let accounts_update_notifier =
+                    AccountsUpdateNotifierImpl::new(plugin_manager.clone());
+                Some(Arc::new(RwLock::new(accounts_update_notifier)))
+            } else {
+                None
+            };
+
+        let transaction_notifier: Option<TransactionNotifierLock> =
+            if transaction_notifications_enabled {
+                let transaction_notifier = TransactionNotifierImpl::new(plugin_manager.clone());
+                Some(Arc::new(RwLock::new(transaction_notifier)))
+            } else {
+                None
+            };
+
+        let slot_status_notifier: SlotStatusNotifier = Arc::new(RwLock::new(
+            SlotStatusNotifierImpl::new(plugin_manager.clone()),
+        ));
+
+        info!("Started AccountsDbPluginService");
+        Ok(AccountsDbPluginService {
+            plugin_manager,
+            accounts_update_notifier,
+            transaction_notifier,
+            slot_status_notifier,
+        })
+    }
+
+    fn load_plugin(
+        plugin_manager: &mut AccountsDbPluginManager,
+        accountsdb_plugin_config_file: &Path,
+    ) -> Result<(), AccountsdbPluginServiceError> {
+        let mut file = match File::open(accountsdb_plugin_config_file) {
+            Ok(file) => file,
+            Err(err) => {
+                return Err(AccountsdbPluginServiceError::CannotOpenConfigFile(format!(
+                    "Failed to open the plugin config file {:?}, error: {:?}",
+                    accountsdb_plugin_config_file, err
+                )));
+            }
+        };
+
+        let mut contents = String::new();
+        if let Err(err) = file.read_to_string(&mut contents) {
+            return Err(AccountsdbPluginServiceError::CannotReadConfigFile(format!(
+                "Failed to read the plugin config file {:?}, error: {:?}",
+                accountsdb_plugin_config_file, err
+            )));
+        }
+
+        let result: serde_json::Value = match serde_json::from_str(&contents) {
+            Ok(value) => value,
+            Err(err) => {
+                return Err(AccountsdbPluginServiceError::InvalidConfigFileFormat(
+                    format!(
+                        "The config file {:?} is not in a valid Json format, error: {:?}",
+                        accountsdb_plugin_config_file, err
+                    ),
+                ));
+            }
+        };
+
+        let libpath = result["libpath"]
+            .as_str()
+            .ok_or(AccountsdbPluginServiceError::LibPathNotSet)?;
+        let config_file = accountsdb_plugin_config_file
+            .as_os_str()
+            .to_str()
+            .ok_or(AccountsdbPluginServiceError::InvalidPluginPath)?;
+
+        unsafe {
+            let result = plugin_manager.load_plugin(libpath, config_file);
+            if let Err(err) = result {
+                let msg = format!(
+                    "Failed to load the plugin library: {:?}, error: {:?}",
+                    libpath, err
+                );
+                return Err(AccountsdbPluginServiceError::PluginLoadError(msg));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_accounts_update_notifier(&self) -> Option<AccountsUpdateNotifier> {
+        self.accounts_update_notifier.clone()
+    }
+
+    pub fn get_transaction_notifier(&self) -> Option<TransactionNotifierLock> {
+        self.transaction_notifier.clone()
+    }
+
+    pub fn get_slot_status_notifier(&self) -> SlotStatusNotifier {
+        self.slot_status_notifier.clone()
+    }
+
+    /// Tells the plugins that all the accounts restored from the snapshot were notified
+    pub fn notify_end_of_startup(&self) {
+        let mut plugin_manager = self.plugin_manager.write().unwrap();
+        for plugin in plugin_manager.plugins.iter_mut() {
+            if let Err(err) = plugin.notify_end_of_startup() {
+                error!(
+                    "Failed to notify the end of startup, error: {} to plugin {}",
+                    err,
+                    plugin.name()
+                );
+            }
+        }
+    }
+
+    pub fn join(self) -> thread::Result<()> {
+        self.plugin_manager.write().unwrap().unload();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_load_plugin_config_errors() {
+        let mut plugin_manager = AccountsDbPluginManager::new();
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let config_file = temp_dir.path().join("missing.json");
+        assert!(matches!(
+            AccountsDbPluginService::load_plugin(&mut plugin_manager, &config_file),
+            Err(AccountsdbPluginServiceError::CannotOpenConfigFile(_))
+        ));
+
+        let config_file = temp_dir.path().join("invalid.json");
+        File::create(&config_file)
+            .unwrap()
+            .write_all(b"{ libpath")
+            .unwrap();
+        assert!(matches!(
+            AccountsDbPluginService::load_plugin(&mut plugin_manager, &config_file),
+            Err(AccountsdbPluginServiceError::InvalidConfigFileFormat(_))
+        ));
+
+        let config_file = temp_dir.path().join("no-libpath.json");
+        File::create(&config_file)
+            .unwrap()
+            .write_all(br#"{"path": "/tmp/plugin.json"}"#)
+            .unwrap();
+        assert!(matches!(
+            AccountsDbPluginService::load_plugin(&mut plugin_manager, &config_file),
+            Err(AccountsdbPluginServiceError::LibPathNotSet)
+        ));
+
+        let config_file = temp_dir.path().join("missing-lib.json");
+        File::create(&config_file)
+            .unwrap()
+            .write_all(br#"{"libpath": "/nonexistent/libplugin.so"}"#)
+            .unwrap();
+        assert!(matches!(
+            AccountsDbPluginService::load_plugin(&mut plugin_manager, &config_file),
+            Err(AccountsdbPluginServiceError::PluginLoadError(_))
+        ));
+        assert!(plugin_manager.plugins.is_empty());
+    }
+}
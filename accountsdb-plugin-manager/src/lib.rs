@@ -0,0 +1,5 @@
+pub mod accounts_update_notifier;
+pub mod accountsdb_plugin_manager;
+pub mod accountsdb_plugin_service;
+pub mod slot_status_notifier;
+pub mod transaction_notifier;
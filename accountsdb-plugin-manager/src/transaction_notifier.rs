@@ -0,0 +1,142 @@
+/// Module responsible for notifying plugins of transactions and EVM receipts
+use {
+    crate::accountsdb_plugin_manager::AccountsDbPluginManager,
+    log::*,
+    solana_accountsdb_plugin_interface::accountsdb_plugin_interface::{
+        ReplicaEvmReceiptInfo, ReplicaEvmReceiptInfoVersions, ReplicaTransactionInfo,
+        ReplicaTransactionInfoVersions,
+    },
+    solana_measure::measure::Measure,
+    solana_metrics::*,
+    solana_runtime::bank::is_simple_vote_transaction,
+    solana_sdk::{clock::Slot, signature::Signature, transaction::Transaction},
+    solana_transaction_status::TransactionStatusMeta,
+    std::sync::{Arc, RwLock},
+};
+
+pub trait TransactionNotifierInterface {
+    /// Notified when a native transaction is committed in a slot
+    fn notify_transaction(
+        &self,
+        slot: Slot,
+        signature: &Signature,
+        transaction_status_meta: &TransactionStatusMeta,
+        transaction: &Transaction,
+    );
+
+    /// Notified for every receipt of an EVM block recorded at a slot
+    fn notify_evm_receipt(
+        &self,
+        slot: Slot,
+        transaction_hash: &evm_state::H256,
+        receipt: &evm_state::TransactionReceipt,
+    );
+}
+
+pub type TransactionNotifierLock = Arc<RwLock<dyn TransactionNotifierInterface + Sync + Send>>;
+
+/// This implementation of TransactionNotifierInterface passes the transactions
+/// and EVM receipts to plugins which are interested in transaction data
+pub struct TransactionNotifierImpl {
+    plugin_manager: Arc<RwLock<AccountsDbPluginManager>>,
+}
+
+impl TransactionNotifierInterface for TransactionNotifierImpl {
+    fn notify_transaction(
+        &self,
+        slot: Slot,
+        signature: &Signature,
+        transaction_status_meta: &TransactionStatusMeta,
+        transaction: &Transaction,
+    ) {
+        let mut measure = Measure::start("accountsdb-plugin-notify_plugins_of_transaction_info");
+        let transaction_info = ReplicaTransactionInfo {
+            signature,
+            is_vote: is_simple_vote_transaction(transaction),
+            transaction,
+            transaction_status_meta,
+        };
+
+        let mut plugin_manager = self.plugin_manager.write().unwrap();
+        for plugin in plugin_manager.plugins.iter_mut() {
+            if !plugin.transaction_notifications_enabled() {
+                continue;
+            }
+            match plugin.notify_transaction(
+                ReplicaTransactionInfoVersions::V0_0_1(&transaction_info),
+                slot,
+            ) {
+                Err(err) => {
+                    error!(
+                        "Failed to notify transaction, error: ({}) to plugin {}",
+                        err,
+                        plugin.name()
+                    )
+                }
+                Ok(_) => {
+                    trace!(
+                        "Successfully notified transaction to plugin {}",
+                        plugin.name()
+                    );
+                }
+            }
+        }
+        measure.stop();
+        inc_new_counter_debug!(
+            "accountsdb-plugin-notify_plugins_of_transaction_info-us",
+            measure.as_us() as usize,
+            10000,
+            10000
+        );
+    }
+
+    fn notify_evm_receipt(
+        &self,
+        slot: Slot,
+        transaction_hash: &evm_state::H256,
+        receipt: &evm_state::TransactionReceipt,
+    ) {
+        let mut measure = Measure::start("accountsdb-plugin-notify_plugins_of_evm_receipt");
+        let receipt_info = ReplicaEvmReceiptInfo {
+            transaction_hash,
+            receipt,
+        };
+
+        let mut plugin_manager = self.plugin_manager.write().unwrap();
+        for plugin in plugin_manager.plugins.iter_mut() {
+            if !plugin.transaction_notifications_enabled() {
+                continue;
+            }
+            match plugin
+                .notify_evm_receipt(ReplicaEvmReceiptInfoVersions::V0_0_1(&receipt_info), slot)
+            {
+                Err(err) => {
+                    error!(
+                        "Failed to notify evm receipt, error: ({}) to plugin {}",
+                        err,
+                        plugin.name()
+                    )
+                }
+                Ok(_) => {
+                    trace!(
+                        "Successfully notified evm receipt to plugin {}",
+                        plugin.name()
+                    );
+                }
+            }
+        }
+        measure.stop();
+        inc_new_counter_debug!(
+            "accountsdb-plugin-notify_plugins_of_evm_receipt-us",
+            measure.as_us() as usize,
+            10000,
+            10000
+        );
+    }
+}
+
+impl TransactionNotifierImpl {
+    pub fn new(plugin_manager: Arc<RwLock<AccountsDbPluginManager>>) -> Self {
+        Self { plugin_manager }
+    }
+}
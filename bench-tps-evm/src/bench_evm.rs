@@ -14,6 +14,7 @@ use std::{
 
 use crate::bench::Result;
 use crate::bench::SharedTransactions;
+use crate::workload::{setup_workload, Workload, WorkloadContext};
 use solana_measure::measure::Measure;
 use solana_metrics::{self, datapoint_info};
 use solana_sdk::{
@@ -32,6 +33,8 @@ use solana_evm_loader_program::scope::evm::U256;
 use solana_evm_loader_program::scope::*;
 
 pub const BENCH_SEED: &str = "authority";
+/// Delay between phases of multi-transaction workloads, to let the previous phase land.
+const PHASE_DELAY_MS: u64 = 1000;
 
 #[derive(Clone, Debug)]
 pub struct Peer(pub std::sync::Arc<Keypair>, pub evm::SecretKey, pub u64);
@@ -291,56 +294,47 @@ pub fn fund_evm_keys<T: 'static + Client + Send + Sync>(
     info!("evm funded: {}", keys.len(),);
 }
 
-fn generate_system_txs(
+fn generate_workload_txs(
+    workload: &WorkloadContext,
     source: &mut [Peer],
     dest: &mut VecDeque<Peer>,
     reclaim: bool,
     blockhash: &Hash,
-    chain_id: Option<u64>,
-) -> Vec<(Transaction, u64)> {
+) -> Vec<Vec<(Transaction, u64)>> {
     let mut pairs: Vec<_> = if !reclaim {
         source.iter_mut().zip(dest.iter_mut()).collect()
     } else {
         dest.iter_mut().zip(source.iter_mut()).collect()
     };
 
-    pairs
+    let per_pair: Vec<_> = pairs
         .par_iter_mut()
-        .map(|(from, to)| {
-            let tx_address = to.1.to_address();
-
-            let tx_call = evm::UnsignedTransaction {
-                nonce: from.2.into(),
-                gas_price: 0.into(),
-                gas_limit: 300000.into(),
-                action: evm::TransactionAction::Call(tx_address),
-                value: 1.into(),
-                input: vec![],
-            };
-
-            let tx_call = tx_call.sign(&from.1, chain_id);
-
-            from.2 += 1;
-            let ix = solana_evm_loader_program::send_raw_tx(from.0.pubkey(), tx_call, None);
-
-            let message = Message::new(&[ix], Some(&from.0.pubkey()));
+        .map(|(from, to)| workload.make_transactions(from, to, blockhash))
+        .collect();
 
-            (
-                Transaction::new(&[&*from.0], message, *blockhash),
-                timestamp(),
-            )
-        })
-        .collect()
+    // Regroup transactions by phase, so each phase of all pairs is sent together.
+    let mut phases: Vec<Vec<_>> = vec![];
+    for pair_phases in per_pair {
+        for (i, phase) in pair_phases.into_iter().enumerate() {
+            if phases.len() <= i {
+                phases.push(vec![]);
+            }
+            phases[i].extend(phase);
+        }
+    }
+    phases
 }
 
+#[allow(clippy::too_many_arguments)]
 fn generate_txs(
     shared_txs: &SharedTransactions,
+    shared_tx_active_thread_count: &Arc<AtomicIsize>,
     blockhash: &Arc<RwLock<Hash>>,
     source: &mut [Peer],
     dest: &mut VecDeque<Peer>,
     threads: usize,
     reclaim: bool,
-    chain_id: Option<u64>,
+    workload: &WorkloadContext,
 ) {
     let blockhash = *blockhash.read().unwrap();
     let tx_count = source.len();
@@ -350,7 +344,7 @@ fn generate_txs(
     );
     let signing_start = Instant::now();
 
-    let transactions = generate_system_txs(source, dest, reclaim, &blockhash, chain_id);
+    let phases = generate_workload_txs(workload, source, dest, reclaim, &blockhash);
 
     let duration = signing_start.elapsed();
     let ns = duration.as_secs() * 1_000_000_000 + u64::from(duration.subsec_nanos());
@@ -368,17 +362,86 @@ fn generate_txs(
         ("duration", duration_as_us(&duration), i64)
     );
 
-    let sz = transactions.len() / threads;
-    let chunks: Vec<_> = transactions.chunks(sz).collect();
-    {
-        let mut shared_txs_wl = shared_txs.write().unwrap();
-        for chunk in chunks {
-            shared_txs_wl.push_back(chunk.to_vec());
+    let phases_count = phases.len();
+    for (i, transactions) in phases.into_iter().enumerate() {
+        let sz = std::cmp::max(transactions.len() / threads, 1);
+        {
+            let mut shared_txs_wl = shared_txs.write().unwrap();
+            for chunk in transactions.chunks(sz) {
+                shared_txs_wl.push_back(chunk.to_vec());
+            }
+        }
+        // Transactions of the next phase depend on this one, wait until they are processed.
+        if i + 1 < phases_count {
+            while !shared_txs.read().unwrap().is_empty()
+                || shared_tx_active_thread_count.load(Ordering::Relaxed) > 0
+            {
+                sleep(Duration::from_millis(1));
+            }
+            sleep(Duration::from_millis(PHASE_DELAY_MS));
         }
     }
 }
 
-pub fn do_bench_tps<T>(client: Arc<T>, config: Config, gen_keypairs: Vec<Peer>) -> u64
+/// Result of a single workload run.
+#[derive(Clone, Debug)]
+pub struct WorkloadStats {
+    pub workload: Workload,
+    pub tps: f32,
+    pub gas_per_sec: f64,
+    pub max_tx_count: u64,
+}
+
+pub fn do_bench_tps<T>(client: Arc<T>, config: Config, mut gen_keypairs: Vec<Peer>) -> u64
+where
+    T: 'static + Client + Send + Sync,
+{
+    let mut results = vec![];
+    for workload in &config.workloads {
+        info!("Starting {} workload", workload);
+        let (deployer, peers) = gen_keypairs.split_first_mut().unwrap();
+        let mut workload_peers = peers.to_vec();
+        workload_peers.insert(0, deployer.clone());
+        let context = setup_workload(
+            &client,
+            *workload,
+            config.storage_slots,
+            config.chain_id,
+            deployer,
+            &workload_peers,
+        );
+        results.push(do_bench_workload(
+            client.clone(),
+            &config,
+            &mut gen_keypairs,
+            &context,
+        ));
+    }
+
+    info!(" Workload  |   Average TPS |          Gas/s");
+    info!("-----------+---------------+---------------");
+    for stats in &results {
+        info!(
+            "{:10} | {:13.2} | {:14.0}",
+            stats.workload, stats.tps, stats.gas_per_sec
+        );
+        datapoint_info!(
+            "bench-tps-evm-workload",
+            ("workload", stats.workload.as_str(), String),
+            ("tps", stats.tps as f64, f64),
+            ("gas_per_sec", stats.gas_per_sec, f64)
+        );
+    }
+
+    results.last().map(|stats| stats.max_tx_count).unwrap_or(0)
+}
+
+fn do_bench_workload<T>(
+    client: Arc<T>,
+    config: &Config,
+    gen_keypairs: &mut Vec<Peer>,
+    workload: &WorkloadContext,
+) -> WorkloadStats
 where
     T: 'static + Client + Send + Sync,
 {
@@ -390,9 +453,15 @@ where
         tx_count,
         sustained,
         target_slots_per_epoch,
-        chain_id,
         ..
     } = config;
+    let (threads, thread_batch_sleep_ms, duration, tx_count, sustained) = (
+        *threads,
+        *thread_batch_sleep_ms,
+        *duration,
+        *tx_count,
+        *sustained,
+    );
 
     let mut source_keypair_chunks: Vec<Vec<_>> = Vec::new();
     let mut dest_keypair_chunks: Vec<VecDeque<_>> = Vec::new();
@@ -453,7 +522,7 @@ where
         &shared_tx_active_thread_count,
     );
 
-    crate::bench::wait_for_target_slots_per_epoch(target_slots_per_epoch, &client);
+    crate::bench::wait_for_target_slots_per_epoch(*target_slots_per_epoch, &client);
 
     let start = Instant::now();
 
@@ -461,12 +530,12 @@ where
         recent_blockhash,
         &shared_txs,
         shared_tx_active_thread_count,
-        source_keypair_chunks,
-        dest_keypair_chunks,
+        &mut source_keypair_chunks,
+        &mut dest_keypair_chunks,
         threads,
         duration,
         sustained,
-        chain_id,
+        workload,
     );
 
     // Stop the sampling threads so it will collect the stats
@@ -493,27 +562,51 @@ where
     let balance = client.get_balance(&id.pubkey()).unwrap_or(0);
     crate::bench::metrics_submit_lamport_balance(balance);
 
+    let elapsed = start.elapsed();
     crate::bench::compute_and_report_stats(
         &maxes,
         sample_period,
-        &start.elapsed(),
+        &elapsed,
         total_tx_sent_count.load(Ordering::Relaxed),
     );
 
-    let r_maxes = maxes.read().unwrap();
-    r_maxes.first().unwrap().1.txs
+    // Keep evm nonces of used keypairs for the next workload.
+    let used_keypairs = source_keypair_chunks.len() * 2 * tx_count;
+    let remaining: Vec<_> = gen_keypairs.drain(used_keypairs..).collect();
+    gen_keypairs.clear();
+    for (source, dest) in source_keypair_chunks.into_iter().zip(dest_keypair_chunks) {
+        gen_keypairs.extend(source);
+        gen_keypairs.extend(dest);
+    }
+    gen_keypairs.extend(remaining);
+
+    let max_tx_count = maxes
+        .read()
+        .unwrap()
+        .iter()
+        .map(|(_, stats)| stats.txs)
+        .max()
+        .unwrap_or_default();
+    let tps = max_tx_count as f32 / duration_as_s(&elapsed);
+    WorkloadStats {
+        workload: workload.workload,
+        tps,
+        gas_per_sec: tps as f64 * workload.gas_per_tx as f64,
+        max_tx_count,
+    }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn generate_chunked_transfers(
     recent_blockhash: Arc<RwLock<Hash>>,
     shared_txs: &SharedTransactions,
     shared_tx_active_thread_count: Arc<AtomicIsize>,
-    mut source_keypair_chunks: Vec<Vec<Peer>>,
-    mut dest_keypair_chunks: Vec<VecDeque<Peer>>,
+    source_keypair_chunks: &mut [Vec<Peer>],
+    dest_keypair_chunks: &mut [VecDeque<Peer>],
     threads: usize,
     duration: Duration,
     sustained: bool,
-    chain_id: Option<u64>,
+    workload: &WorkloadContext,
 ) {
     // generate and send transactions for the specified duration
     let start = Instant::now();
@@ -523,12 +616,13 @@ fn generate_chunked_transfers(
     while start.elapsed() < duration {
         generate_txs(
             shared_txs,
+            &shared_tx_active_thread_count,
             &recent_blockhash,
             &mut source_keypair_chunks[chunk_index],
             &mut dest_keypair_chunks[chunk_index],
             threads,
             reclaim_lamports_back_to_source_account,
-            chain_id,
+            workload,
         );
 
         // In sustained mode, overlap the transfers with generation. This has higher average
@@ -1,4 +1,5 @@
-use clap::{crate_description, crate_name, App, Arg, ArgMatches};
+use crate::workload::{Workload, DEFAULT_STORAGE_SLOTS};
+use clap::{crate_description, crate_name, values_t_or_exit, App, Arg, ArgMatches};
 use solana_faucet::faucet::FAUCET_PORT;
 use solana_sdk::fee_calculator::FeeRateGovernor;
 use solana_sdk::{
@@ -28,6 +29,8 @@ pub struct Config {
     pub target_slots_per_epoch: u64,
     pub target_node: Option<Pubkey>,
     pub chain_id: Option<u64>,
+    pub workloads: Vec<Workload>,
+    pub storage_slots: usize,
 }
 
 impl Default for Config {
@@ -50,6 +53,8 @@ impl Default for Config {
             target_slots_per_epoch: 0,
             target_node: None,
             chain_id: None,
+            workloads: vec![Workload::Transfer],
+            storage_slots: DEFAULT_STORAGE_SLOTS,
         }
     }
 }
@@ -180,6 +185,27 @@ pub fn build_args<'a, 'b>(version: &'b str) -> App<'a, 'b> {
                     "Wait until epochs are this many slots long.",
                 ),
         )
+        .arg(
+            Arg::with_name("workload")
+                .long("workload")
+                .value_name("WORKLOAD")
+                .takes_value(true)
+                .multiple(true)
+                .possible_values(Workload::NAMES)
+                .help(
+                    "Kind of transactions to send, each workload runs for the whole duration; \
+                     defaults to transfer",
+                ),
+        )
+        .arg(
+            Arg::with_name("storage_slots")
+                .long("storage-slots")
+                .value_name("NUM")
+                .takes_value(true)
+                .help(
+                    "Number of storage slots written by each transaction in storage and big-tx workloads",
+                ),
+        )
 }
 
 /// Parses a clap `ArgMatches` structure into a `Config`
@@ -268,5 +294,13 @@ pub fn extract_args(matches: &ArgMatches) -> Config {
             .expect("can't parse target slots per epoch");
     }
 
+    if matches.is_present("workload") {
+        args.workloads = values_t_or_exit!(matches, "workload", Workload);
+    }
+
+    if let Some(s) = matches.value_of("storage_slots") {
+        args.storage_slots = s.to_string().parse().expect("can't parse storage-slots");
+    }
+
     args
 }
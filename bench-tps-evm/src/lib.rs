@@ -1,3 +1,4 @@
 pub mod bench;
 pub mod bench_evm;
 pub mod cli;
+pub mod workload;
@@ -0,0 +1,483 @@
+//! Contract workloads, that can be used instead of plain value transfers.
+//!
+//! Contracts are small hand-assembled programs, so the benchmark does not depend on a solidity
+//! compiler. Each workload deploys its contract once (from the first keypair), and then every
+//! generated transaction is a call to that contract.
+use log::*;
+use std::{fmt, str::FromStr, sync::Arc, thread::sleep, time::Duration};
+
+use solana_evm_loader_program::scope::evm::{self, FromKey, U256};
+use solana_sdk::{
+    client::Client,
+    hash::Hash,
+    message::Message,
+    signature::{Keypair, Signer},
+    system_instruction,
+    timing::timestamp,
+    transaction::Transaction,
+};
+
+use crate::bench_evm::Peer;
+
+/// Gas limit for value transfers and token calls.
+pub const DEFAULT_GAS_LIMIT: u64 = 300_000;
+/// Gas limit for storage-heavy and big transactions.
+pub const HEAVY_GAS_LIMIT: u64 = 3_000_000;
+/// Number of storage slots written by each call in storage-heavy workloads.
+pub const DEFAULT_STORAGE_SLOTS: usize = 16;
+/// Size of calldata used in big transactions, it should not fit in a single native transaction.
+pub const BIG_TX_INPUT_LEN: usize = 4 * evm::TX_MTU;
+/// Amount of tokens each keypair receive before erc20 workload starts.
+const ERC20_TOKENS_PER_ACCOUNT: u64 = 1_000_000_000;
+/// How many times setup transactions are resent, duplicates are rejected by evm nonce check.
+const SETUP_RESEND_COUNT: usize = 3;
+
+/// Minimal ERC-20 like token.
+/// Constructor mints `U256::MAX` tokens to deployer.
+/// Any call is handled as `transfer(address,uint256)`: selector is not checked,
+/// balance of address is stored in the storage slot equal to address.
+/// Emits `Transfer(from, to, amount)` event, and returns `true`.
+const ERC20_CODE: &str = concat!(
+    // constructor: sstore(caller, not(0)), return runtime code
+    "600019335560558060106000396000f3",
+    // runtime: move amount from sender to recipient balance, store amount for log data
+    "60243533548181106050578190033355600435805482019055600052600435",
+    // push sender as the second topic
+    "33",
+    // Transfer(address,address,uint256) topic
+    "7fddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+    // log3, return true, revert branch
+    "60206000a3600160005260206000f35b600080fd",
+);
+const ERC20_TRANSFER_SELECTOR: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+
+/// Writes `calldata[0..32]` storage slots with value `calldata[32..64]`.
+/// Slots are `caller + i`, so different callers don't contend for the same keys.
+const STORAGE_CODE: &str = concat!(
+    // constructor: return runtime code
+    "601980600b6000396000f3",
+    // runtime: loop from calldata[0..32] down to zero
+    "6000355b801560175760019003602035813301556003565b00",
+);
+
+/// Increments a single storage slot on every call.
+const HOT_SPOT_CODE: &str = concat!(
+    // constructor: return runtime code
+    "600a80600b6000396000f3",
+    // runtime: sstore(0, sload(0) + 1)
+    "60005460010160005500",
+);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Workload {
+    /// Simple value transfers between keypairs.
+    Transfer,
+    /// Token transfers between keypairs.
+    Erc20,
+    /// Calls writing many storage slots.
+    Storage,
+    /// Storage calls with large calldata, sent through `EvmBigTransaction` instructions.
+    BigTx,
+    /// All keypairs call a single counter.
+    HotSpot,
+}
+
+impl Workload {
+    pub const NAMES: &'static [&'static str] =
+        &["transfer", "erc20", "storage", "big-tx", "hot-spot"];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Workload::Transfer => "transfer",
+            Workload::Erc20 => "erc20",
+            Workload::Storage => "storage",
+            Workload::BigTx => "big-tx",
+            Workload::HotSpot => "hot-spot",
+        }
+    }
+
+    fn contract_code(&self) -> Option<Vec<u8>> {
+        let code = match self {
+            Workload::Transfer => return None,
+            Workload::Erc20 => ERC20_CODE,
+            Workload::Storage | Workload::BigTx => STORAGE_CODE,
+            Workload::HotSpot => HOT_SPOT_CODE,
+        };
+        Some(hex::decode(code).expect("Workload contract code is invalid hex"))
+    }
+
+    fn gas_limit(&self) -> u64 {
+        match self {
+            Workload::Transfer | Workload::Erc20 | Workload::HotSpot => DEFAULT_GAS_LIMIT,
+            Workload::Storage | Workload::BigTx => HEAVY_GAS_LIMIT,
+        }
+    }
+}
+
+impl fmt::Display for Workload {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Workload {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "transfer" => Ok(Workload::Transfer),
+            "erc20" => Ok(Workload::Erc20),
+            "storage" => Ok(Workload::Storage),
+            "big-tx" => Ok(Workload::BigTx),
+            "hot-spot" => Ok(Workload::HotSpot),
+            _ => Err(format!("Unknown workload: {}", s)),
+        }
+    }
+}
+
+/// State of a workload, that was deployed on chain.
+#[derive(Clone, Debug)]
+pub struct WorkloadContext {
+    pub workload: Workload,
+    pub contract: Option<evm::Address>,
+    /// Gas used by a single workload transaction, measured by local execution.
+    pub gas_per_tx: u64,
+    pub storage_slots: usize,
+    pub chain_id: Option<u64>,
+    /// Lamports for rent exempt storage account of a big transaction.
+    pub storage_rent: u64,
+}
+
+impl WorkloadContext {
+    fn new(workload: Workload, storage_slots: usize, chain_id: Option<u64>) -> Self {
+        Self {
+            workload,
+            contract: None,
+            gas_per_tx: 0,
+            storage_slots,
+            chain_id,
+            storage_rent: 0,
+        }
+    }
+
+    /// Create evm transaction from `from` to `to`, and increment `from` nonce.
+    fn make_evm_tx(&self, from: &mut Peer, to: &Peer) -> evm::Transaction {
+        let (action, value, input) = match self.workload {
+            Workload::Transfer => (
+                evm::TransactionAction::Call(to.1.to_address()),
+                1.into(),
+                vec![],
+            ),
+            Workload::Erc20 => (
+                self.contract_call(),
+                0.into(),
+                erc20_transfer_input(to.1.to_address(), 1.into()),
+            ),
+            Workload::Storage => (
+                self.contract_call(),
+                0.into(),
+                storage_input(self.storage_slots, from.2),
+            ),
+            Workload::BigTx => {
+                let mut input = storage_input(self.storage_slots, from.2);
+                input.resize(BIG_TX_INPUT_LEN, 0);
+                (self.contract_call(), 0.into(), input)
+            }
+            Workload::HotSpot => (self.contract_call(), 0.into(), vec![]),
+        };
+
+        let tx = evm::UnsignedTransaction {
+            nonce: from.2.into(),
+            gas_price: 0.into(),
+            gas_limit: self.workload.gas_limit().into(),
+            action,
+            value,
+            input,
+        }
+        .sign(&from.1, self.chain_id);
+        from.2 += 1;
+        tx
+    }
+
+    fn contract_call(&self) -> evm::TransactionAction {
+        evm::TransactionAction::Call(self.contract.expect("Workload contract was not deployed"))
+    }
+
+    /// Create native transactions for single transfer from `from` to `to`.
+    /// Result is splitted into phases: transactions of next phase can be sent only after
+    /// the previous phase was processed.
+    pub fn make_transactions(
+        &self,
+        from: &mut Peer,
+        to: &Peer,
+        blockhash: &Hash,
+    ) -> Vec<Vec<(Transaction, u64)>> {
+        let evm_tx = self.make_evm_tx(from, to);
+        let payer = &*from.0;
+        if self.workload != Workload::BigTx {
+            let ix = solana_evm_loader_program::send_raw_tx(payer.pubkey(), evm_tx, None);
+            let message = Message::new(&[ix], Some(&payer.pubkey()));
+            return vec![vec![(
+                Transaction::new(&[payer], message, *blockhash),
+                timestamp(),
+            )]];
+        }
+
+        let storage = Keypair::new();
+        let signers: [&dyn Signer; 2] = [payer, &storage];
+        let tx_bytes = bincode::serialize(&evm_tx).expect("Unable to serialize evm transaction");
+        let new_tx = |instructions: &[_]| {
+            (
+                Transaction::new_signed_with_payer(
+                    instructions,
+                    Some(&payer.pubkey()),
+                    &signers,
+                    *blockhash,
+                ),
+                timestamp(),
+            )
+        };
+
+        let allocate = vec![new_tx(&[
+            system_instruction::create_account(
+                &payer.pubkey(),
+                &storage.pubkey(),
+                self.storage_rent,
+                tx_bytes.len() as u64,
+                &solana_evm_loader_program::ID,
+            ),
+            solana_evm_loader_program::big_tx_allocate(&storage.pubkey(), tx_bytes.len()),
+        ])];
+        let write = tx_bytes
+            .chunks(evm::TX_MTU)
+            .enumerate()
+            .map(|(i, chunk)| {
+                new_tx(&[solana_evm_loader_program::big_tx_write(
+                    &storage.pubkey(),
+                    (i * evm::TX_MTU) as u64,
+                    chunk.to_vec(),
+                )])
+            })
+            .collect();
+        let execute = vec![new_tx(&[solana_evm_loader_program::big_tx_execute(
+            &storage.pubkey(),
+            Some(&payer.pubkey()),
+        )])];
+        vec![allocate, write, execute]
+    }
+
+    /// Upper bound of storage account size, that keeps single big transaction.
+    fn big_tx_storage_len(&self, from: &Peer) -> usize {
+        let mut from = Peer(from.0.clone(), from.1, u64::MAX - 1);
+        let to = from.clone();
+        bincode::serialized_size(&self.make_evm_tx(&mut from, &to))
+            .expect("Unable to serialize evm transaction") as usize
+    }
+
+    /// Execute workload locally, and return gas used by a transaction after the setup.
+    fn measure_gas_per_tx(&self, deployer: &Peer, from: &Peer, to: &Peer) -> u64 {
+        let mut executor = evm::Executor::with_config(
+            evm::EvmBackend::default(),
+            Default::default(),
+            evm::EvmConfig::new(self.chain_id.unwrap_or(evm::TEST_CHAIN_ID)),
+        );
+        let mut deployer = Peer(deployer.0.clone(), deployer.1, 0);
+        let mut from = Peer(from.0.clone(), from.1, 0);
+        executor.deposit(from.1.to_address(), U256::from(u64::MAX));
+
+        let mut local = self.clone();
+        if let Some(code) = self.workload.contract_code() {
+            let tx = deploy_tx(&mut deployer, code, self.chain_id);
+            executor
+                .transaction_execute(tx, |_, _, _, _| None)
+                .expect("Unable to deploy workload contract locally");
+            local.contract =
+                Some(evm::TransactionAction::Create.address(deployer.1.to_address(), U256::zero()));
+        }
+        if self.workload == Workload::Erc20 {
+            let tx = local.token_funding_tx(&mut deployer, &from);
+            executor
+                .transaction_execute(tx, |_, _, _, _| None)
+                .expect("Unable to fund token account locally");
+        }
+
+        let mut used_gas = 0;
+        // First call can initialize storage, so take gas of the second one.
+        for _ in 0..2 {
+            let tx = local.make_evm_tx(&mut from, to);
+            used_gas = executor
+                .transaction_execute(tx, |_, _, _, _| None)
+                .expect("Unable to execute workload transaction locally")
+                .used_gas;
+        }
+        used_gas
+    }
+
+    fn token_funding_tx(&self, deployer: &mut Peer, to: &Peer) -> evm::Transaction {
+        let tx = evm::UnsignedTransaction {
+            nonce: deployer.2.into(),
+            gas_price: 0.into(),
+            gas_limit: DEFAULT_GAS_LIMIT.into(),
+            action: self.contract_call(),
+            value: 0.into(),
+            input: erc20_transfer_input(to.1.to_address(), ERC20_TOKENS_PER_ACCOUNT.into()),
+        }
+        .sign(&deployer.1, self.chain_id);
+        deployer.2 += 1;
+        tx
+    }
+}
+
+fn deploy_tx(deployer: &mut Peer, code: Vec<u8>, chain_id: Option<u64>) -> evm::Transaction {
+    let tx = evm::UnsignedTransaction {
+        nonce: deployer.2.into(),
+        gas_price: 0.into(),
+        gas_limit: HEAVY_GAS_LIMIT.into(),
+        action: evm::TransactionAction::Create,
+        value: 0.into(),
+        input: code,
+    }
+    .sign(&deployer.1, chain_id);
+    deployer.2 += 1;
+    tx
+}
+
+fn erc20_transfer_input(to: evm::Address, amount: U256) -> Vec<u8> {
+    let mut input = ERC20_TRANSFER_SELECTOR.to_vec();
+    input.extend_from_slice(evm::H256::from(to).as_bytes());
+    let mut amount_bytes = [0u8; 32];
+    amount.to_big_endian(&mut amount_bytes);
+    input.extend_from_slice(&amount_bytes);
+    input
+}
+
+fn storage_input(slots: usize, nonce: u64) -> Vec<u8> {
+    let mut input = [0u8; 64];
+    U256::from(slots).to_big_endian(&mut input[..32]);
+    // Value should be non zero, and differ between calls, to make each write effective.
+    U256::from(nonce.saturating_add(1)).to_big_endian(&mut input[32..]);
+    input.to_vec()
+}
+
+/// Send evm transactions of `deployer`, then wait a few slots.
+/// Transactions are resent few times, because duplicates are rejected by evm nonce check.
+fn send_setup_transactions<T: Client>(
+    client: &Arc<T>,
+    deployer: &Peer,
+    evm_txs: &[evm::Transaction],
+) {
+    for _ in 0..SETUP_RESEND_COUNT {
+        let (blockhash, _fee_calculator) = crate::bench::get_recent_blockhash(client.as_ref());
+        for evm_tx in evm_txs {
+            let ix =
+                solana_evm_loader_program::send_raw_tx(deployer.0.pubkey(), evm_tx.clone(), None);
+            let message = Message::new(&[ix], Some(&deployer.0.pubkey()));
+            let tx = Transaction::new(&[&*deployer.0], message, blockhash);
+            if let Err(e) = client.async_send_transaction(tx) {
+                warn!("Unable to send setup transaction: {:?}", e);
+            }
+        }
+        if cfg!(not(test)) {
+            sleep(Duration::from_secs(2));
+        }
+    }
+}
+
+/// Deploy workload contract from `deployer`, and prepare `peers` for the workload.
+pub fn setup_workload<T: Client>(
+    client: &Arc<T>,
+    workload: Workload,
+    storage_slots: usize,
+    chain_id: Option<u64>,
+    deployer: &mut Peer,
+    peers: &[Peer],
+) -> WorkloadContext {
+    let mut context = WorkloadContext::new(workload, storage_slots, chain_id);
+    assert!(peers.len() >= 2, "Workload requires at least two keypairs");
+    let initial_deployer = deployer.clone();
+
+    if let Some(code) = workload.contract_code() {
+        // Deployer could already send transactions before this run
+        match client.get_evm_transaction_count(&deployer.1.to_address()) {
+            Ok(nonce) => deployer.2 = nonce.as_u64(),
+            Err(e) => warn!("Unable to get deployer nonce: {:?}", e),
+        }
+        let nonce = deployer.2;
+        let tx = deploy_tx(deployer, code, chain_id);
+        let contract =
+            evm::TransactionAction::Create.address(deployer.1.to_address(), nonce.into());
+        info!("Deploying {} contract at {:?}", workload, contract);
+        context.contract = Some(contract);
+        send_setup_transactions(client, deployer, &[tx]);
+        match client.get_evm_code(&contract) {
+            Ok(code) if !code.is_empty() => info!("Contract {:?} is deployed", contract),
+            result => warn!("Contract {:?} is not deployed: {:?}", contract, result),
+        }
+    }
+
+    if workload == Workload::BigTx {
+        let storage_len = context.big_tx_storage_len(&peers[0]);
+        context.storage_rent = client
+            .get_minimum_balance_for_rent_exemption(storage_len)
+            .expect("Unable to get rent for big transaction storage");
+    }
+
+    if workload == Workload::Erc20 {
+        info!("Funding {} token accounts", peers.len());
+        let txs: Vec<_> = peers
+            .iter()
+            .map(|peer| context.token_funding_tx(deployer, peer))
+            .collect();
+        send_setup_transactions(client, deployer, &txs);
+    }
+
+    context.gas_per_tx = context.measure_gas_per_tx(&initial_deployer, &peers[0], &peers[1]);
+    info!(
+        "Workload {} is ready, expected gas per transaction: {}",
+        workload, context.gas_per_tx
+    );
+    context
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_peer() -> Peer {
+        Peer(
+            Arc::new(Keypair::new()),
+            evm::SecretKey::new(&mut evm::rand::thread_rng()),
+            0,
+        )
+    }
+
+    #[test]
+    fn test_workload_from_str() {
+        for name in Workload::NAMES {
+            assert_eq!(name.parse::<Workload>().unwrap().as_str(), *name);
+        }
+        assert!("unknown".parse::<Workload>().is_err());
+    }
+
+    #[test]
+    fn test_workload_contracts_execute() {
+        let (deployer, from, to) = (new_peer(), new_peer(), new_peer());
+        for name in Workload::NAMES {
+            let workload = name.parse().unwrap();
+            let context = WorkloadContext::new(workload, DEFAULT_STORAGE_SLOTS, None);
+            let gas = context.measure_gas_per_tx(&deployer, &from, &to);
+            assert!(gas >= 21_000, "{} used {} gas", workload, gas);
+        }
+    }
+
+    #[test]
+    fn test_big_tx_is_chunked() {
+        let mut context = WorkloadContext::new(Workload::BigTx, DEFAULT_STORAGE_SLOTS, None);
+        context.contract = Some(evm::Address::repeat_byte(1));
+        let (mut from, to) = (new_peer(), new_peer());
+        let phases = context.make_transactions(&mut from, &to, &Hash::default());
+        assert_eq!(phases.len(), 3);
+        assert!(phases[1].len() > 1);
+        assert_eq!(from.2, 1);
+    }
+}
@@ -554,6 +554,92 @@ impl fmt::Display for CliNonceAccount {
     }
 }
 
+#[derive(Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliVestAccount {
+    pub balance: u64,
+    pub terminator: String,
+    pub payee: String,
+    pub start_date: String,
+    pub date_oracle: String,
+    pub current_date: Option<String>,
+    pub schedule: String,
+    pub vesting_dates: Vec<CliVestingDate>,
+    pub total_lamports: u64,
+    pub vested_lamports: Option<u64>,
+    pub redeemed_lamports: u64,
+    pub reneged_lamports: u64,
+    pub is_fully_vested: bool,
+    #[serde(skip_serializing)]
+    pub use_lamports_unit: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliVestingDate {
+    pub date: String,
+    pub lamports: u64,
+}
+
+impl QuietDisplay for CliVestAccount {}
+impl VerboseDisplay for CliVestAccount {}
+
+impl fmt::Display for CliVestAccount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "Balance: {}",
+            build_balance_message(self.balance, self.use_lamports_unit, true)
+        )?;
+        writeln!(f, "Terminator: {}", self.terminator)?;
+        writeln!(f, "Payee: {}", self.payee)?;
+        writeln!(f, "Start Date: {}", self.start_date)?;
+        let current_date = self.current_date.as_deref().unwrap_or("unavailable");
+        writeln!(
+            f,
+            "Date Oracle: {} (current date: {})",
+            self.date_oracle, current_date
+        )?;
+        writeln!(
+            f,
+            "Total: {}",
+            build_balance_message(self.total_lamports, self.use_lamports_unit, true)
+        )?;
+        if let Some(vested_lamports) = self.vested_lamports {
+            writeln!(
+                f,
+                "Vested: {}",
+                build_balance_message(vested_lamports, self.use_lamports_unit, true)
+            )?;
+        }
+        writeln!(
+            f,
+            "Redeemed: {}",
+            build_balance_message(self.redeemed_lamports, self.use_lamports_unit, true)
+        )?;
+        if self.reneged_lamports > 0 {
+            writeln!(
+                f,
+                "Reneged: {}",
+                build_balance_message(self.reneged_lamports, self.use_lamports_unit, true)
+            )?;
+        }
+        if self.is_fully_vested {
+            writeln!(f, "Fully vested by the terminator")?;
+        }
+        writeln!(f, "Schedule: {}", self.schedule)?;
+        for vesting_date in &self.vesting_dates {
+            writeln!(
+                f,
+                "  {}: {}",
+                vesting_date.date,
+                build_balance_message(vesting_date.lamports, self.use_lamports_unit, true)
+            )?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct CliStakeVec(Vec<CliKeyedStakeState>);
 
@@ -616,6 +702,29 @@ pub struct CliEpochReward {
     pub post_balance: u64, // lamports
     pub percent_change: f64,
     pub apr: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commission: Option<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CliEpochRewards {
+    pub address: String,
+    pub epoch_rewards: Vec<CliEpochReward>,
+}
+
+impl QuietDisplay for CliEpochRewards {}
+impl VerboseDisplay for CliEpochRewards {}
+
+impl fmt::Display for CliEpochRewards {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Address: {}", self.address)?;
+        if self.epoch_rewards.is_empty() {
+            writeln!(f, "No rewards found")
+        } else {
+            show_epoch_rewards(f, &self.epoch_rewards)
+        }
+    }
 }
 
 fn show_votes_and_credits(
@@ -657,36 +766,35 @@ fn show_votes_and_credits(
     Ok(())
 }
 
-fn show_epoch_rewards(
-    f: &mut fmt::Formatter,
-    epoch_rewards: &Option<Vec<CliEpochReward>>,
-) -> fmt::Result {
-    if let Some(epoch_rewards) = epoch_rewards {
-        if epoch_rewards.is_empty() {
-            return Ok(());
-        }
+fn show_epoch_rewards(f: &mut fmt::Formatter, epoch_rewards: &[CliEpochReward]) -> fmt::Result {
+    if epoch_rewards.is_empty() {
+        return Ok(());
+    }
 
-        writeln!(f, "Epoch Rewards:")?;
+    writeln!(f, "Epoch Rewards:")?;
+    writeln!(
+        f,
+        "  {:<6}  {:<11}  {:<16}  {:<16}  {:>14}  {:>14}  {:>10}",
+        "Epoch", "Reward Slot", "Amount", "New Balance", "Percent Change", "APR", "Commission"
+    )?;
+    for reward in epoch_rewards {
         writeln!(
             f,
-            "  {:<6}  {:<11}  {:<16}  {:<16}  {:>14}  {:>14}",
-            "Epoch", "Reward Slot", "Amount", "New Balance", "Percent Change", "APR"
+            "  {:<6}  {:<11}  ◎{:<16.9}  ◎{:<14.9}  {:>13.2}%  {:>14}  {:>10}",
+            reward.epoch,
+            reward.effective_slot,
+            lamports_to_sol(reward.amount),
+            lamports_to_sol(reward.post_balance),
+            reward.percent_change,
+            reward
+                .apr
+                .map(|apr| format!("{:.2}%", apr))
+                .unwrap_or_default(),
+            reward
+                .commission
+                .map(|commission| format!("{}%", commission))
+                .unwrap_or_else(|| "-".to_string()),
         )?;
-        for reward in epoch_rewards {
-            writeln!(
-                f,
-                "  {:<6}  {:<11}  ◎{:<16.9}  ◎{:<14.9}  {:>13.2}%  {}",
-                reward.epoch,
-                reward.effective_slot,
-                lamports_to_sol(reward.amount),
-                lamports_to_sol(reward.post_balance),
-                reward.percent_change,
-                reward
-                    .apr
-                    .map(|apr| format!("{:>13.2}%", apr))
-                    .unwrap_or_default(),
-            )?;
-        }
     }
     Ok(())
 }
@@ -880,7 +988,9 @@ impl fmt::Display for CliStakeState {
                 }
                 show_authorized(f, self.authorized.as_ref().unwrap())?;
                 show_lockup(f, self.lockup.as_ref())?;
-                show_epoch_rewards(f, &self.epoch_rewards)?
+                if let Some(epoch_rewards) = &self.epoch_rewards {
+                    show_epoch_rewards(f, epoch_rewards)?
+                }
             }
         }
         Ok(())
@@ -1103,7 +1213,9 @@ impl fmt::Display for CliVoteAccount {
             self.recent_timestamp.slot
         )?;
         show_votes_and_credits(f, &self.votes, &self.epoch_voting_history)?;
-        show_epoch_rewards(f, &self.epoch_rewards)?;
+        if let Some(epoch_rewards) = &self.epoch_rewards {
+            show_epoch_rewards(f, epoch_rewards)?;
+        }
         Ok(())
     }
 }
@@ -1,7 +1,8 @@
 use crate::{
     cluster_query::*, feature::*, inflation::*, nonce::*, program::*, spend_utils::*, stake::*,
-    validator_info::*, vote::*,
+    validator_info::*, vest::*, vote::*,
 };
+use chrono::{Date, Utc};
 use clap::{value_t_or_exit, App, AppSettings, Arg, ArgMatches, SubCommand};
 use log::*;
 use num_traits::FromPrimitive;
@@ -52,6 +53,7 @@ use solana_stake_program::{
     stake_state::{Lockup, StakeAuthorize},
 };
 use solana_transaction_status::{EncodedTransaction, UiTransactionEncoding};
+use solana_vest_program::vest_schedule::VestSchedule;
 use solana_vote_program::vote_state::VoteAuthorize;
 use std::{
     collections::HashMap,
@@ -158,6 +160,7 @@ pub enum CliCommand {
         nonce_account: Pubkey,
         nonce_authority: SignerIndex,
         new_authority: Pubkey,
+        new_authority_signer: Option<SignerIndex>,
     },
     CreateNonceAccount {
         nonce_account: SignerIndex,
@@ -245,6 +248,19 @@ pub enum CliCommand {
         nonce_authority: SignerIndex,
         fee_payer: SignerIndex,
     },
+    RedelegateStake {
+        stake_account_pubkey: Pubkey,
+        vote_account_pubkey: Pubkey,
+        stake_authority: SignerIndex,
+        force: bool,
+        sign_only: bool,
+        blockhash_query: BlockhashQuery,
+        nonce_account: Option<Pubkey>,
+        nonce_authority: SignerIndex,
+        redelegated_stake_account: SignerIndex,
+        seed: Option<String>,
+        fee_payer: SignerIndex,
+    },
     ShowStakeHistory {
         use_lamports_unit: bool,
     },
@@ -252,9 +268,13 @@ pub enum CliCommand {
         pubkey: Pubkey,
         use_lamports_unit: bool,
     },
+    ShowStakeRewards {
+        pubkey: Pubkey,
+        num_rewards_epochs: usize,
+    },
     StakeAuthorize {
         stake_account_pubkey: Pubkey,
-        new_authorizations: Vec<(StakeAuthorize, Pubkey, SignerIndex)>,
+        new_authorizations: Vec<(StakeAuthorize, Pubkey, SignerIndex, Option<SignerIndex>)>,
         sign_only: bool,
         blockhash_query: BlockhashQuery,
         nonce_account: Option<Pubkey>,
@@ -291,6 +311,20 @@ pub enum CliCommand {
         force_keybase: bool,
         info_pubkey: Option<Pubkey>,
     },
+    // Vest Commands
+    CreateVestAccount {
+        vest_account: SignerIndex,
+        payee: Pubkey,
+        terminator: Option<Pubkey>,
+        date_oracle: Pubkey,
+        start_date: Date<Utc>,
+        schedule: VestSchedule,
+        lamports: u64,
+    },
+    ShowVestAccount {
+        vest_account_pubkey: Pubkey,
+        use_lamports_unit: bool,
+    },
     // Vote Commands
     CreateVoteAccount {
         vote_account: SignerIndex,
@@ -303,6 +337,7 @@ pub enum CliCommand {
     ShowVoteAccount {
         pubkey: Pubkey,
         use_lamports_unit: bool,
+        with_rewards: Option<usize>,
     },
     WithdrawFromVoteAccount {
         vote_account_pubkey: Pubkey,
@@ -314,6 +349,8 @@ pub enum CliCommand {
         vote_account_pubkey: Pubkey,
         new_authorized_pubkey: Pubkey,
         vote_authorize: VoteAuthorize,
+        authorized: SignerIndex,
+        new_authorized: Option<SignerIndex>,
     },
     VoteUpdateValidator {
         vote_account_pubkey: Pubkey,
@@ -702,6 +739,9 @@ pub fn parse_command(
         ("merge-stake", Some(matches)) => {
             parse_merge_stake(matches, default_signer, wallet_manager)
         }
+        ("redelegate-stake", Some(matches)) => {
+            parse_redelegate_stake(matches, default_signer, wallet_manager)
+        }
         ("stake-authorize", Some(matches)) => {
             parse_stake_authorize(matches, default_signer, wallet_manager)
         }
@@ -709,6 +749,7 @@ pub fn parse_command(
             parse_stake_set_lockup(matches, default_signer, wallet_manager)
         }
         ("stake-account", Some(matches)) => parse_show_stake_account(matches, wallet_manager),
+        ("stake-rewards", Some(matches)) => parse_show_stake_rewards(matches, wallet_manager),
         ("stake-history", Some(matches)) => parse_show_stake_history(matches),
         // Validator Info Commands
         ("validator-info", Some(matches)) => match matches.subcommand() {
@@ -718,6 +759,11 @@ pub fn parse_command(
             ("get", Some(matches)) => parse_get_validator_info_command(matches),
             _ => unreachable!(),
         },
+        // Vest Commands
+        ("create-vest-account", Some(matches)) => {
+            parse_create_vest_account(matches, default_signer, wallet_manager)
+        }
+        ("vest-account", Some(matches)) => parse_show_vest_account(matches, wallet_manager),
         // Vote Commands
         ("create-vote-account", Some(matches)) => {
             parse_create_vote_account(matches, default_signer, wallet_manager)
@@ -1360,12 +1406,14 @@ pub fn process_command(config: &CliConfig) -> ProcessResult {
             nonce_account,
             nonce_authority,
             new_authority,
+            new_authority_signer,
         } => process_authorize_nonce_account(
             &rpc_client,
             config,
             nonce_account,
             *nonce_authority,
             new_authority,
+            *new_authority_signer,
         ),
         // Create nonce account
         CliCommand::CreateNonceAccount {
@@ -1555,6 +1603,33 @@ pub fn process_command(config: &CliConfig) -> ProcessResult {
             *nonce_authority,
             *fee_payer,
         ),
+        CliCommand::RedelegateStake {
+            stake_account_pubkey,
+            vote_account_pubkey,
+            stake_authority,
+            force,
+            sign_only,
+            blockhash_query,
+            nonce_account,
+            nonce_authority,
+            redelegated_stake_account,
+            seed,
+            fee_payer,
+        } => process_redelegate_stake(
+            &rpc_client,
+            config,
+            &stake_account_pubkey,
+            &vote_account_pubkey,
+            *stake_authority,
+            *force,
+            *sign_only,
+            blockhash_query,
+            *nonce_account,
+            *nonce_authority,
+            *redelegated_stake_account,
+            seed,
+            *fee_payer,
+        ),
         CliCommand::ShowStakeAccount {
             pubkey: stake_account_pubkey,
             use_lamports_unit,
@@ -1564,6 +1639,10 @@ pub fn process_command(config: &CliConfig) -> ProcessResult {
             &stake_account_pubkey,
             *use_lamports_unit,
         ),
+        CliCommand::ShowStakeRewards {
+            pubkey,
+            num_rewards_epochs,
+        } => process_show_stake_rewards(&rpc_client, config, pubkey, *num_rewards_epochs),
         CliCommand::ShowStakeHistory { use_lamports_unit } => {
             process_show_stake_history(&rpc_client, config, *use_lamports_unit)
         }
@@ -1654,6 +1733,33 @@ pub fn process_command(config: &CliConfig) -> ProcessResult {
             *info_pubkey,
         ),
 
+        // Vest Commands
+        CliCommand::CreateVestAccount {
+            vest_account,
+            payee,
+            terminator,
+            date_oracle,
+            start_date,
+            schedule,
+            lamports,
+        } => process_create_vest_account(
+            &rpc_client,
+            config,
+            *vest_account,
+            payee,
+            *terminator,
+            date_oracle,
+            *start_date,
+            schedule,
+            *lamports,
+        ),
+        CliCommand::ShowVestAccount {
+            vest_account_pubkey,
+            use_lamports_unit,
+        } => {
+            process_show_vest_account(&rpc_client, config, vest_account_pubkey, *use_lamports_unit)
+        }
+
         // Vote Commands
 
         // Create vote account
@@ -1677,11 +1783,13 @@ pub fn process_command(config: &CliConfig) -> ProcessResult {
         CliCommand::ShowVoteAccount {
             pubkey: vote_account_pubkey,
             use_lamports_unit,
+            with_rewards,
         } => process_show_vote_account(
             &rpc_client,
             config,
             &vote_account_pubkey,
             *use_lamports_unit,
+            *with_rewards,
         ),
         CliCommand::WithdrawFromVoteAccount {
             vote_account_pubkey,
@@ -1700,12 +1808,16 @@ pub fn process_command(config: &CliConfig) -> ProcessResult {
             vote_account_pubkey,
             new_authorized_pubkey,
             vote_authorize,
+            authorized,
+            new_authorized,
         } => process_vote_authorize(
             &rpc_client,
             config,
             &vote_account_pubkey,
             &new_authorized_pubkey,
             *vote_authorize,
+            *authorized,
+            *new_authorized,
         ),
         CliCommand::VoteUpdateValidator {
             vote_account_pubkey,
@@ -2190,6 +2302,7 @@ pub fn app<'ab, 'v>(name: &str, about: &'ab str, version: &'v str) -> App<'ab, '
                 ),
         )
         .validator_info_subcommands()
+        .vest_subcommands()
         .vote_subcommands()
 }
 
@@ -2574,6 +2687,20 @@ mod tests {
             vote_account_pubkey: bob_pubkey,
             new_authorized_pubkey,
             vote_authorize: VoteAuthorize::Voter,
+            authorized: 0,
+            new_authorized: None,
+        };
+        let result = process_command(&config);
+        assert!(result.is_ok());
+
+        let new_authorized_keypair = Keypair::new();
+        config.signers = vec![&bob_keypair, &new_authorized_keypair];
+        config.command = CliCommand::VoteAuthorize {
+            vote_account_pubkey: bob_pubkey,
+            new_authorized_pubkey: new_authorized_keypair.pubkey(),
+            vote_authorize: VoteAuthorize::Withdrawer,
+            authorized: 0,
+            new_authorized: Some(1),
         };
         let result = process_command(&config);
         assert!(result.is_ok());
@@ -2753,6 +2880,8 @@ mod tests {
             vote_account_pubkey: bob_pubkey,
             new_authorized_pubkey: bob_pubkey,
             vote_authorize: VoteAuthorize::Voter,
+            authorized: 0,
+            new_authorized: Some(1),
         };
         assert!(process_command(&config).is_err());
 
@@ -33,4 +33,5 @@ pub mod spend_utils;
 pub mod stake;
 pub mod test_utils;
 pub mod validator_info;
+pub mod vest;
 pub mod vote;
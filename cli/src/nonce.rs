@@ -23,8 +23,9 @@ use solana_sdk::{
     nonce::{self, State},
     pubkey::Pubkey,
     system_instruction::{
-        advance_nonce_account, authorize_nonce_account, create_nonce_account,
-        create_nonce_account_with_seed, withdraw_nonce_account, NonceError, SystemError,
+        advance_nonce_account, authorize_nonce_account, authorize_nonce_account_checked,
+        create_nonce_account, create_nonce_account_with_seed, withdraw_nonce_account, NonceError,
+        SystemError,
     },
     system_program,
     transaction::Transaction,
@@ -48,11 +49,19 @@ impl NonceSubCommands for App<'_, '_> {
                         "Address of the nonce account. "),
                 )
                 .arg(
-                    pubkey!(Arg::with_name("new_authority")
+                    Arg::with_name("new_authority")
                         .index(2)
-                        .value_name("AUTHORITY_PUBKEY")
-                        .required(true),
-                        "Account to be granted authority of the nonce account. "),
+                        .value_name("AUTHORITY_KEYPAIR")
+                        .required(true)
+                        .validator(is_valid_signer)
+                        .help("Account to be granted authority of the nonce account. Must sign the transaction unless --unchecked is given"),
+                )
+                .arg(
+                    Arg::with_name("unchecked")
+                        .long("unchecked")
+                        .takes_value(false)
+                        .help("Use the legacy authorize instruction, which does not require the new authority to sign. \
+                            Required until the cluster activates checked authorize instructions"),
                 )
                 .arg(nonce_authority_arg()),
         )
@@ -170,22 +179,32 @@ pub fn parse_authorize_nonce_account(
     wallet_manager: &mut Option<Arc<RemoteWalletManager>>,
 ) -> Result<CliCommandInfo, CliError> {
     let nonce_account = pubkey_of_signer(matches, "nonce_account_pubkey", wallet_manager)?.unwrap();
-    let new_authority = pubkey_of_signer(matches, "new_authority", wallet_manager)?.unwrap();
     let (nonce_authority, nonce_authority_pubkey) =
         signer_of(matches, NONCE_AUTHORITY_ARG.name, wallet_manager)?;
 
     let payer_provided = None;
-    let signer_info = default_signer.generate_unique_signers(
-        vec![payer_provided, nonce_authority],
-        matches,
-        wallet_manager,
-    )?;
+    let mut bulk_signers = vec![payer_provided, nonce_authority];
+
+    let (new_authority, new_authority_signer_pubkey) = if matches.is_present("unchecked") {
+        let new_authority = pubkey_of_signer(matches, "new_authority", wallet_manager)?.unwrap();
+        (new_authority, None)
+    } else {
+        let (new_authority_signer, new_authority) =
+            signer_of(matches, "new_authority", wallet_manager)?;
+        bulk_signers.push(new_authority_signer);
+        (new_authority.unwrap(), new_authority)
+    };
+
+    let signer_info =
+        default_signer.generate_unique_signers(bulk_signers, matches, wallet_manager)?;
 
     Ok(CliCommandInfo {
         command: CliCommand::AuthorizeNonceAccount {
             nonce_account,
             nonce_authority: signer_info.index_of(nonce_authority_pubkey).unwrap(),
             new_authority,
+            new_authority_signer: new_authority_signer_pubkey
+                .map(|pubkey| signer_info.index_of(Some(pubkey)).unwrap()),
         },
         signers: signer_info.signers,
     })
@@ -331,11 +350,16 @@ pub fn process_authorize_nonce_account(
     nonce_account: &Pubkey,
     nonce_authority: SignerIndex,
     new_authority: &Pubkey,
+    new_authority_signer: Option<SignerIndex>,
 ) -> ProcessResult {
     let (recent_blockhash, fee_calculator) = rpc_client.get_recent_blockhash()?;
 
     let nonce_authority = config.signers[nonce_authority];
-    let ix = authorize_nonce_account(nonce_account, &nonce_authority.pubkey(), new_authority);
+    let ix = if new_authority_signer.is_some() {
+        authorize_nonce_account_checked(nonce_account, &nonce_authority.pubkey(), new_authority)
+    } else {
+        authorize_nonce_account(nonce_account, &nonce_authority.pubkey(), new_authority)
+    };
     let message = Message::new(&[ix], Some(&config.signers[0].pubkey()));
     let mut tx = Transaction::new_unsigned(message);
     tx.try_sign(&config.signers, recent_blockhash)?;
@@ -590,6 +614,7 @@ mod tests {
             "authorize-nonce-account",
             &keypair_file,
             &Pubkey::default().to_string(),
+            "--unchecked",
         ]);
         assert_eq!(
             parse_command(&test_authorize_nonce_account, &default_signer, &mut None).unwrap(),
@@ -598,11 +623,21 @@ mod tests {
                     nonce_account: nonce_account_pubkey,
                     nonce_authority: 0,
                     new_authority: Pubkey::default(),
+                    new_authority_signer: None,
                 },
                 signers: vec![read_keypair_file(&default_keypair_file).unwrap().into()],
             }
         );
 
+        // Test AuthorizeNonceAccount Subcommand requires the new authority to sign
+        let test_authorize_nonce_account = test_commands.clone().get_matches_from(vec![
+            "test",
+            "authorize-nonce-account",
+            &keypair_file,
+            &Pubkey::default().to_string(),
+        ]);
+        assert!(parse_command(&test_authorize_nonce_account, &default_signer, &mut None).is_err());
+
         // Test AuthorizeNonceAccount Subcommand with authority
         let test_authorize_nonce_account = test_commands.clone().get_matches_from(vec![
             "test",
@@ -611,6 +646,7 @@ mod tests {
             &Pubkey::default().to_string(),
             "--nonce-authority",
             &authority_keypair_file,
+            "--unchecked",
         ]);
         assert_eq!(
             parse_command(&test_authorize_nonce_account, &default_signer, &mut None).unwrap(),
@@ -619,6 +655,30 @@ mod tests {
                     nonce_account: read_keypair_file(&keypair_file).unwrap().pubkey(),
                     nonce_authority: 1,
                     new_authority: Pubkey::default(),
+                    new_authority_signer: None,
+                },
+                signers: vec![
+                    read_keypair_file(&default_keypair_file).unwrap().into(),
+                    read_keypair_file(&authority_keypair_file).unwrap().into()
+                ],
+            }
+        );
+
+        // Test AuthorizeNonceAccount Subcommand with a signing new authority
+        let test_authorize_nonce_account = test_commands.clone().get_matches_from(vec![
+            "test",
+            "authorize-nonce-account",
+            &keypair_file,
+            &authority_keypair_file,
+        ]);
+        assert_eq!(
+            parse_command(&test_authorize_nonce_account, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::AuthorizeNonceAccount {
+                    nonce_account: read_keypair_file(&keypair_file).unwrap().pubkey(),
+                    nonce_authority: 0,
+                    new_authority: nonce_authority_keypair.pubkey(),
+                    new_authority_signer: Some(1),
                 },
                 signers: vec![
                     read_keypair_file(&default_keypair_file).unwrap().into(),
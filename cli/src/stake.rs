@@ -7,8 +7,7 @@ use crate::{
     nonce::check_nonce_account,
     spend_utils::{resolve_spend_tx_and_check_account_balances, SpendAmount},
 };
-use chrono::{Local, TimeZone};
-use clap::{App, Arg, ArgGroup, ArgMatches, SubCommand};
+use clap::{value_t_or_exit, App, Arg, ArgGroup, ArgMatches, SubCommand};
 use solana_clap_utils::{
     fee_payer::{fee_payer_arg, FEE_PAYER_ARG},
     input_parsers::*,
@@ -19,26 +18,31 @@ use solana_clap_utils::{
     ArgConstant,
 };
 use solana_cli_output::{
-    return_signers, CliEpochReward, CliStakeHistory, CliStakeHistoryEntry, CliStakeState,
-    CliStakeType,
+    return_signers, CliEpochReward, CliEpochRewards, CliStakeHistory, CliStakeHistoryEntry,
+    CliStakeState, CliStakeType,
 };
 use solana_client::{
     blockhash_query::BlockhashQuery,
     client_error::{ClientError, ClientErrorKind},
     nonce_utils,
     rpc_client::RpcClient,
-    rpc_custom_error,
-    rpc_request::{self, DELINQUENT_VALIDATOR_SLOT_DISTANCE},
+    rpc_custom_error::{
+        JSON_RPC_SERVER_ERROR_BLOCK_CLEANED_UP, JSON_RPC_SERVER_ERROR_BLOCK_NOT_AVAILABLE,
+        JSON_RPC_SERVER_ERROR_LONG_TERM_STORAGE_SLOT_SKIPPED,
+    },
+    rpc_request::{RpcError, DELINQUENT_VALIDATOR_SLOT_DISTANCE},
 };
 use solana_remote_wallet::remote_wallet::RemoteWalletManager;
 use solana_sdk::{
     account::from_account,
     account_utils::StateMut,
-    clock::{Clock, Epoch, Slot, UnixTimestamp, SECONDS_PER_DAY},
+    clock::{Clock, Epoch, Slot, SECONDS_PER_DAY},
+    epoch_schedule::EpochSchedule,
     feature, feature_set,
     message::Message,
     native_token::lamports_to_sol,
     pubkey::Pubkey,
+    signature::Signer,
     system_instruction::SystemError,
     sysvar::{
         clock,
@@ -53,7 +57,9 @@ use solana_stake_program::{
     },
 };
 use solana_vote_program::vote_state::VoteState;
-use std::{convert::TryInto, ops::Deref, sync::Arc};
+use std::{ops::Deref, sync::Arc};
+
+pub const DEFAULT_NUM_REWARDS_EPOCHS: &str = "5";
 
 pub const STAKE_AUTHORITY_ARG: ArgConstant<'static> = ArgConstant {
     name: "stake_authority",
@@ -223,18 +229,29 @@ impl StakeSubCommands for App<'_, '_> {
                         "Stake account in which to set a new authority. ")
                 )
                 .arg(
-                    pubkey!(Arg::with_name("new_stake_authority")
+                    Arg::with_name("new_stake_authority")
                         .long("new-stake-authority")
                         .required_unless("new_withdraw_authority")
-                        .value_name("PUBKEY"),
-                        "New authorized staker")
+                        .value_name("KEYPAIR")
+                        .takes_value(true)
+                        .validator(is_valid_signer)
+                        .help("New authorized staker. Must sign the transaction unless --unchecked is given")
                 )
                 .arg(
-                    pubkey!(Arg::with_name("new_withdraw_authority")
+                    Arg::with_name("new_withdraw_authority")
                         .long("new-withdraw-authority")
                         .required_unless("new_stake_authority")
-                        .value_name("PUBKEY"),
-                        "New authorized withdrawer. ")
+                        .value_name("KEYPAIR")
+                        .takes_value(true)
+                        .validator(is_valid_signer)
+                        .help("New authorized withdrawer. Must sign the transaction unless --unchecked is given")
+                )
+                .arg(
+                    Arg::with_name("unchecked")
+                        .long("unchecked")
+                        .takes_value(false)
+                        .help("Use the legacy authorize instruction, which does not require the new authorities to sign. \
+                            Required until the cluster activates checked authorize instructions")
                 )
                 .arg(stake_authority_arg())
                 .arg(withdraw_authority_arg())
@@ -320,6 +337,51 @@ impl StakeSubCommands for App<'_, '_> {
                 .nonce_args(false)
                 .arg(fee_payer_arg())
         )
+        .subcommand(
+            SubCommand::with_name("redelegate-stake")
+                .about("Move the active stake of a stake account to another vote account without waiting for a full cooldown")
+                .arg(
+                    Arg::with_name("force")
+                        .long("force")
+                        .takes_value(false)
+                        .hidden(true) // Don't document this argument to discourage its use
+                        .help("Override vote account sanity checks (use carefully!)")
+                )
+                .arg(
+                    pubkey!(Arg::with_name("stake_account_pubkey")
+                        .index(1)
+                        .value_name("STAKE_ACCOUNT_ADDRESS")
+                        .required(true),
+                        "Fully active stake account to redelegate")
+                )
+                .arg(
+                    pubkey!(Arg::with_name("vote_account_pubkey")
+                        .index(2)
+                        .value_name("VOTE_ACCOUNT_ADDRESS")
+                        .required(true),
+                        "The vote account to which the stake will be redelegated")
+                )
+                .arg(
+                    Arg::with_name("redelegated_stake_account")
+                        .index(3)
+                        .value_name("REDELEGATED_STAKE_ACCOUNT")
+                        .takes_value(true)
+                        .required(true)
+                        .validator(is_valid_signer)
+                        .help("Keypair of the new stake account that will hold the redelegated stake")
+                )
+                .arg(
+                    Arg::with_name("seed")
+                        .long("seed")
+                        .value_name("STRING")
+                        .takes_value(true)
+                        .help("Seed for address generation; if specified, the resulting account will be at a derived address of the REDELEGATED_STAKE_ACCOUNT pubkey")
+                )
+                .arg(stake_authority_arg())
+                .offline_args()
+                .nonce_args(false)
+                .arg(fee_payer_arg())
+        )
         .subcommand(
             SubCommand::with_name("withdraw-stake")
                 .about("Withdraw the unstaked VLX from the stake account")
@@ -417,6 +479,18 @@ impl StakeSubCommands for App<'_, '_> {
                         .help("Display balance in lamports instead of VLX")
                 ),
         )
+        .subcommand(
+            SubCommand::with_name("stake-rewards")
+                .about("Show inflation rewards of a stake or vote account")
+                .arg(
+                    pubkey!(Arg::with_name("account_pubkey")
+                        .index(1)
+                        .value_name("ACCOUNT_ADDRESS")
+                        .required(true),
+                        "The stake or vote account to display rewards for. ")
+                )
+                .arg(num_rewards_epochs_arg()),
+        )
         .subcommand(
             SubCommand::with_name("stake-history")
                 .about("Show the stake history")
@@ -525,6 +599,22 @@ pub fn parse_stake_delegate_stake(
     })
 }
 
+/// Resolves a new authority argument. Checked authorizations require the new authority to
+/// sign, so its signer is returned alongside the pubkey.
+fn new_authority_of(
+    matches: &ArgMatches<'_>,
+    name: &str,
+    checked: bool,
+    wallet_manager: &mut Option<Arc<RemoteWalletManager>>,
+) -> Result<Option<(Pubkey, Option<Box<dyn Signer>>)>, CliError> {
+    if checked {
+        let (new_authority, new_authority_pubkey) = signer_of(matches, name, wallet_manager)?;
+        Ok(new_authority_pubkey.map(|pubkey| (pubkey, new_authority)))
+    } else {
+        Ok(pubkey_of_signer(matches, name, wallet_manager)?.map(|pubkey| (pubkey, None)))
+    }
+}
+
 pub fn parse_stake_authorize(
     matches: &ArgMatches<'_>,
     default_signer: &DefaultSigner,
@@ -533,10 +623,11 @@ pub fn parse_stake_authorize(
     let stake_account_pubkey =
         pubkey_of_signer(matches, "stake_account_pubkey", wallet_manager)?.unwrap();
 
+    let checked = !matches.is_present("unchecked");
     let mut new_authorizations = Vec::new();
     let mut bulk_signers = Vec::new();
-    if let Some(new_authority_pubkey) =
-        pubkey_of_signer(matches, "new_stake_authority", wallet_manager)?
+    if let Some((new_authority_pubkey, new_authority)) =
+        new_authority_of(matches, "new_stake_authority", checked, wallet_manager)?
     {
         let (authority, authority_pubkey) = {
             let (authority, authority_pubkey) =
@@ -552,11 +643,13 @@ pub fn parse_stake_authorize(
             StakeAuthorize::Staker,
             new_authority_pubkey,
             authority_pubkey,
+            new_authority.as_ref().map(|_| new_authority_pubkey),
         ));
         bulk_signers.push(authority);
+        bulk_signers.push(new_authority);
     };
-    if let Some(new_authority_pubkey) =
-        pubkey_of_signer(matches, "new_withdraw_authority", wallet_manager)?
+    if let Some((new_authority_pubkey, new_authority)) =
+        new_authority_of(matches, "new_withdraw_authority", checked, wallet_manager)?
     {
         let (authority, authority_pubkey) =
             signer_of(matches, WITHDRAW_AUTHORITY_ARG.name, wallet_manager)?;
@@ -564,8 +657,10 @@ pub fn parse_stake_authorize(
             StakeAuthorize::Withdrawer,
             new_authority_pubkey,
             authority_pubkey,
+            new_authority.as_ref().map(|_| new_authority_pubkey),
         ));
         bulk_signers.push(authority);
+        bulk_signers.push(new_authority);
     };
     let sign_only = matches.is_present(SIGN_ONLY_ARG.name);
     let blockhash_query = BlockhashQuery::new_from_matches(matches);
@@ -588,11 +683,12 @@ pub fn parse_stake_authorize(
     let new_authorizations = new_authorizations
         .into_iter()
         .map(
-            |(stake_authorize, new_authority_pubkey, authority_pubkey)| {
+            |(stake_authorize, new_authority_pubkey, authority_pubkey, new_authority_signer)| {
                 (
                     stake_authorize,
                     new_authority_pubkey,
                     signer_info.index_of(authority_pubkey).unwrap(),
+                    new_authority_signer.map(|pubkey| signer_info.index_of(Some(pubkey)).unwrap()),
                 )
             },
         )
@@ -699,6 +795,56 @@ pub fn parse_merge_stake(
     })
 }
 
+pub fn parse_redelegate_stake(
+    matches: &ArgMatches<'_>,
+    default_signer: &DefaultSigner,
+    wallet_manager: &mut Option<Arc<RemoteWalletManager>>,
+) -> Result<CliCommandInfo, CliError> {
+    let stake_account_pubkey =
+        pubkey_of_signer(matches, "stake_account_pubkey", wallet_manager)?.unwrap();
+    let vote_account_pubkey =
+        pubkey_of_signer(matches, "vote_account_pubkey", wallet_manager)?.unwrap();
+    let (redelegated_stake_account, redelegated_stake_account_pubkey) =
+        signer_of(matches, "redelegated_stake_account", wallet_manager)?;
+    let seed = matches.value_of("seed").map(|s| s.to_string());
+    let force = matches.is_present("force");
+
+    let sign_only = matches.is_present(SIGN_ONLY_ARG.name);
+    let blockhash_query = BlockhashQuery::new_from_matches(matches);
+    let nonce_account = pubkey_of(matches, NONCE_ARG.name);
+    let (stake_authority, stake_authority_pubkey) =
+        signer_of(matches, STAKE_AUTHORITY_ARG.name, wallet_manager)?;
+    let (nonce_authority, nonce_authority_pubkey) =
+        signer_of(matches, NONCE_AUTHORITY_ARG.name, wallet_manager)?;
+    let (fee_payer, fee_payer_pubkey) = signer_of(matches, FEE_PAYER_ARG.name, wallet_manager)?;
+
+    let mut bulk_signers = vec![stake_authority, fee_payer, redelegated_stake_account];
+    if nonce_account.is_some() {
+        bulk_signers.push(nonce_authority);
+    }
+    let signer_info =
+        default_signer.generate_unique_signers(bulk_signers, matches, wallet_manager)?;
+
+    Ok(CliCommandInfo {
+        command: CliCommand::RedelegateStake {
+            stake_account_pubkey,
+            vote_account_pubkey,
+            stake_authority: signer_info.index_of(stake_authority_pubkey).unwrap(),
+            force,
+            sign_only,
+            blockhash_query,
+            nonce_account,
+            nonce_authority: signer_info.index_of(nonce_authority_pubkey).unwrap(),
+            redelegated_stake_account: signer_info
+                .index_of(redelegated_stake_account_pubkey)
+                .unwrap(),
+            seed,
+            fee_payer: signer_info.index_of(fee_payer_pubkey).unwrap(),
+        },
+        signers: signer_info.signers,
+    })
+}
+
 pub fn parse_stake_deactivate_stake(
     matches: &ArgMatches<'_>,
     default_signer: &DefaultSigner,
@@ -845,6 +991,21 @@ pub fn parse_show_stake_account(
     })
 }
 
+pub fn parse_show_stake_rewards(
+    matches: &ArgMatches<'_>,
+    wallet_manager: &mut Option<Arc<RemoteWalletManager>>,
+) -> Result<CliCommandInfo, CliError> {
+    let pubkey = pubkey_of_signer(matches, "account_pubkey", wallet_manager)?.unwrap();
+    let num_rewards_epochs = value_t_or_exit!(matches, "num_rewards_epochs", usize);
+    Ok(CliCommandInfo {
+        command: CliCommand::ShowStakeRewards {
+            pubkey,
+            num_rewards_epochs,
+        },
+        signers: vec![],
+    })
+}
+
 pub fn parse_show_stake_history(matches: &ArgMatches<'_>) -> Result<CliCommandInfo, CliError> {
     let use_lamports_unit = matches.is_present("lamports");
     Ok(CliCommandInfo {
@@ -998,7 +1159,7 @@ pub fn process_stake_authorize(
     rpc_client: &RpcClient,
     config: &CliConfig,
     stake_account_pubkey: &Pubkey,
-    new_authorizations: &[(StakeAuthorize, Pubkey, SignerIndex)],
+    new_authorizations: &[(StakeAuthorize, Pubkey, SignerIndex, Option<SignerIndex>)],
     custodian: Option<SignerIndex>,
     sign_only: bool,
     blockhash_query: &BlockhashQuery,
@@ -1008,19 +1169,31 @@ pub fn process_stake_authorize(
 ) -> ProcessResult {
     let mut ixs = Vec::new();
     let custodian = custodian.map(|index| config.signers[index]);
-    for (stake_authorize, authorized_pubkey, authority) in new_authorizations.iter() {
+    for (stake_authorize, authorized_pubkey, authority, new_authority_signer) in
+        new_authorizations.iter()
+    {
         check_unique_pubkeys(
             (stake_account_pubkey, "stake_account_pubkey".to_string()),
             (authorized_pubkey, "new_authorized_pubkey".to_string()),
         )?;
         let authority = config.signers[*authority];
-        ixs.push(stake_instruction::authorize(
-            stake_account_pubkey, // stake account to update
-            &authority.pubkey(),  // currently authorized
-            authorized_pubkey,    // new stake signer
-            *stake_authorize,     // stake or withdraw
-            custodian.map(|signer| signer.pubkey()).as_ref(),
-        ));
+        if new_authority_signer.is_some() {
+            ixs.push(stake_instruction::authorize_checked(
+                stake_account_pubkey, // stake account to update
+                &authority.pubkey(),  // currently authorized
+                authorized_pubkey,    // new stake signer
+                *stake_authorize,     // stake or withdraw
+                custodian.map(|signer| signer.pubkey()).as_ref(),
+            ));
+        } else {
+            ixs.push(stake_instruction::authorize(
+                stake_account_pubkey, // stake account to update
+                &authority.pubkey(),  // currently authorized
+                authorized_pubkey,    // new stake signer
+                *stake_authorize,     // stake or withdraw
+                custodian.map(|signer| signer.pubkey()).as_ref(),
+            ));
+        }
     }
 
     let (recent_blockhash, fee_calculator) =
@@ -1611,109 +1784,117 @@ pub fn build_stake_state(
     }
 }
 
+pub(crate) fn num_rewards_epochs_arg<'a, 'b>() -> Arg<'a, 'b> {
+    Arg::with_name("num_rewards_epochs")
+        .long("num-rewards-epochs")
+        .takes_value(true)
+        .value_name("NUM")
+        .validator(is_parsable::<usize>)
+        .default_value(DEFAULT_NUM_REWARDS_EPOCHS)
+        .help("Display rewards for this number of recent epochs")
+}
+
+/// Wallclock duration of `epoch` in seconds, `effective_slot` is the first block of the next epoch
+fn epoch_duration(
+    rpc_client: &RpcClient,
+    epoch_schedule: &EpochSchedule,
+    epoch: Epoch,
+    effective_slot: Slot,
+) -> Option<f64> {
+    let first_slot_in_epoch = epoch_schedule.get_first_slot_in_epoch(epoch);
+    let first_confirmed_block_in_epoch = *rpc_client
+        .get_confirmed_blocks_with_limit(first_slot_in_epoch, 1)
+        .ok()?
+        .get(0)?;
+    let epoch_start_time = rpc_client
+        .get_block_time(first_confirmed_block_in_epoch)
+        .ok()?;
+    let epoch_end_time = rpc_client.get_block_time(effective_slot).ok()?;
+    if epoch_end_time > epoch_start_time {
+        Some((epoch_end_time - epoch_start_time) as f64)
+    } else {
+        None
+    }
+}
+
+/// Whether the RPC node failed because it has no block history for the requested epoch
+fn is_epoch_rewards_unavailable(err: &ClientError) -> bool {
+    matches!(
+        err.kind(),
+        ClientErrorKind::RpcError(RpcError::RpcResponseError { code, .. })
+            if *code == JSON_RPC_SERVER_ERROR_BLOCK_CLEANED_UP
+                || *code == JSON_RPC_SERVER_ERROR_BLOCK_NOT_AVAILABLE
+                || *code == JSON_RPC_SERVER_ERROR_LONG_TERM_STORAGE_SLOT_SKIPPED
+    )
+}
+
+/// Fetch inflation rewards of `address` for epochs after `lowest_epoch`, newest first
 pub(crate) fn fetch_epoch_rewards(
     rpc_client: &RpcClient,
     address: &Pubkey,
     lowest_epoch: Epoch,
+    num_epochs: Option<usize>,
 ) -> Result<Vec<CliEpochReward>, Box<dyn std::error::Error>> {
     let mut all_epoch_rewards = vec![];
 
     let epoch_schedule = rpc_client.get_epoch_schedule()?;
-    let slot = rpc_client.get_slot()?;
-    let first_available_block = rpc_client.get_first_available_block()?;
-
-    let mut epoch = epoch_schedule.get_epoch_and_slot_index(slot).0;
-    let mut epoch_info: Option<(Slot, UnixTimestamp, solana_transaction_status::Rewards)> = None;
-    while epoch > lowest_epoch {
-        let first_slot_in_epoch = epoch_schedule.get_first_slot_in_epoch(epoch);
-        if first_slot_in_epoch < first_available_block {
-            // RPC node is out of history data
-            break;
-        }
+    let current_epoch = rpc_client.get_epoch_info()?.epoch;
 
-        let first_confirmed_block_in_epoch = *rpc_client
-            .get_confirmed_blocks_with_limit(first_slot_in_epoch, 1)?
-            .get(0)
-            .ok_or_else(|| format!("Unable to fetch first confirmed block for epoch {}", epoch))?;
-
-        let first_confirmed_block = match rpc_client.get_confirmed_block_with_encoding(
-            first_confirmed_block_in_epoch,
-            solana_transaction_status::UiTransactionEncoding::Base64,
-        ) {
-            Ok(first_confirmed_block) => first_confirmed_block,
-            Err(ClientError {
-                kind:
-                    ClientErrorKind::RpcError(rpc_request::RpcError::RpcResponseError {
-                        code: rpc_custom_error::JSON_RPC_SERVER_ERROR_BLOCK_NOT_AVAILABLE,
-                        ..
-                    }),
-                ..
-            }) => {
-                // RPC node doesn't have this block
+    // Rewards for the current epoch are not distributed yet
+    for epoch in (lowest_epoch + 1..current_epoch)
+        .rev()
+        .take(num_epochs.unwrap_or(usize::MAX))
+    {
+        let reward = match rpc_client.get_inflation_reward(&[*address], Some(epoch)) {
+            Ok(mut rewards) => rewards.pop().flatten(),
+            Err(err) if is_epoch_rewards_unavailable(&err) => {
+                // RPC node is out of history data
                 break;
             }
-            Err(err) => {
-                return Err(err.into());
-            }
-        };
-
-        let epoch_start_time = if let Some(block_time) = first_confirmed_block.block_time {
-            block_time
-        } else {
-            break;
+            Err(err) => return Err(err.into()),
         };
 
-        // Rewards for the previous epoch are found in the first confirmed block of the current epoch
-        let previous_epoch_rewards = first_confirmed_block.rewards;
-
-        if let Some((effective_slot, epoch_end_time, epoch_rewards)) = epoch_info {
-            let wallclock_epoch_duration = if epoch_end_time > epoch_start_time {
-                Some(
-                    { Local.timestamp(epoch_end_time, 0) - Local.timestamp(epoch_start_time, 0) }
-                        .to_std()?
-                        .as_secs_f64(),
-                )
-            } else {
-                None
-            };
-
-            if let Some(reward) = epoch_rewards
-                .into_iter()
-                .find(|reward| reward.pubkey == address.to_string())
-            {
-                if reward.post_balance > reward.lamports.try_into().unwrap_or(0) {
-                    let rate_change = reward.lamports.abs() as f64
-                        / (reward.post_balance as f64 - reward.lamports as f64);
+        if let Some(reward) = reward {
+            if reward.post_balance > reward.amount {
+                let rate_change =
+                    reward.amount as f64 / (reward.post_balance - reward.amount) as f64;
 
-                    let apr = wallclock_epoch_duration.map(|wallclock_epoch_duration| {
+                let apr = epoch_duration(rpc_client, &epoch_schedule, epoch, reward.effective_slot)
+                    .map(|wallclock_epoch_duration| {
                         let wallclock_epochs_per_year =
-                            (SECONDS_PER_DAY * 356) as f64 / wallclock_epoch_duration;
+                            (SECONDS_PER_DAY * 365) as f64 / wallclock_epoch_duration;
                         rate_change * wallclock_epochs_per_year
                     });
 
-                    all_epoch_rewards.push(CliEpochReward {
-                        epoch,
-                        effective_slot,
-                        amount: reward.lamports.abs() as u64,
-                        post_balance: reward.post_balance,
-                        percent_change: rate_change * 100.0,
-                        apr: apr.map(|r| r * 100.0),
-                    });
-                }
+                all_epoch_rewards.push(CliEpochReward {
+                    epoch,
+                    effective_slot: reward.effective_slot,
+                    amount: reward.amount,
+                    post_balance: reward.post_balance,
+                    percent_change: rate_change * 100.0,
+                    apr: apr.map(|r| r * 100.0),
+                    commission: reward.commission,
+                });
             }
         }
-
-        epoch -= 1;
-        epoch_info = Some((
-            first_confirmed_block_in_epoch,
-            epoch_start_time,
-            previous_epoch_rewards,
-        ));
     }
 
     Ok(all_epoch_rewards)
 }
 
+pub fn process_show_stake_rewards(
+    rpc_client: &RpcClient,
+    config: &CliConfig,
+    address: &Pubkey,
+    num_rewards_epochs: usize,
+) -> ProcessResult {
+    let epoch_rewards = fetch_epoch_rewards(rpc_client, address, 0, Some(num_rewards_epochs))?;
+    Ok(config.output_format.formatted_string(&CliEpochRewards {
+        address: address.to_string(),
+        epoch_rewards,
+    }))
+}
+
 pub fn process_show_stake_account(
     rpc_client: &RpcClient,
     config: &CliConfig,
@@ -1750,8 +1931,12 @@ pub fn process_show_stake_account(
 
             if state.stake_type == CliStakeType::Stake {
                 if let Some(activation_epoch) = state.activation_epoch {
-                    let rewards =
-                        fetch_epoch_rewards(rpc_client, stake_account_address, activation_epoch);
+                    let rewards = fetch_epoch_rewards(
+                        rpc_client,
+                        stake_account_address,
+                        activation_epoch,
+                        None,
+                    );
                     match rewards {
                         Ok(rewards) => state.epoch_rewards = Some(rewards),
                         Err(error) => eprintln!("Failed to fetch epoch rewards: {:?}", error),
@@ -1790,6 +1975,59 @@ pub fn process_show_stake_history(
 }
 
 #[allow(clippy::too_many_arguments)]
+/// Sanity check the vote account to ensure it is attached to a validator that has recently
+/// voted at the tip of the ledger
+fn check_vote_account_for_delegation(
+    rpc_client: &RpcClient,
+    vote_account_pubkey: &Pubkey,
+    force: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let vote_account_data = rpc_client
+        .get_account(vote_account_pubkey)
+        .map_err(|err| {
+            CliError::RpcRequestError(format!(
+                "Vote account not found: {}. error: {}",
+                vote_account_pubkey, err,
+            ))
+        })?
+        .data;
+
+    let vote_state = VoteState::deserialize(&vote_account_data).map_err(|_| {
+        CliError::RpcRequestError(
+            "Account data could not be deserialized to vote state".to_string(),
+        )
+    })?;
+
+    let sanity_check_result = match vote_state.root_slot {
+        None => Err(CliError::BadParameter(
+            "Unable to delegate. Vote account has no root slot".to_string(),
+        )),
+        Some(root_slot) => {
+            let min_root_slot = rpc_client
+                .get_slot()?
+                .saturating_sub(DELINQUENT_VALIDATOR_SLOT_DISTANCE);
+            if root_slot < min_root_slot {
+                Err(CliError::DynamicProgramError(format!(
+                    "Unable to delegate.  Vote account appears delinquent \
+                             because its current root slot, {}, is less than {}",
+                    root_slot, min_root_slot
+                )))
+            } else {
+                Ok(())
+            }
+        }
+    };
+
+    if let Err(err) = &sanity_check_result {
+        if !force {
+            sanity_check_result?;
+        } else {
+            println!("--force supplied, ignoring: {}", err);
+        }
+    }
+    Ok(())
+}
+
 pub fn process_delegate_stake(
     rpc_client: &RpcClient,
     config: &CliConfig,
@@ -1810,51 +2048,7 @@ pub fn process_delegate_stake(
     let stake_authority = config.signers[stake_authority];
 
     if !sign_only {
-        // Sanity check the vote account to ensure it is attached to a validator that has recently
-        // voted at the tip of the ledger
-        let vote_account_data = rpc_client
-            .get_account(vote_account_pubkey)
-            .map_err(|err| {
-                CliError::RpcRequestError(format!(
-                    "Vote account not found: {}. error: {}",
-                    vote_account_pubkey, err,
-                ))
-            })?
-            .data;
-
-        let vote_state = VoteState::deserialize(&vote_account_data).map_err(|_| {
-            CliError::RpcRequestError(
-                "Account data could not be deserialized to vote state".to_string(),
-            )
-        })?;
-
-        let sanity_check_result = match vote_state.root_slot {
-            None => Err(CliError::BadParameter(
-                "Unable to delegate. Vote account has no root slot".to_string(),
-            )),
-            Some(root_slot) => {
-                let min_root_slot = rpc_client
-                    .get_slot()?
-                    .saturating_sub(DELINQUENT_VALIDATOR_SLOT_DISTANCE);
-                if root_slot < min_root_slot {
-                    Err(CliError::DynamicProgramError(format!(
-                        "Unable to delegate.  Vote account appears delinquent \
-                                 because its current root slot, {}, is less than {}",
-                        root_slot, min_root_slot
-                    )))
-                } else {
-                    Ok(())
-                }
-            }
-        };
-
-        if let Err(err) = &sanity_check_result {
-            if !force {
-                sanity_check_result?;
-            } else {
-                println!("--force supplied, ignoring: {}", err);
-            }
-        }
+        check_vote_account_for_delegation(rpc_client, vote_account_pubkey, force)?;
     }
 
     let (recent_blockhash, fee_calculator) =
@@ -1905,6 +2099,137 @@ pub fn process_delegate_stake(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn process_redelegate_stake(
+    rpc_client: &RpcClient,
+    config: &CliConfig,
+    stake_account_pubkey: &Pubkey,
+    vote_account_pubkey: &Pubkey,
+    stake_authority: SignerIndex,
+    force: bool,
+    sign_only: bool,
+    blockhash_query: &BlockhashQuery,
+    nonce_account: Option<Pubkey>,
+    nonce_authority: SignerIndex,
+    redelegated_stake_account: SignerIndex,
+    redelegated_stake_account_seed: &Option<String>,
+    fee_payer: SignerIndex,
+) -> ProcessResult {
+    let redelegated_stake_account = config.signers[redelegated_stake_account];
+    let fee_payer = config.signers[fee_payer];
+
+    if redelegated_stake_account_seed.is_none() {
+        check_unique_pubkeys(
+            (&fee_payer.pubkey(), "fee-payer keypair".to_string()),
+            (
+                &redelegated_stake_account.pubkey(),
+                "redelegated_stake_account".to_string(),
+            ),
+        )?;
+    }
+    check_unique_pubkeys(
+        (&fee_payer.pubkey(), "fee-payer keypair".to_string()),
+        (&stake_account_pubkey, "stake_account".to_string()),
+    )?;
+    check_unique_pubkeys(
+        (&stake_account_pubkey, "stake_account".to_string()),
+        (
+            &redelegated_stake_account.pubkey(),
+            "redelegated_stake_account".to_string(),
+        ),
+    )?;
+
+    let stake_authority = config.signers[stake_authority];
+
+    let redelegated_stake_account_address = if let Some(seed) = redelegated_stake_account_seed {
+        Pubkey::create_with_seed(
+            &redelegated_stake_account.pubkey(),
+            &seed,
+            &solana_stake_program::id(),
+        )?
+    } else {
+        redelegated_stake_account.pubkey()
+    };
+
+    if !sign_only {
+        if let Ok(stake_account) = rpc_client.get_account(&redelegated_stake_account_address) {
+            let err_msg = if stake_account.owner == solana_stake_program::id() {
+                format!(
+                    "Stake account {} already exists",
+                    redelegated_stake_account_address
+                )
+            } else {
+                format!(
+                    "Account {} already exists and is not a stake account",
+                    redelegated_stake_account_address
+                )
+            };
+            return Err(CliError::BadParameter(err_msg).into());
+        }
+
+        check_vote_account_for_delegation(rpc_client, vote_account_pubkey, force)?;
+    }
+
+    let (recent_blockhash, fee_calculator) =
+        blockhash_query.get_blockhash_and_fee_calculator(rpc_client, config.commitment)?;
+
+    let ixs = if let Some(seed) = redelegated_stake_account_seed {
+        stake_instruction::redelegate_with_seed(
+            &stake_account_pubkey,
+            &stake_authority.pubkey(),
+            &vote_account_pubkey,
+            &redelegated_stake_account_address,
+            &redelegated_stake_account.pubkey(),
+            seed,
+        )
+    } else {
+        stake_instruction::redelegate(
+            &stake_account_pubkey,
+            &stake_authority.pubkey(),
+            &vote_account_pubkey,
+            &redelegated_stake_account_address,
+        )
+    };
+
+    let nonce_authority = config.signers[nonce_authority];
+
+    let message = if let Some(nonce_account) = &nonce_account {
+        Message::new_with_nonce(
+            ixs,
+            Some(&fee_payer.pubkey()),
+            nonce_account,
+            &nonce_authority.pubkey(),
+        )
+    } else {
+        Message::new(&ixs, Some(&fee_payer.pubkey()))
+    };
+    let mut tx = Transaction::new_unsigned(message);
+
+    if sign_only {
+        tx.try_partial_sign(&config.signers, recent_blockhash)?;
+        return_signers(&tx, &config.output_format)
+    } else {
+        tx.try_sign(&config.signers, recent_blockhash)?;
+        if let Some(nonce_account) = &nonce_account {
+            let nonce_account = nonce_utils::get_account_with_commitment(
+                rpc_client,
+                nonce_account,
+                config.commitment,
+            )?;
+            check_nonce_account(&nonce_account, &nonce_authority.pubkey(), &recent_blockhash)?;
+        }
+        check_account_for_fee_with_commitment(
+            rpc_client,
+            &tx.message.account_keys[0],
+            &fee_calculator,
+            &tx.message,
+            config.commitment,
+        )?;
+        let result = rpc_client.send_and_confirm_transaction_with_spinner(&tx);
+        log_instruction_custom_error::<StakeError>(result, &config)
+    }
+}
+
 pub fn is_stake_program_v2_enabled(
     rpc_client: &RpcClient,
 ) -> Result<bool, Box<dyn std::error::Error>> {
@@ -1963,6 +2288,7 @@ mod tests {
         let test_stake_authorize = test_commands.clone().get_matches_from(vec![
             "test",
             "stake-authorize",
+            "--unchecked",
             &stake_account_string,
             "--new-stake-authority",
             &new_stake_string,
@@ -1975,8 +2301,8 @@ mod tests {
                 command: CliCommand::StakeAuthorize {
                     stake_account_pubkey,
                     new_authorizations: vec![
-                        (StakeAuthorize::Staker, new_stake_authority, 0,),
-                        (StakeAuthorize::Withdrawer, new_withdraw_authority, 0,),
+                        (StakeAuthorize::Staker, new_stake_authority, 0, None),
+                        (StakeAuthorize::Withdrawer, new_withdraw_authority, 0, None),
                     ],
                     sign_only: false,
                     blockhash_query: BlockhashQuery::All(blockhash_query::Source::Cluster),
@@ -1988,12 +2314,68 @@ mod tests {
                 signers: vec![read_keypair_file(&default_keypair_file).unwrap().into(),],
             },
         );
+        // Checked authorization is the default and requires the new authority to sign
+        let test_stake_authorize = test_commands.clone().get_matches_from(vec![
+            "test",
+            "stake-authorize",
+            &stake_account_string,
+            "--new-stake-authority",
+            &new_stake_string,
+        ]);
+        assert!(parse_command(&test_stake_authorize, &default_signer, &mut None).is_err());
+        let (new_authority_keypair_file, mut tmp_file) = make_tmp_file();
+        let new_authority_keypair = Keypair::new();
+        write_keypair(&new_authority_keypair, tmp_file.as_file_mut()).unwrap();
+        let test_stake_authorize = test_commands.clone().get_matches_from(vec![
+            "test",
+            "stake-authorize",
+            &stake_account_string,
+            "--new-stake-authority",
+            &new_authority_keypair_file,
+            "--new-withdraw-authority",
+            &new_authority_keypair_file,
+        ]);
+        assert_eq!(
+            parse_command(&test_stake_authorize, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::StakeAuthorize {
+                    stake_account_pubkey,
+                    new_authorizations: vec![
+                        (
+                            StakeAuthorize::Staker,
+                            new_authority_keypair.pubkey(),
+                            0,
+                            Some(1)
+                        ),
+                        (
+                            StakeAuthorize::Withdrawer,
+                            new_authority_keypair.pubkey(),
+                            0,
+                            Some(1)
+                        ),
+                    ],
+                    sign_only: false,
+                    blockhash_query: BlockhashQuery::All(blockhash_query::Source::Cluster),
+                    nonce_account: None,
+                    nonce_authority: 0,
+                    fee_payer: 0,
+                    custodian: None,
+                },
+                signers: vec![
+                    read_keypair_file(&default_keypair_file).unwrap().into(),
+                    read_keypair_file(&new_authority_keypair_file)
+                        .unwrap()
+                        .into(),
+                ],
+            },
+        );
         let (withdraw_authority_keypair_file, mut tmp_file) = make_tmp_file();
         let withdraw_authority_keypair = Keypair::new();
         write_keypair(&withdraw_authority_keypair, tmp_file.as_file_mut()).unwrap();
         let test_stake_authorize = test_commands.clone().get_matches_from(vec![
             "test",
             "stake-authorize",
+            "--unchecked",
             &stake_account_string,
             "--new-stake-authority",
             &new_stake_string,
@@ -2010,8 +2392,8 @@ mod tests {
                 command: CliCommand::StakeAuthorize {
                     stake_account_pubkey,
                     new_authorizations: vec![
-                        (StakeAuthorize::Staker, new_stake_authority, 1,),
-                        (StakeAuthorize::Withdrawer, new_withdraw_authority, 2,),
+                        (StakeAuthorize::Staker, new_stake_authority, 1, None),
+                        (StakeAuthorize::Withdrawer, new_withdraw_authority, 2, None),
                     ],
                     sign_only: false,
                     blockhash_query: BlockhashQuery::All(blockhash_query::Source::Cluster),
@@ -2035,6 +2417,7 @@ mod tests {
         let test_stake_authorize = test_commands.clone().get_matches_from(vec![
             "test",
             "stake-authorize",
+            "--unchecked",
             &stake_account_string,
             "--new-stake-authority",
             &new_stake_string,
@@ -2049,8 +2432,8 @@ mod tests {
                 command: CliCommand::StakeAuthorize {
                     stake_account_pubkey,
                     new_authorizations: vec![
-                        (StakeAuthorize::Staker, new_stake_authority, 1,),
-                        (StakeAuthorize::Withdrawer, new_withdraw_authority, 1,),
+                        (StakeAuthorize::Staker, new_stake_authority, 1, None),
+                        (StakeAuthorize::Withdrawer, new_withdraw_authority, 1, None),
                     ],
                     sign_only: false,
                     blockhash_query: BlockhashQuery::All(blockhash_query::Source::Cluster),
@@ -2070,6 +2453,7 @@ mod tests {
         let test_stake_authorize = test_commands.clone().get_matches_from(vec![
             "test",
             "stake-authorize",
+            "--unchecked",
             &stake_account_string,
             "--new-stake-authority",
             &new_stake_string,
@@ -2079,7 +2463,12 @@ mod tests {
             CliCommandInfo {
                 command: CliCommand::StakeAuthorize {
                     stake_account_pubkey,
-                    new_authorizations: vec![(StakeAuthorize::Staker, new_stake_authority, 0,),],
+                    new_authorizations: vec![(
+                        StakeAuthorize::Staker,
+                        new_stake_authority,
+                        0,
+                        None
+                    ),],
                     sign_only: false,
                     blockhash_query: BlockhashQuery::All(blockhash_query::Source::Cluster),
                     nonce_account: None,
@@ -2093,6 +2482,7 @@ mod tests {
         let test_stake_authorize = test_commands.clone().get_matches_from(vec![
             "test",
             "stake-authorize",
+            "--unchecked",
             &stake_account_string,
             "--new-stake-authority",
             &new_stake_string,
@@ -2104,7 +2494,12 @@ mod tests {
             CliCommandInfo {
                 command: CliCommand::StakeAuthorize {
                     stake_account_pubkey,
-                    new_authorizations: vec![(StakeAuthorize::Staker, new_stake_authority, 1,),],
+                    new_authorizations: vec![(
+                        StakeAuthorize::Staker,
+                        new_stake_authority,
+                        1,
+                        None
+                    ),],
                     sign_only: false,
                     blockhash_query: BlockhashQuery::All(blockhash_query::Source::Cluster),
                     nonce_account: None,
@@ -2124,6 +2519,7 @@ mod tests {
         let test_stake_authorize = test_commands.clone().get_matches_from(vec![
             "test",
             "stake-authorize",
+            "--unchecked",
             &stake_account_string,
             "--new-stake-authority",
             &new_stake_string,
@@ -2135,7 +2531,12 @@ mod tests {
             CliCommandInfo {
                 command: CliCommand::StakeAuthorize {
                     stake_account_pubkey,
-                    new_authorizations: vec![(StakeAuthorize::Staker, new_stake_authority, 1,),],
+                    new_authorizations: vec![(
+                        StakeAuthorize::Staker,
+                        new_stake_authority,
+                        1,
+                        None
+                    ),],
                     sign_only: false,
                     blockhash_query: BlockhashQuery::All(blockhash_query::Source::Cluster),
                     nonce_account: None,
@@ -2154,6 +2555,7 @@ mod tests {
         let test_stake_authorize = test_commands.clone().get_matches_from(vec![
             "test",
             "stake-authorize",
+            "--unchecked",
             &stake_account_string,
             "--new-withdraw-authority",
             &new_withdraw_string,
@@ -2167,6 +2569,7 @@ mod tests {
                         StakeAuthorize::Withdrawer,
                         new_withdraw_authority,
                         0,
+                        None
                     ),],
                     sign_only: false,
                     blockhash_query: BlockhashQuery::All(blockhash_query::Source::Cluster),
@@ -2181,6 +2584,7 @@ mod tests {
         let test_stake_authorize = test_commands.clone().get_matches_from(vec![
             "test",
             "stake-authorize",
+            "--unchecked",
             &stake_account_string,
             "--new-withdraw-authority",
             &new_withdraw_string,
@@ -2196,6 +2600,7 @@ mod tests {
                         StakeAuthorize::Withdrawer,
                         new_withdraw_authority,
                         1,
+                        None
                     ),],
                     sign_only: false,
                     blockhash_query: BlockhashQuery::All(blockhash_query::Source::Cluster),
@@ -2219,6 +2624,7 @@ mod tests {
         let test_authorize = test_commands.clone().get_matches_from(vec![
             "test",
             "stake-authorize",
+            "--unchecked",
             &stake_account_string,
             "--new-stake-authority",
             &stake_account_string,
@@ -2231,7 +2637,12 @@ mod tests {
             CliCommandInfo {
                 command: CliCommand::StakeAuthorize {
                     stake_account_pubkey,
-                    new_authorizations: vec![(StakeAuthorize::Staker, stake_account_pubkey, 0)],
+                    new_authorizations: vec![(
+                        StakeAuthorize::Staker,
+                        stake_account_pubkey,
+                        0,
+                        None
+                    )],
                     sign_only: true,
                     blockhash_query: BlockhashQuery::None(blockhash),
                     nonce_account: None,
@@ -2250,6 +2661,7 @@ mod tests {
         let test_authorize = test_commands.clone().get_matches_from(vec![
             "test",
             "stake-authorize",
+            "--unchecked",
             &stake_account_string,
             "--new-stake-authority",
             &stake_account_string,
@@ -2265,7 +2677,12 @@ mod tests {
             CliCommandInfo {
                 command: CliCommand::StakeAuthorize {
                     stake_account_pubkey,
-                    new_authorizations: vec![(StakeAuthorize::Staker, stake_account_pubkey, 0)],
+                    new_authorizations: vec![(
+                        StakeAuthorize::Staker,
+                        stake_account_pubkey,
+                        0,
+                        None
+                    )],
                     sign_only: false,
                     blockhash_query: BlockhashQuery::FeeCalculator(
                         blockhash_query::Source::Cluster,
@@ -2291,6 +2708,7 @@ mod tests {
         let test_authorize = test_commands.clone().get_matches_from(vec![
             "test",
             "stake-authorize",
+            "--unchecked",
             &stake_account_string,
             "--new-stake-authority",
             &stake_account_string,
@@ -2312,7 +2730,12 @@ mod tests {
             CliCommandInfo {
                 command: CliCommand::StakeAuthorize {
                     stake_account_pubkey,
-                    new_authorizations: vec![(StakeAuthorize::Staker, stake_account_pubkey, 0)],
+                    new_authorizations: vec![(
+                        StakeAuthorize::Staker,
+                        stake_account_pubkey,
+                        0,
+                        None
+                    )],
                     sign_only: false,
                     blockhash_query: BlockhashQuery::FeeCalculator(
                         blockhash_query::Source::NonceAccount(nonce_account),
@@ -2334,6 +2757,7 @@ mod tests {
         let test_authorize = test_commands.clone().get_matches_from(vec![
             "test",
             "stake-authorize",
+            "--unchecked",
             &stake_account_string,
             "--new-stake-authority",
             &stake_account_string,
@@ -2345,7 +2769,12 @@ mod tests {
             CliCommandInfo {
                 command: CliCommand::StakeAuthorize {
                     stake_account_pubkey,
-                    new_authorizations: vec![(StakeAuthorize::Staker, stake_account_pubkey, 0)],
+                    new_authorizations: vec![(
+                        StakeAuthorize::Staker,
+                        stake_account_pubkey,
+                        0,
+                        None
+                    )],
                     sign_only: false,
                     blockhash_query: BlockhashQuery::FeeCalculator(
                         blockhash_query::Source::Cluster,
@@ -2368,6 +2797,7 @@ mod tests {
         let test_authorize = test_commands.clone().get_matches_from(vec![
             "test",
             "stake-authorize",
+            "--unchecked",
             &stake_account_string,
             "--new-stake-authority",
             &stake_account_string,
@@ -2383,7 +2813,12 @@ mod tests {
             CliCommandInfo {
                 command: CliCommand::StakeAuthorize {
                     stake_account_pubkey,
-                    new_authorizations: vec![(StakeAuthorize::Staker, stake_account_pubkey, 0)],
+                    new_authorizations: vec![(
+                        StakeAuthorize::Staker,
+                        stake_account_pubkey,
+                        0,
+                        None
+                    )],
                     sign_only: false,
                     blockhash_query: BlockhashQuery::FeeCalculator(
                         blockhash_query::Source::NonceAccount(nonce_account_pubkey),
@@ -2409,6 +2844,7 @@ mod tests {
         let test_authorize = test_commands.clone().get_matches_from(vec![
             "test",
             "stake-authorize",
+            "--unchecked",
             &stake_account_string,
             "--new-stake-authority",
             &stake_account_string,
@@ -2420,7 +2856,12 @@ mod tests {
             CliCommandInfo {
                 command: CliCommand::StakeAuthorize {
                     stake_account_pubkey,
-                    new_authorizations: vec![(StakeAuthorize::Staker, stake_account_pubkey, 0)],
+                    new_authorizations: vec![(
+                        StakeAuthorize::Staker,
+                        stake_account_pubkey,
+                        0,
+                        None
+                    )],
                     sign_only: false,
                     blockhash_query: BlockhashQuery::All(blockhash_query::Source::Cluster),
                     nonce_account: None,
@@ -2440,6 +2881,7 @@ mod tests {
         let test_authorize = test_commands.clone().get_matches_from(vec![
             "test",
             "stake-authorize",
+            "--unchecked",
             &stake_account_string,
             "--new-stake-authority",
             &stake_account_string,
@@ -2455,7 +2897,12 @@ mod tests {
             CliCommandInfo {
                 command: CliCommand::StakeAuthorize {
                     stake_account_pubkey,
-                    new_authorizations: vec![(StakeAuthorize::Staker, stake_account_pubkey, 0)],
+                    new_authorizations: vec![(
+                        StakeAuthorize::Staker,
+                        stake_account_pubkey,
+                        0,
+                        None
+                    )],
                     sign_only: false,
                     blockhash_query: BlockhashQuery::FeeCalculator(
                         blockhash_query::Source::Cluster,
@@ -3367,5 +3814,80 @@ mod tests {
                 signers: vec![read_keypair_file(&default_keypair_file).unwrap().into(),],
             }
         );
+
+        // Test RedelegateStake SubCommand
+        let stake_account_pubkey = solana_sdk::pubkey::new_rand();
+        let vote_account_pubkey = solana_sdk::pubkey::new_rand();
+        let (redelegated_stake_account_keypair_file, mut tmp_file) = make_tmp_file();
+        let redelegated_stake_account_keypair = Keypair::new();
+        write_keypair(&redelegated_stake_account_keypair, tmp_file.as_file_mut()).unwrap();
+
+        let test_redelegate_stake = test_commands.clone().get_matches_from(vec![
+            "test",
+            "redelegate-stake",
+            &stake_account_pubkey.to_string(),
+            &vote_account_pubkey.to_string(),
+            &redelegated_stake_account_keypair_file,
+        ]);
+        assert_eq!(
+            parse_command(&test_redelegate_stake, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::RedelegateStake {
+                    stake_account_pubkey,
+                    vote_account_pubkey,
+                    stake_authority: 0,
+                    force: false,
+                    sign_only: false,
+                    blockhash_query: BlockhashQuery::default(),
+                    nonce_account: None,
+                    nonce_authority: 0,
+                    redelegated_stake_account: 1,
+                    seed: None,
+                    fee_payer: 0,
+                },
+                signers: vec![
+                    read_keypair_file(&default_keypair_file).unwrap().into(),
+                    read_keypair_file(&redelegated_stake_account_keypair_file)
+                        .unwrap()
+                        .into(),
+                ],
+            }
+        );
+
+        // Test RedelegateStake SubCommand w/ seed and force
+        let test_redelegate_stake = test_commands.clone().get_matches_from(vec![
+            "test",
+            "redelegate-stake",
+            &stake_account_pubkey.to_string(),
+            &vote_account_pubkey.to_string(),
+            &redelegated_stake_account_keypair_file,
+            "--seed",
+            "redelegate",
+            "--force",
+        ]);
+        assert_eq!(
+            parse_command(&test_redelegate_stake, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::RedelegateStake {
+                    stake_account_pubkey,
+                    vote_account_pubkey,
+                    stake_authority: 0,
+                    force: true,
+                    sign_only: false,
+                    blockhash_query: BlockhashQuery::default(),
+                    nonce_account: None,
+                    nonce_authority: 0,
+                    redelegated_stake_account: 1,
+                    seed: Some("redelegate".to_string()),
+                    fee_payer: 0,
+                },
+                signers: vec![
+                    read_keypair_file(&default_keypair_file).unwrap().into(),
+                    read_keypair_file(&redelegated_stake_account_keypair_file)
+                        .unwrap()
+                        .into(),
+                ],
+            }
+        );
     }
 }
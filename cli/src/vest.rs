@@ -0,0 +1,595 @@
+use crate::{
+    checks::check_unique_pubkeys,
+    cli::{
+        log_instruction_custom_error, CliCommand, CliCommandInfo, CliConfig, CliError,
+        ProcessResult,
+    },
+    spend_utils::{resolve_spend_tx_and_check_account_balance, SpendAmount},
+};
+use chrono::prelude::*;
+use clap::{App, Arg, ArgMatches, SubCommand};
+use solana_clap_utils::{
+    input_parsers::*,
+    input_validators::*,
+    keypair::{DefaultSigner, SignerIndex},
+};
+use solana_cli_output::{CliVestAccount, CliVestingDate};
+use solana_client::rpc_client::RpcClient;
+use solana_config_program::{date_instruction::DateConfig, get_config_data};
+use solana_remote_wallet::remote_wallet::RemoteWalletManager;
+use solana_sdk::{
+    message::Message, native_token::sol_to_lamports, pubkey::Pubkey, transaction::Transaction,
+};
+use solana_vest_program::{
+    vest_instruction::{self, VestError},
+    vest_schedule::{VestSchedule, VestTranche},
+    vest_state::VestState,
+};
+use std::sync::Arc;
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+pub trait VestSubCommands {
+    fn vest_subcommands(self) -> Self;
+}
+
+impl VestSubCommands for App<'_, '_> {
+    fn vest_subcommands(self) -> Self {
+        self.subcommand(
+            SubCommand::with_name("create-vest-account")
+                .about("Create a vesting contract")
+                .arg(
+                    Arg::with_name("vest_account_keypair")
+                        .index(1)
+                        .value_name("ACCOUNT_KEYPAIR")
+                        .takes_value(true)
+                        .required(true)
+                        .validator(is_valid_signer)
+                        .help("Keypair of the vesting contract account to fund"),
+                )
+                .arg(
+                    Arg::with_name("amount")
+                        .index(2)
+                        .value_name("AMOUNT")
+                        .takes_value(true)
+                        .required(true)
+                        .validator(is_amount)
+                        .help("The amount to vest, in VLX"),
+                )
+                .arg(
+                    pubkey!(Arg::with_name("payee")
+                        .long("payee")
+                        .value_name("PAYEE_PUBKEY")
+                        .required(true),
+                        "Address authorized to redeem vested tokens. "),
+                )
+                .arg(
+                    pubkey!(Arg::with_name("date_oracle")
+                        .long("date-oracle")
+                        .value_name("DATE_ACCOUNT_ADDRESS")
+                        .required(true),
+                        "Config account containing the trusted date that drives the schedule. "),
+                )
+                .arg(
+                    Arg::with_name("start_date")
+                        .long("start-date")
+                        .value_name("YYYY-MM-DD")
+                        .takes_value(true)
+                        .required(true)
+                        .validator(is_vest_date)
+                        .help("The day from which the vesting contract begins"),
+                )
+                .arg(
+                    pubkey!(Arg::with_name("terminator")
+                        .long("terminator")
+                        .value_name("TERMINATOR_PUBKEY"),
+                        "Address authorized to terminate the contract [default: cli config pubkey]. "),
+                )
+                .arg(
+                    Arg::with_name("cliff_months")
+                        .long("cliff-months")
+                        .value_name("MONTHS")
+                        .takes_value(true)
+                        .requires("period_months")
+                        .validator(is_parsable::<u32>)
+                        .help("Nothing vests before this many months after the start date [default: 0]"),
+                )
+                .arg(
+                    Arg::with_name("period_months")
+                        .long("period-months")
+                        .value_name("MONTHS")
+                        .takes_value(true)
+                        .requires("periods")
+                        .conflicts_with("tranche")
+                        .validator(is_parsable::<u32>)
+                        .help("Vest in equal payments, one every this many months"),
+                )
+                .arg(
+                    Arg::with_name("periods")
+                        .long("periods")
+                        .value_name("COUNT")
+                        .takes_value(true)
+                        .requires("period_months")
+                        .validator(is_parsable::<u32>)
+                        .help("Number of equal payments"),
+                )
+                .arg(
+                    Arg::with_name("tranche")
+                        .long("tranche")
+                        .value_name("YYYY-MM-DD:AMOUNT")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1)
+                        .validator(is_vest_tranche)
+                        .help("Vest AMOUNT VLX on the given date; may be specified multiple times. \
+                            The tranches must add up to the vested amount"),
+                )
+                .after_help("Without --period-months or --tranche, one third vests 12 months \
+                    after the start date and the rest vests monthly over the following 24 months"),
+        )
+        .subcommand(
+            SubCommand::with_name("vest-account")
+                .about("Show the contents of a vesting contract")
+                .arg(
+                    pubkey!(Arg::with_name("vest_account_pubkey")
+                        .index(1)
+                        .value_name("VEST_ACCOUNT_ADDRESS")
+                        .required(true),
+                        "Address of the vesting contract to display. "),
+                )
+                .arg(
+                    Arg::with_name("lamports")
+                        .long("lamports")
+                        .takes_value(false)
+                        .help("Display balance in lamports instead of VLX"),
+                ),
+        )
+    }
+}
+
+fn parse_vest_date(date: &str) -> Result<Date<Utc>, String> {
+    NaiveDate::parse_from_str(date, DATE_FORMAT)
+        .map(|date| Utc.from_utc_date(&date))
+        .map_err(|err| format!("Unable to parse date {}: {}", date, err))
+}
+
+fn parse_vest_tranche(tranche: &str) -> Result<VestTranche, String> {
+    let mut parts = tranche.splitn(2, ':');
+    let date = parse_vest_date(parts.next().unwrap_or_default())?;
+    let amount = parts
+        .next()
+        .ok_or_else(|| format!("Tranche {} is missing an amount", tranche))?;
+    is_amount(amount)?;
+    Ok(VestTranche {
+        date_time: date.and_hms(0, 0, 0),
+        lamports: sol_to_lamports(amount.parse().unwrap()),
+    })
+}
+
+fn is_vest_date(date: String) -> Result<(), String> {
+    parse_vest_date(&date).map(|_| ())
+}
+
+fn is_vest_tranche(tranche: String) -> Result<(), String> {
+    parse_vest_tranche(&tranche).map(|_| ())
+}
+
+pub fn parse_create_vest_account(
+    matches: &ArgMatches<'_>,
+    default_signer: &DefaultSigner,
+    wallet_manager: &mut Option<Arc<RemoteWalletManager>>,
+) -> Result<CliCommandInfo, CliError> {
+    let (vest_account, vest_account_pubkey) =
+        signer_of(matches, "vest_account_keypair", wallet_manager)?;
+    let lamports = lamports_of_sol(matches, "amount").unwrap();
+    let payee = pubkey_of_signer(matches, "payee", wallet_manager)?.unwrap();
+    let date_oracle = pubkey_of_signer(matches, "date_oracle", wallet_manager)?.unwrap();
+    let terminator = pubkey_of_signer(matches, "terminator", wallet_manager)?;
+    let start_date =
+        parse_vest_date(matches.value_of("start_date").unwrap()).map_err(CliError::BadParameter)?;
+
+    let schedule = if let Some(period_months) = value_of(matches, "period_months") {
+        VestSchedule::Periodic {
+            cliff_months: value_of(matches, "cliff_months").unwrap_or(0),
+            period_months,
+            periods: value_of(matches, "periods").unwrap(),
+        }
+    } else if let Some(tranches) = matches.values_of("tranche") {
+        VestSchedule::Tranches(
+            tranches
+                .map(parse_vest_tranche)
+                .collect::<Result<_, _>>()
+                .map_err(CliError::BadParameter)?,
+        )
+    } else {
+        VestSchedule::Standard
+    };
+
+    let payer_provided = None;
+    let signer_info = default_signer.generate_unique_signers(
+        vec![payer_provided, vest_account],
+        matches,
+        wallet_manager,
+    )?;
+
+    Ok(CliCommandInfo {
+        command: CliCommand::CreateVestAccount {
+            vest_account: signer_info.index_of(vest_account_pubkey).unwrap(),
+            payee,
+            terminator,
+            date_oracle,
+            start_date,
+            schedule,
+            lamports,
+        },
+        signers: signer_info.signers,
+    })
+}
+
+pub fn parse_show_vest_account(
+    matches: &ArgMatches<'_>,
+    wallet_manager: &mut Option<Arc<RemoteWalletManager>>,
+) -> Result<CliCommandInfo, CliError> {
+    let vest_account_pubkey =
+        pubkey_of_signer(matches, "vest_account_pubkey", wallet_manager)?.unwrap();
+    let use_lamports_unit = matches.is_present("lamports");
+
+    Ok(CliCommandInfo {
+        command: CliCommand::ShowVestAccount {
+            vest_account_pubkey,
+            use_lamports_unit,
+        },
+        signers: vec![],
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn process_create_vest_account(
+    rpc_client: &RpcClient,
+    config: &CliConfig,
+    vest_account: SignerIndex,
+    payee: &Pubkey,
+    terminator: Option<Pubkey>,
+    date_oracle: &Pubkey,
+    start_date: Date<Utc>,
+    schedule: &VestSchedule,
+    lamports: u64,
+) -> ProcessResult {
+    let vest_account_pubkey = config.signers[vest_account].pubkey();
+    check_unique_pubkeys(
+        (&config.signers[0].pubkey(), "cli keypair".to_string()),
+        (&vest_account_pubkey, "vest_account".to_string()),
+    )?;
+
+    schedule
+        .validate(start_date, lamports)
+        .map_err(|err| CliError::BadParameter(format!("{}: {:?}", err, schedule)))?;
+
+    if rpc_client.get_account(&vest_account_pubkey).is_ok() {
+        return Err(CliError::BadParameter(format!(
+            "Account {} already exists",
+            vest_account_pubkey
+        ))
+        .into());
+    }
+
+    let terminator = terminator.unwrap_or_else(|| config.signers[0].pubkey());
+    let build_message = |lamports| {
+        let ixs = vest_instruction::create_account_with_schedule(
+            &config.signers[0].pubkey(),
+            &terminator,
+            &vest_account_pubkey,
+            payee,
+            start_date,
+            date_oracle,
+            lamports,
+            schedule.clone(),
+        );
+        Message::new(&ixs, Some(&config.signers[0].pubkey()))
+    };
+
+    let (recent_blockhash, fee_calculator) = rpc_client.get_recent_blockhash()?;
+
+    let (message, _) = resolve_spend_tx_and_check_account_balance(
+        rpc_client,
+        false,
+        SpendAmount::Some(lamports),
+        &fee_calculator,
+        &config.signers[0].pubkey(),
+        build_message,
+        config.commitment,
+    )?;
+
+    let mut tx = Transaction::new_unsigned(message);
+    tx.try_sign(&config.signers, recent_blockhash)?;
+    let result = rpc_client.send_and_confirm_transaction_with_spinner(&tx);
+    log_instruction_custom_error::<VestError>(result, &config)
+}
+
+fn describe_schedule(schedule: &VestSchedule) -> String {
+    match schedule {
+        VestSchedule::Standard => {
+            "one third after 12 months, then monthly over 24 months".to_string()
+        }
+        VestSchedule::Periodic {
+            cliff_months,
+            period_months,
+            periods,
+        } => format!(
+            "{} payments, one every {} months, with a {} month cliff",
+            periods, period_months, cliff_months
+        ),
+        VestSchedule::Tranches(tranches) => format!("{} tranches", tranches.len()),
+    }
+}
+
+pub fn process_show_vest_account(
+    rpc_client: &RpcClient,
+    config: &CliConfig,
+    vest_account_pubkey: &Pubkey,
+    use_lamports_unit: bool,
+) -> ProcessResult {
+    let vest_account = rpc_client
+        .get_account_with_commitment(vest_account_pubkey, config.commitment)?
+        .value
+        .ok_or_else(|| {
+            CliError::RpcRequestError(format!("{} account does not exist", vest_account_pubkey))
+        })?;
+    if vest_account.owner != solana_vest_program::id() {
+        return Err(CliError::RpcRequestError(format!(
+            "{:?} is not a vesting contract",
+            vest_account_pubkey
+        ))
+        .into());
+    }
+    let vest_state = VestState::deserialize(&vest_account.data).map_err(|err| {
+        CliError::RpcRequestError(format!(
+            "Account data could not be deserialized to vest state: {}",
+            err
+        ))
+    })?;
+
+    // The contract is driven by the date oracle, not by the local clock
+    let current_date = rpc_client
+        .get_account_with_commitment(&vest_state.date_pubkey, config.commitment)?
+        .value
+        .and_then(|account| {
+            let config_data = get_config_data(&account.data).ok()?;
+            DateConfig::deserialize(config_data)
+        })
+        .map(|date_config| date_config.date_time.date());
+
+    let start_date = vest_state.start_date_time.date();
+    let vesting_dates = vest_state
+        .schedule
+        .vesting_dates(start_date, vest_state.total_lamports)
+        .into_iter()
+        .map(|(date, lamports)| CliVestingDate {
+            date: date.format(DATE_FORMAT).to_string(),
+            lamports,
+        })
+        .collect();
+
+    let vest_account = CliVestAccount {
+        balance: vest_account.lamports,
+        terminator: vest_state.terminator_pubkey.to_string(),
+        payee: vest_state.payee_pubkey.to_string(),
+        start_date: start_date.format(DATE_FORMAT).to_string(),
+        date_oracle: vest_state.date_pubkey.to_string(),
+        current_date: current_date.map(|date| date.format(DATE_FORMAT).to_string()),
+        schedule: describe_schedule(&vest_state.schedule),
+        vesting_dates,
+        total_lamports: vest_state.total_lamports,
+        vested_lamports: current_date.map(|date| vest_state.calc_vested_lamports(date)),
+        redeemed_lamports: vest_state.redeemed_lamports,
+        reneged_lamports: vest_state.reneged_lamports,
+        is_fully_vested: vest_state.is_fully_vested,
+        use_lamports_unit,
+    };
+    Ok(config.output_format.formatted_string(&vest_account))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{app, parse_command};
+    use solana_sdk::signature::{read_keypair_file, write_keypair, Keypair, Signer};
+    use tempfile::NamedTempFile;
+
+    fn make_tmp_file() -> (String, NamedTempFile) {
+        let tmp_file = NamedTempFile::new().unwrap();
+        (String::from(tmp_file.path().to_str().unwrap()), tmp_file)
+    }
+
+    #[test]
+    fn test_parse_command() {
+        let test_commands = app("test", "desc", "version");
+        let default_keypair = Keypair::new();
+        let (default_keypair_file, mut tmp_file) = make_tmp_file();
+        write_keypair(&default_keypair, tmp_file.as_file_mut()).unwrap();
+        let default_signer = DefaultSigner {
+            path: default_keypair_file.clone(),
+            arg_name: String::new(),
+        };
+        let (keypair_file, mut tmp_file) = make_tmp_file();
+        let vest_account_keypair = Keypair::new();
+        write_keypair(&vest_account_keypair, tmp_file.as_file_mut()).unwrap();
+        let vest_account_pubkey = vest_account_keypair.pubkey();
+        let payee = solana_sdk::pubkey::new_rand();
+        let date_oracle = solana_sdk::pubkey::new_rand();
+        let signers = || {
+            vec![
+                read_keypair_file(&default_keypair_file).unwrap().into(),
+                read_keypair_file(&keypair_file).unwrap().into(),
+            ]
+        };
+
+        // Test CreateVestAccount SubCommand with the standard schedule
+        let test_create_vest_account = test_commands.clone().get_matches_from(vec![
+            "test",
+            "create-vest-account",
+            &keypair_file,
+            "50",
+            "--payee",
+            &payee.to_string(),
+            "--date-oracle",
+            &date_oracle.to_string(),
+            "--start-date",
+            "2021-01-31",
+        ]);
+        assert_eq!(
+            parse_command(&test_create_vest_account, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::CreateVestAccount {
+                    vest_account: 1,
+                    payee,
+                    terminator: None,
+                    date_oracle,
+                    start_date: Utc.ymd(2021, 1, 31),
+                    schedule: VestSchedule::Standard,
+                    lamports: 50_000_000_000,
+                },
+                signers: signers(),
+            }
+        );
+
+        // Test CreateVestAccount SubCommand with a periodic schedule
+        let terminator = solana_sdk::pubkey::new_rand();
+        let test_create_vest_account = test_commands.clone().get_matches_from(vec![
+            "test",
+            "create-vest-account",
+            &keypair_file,
+            "50",
+            "--payee",
+            &payee.to_string(),
+            "--date-oracle",
+            &date_oracle.to_string(),
+            "--start-date",
+            "2021-01-31",
+            "--terminator",
+            &terminator.to_string(),
+            "--cliff-months",
+            "6",
+            "--period-months",
+            "3",
+            "--periods",
+            "8",
+        ]);
+        assert_eq!(
+            parse_command(&test_create_vest_account, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::CreateVestAccount {
+                    vest_account: 1,
+                    payee,
+                    terminator: Some(terminator),
+                    date_oracle,
+                    start_date: Utc.ymd(2021, 1, 31),
+                    schedule: VestSchedule::Periodic {
+                        cliff_months: 6,
+                        period_months: 3,
+                        periods: 8,
+                    },
+                    lamports: 50_000_000_000,
+                },
+                signers: signers(),
+            }
+        );
+
+        // Test CreateVestAccount SubCommand with tranches
+        let test_create_vest_account = test_commands.clone().get_matches_from(vec![
+            "test",
+            "create-vest-account",
+            &keypair_file,
+            "50",
+            "--payee",
+            &payee.to_string(),
+            "--date-oracle",
+            &date_oracle.to_string(),
+            "--start-date",
+            "2021-01-31",
+            "--tranche",
+            "2021-07-31:20",
+            "--tranche",
+            "2022-01-31:30",
+        ]);
+        assert_eq!(
+            parse_command(&test_create_vest_account, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::CreateVestAccount {
+                    vest_account: 1,
+                    payee,
+                    terminator: None,
+                    date_oracle,
+                    start_date: Utc.ymd(2021, 1, 31),
+                    schedule: VestSchedule::Tranches(vec![
+                        VestTranche {
+                            date_time: Utc.ymd(2021, 7, 31).and_hms(0, 0, 0),
+                            lamports: 20_000_000_000,
+                        },
+                        VestTranche {
+                            date_time: Utc.ymd(2022, 1, 31).and_hms(0, 0, 0),
+                            lamports: 30_000_000_000,
+                        },
+                    ]),
+                    lamports: 50_000_000_000,
+                },
+                signers: signers(),
+            }
+        );
+
+        // Periods and tranches can't be combined
+        assert!(test_commands
+            .clone()
+            .get_matches_from_safe(vec![
+                "test",
+                "create-vest-account",
+                &keypair_file,
+                "50",
+                "--payee",
+                &payee.to_string(),
+                "--date-oracle",
+                &date_oracle.to_string(),
+                "--start-date",
+                "2021-01-31",
+                "--period-months",
+                "3",
+                "--periods",
+                "8",
+                "--tranche",
+                "2021-07-31:50",
+            ])
+            .is_err());
+
+        // Test ShowVestAccount SubCommand
+        let test_show_vest_account = test_commands.clone().get_matches_from(vec![
+            "test",
+            "vest-account",
+            &vest_account_pubkey.to_string(),
+            "--lamports",
+        ]);
+        assert_eq!(
+            parse_command(&test_show_vest_account, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::ShowVestAccount {
+                    vest_account_pubkey,
+                    use_lamports_unit: true,
+                },
+                signers: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_vest_tranche() {
+        assert_eq!(
+            parse_vest_tranche("2021-02-28:1.5"),
+            Ok(VestTranche {
+                date_time: Utc.ymd(2021, 2, 28).and_hms(0, 0, 0),
+                lamports: 1_500_000_000,
+            })
+        );
+        assert!(parse_vest_tranche("2021-02-30:1").is_err());
+        assert!(parse_vest_tranche("2021-02-28").is_err());
+        assert!(parse_vest_tranche("2021-02-28:one").is_err());
+    }
+}
@@ -5,6 +5,7 @@ use crate::{
         ProcessResult,
     },
     spend_utils::{resolve_spend_tx_and_check_account_balance, SpendAmount},
+    stake::{fetch_epoch_rewards, num_rewards_epochs_arg},
 };
 use clap::{value_t_or_exit, App, Arg, ArgMatches, SubCommand};
 use solana_clap_utils::{
@@ -100,11 +101,19 @@ impl VoteSubCommands for App<'_, '_> {
                         .help("Current authorized vote signer."),
                 )
                 .arg(
-                    pubkey!(Arg::with_name("new_authorized_pubkey")
+                    Arg::with_name("new_authorized")
                         .index(3)
-                        .value_name("NEW_AUTHORIZED_PUBKEY")
-                        .required(true),
-                        "New authorized vote signer. "),
+                        .value_name("NEW_AUTHORIZED_KEYPAIR")
+                        .required(true)
+                        .validator(is_valid_signer)
+                        .help("New authorized vote signer. Must sign the transaction unless --unchecked is given"),
+                )
+                .arg(
+                    Arg::with_name("unchecked")
+                        .long("unchecked")
+                        .takes_value(false)
+                        .help("Use the legacy authorize instruction, which does not require the new authority to sign. \
+                            Required until the cluster activates checked authorize instructions"),
                 ),
         )
         .subcommand(
@@ -126,11 +135,19 @@ impl VoteSubCommands for App<'_, '_> {
                         .help("Current authorized withdrawer."),
                 )
                 .arg(
-                    pubkey!(Arg::with_name("new_authorized_pubkey")
+                    Arg::with_name("new_authorized")
                         .index(3)
-                        .value_name("AUTHORIZED_PUBKEY")
-                        .required(true),
-                        "New authorized withdrawer. "),
+                        .value_name("NEW_AUTHORIZED_KEYPAIR")
+                        .required(true)
+                        .validator(is_valid_signer)
+                        .help("New authorized withdrawer. Must sign the transaction unless --unchecked is given"),
+                )
+                .arg(
+                    Arg::with_name("unchecked")
+                        .long("unchecked")
+                        .takes_value(false)
+                        .help("Use the legacy authorize instruction, which does not require the new authority to sign. \
+                            Required until the cluster activates checked authorize instructions"),
                 ),
         )
         .subcommand(
@@ -207,7 +224,14 @@ impl VoteSubCommands for App<'_, '_> {
                         .long("lamports")
                         .takes_value(false)
                         .help("Display balance in lamports instead of VLX"),
-                ),
+                )
+                .arg(
+                    Arg::with_name("with_rewards")
+                        .long("with-rewards")
+                        .takes_value(false)
+                        .help("Display inflation rewards"),
+                )
+                .arg(num_rewards_epochs_arg()),
         )
         .subcommand(
             SubCommand::with_name("withdraw-from-vote-account")
@@ -288,22 +312,33 @@ pub fn parse_vote_authorize(
 ) -> Result<CliCommandInfo, CliError> {
     let vote_account_pubkey =
         pubkey_of_signer(matches, "vote_account_pubkey", wallet_manager)?.unwrap();
-    let new_authorized_pubkey =
-        pubkey_of_signer(matches, "new_authorized_pubkey", wallet_manager)?.unwrap();
-    let (authorized, _) = signer_of(matches, "authorized", wallet_manager)?;
+    let (authorized, authorized_pubkey) = signer_of(matches, "authorized", wallet_manager)?;
 
     let payer_provided = None;
-    let signer_info = default_signer.generate_unique_signers(
-        vec![payer_provided, authorized],
-        matches,
-        wallet_manager,
-    )?;
+    let mut bulk_signers = vec![payer_provided, authorized];
+
+    let (new_authorized_pubkey, new_authorized_signer_pubkey) = if matches.is_present("unchecked") {
+        let new_authorized_pubkey =
+            pubkey_of_signer(matches, "new_authorized", wallet_manager)?.unwrap();
+        (new_authorized_pubkey, None)
+    } else {
+        let (new_authorized_signer, new_authorized_pubkey) =
+            signer_of(matches, "new_authorized", wallet_manager)?;
+        bulk_signers.push(new_authorized_signer);
+        (new_authorized_pubkey.unwrap(), new_authorized_pubkey)
+    };
+
+    let signer_info =
+        default_signer.generate_unique_signers(bulk_signers, matches, wallet_manager)?;
 
     Ok(CliCommandInfo {
         command: CliCommand::VoteAuthorize {
             vote_account_pubkey,
             new_authorized_pubkey,
             vote_authorize,
+            authorized: signer_info.index_of(authorized_pubkey).unwrap(),
+            new_authorized: new_authorized_signer_pubkey
+                .map(|pubkey| signer_info.index_of(Some(pubkey)).unwrap()),
         },
         signers: signer_info.signers,
     })
@@ -373,10 +408,16 @@ pub fn parse_vote_get_account_command(
     let vote_account_pubkey =
         pubkey_of_signer(matches, "vote_account_pubkey", wallet_manager)?.unwrap();
     let use_lamports_unit = matches.is_present("lamports");
+    let with_rewards = if matches.is_present("with_rewards") {
+        Some(value_t_or_exit!(matches, "num_rewards_epochs", usize))
+    } else {
+        None
+    };
     Ok(CliCommandInfo {
         command: CliCommand::ShowVoteAccount {
             pubkey: vote_account_pubkey,
             use_lamports_unit,
+            with_rewards,
         },
         signers: vec![],
     })
@@ -515,26 +556,32 @@ pub fn process_vote_authorize(
     vote_account_pubkey: &Pubkey,
     new_authorized_pubkey: &Pubkey,
     vote_authorize: VoteAuthorize,
+    authorized: SignerIndex,
+    new_authorized: Option<SignerIndex>,
 ) -> ProcessResult {
-    // If the `authorized_account` is also the fee payer, `config.signers` will only have one
-    // keypair in it
-    let authorized = if config.signers.len() == 2 {
-        config.signers[1]
-    } else {
-        config.signers[0]
-    };
+    let authorized = config.signers[authorized];
 
     check_unique_pubkeys(
         (&authorized.pubkey(), "authorized_account".to_string()),
         (new_authorized_pubkey, "new_authorized_pubkey".to_string()),
     )?;
     let (recent_blockhash, fee_calculator) = rpc_client.get_recent_blockhash()?;
-    let ixs = vec![vote_instruction::authorize(
-        vote_account_pubkey,   // vote account to update
-        &authorized.pubkey(),  // current authorized
-        new_authorized_pubkey, // new vote signer/withdrawer
-        vote_authorize,        // vote or withdraw
-    )];
+    let ix = if new_authorized.is_some() {
+        vote_instruction::authorize_checked(
+            vote_account_pubkey,   // vote account to update
+            &authorized.pubkey(),  // current authorized
+            new_authorized_pubkey, // new vote signer/withdrawer
+            vote_authorize,        // vote or withdraw
+        )
+    } else {
+        vote_instruction::authorize(
+            vote_account_pubkey,   // vote account to update
+            &authorized.pubkey(),  // current authorized
+            new_authorized_pubkey, // new vote signer/withdrawer
+            vote_authorize,        // vote or withdraw
+        )
+    };
+    let ixs = vec![ix];
 
     let message = Message::new(&ixs, Some(&config.signers[0].pubkey()));
     let mut tx = Transaction::new_unsigned(message);
@@ -647,6 +694,7 @@ pub fn process_show_vote_account(
     config: &CliConfig,
     vote_account_address: &Pubkey,
     use_lamports_unit: bool,
+    with_rewards: Option<usize>,
 ) -> ProcessResult {
     let (vote_account, vote_state) =
         get_vote_account(rpc_client, vote_account_address, config.commitment)?;
@@ -672,14 +720,15 @@ pub fn process_show_vote_account(
         }
     }
 
-    let epoch_rewards = match crate::stake::fetch_epoch_rewards(rpc_client, vote_account_address, 1)
-    {
-        Ok(rewards) => Some(rewards),
-        Err(error) => {
-            eprintln!("Failed to fetch epoch rewards: {:?}", error);
-            None
+    let epoch_rewards = with_rewards.and_then(|num_epochs| {
+        match fetch_epoch_rewards(rpc_client, vote_account_address, 0, Some(num_epochs)) {
+            Ok(rewards) => Some(rewards),
+            Err(error) => {
+                eprintln!("Failed to fetch epoch rewards: {:?}", error);
+                None
+            }
         }
-    };
+    });
 
     let vote_account_data = CliVoteAccount {
         account_balance: vote_account.lamports,
@@ -783,6 +832,7 @@ mod tests {
             &pubkey_string,
             &default_keypair_file,
             &pubkey2_string,
+            "--unchecked",
         ]);
         assert_eq!(
             parse_command(&test_authorize_voter, &default_signer, &mut None).unwrap(),
@@ -790,12 +840,24 @@ mod tests {
                 command: CliCommand::VoteAuthorize {
                     vote_account_pubkey: pubkey,
                     new_authorized_pubkey: pubkey2,
-                    vote_authorize: VoteAuthorize::Voter
+                    vote_authorize: VoteAuthorize::Voter,
+                    authorized: 0,
+                    new_authorized: None,
                 },
                 signers: vec![read_keypair_file(&default_keypair_file).unwrap().into()],
             }
         );
 
+        // Checked authorization requires the new authority to be a signer
+        let test_authorize_voter = test_commands.clone().get_matches_from(vec![
+            "test",
+            "vote-authorize-voter",
+            &pubkey_string,
+            &default_keypair_file,
+            &pubkey2_string,
+        ]);
+        assert!(parse_command(&test_authorize_voter, &default_signer, &mut None).is_err());
+
         let authorized_keypair = Keypair::new();
         let (authorized_keypair_file, mut tmp_file) = make_tmp_file();
         write_keypair(&authorized_keypair, tmp_file.as_file_mut()).unwrap();
@@ -806,6 +868,7 @@ mod tests {
             &pubkey_string,
             &authorized_keypair_file,
             &pubkey2_string,
+            "--unchecked",
         ]);
         assert_eq!(
             parse_command(&test_authorize_voter, &default_signer, &mut None).unwrap(),
@@ -813,7 +876,9 @@ mod tests {
                 command: CliCommand::VoteAuthorize {
                     vote_account_pubkey: pubkey,
                     new_authorized_pubkey: pubkey2,
-                    vote_authorize: VoteAuthorize::Voter
+                    vote_authorize: VoteAuthorize::Voter,
+                    authorized: 1,
+                    new_authorized: None,
                 },
                 signers: vec![
                     read_keypair_file(&default_keypair_file).unwrap().into(),
@@ -822,6 +887,36 @@ mod tests {
             }
         );
 
+        let (new_authorized_keypair_file, mut tmp_file) = make_tmp_file();
+        write_keypair(&keypair2, tmp_file.as_file_mut()).unwrap();
+
+        let test_authorize_withdrawer = test_commands.clone().get_matches_from(vec![
+            "test",
+            "vote-authorize-withdrawer",
+            &pubkey_string,
+            &authorized_keypair_file,
+            &new_authorized_keypair_file,
+        ]);
+        assert_eq!(
+            parse_command(&test_authorize_withdrawer, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::VoteAuthorize {
+                    vote_account_pubkey: pubkey,
+                    new_authorized_pubkey: pubkey2,
+                    vote_authorize: VoteAuthorize::Withdrawer,
+                    authorized: 1,
+                    new_authorized: Some(2),
+                },
+                signers: vec![
+                    read_keypair_file(&default_keypair_file).unwrap().into(),
+                    read_keypair_file(&authorized_keypair_file).unwrap().into(),
+                    read_keypair_file(&new_authorized_keypair_file)
+                        .unwrap()
+                        .into(),
+                ],
+            }
+        );
+
         let (keypair_file, mut tmp_file) = make_tmp_file();
         let keypair = Keypair::new();
         write_keypair(&keypair, tmp_file.as_file_mut()).unwrap();
@@ -1064,5 +1159,43 @@ mod tests {
                 ],
             }
         );
+
+        // Test ShowVoteAccount subcommand
+        let test_show_vote_account =
+            test_commands
+                .clone()
+                .get_matches_from(vec!["test", "vote-account", &pubkey_string]);
+        assert_eq!(
+            parse_command(&test_show_vote_account, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::ShowVoteAccount {
+                    pubkey,
+                    use_lamports_unit: false,
+                    with_rewards: None,
+                },
+                signers: vec![],
+            }
+        );
+
+        // Test ShowVoteAccount subcommand with rewards
+        let test_show_vote_account = test_commands.clone().get_matches_from(vec![
+            "test",
+            "vote-account",
+            &pubkey_string,
+            "--with-rewards",
+            "--num-rewards-epochs",
+            "3",
+        ]);
+        assert_eq!(
+            parse_command(&test_show_vote_account, &default_signer, &mut None).unwrap(),
+            CliCommandInfo {
+                command: CliCommand::ShowVoteAccount {
+                    pubkey,
+                    use_lamports_unit: false,
+                    with_rewards: Some(3),
+                },
+                signers: vec![],
+            }
+        );
     }
 }
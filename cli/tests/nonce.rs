@@ -179,6 +179,7 @@ fn full_battery_tests(
         nonce_account,
         nonce_authority: index,
         new_authority: new_authority.pubkey(),
+        new_authority_signer: None,
     };
     process_command(&config_payer).unwrap();
 
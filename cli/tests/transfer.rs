@@ -192,6 +192,7 @@ fn test_transfer() {
         nonce_account: nonce_account.pubkey(),
         nonce_authority: 0,
         new_authority: offline_pubkey,
+        new_authority_signer: None,
     };
     process_command(&config).unwrap();
     check_recent_balance(49_975 - minimum_nonce_balance, &rpc_client, &sender_pubkey);
@@ -85,11 +85,13 @@ fn test_vote_authorize_and_withdraw() {
 
     // Authorize vote account withdrawal to another signer
     let withdraw_authority = Keypair::new();
-    config.signers = vec![&default_signer];
+    config.signers = vec![&default_signer, &withdraw_authority];
     config.command = CliCommand::VoteAuthorize {
         vote_account_pubkey,
         new_authorized_pubkey: withdraw_authority.pubkey(),
         vote_authorize: VoteAuthorize::Withdrawer,
+        authorized: 0,
+        new_authorized: Some(1),
     };
     process_command(&config).unwrap();
     let vote_account = rpc_client
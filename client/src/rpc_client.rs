@@ -4,15 +4,16 @@ use crate::{
     mock_sender::{MockSender, Mocks},
     rpc_config::RpcAccountInfoConfig,
     rpc_config::{
-        RpcGetConfirmedSignaturesForAddress2Config, RpcLargestAccountsConfig,
-        RpcProgramAccountsConfig, RpcSendTransactionConfig, RpcSimulateTransactionConfig,
-        RpcTokenAccountsFilter,
+        RpcBlockProductionConfig, RpcEpochConfig, RpcGetConfirmedSignaturesForAddress2Config,
+        RpcLargestAccountsConfig, RpcProgramAccountsConfig, RpcSendTransactionConfig,
+        RpcSimulateTransactionConfig, RpcTokenAccountsFilter,
     },
     rpc_request::{RpcError, RpcRequest, RpcResponseErrorData, TokenAccountsFilter},
     rpc_response::*,
     rpc_sender::RpcSender,
 };
 use bincode::serialize;
+use evm_rpc::{BlockId, Bytes, Hex, RPCBlock, RPCLog, RPCLogFilter, RPCReceipt, RPCTransaction};
 use indicatif::{ProgressBar, ProgressStyle};
 use log::*;
 use serde_json::{json, Value};
@@ -23,7 +24,7 @@ use solana_account_decoder::{
 use solana_sdk::{
     account::Account,
     clock::{
-        Slot, UnixTimestamp, DEFAULT_TICKS_PER_SECOND, DEFAULT_TICKS_PER_SLOT,
+        Epoch, Slot, UnixTimestamp, DEFAULT_TICKS_PER_SECOND, DEFAULT_TICKS_PER_SLOT,
         MAX_HASH_AGE_IN_SECONDS,
     },
     commitment_config::{CommitmentConfig, CommitmentLevel},
@@ -636,6 +637,24 @@ impl RpcClient {
         )
     }
 
+    /// Get block production for the current epoch
+    pub fn get_block_production(&self) -> RpcResult<RpcBlockProduction> {
+        self.send(RpcRequest::GetBlockProduction, Value::Null)
+    }
+
+    pub fn get_block_production_with_config(
+        &self,
+        config: RpcBlockProductionConfig,
+    ) -> RpcResult<RpcBlockProduction> {
+        let commitment = config.commitment.unwrap_or_default();
+        let commitment = self.maybe_map_commitment(commitment)?;
+        let config = RpcBlockProductionConfig {
+            commitment: Some(commitment),
+            ..config
+        };
+        self.send(RpcRequest::GetBlockProduction, json!([config]))
+    }
+
     pub fn get_epoch_schedule(&self) -> ClientResult<EpochSchedule> {
         self.send(RpcRequest::GetEpochSchedule, Value::Null)
     }
@@ -659,6 +678,28 @@ impl RpcClient {
         self.send(RpcRequest::GetInflationRate, Value::Null)
     }
 
+    /// Get inflation rewards of `addresses` for `epoch`, defaults to the previous epoch
+    pub fn get_inflation_reward(
+        &self,
+        addresses: &[Pubkey],
+        epoch: Option<Epoch>,
+    ) -> ClientResult<Vec<Option<RpcInflationReward>>> {
+        let addresses: Vec<_> = addresses
+            .iter()
+            .map(|address| address.to_string())
+            .collect();
+        self.send(
+            RpcRequest::GetInflationReward,
+            json!([
+                addresses,
+                RpcEpochConfig {
+                    epoch,
+                    commitment: Some(self.maybe_map_commitment(self.commitment_config)?),
+                }
+            ]),
+        )
+    }
+
     pub fn get_version(&self) -> ClientResult<RpcVersionInfo> {
         self.send(RpcRequest::GetVersion, Value::Null)
     }
@@ -1511,6 +1552,30 @@ impl RpcClient {
         .map(|h| h.0)
     }
 
+    pub fn get_evm_transaction_count_with_commitment(
+        &self,
+        address: &evm_state::Address,
+        commitment_config: CommitmentConfig,
+    ) -> ClientResult<evm_state::U256> {
+        self.send::<Hex<_>>(
+            RpcRequest::EthGetTransactionCount,
+            json!([Hex(*address), commitment_config.commitment]),
+        )
+        .map(|h| h.0)
+    }
+
+    pub fn get_evm_transaction_count_at_block(
+        &self,
+        address: &evm_state::Address,
+        block: BlockId,
+    ) -> ClientResult<evm_state::U256> {
+        self.send::<Hex<_>>(
+            RpcRequest::EthGetTransactionCount,
+            json!([Hex(*address), block]),
+        )
+        .map(|h| h.0)
+    }
+
     pub fn get_evm_balance(&self, address: &evm_state::Address) -> ClientResult<evm_state::U256> {
         self.send::<evm_rpc::Hex<_>>(
             RpcRequest::EthGetBalance,
@@ -1519,6 +1584,165 @@ impl RpcClient {
         .map(|h| h.0)
     }
 
+    pub fn get_evm_balance_with_commitment(
+        &self,
+        address: &evm_state::Address,
+        commitment_config: CommitmentConfig,
+    ) -> ClientResult<evm_state::U256> {
+        self.send::<Hex<_>>(
+            RpcRequest::EthGetBalance,
+            json!([Hex(*address), commitment_config.commitment]),
+        )
+        .map(|h| h.0)
+    }
+
+    pub fn get_evm_balance_at_block(
+        &self,
+        address: &evm_state::Address,
+        block: BlockId,
+    ) -> ClientResult<evm_state::U256> {
+        self.send::<Hex<_>>(RpcRequest::EthGetBalance, json!([Hex(*address), block]))
+            .map(|h| h.0)
+    }
+
+    pub fn get_evm_code(&self, address: &evm_state::Address) -> ClientResult<Vec<u8>> {
+        self.get_evm_code_at_block(address, BlockId::Latest)
+    }
+
+    pub fn get_evm_code_with_commitment(
+        &self,
+        address: &evm_state::Address,
+        commitment_config: CommitmentConfig,
+    ) -> ClientResult<Vec<u8>> {
+        self.send::<Bytes>(
+            RpcRequest::EthGetCode,
+            json!([Hex(*address), commitment_config.commitment]),
+        )
+        .map(|b| b.0)
+    }
+
+    pub fn get_evm_code_at_block(
+        &self,
+        address: &evm_state::Address,
+        block: BlockId,
+    ) -> ClientResult<Vec<u8>> {
+        self.send::<Bytes>(RpcRequest::EthGetCode, json!([Hex(*address), block]))
+            .map(|b| b.0)
+    }
+
+    pub fn get_evm_storage_at(
+        &self,
+        address: &evm_state::Address,
+        index: &evm_state::H256,
+    ) -> ClientResult<evm_state::H256> {
+        self.get_evm_storage_at_block(address, index, BlockId::Latest)
+    }
+
+    pub fn get_evm_storage_at_with_commitment(
+        &self,
+        address: &evm_state::Address,
+        index: &evm_state::H256,
+        commitment_config: CommitmentConfig,
+    ) -> ClientResult<evm_state::H256> {
+        self.send::<Hex<_>>(
+            RpcRequest::EthGetStorageAt,
+            json!([Hex(*address), Hex(*index), commitment_config.commitment]),
+        )
+        .map(|h| h.0)
+    }
+
+    pub fn get_evm_storage_at_block(
+        &self,
+        address: &evm_state::Address,
+        index: &evm_state::H256,
+        block: BlockId,
+    ) -> ClientResult<evm_state::H256> {
+        self.send::<Hex<_>>(
+            RpcRequest::EthGetStorageAt,
+            json!([Hex(*address), Hex(*index), block]),
+        )
+        .map(|h| h.0)
+    }
+
+    pub fn get_evm_block_number(&self) -> ClientResult<u64> {
+        self.send::<Hex<_>>(RpcRequest::EthBlockNumber, Value::Null)
+            .map(|h| h.0)
+    }
+
+    /// Returns the block with transaction hashes, or with full transactions if `full` is set
+    pub fn get_evm_block_by_number(
+        &self,
+        block: BlockId,
+        full: bool,
+    ) -> ClientResult<Option<RPCBlock>> {
+        self.send(RpcRequest::EthGetBlockByNumber, json!([block, full]))
+    }
+
+    pub fn get_evm_transaction_by_hash(
+        &self,
+        hash: &evm_state::H256,
+    ) -> ClientResult<Option<RPCTransaction>> {
+        self.send(RpcRequest::EthGetTransactionByHash, json!([Hex(*hash)]))
+    }
+
+    pub fn get_evm_transaction_receipt(
+        &self,
+        hash: &evm_state::H256,
+    ) -> ClientResult<Option<RPCReceipt>> {
+        self.send(RpcRequest::EthGetTransactionReceipt, json!([Hex(*hash)]))
+    }
+
+    pub fn get_evm_logs(&self, filter: &RPCLogFilter) -> ClientResult<Vec<RPCLog>> {
+        self.send(RpcRequest::EthGetLogs, json!([filter]))
+    }
+
+    /// Executes `tx` without creating a transaction and returns its output
+    pub fn evm_call(&self, tx: &RPCTransaction) -> ClientResult<Vec<u8>> {
+        self.evm_call_at_block(tx, BlockId::Latest)
+    }
+
+    pub fn evm_call_with_commitment(
+        &self,
+        tx: &RPCTransaction,
+        commitment_config: CommitmentConfig,
+    ) -> ClientResult<Vec<u8>> {
+        self.send::<Bytes>(
+            RpcRequest::EthCall,
+            json!([tx, commitment_config.commitment]),
+        )
+        .map(|b| b.0)
+    }
+
+    pub fn evm_call_at_block(&self, tx: &RPCTransaction, block: BlockId) -> ClientResult<Vec<u8>> {
+        self.send::<Bytes>(RpcRequest::EthCall, json!([tx, block]))
+            .map(|b| b.0)
+    }
+
+    pub fn evm_estimate_gas(&self, tx: &RPCTransaction) -> ClientResult<evm_state::Gas> {
+        self.evm_estimate_gas_at_block(tx, BlockId::Latest)
+    }
+
+    pub fn evm_estimate_gas_with_commitment(
+        &self,
+        tx: &RPCTransaction,
+        commitment_config: CommitmentConfig,
+    ) -> ClientResult<evm_state::Gas> {
+        self.send::<Hex<_>>(
+            RpcRequest::EthEstimateGas,
+            json!([tx, commitment_config.commitment]),
+        )
+        .map(|h| h.0)
+    }
+
+    pub fn evm_estimate_gas_at_block(
+        &self,
+        tx: &RPCTransaction,
+        block: BlockId,
+    ) -> ClientResult<evm_state::Gas> {
+        self.send::<Hex<_>>(RpcRequest::EthEstimateGas, json!([tx, block]))
+            .map(|h| h.0)
+    }
+
     pub fn send<T>(&self, request: RpcRequest, params: Value) -> ClientResult<T>
     where
         T: serde::de::DeserializeOwned,
@@ -1734,6 +1958,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_evm_requests() {
+        let mut mocks = Mocks::default();
+        mocks.insert(RpcRequest::EthBlockNumber, json!("0x2a"));
+        mocks.insert(RpcRequest::EthGetCode, json!("0x6001"));
+        mocks.insert(RpcRequest::EthEstimateGas, json!("0x5208"));
+        let rpc_client = RpcClient::new_mock_with_mocks("succeeds".to_string(), mocks);
+
+        assert_eq!(rpc_client.get_evm_block_number().unwrap(), 42);
+        assert_eq!(
+            rpc_client
+                .get_evm_code_with_commitment(
+                    &evm_state::Address::zero(),
+                    CommitmentConfig::confirmed()
+                )
+                .unwrap(),
+            vec![0x60, 0x01]
+        );
+        let tx = RPCTransaction {
+            from: None,
+            to: Some(Hex(evm_state::Address::zero())),
+            creates: None,
+            gas: None,
+            gas_price: None,
+            value: None,
+            data: None,
+            nonce: None,
+            hash: None,
+            block_hash: None,
+            block_number: None,
+            transaction_index: None,
+        };
+        assert_eq!(
+            rpc_client
+                .evm_estimate_gas_at_block(&tx, BlockId::Pending)
+                .unwrap(),
+            21000.into()
+        );
+        assert!(rpc_client
+            .get_evm_block_by_number(BlockId::Num(1), false)
+            .unwrap()
+            .is_none());
+        assert!(rpc_client
+            .get_evm_transaction_receipt(&evm_state::H256::zero())
+            .unwrap()
+            .is_none());
+    }
+
     #[test]
     fn test_rpc_client_thread() {
         let rpc_client = RpcClient::new_mock("succeeds".to_string());
@@ -571,6 +571,71 @@ impl SyncClient for ThinClient {
             .get_evm_balance(pubkey)
             .map_err(|e| e.into())
     }
+
+    fn get_evm_block_number(&self) -> TransportResult<u64> {
+        self.rpc_client()
+            .get_evm_block_number()
+            .map_err(|e| e.into())
+    }
+
+    fn get_evm_transaction_count(
+        &self,
+        address: &evm_state::Address,
+    ) -> TransportResult<evm_state::U256> {
+        self.rpc_client()
+            .get_evm_transaction_count(address)
+            .map_err(|e| e.into())
+    }
+
+    fn get_evm_code(&self, address: &evm_state::Address) -> TransportResult<Vec<u8>> {
+        self.rpc_client()
+            .get_evm_code(address)
+            .map_err(|e| e.into())
+    }
+
+    fn get_evm_storage_at(
+        &self,
+        address: &evm_state::Address,
+        index: &evm_state::H256,
+    ) -> TransportResult<evm_state::H256> {
+        self.rpc_client()
+            .get_evm_storage_at(address, index)
+            .map_err(|e| e.into())
+    }
+
+    fn get_evm_block_by_number(
+        &self,
+        block: evm_rpc::BlockId,
+        full: bool,
+    ) -> TransportResult<Option<evm_rpc::RPCBlock>> {
+        self.rpc_client()
+            .get_evm_block_by_number(block, full)
+            .map_err(|e| e.into())
+    }
+
+    fn get_evm_transaction_receipt(
+        &self,
+        hash: &evm_state::H256,
+    ) -> TransportResult<Option<evm_rpc::RPCReceipt>> {
+        self.rpc_client()
+            .get_evm_transaction_receipt(hash)
+            .map_err(|e| e.into())
+    }
+
+    fn get_evm_logs(
+        &self,
+        filter: &evm_rpc::RPCLogFilter,
+    ) -> TransportResult<Vec<evm_rpc::RPCLog>> {
+        self.rpc_client().get_evm_logs(filter).map_err(|e| e.into())
+    }
+
+    fn evm_call(&self, tx: &evm_rpc::RPCTransaction) -> TransportResult<Vec<u8>> {
+        self.rpc_client().evm_call(tx).map_err(|e| e.into())
+    }
+
+    fn evm_estimate_gas(&self, tx: &evm_rpc::RPCTransaction) -> TransportResult<evm_state::Gas> {
+        self.rpc_client().evm_estimate_gas(tx).map_err(|e| e.into())
+    }
 }
 
 impl AsyncClient for ThinClient {
@@ -155,9 +155,20 @@ impl AccountsHashVerifier {
             }
         }
 
-        if accounts_package.block_height % snapshot_interval_slots == 0 {
+        if accounts_package.is_incremental()
+            || accounts_package.block_height % snapshot_interval_slots == 0
+        {
             if let Some(pending_snapshot_package) = pending_snapshot_package.as_ref() {
-                *pending_snapshot_package.lock().unwrap() = Some(accounts_package);
+                let mut pending_snapshot_package = pending_snapshot_package.lock().unwrap();
+                // Incremental snapshots are useless without their base, so never replace
+                // a full snapshot that is still waiting to be archived
+                let keep_pending_full_snapshot = accounts_package.is_incremental()
+                    && pending_snapshot_package
+                        .as_ref()
+                        .map_or(false, |pending| !pending.is_incremental());
+                if !keep_pending_full_snapshot {
+                    *pending_snapshot_package = Some(accounts_package);
+                }
             }
         }
         cluster_info.push_accounts_hashes(hashes.clone());
@@ -259,6 +270,80 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_incremental_package_keeps_pending_full_package() {
+        use std::path::PathBuf;
+        use tempfile::TempDir;
+        let keypair = Keypair::new();
+
+        let contact_info = ContactInfo::new_localhost(&keypair.pubkey(), 0);
+        let cluster_info = ClusterInfo::new_with_invalid_keypair(contact_info);
+        let cluster_info = Arc::new(cluster_info);
+
+        let exit = Arc::new(AtomicBool::new(false));
+        let pending_snapshot_package = Some(PendingSnapshotPackage::default());
+        let mut hashes = vec![];
+        let make_package = |slot, incremental_snapshot_base_slot| AccountsPackage {
+            hash: hash(&[slot as u8]),
+            block_height: slot,
+            slot,
+            slot_deltas: vec![],
+            snapshot_links: TempDir::new().unwrap(),
+            tar_output_file: PathBuf::from("."),
+            storages: vec![],
+            archive_format: ArchiveFormat::TarBzip2,
+            snapshot_version: SnapshotVersion::default(),
+            incremental_snapshot_base_slot,
+        };
+        let pending_slot = || {
+            pending_snapshot_package
+                .as_ref()
+                .unwrap()
+                .lock()
+                .unwrap()
+                .as_ref()
+                .map(|package| (package.slot, package.incremental_snapshot_base_slot))
+        };
+
+        for (package, expected_pending) in vec![
+            (make_package(100, None), Some((100, None))),
+            (make_package(150, Some(100)), Some((100, None))),
+        ] {
+            AccountsHashVerifier::process_accounts_package(
+                package,
+                &cluster_info,
+                &None,
+                false,
+                &pending_snapshot_package,
+                &mut hashes,
+                &exit,
+                0,
+                100,
+            );
+            assert_eq!(pending_slot(), expected_pending);
+        }
+
+        // Once the full package is taken by the packager, incremental one can be pending
+        pending_snapshot_package
+            .as_ref()
+            .unwrap()
+            .lock()
+            .unwrap()
+            .take();
+        AccountsHashVerifier::process_accounts_package(
+            make_package(170, Some(100)),
+            &cluster_info,
+            &None,
+            false,
+            &pending_snapshot_package,
+            &mut hashes,
+            &exit,
+            0,
+            100,
+        );
+        assert_eq!(pending_slot(), Some((170, Some(100))));
+    }
+
     #[test]
     fn test_max_hashes() {
         solana_logger::setup();
@@ -285,6 +370,7 @@ mod tests {
                 storages: vec![],
                 archive_format: ArchiveFormat::TarBzip2,
                 snapshot_version: SnapshotVersion::default(),
+                incremental_snapshot_base_slot: None,
             };
 
             AccountsHashVerifier::process_accounts_package(
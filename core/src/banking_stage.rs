@@ -696,6 +696,7 @@ impl BankingStage {
             results,
             inner_instructions,
             transaction_logs,
+            transaction_return_data,
             mut retryable_txs,
             tx_count,
             signature_count,
@@ -759,6 +760,7 @@ impl BankingStage {
                     TransactionTokenBalancesSet::new(pre_token_balances, post_token_balances),
                     inner_instructions,
                     transaction_logs,
+                    transaction_return_data,
                     transaction_status_sender,
                 );
             }
@@ -2303,6 +2305,9 @@ mod tests {
             let (transaction_status_sender, transaction_status_receiver) = unbounded();
             let transaction_status_service = TransactionStatusService::new(
                 transaction_status_receiver,
+                Arc::new(AtomicU64::default()),
+                true,
+                None,
                 blockstore.clone(),
                 &Arc::new(AtomicBool::new(false)),
             );
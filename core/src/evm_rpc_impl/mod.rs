@@ -7,7 +7,7 @@ use evm_rpc::{
     basic::BasicERPC,
     chain_mock::ChainMockERPC,
     error::{Error, IntoNativeRpcError},
-    Bytes, Either, Hex, RPCBlock, RPCLog, RPCLogFilter, RPCReceipt, RPCTopicFilter, RPCTransaction,
+    Bytes, Hex, RPCBlock, RPCLog, RPCLogFilter, RPCReceipt, RPCTopicFilter, RPCTransaction,
 };
 use evm_state::{AccountProvider, Address, Gas, LogFilter, H256, U256};
 use solana_runtime::bank::Bank;
@@ -30,7 +30,7 @@ fn block_to_bank_and_root(
             "pending" => Some(CommitmentLevel::Processed),
             v => {
                 // Try to parse newest version of block commitment.
-                if let Ok(c) = serde_json::from_str::<CommitmentLevel>(v) {
+                if let Ok(c) = serde_json::from_value::<CommitmentLevel>(serde_json::json!(v)) {
                     Some(c)
                 } else {
                     // Probably user provide specific slot number, we didn't support bank from future, so just return default.
@@ -144,49 +144,7 @@ impl ChainMockERPC for ChainMockErpcImpl {
             Ok(b) => b,
         };
 
-        let block_hash = block.header.hash();
-        let parent_hash = block.header.parent_hash;
-        let transactions = if full {
-            let txs = block
-                .transactions
-                .into_iter()
-                .map(|(_k, v)| v)
-                .filter_map(|receipt| RPCTransaction::new_from_receipt(receipt, block_hash).ok())
-                .collect();
-            Either::Right(txs)
-        } else {
-            let txs = block
-                .transactions
-                .into_iter()
-                .map(|(k, _v)| Hex(k))
-                .collect();
-            Either::Left(txs)
-        };
-
-        let result = RPCBlock {
-            number: U256::from(block.header.block_number).into(),
-            hash: block_hash.into(),
-            parent_hash: parent_hash.into(),
-            size: 0x100.into(),
-            gas_limit: Hex(block.header.gas_limit.into()),
-            gas_used: Hex(block.header.gas_used.into()),
-            timestamp: Hex(block.header.timestamp),
-            transactions,
-            nonce: block.header.native_chain_slot,
-            mix_hash: block.header.native_chain_hash.into(),
-            logs_bloom: block.header.logs_bloom, // H2048
-            transactions_root: Hex(block.header.transactions_root),
-            state_root: Hex(block.header.state_root),
-            receipts_root: Hex(block.header.receipts_root),
-            is_finalized: confirmed,
-            miner: Address::zero().into(),
-            difficulty: U256::zero().into(),
-            total_difficulty: U256::zero().into(),
-            extra_data: b"Velas EVM compatibility layer...".to_vec().into(),
-            sha3_uncles: H256::zero().into(),
-            uncles: vec![],
-        };
-        Ok(Some(result))
+        Ok(Some(RPCBlock::new_from_block(block, confirmed, full)))
     }
 
     fn block_transaction_count_by_number(
@@ -459,7 +417,7 @@ fn call(
     };
     let estimate_config = evm_state::EvmConfig {
         estimate: true,
-        ..Default::default()
+        ..bank.evm_params().evm_config(bank.evm_chain_id)
     };
 
     let last_hashes = bank.evm_hashes();
@@ -1,4 +1,5 @@
 use crossbeam_channel::{Receiver, RecvTimeoutError, Sender};
+use solana_accountsdb_plugin_manager::transaction_notifier::TransactionNotifierLock;
 use solana_ledger::blockstore::Blockstore;
 use std::{
     sync::{
@@ -22,6 +23,8 @@ impl EvmRecorderService {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(
         evm_recorder_receiver: EvmRecorderReceiver,
+        enable_rpc_transaction_history: bool,
+        transaction_notifier: Option<TransactionNotifierLock>,
         blockstore: Arc<Blockstore>,
         exit: &Arc<AtomicBool>,
     ) -> Self {
@@ -32,9 +35,12 @@ impl EvmRecorderService {
                 if exit.load(Ordering::Relaxed) {
                     break;
                 }
-                if let Err(RecvTimeoutError::Disconnected) =
-                    Self::write_evm_record(&evm_recorder_receiver, &blockstore)
-                {
+                if let Err(RecvTimeoutError::Disconnected) = Self::write_evm_record(
+                    &evm_recorder_receiver,
+                    enable_rpc_transaction_history,
+                    &transaction_notifier,
+                    &blockstore,
+                ) {
                     break;
                 }
             })
@@ -44,19 +50,34 @@ impl EvmRecorderService {
 
     fn write_evm_record(
         evm_records_receiver: &EvmRecorderReceiver,
+        enable_rpc_transaction_history: bool,
+        transaction_notifier: &Option<TransactionNotifierLock>,
         blockstore: &Arc<Blockstore>,
     ) -> Result<(), RecvTimeoutError> {
         let block = evm_records_receiver.recv_timeout(Duration::from_secs(1))?;
         let block_header = block.header;
-        debug!("Writing evm block num = {}", block_header.block_number);
-        blockstore
-            .write_evm_block_header(block_header.native_chain_slot, &block_header)
-            .expect("Expected database write to succed");
+        debug!(
+            "Writing evm block num = {}, slot = {}",
+            block_header.block_number, block_header.native_chain_slot
+        );
+        if let Some(transaction_notifier) = transaction_notifier.as_ref() {
+            let transaction_notifier = transaction_notifier.read().unwrap();
+            for (hash, tx) in &block.transactions {
+                transaction_notifier.notify_evm_receipt(block_header.native_chain_slot, hash, tx);
+            }
+        }
+        if !enable_rpc_transaction_history {
+            return Ok(());
+        }
+        // Write receipts first, so block is never visible without its transactions.
         for (hash, tx) in block.transactions {
             blockstore
-                .write_evm_transaction(block_header.block_number, hash, tx)
+                .write_evm_transaction(block_header.native_chain_slot, hash, tx)
                 .expect("Expected database write to succed");
         }
+        blockstore
+            .write_evm_block_header(block_header.native_chain_slot, &block_header)
+            .expect("Expected database write to succed");
         Ok(())
     }
 
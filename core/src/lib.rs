@@ -71,6 +71,7 @@ pub mod serve_repair_service;
 pub mod sigverify;
 pub mod sigverify_shreds;
 pub mod sigverify_stage;
+pub mod slot_status_observer;
 pub mod snapshot_packager_service;
 pub mod test_validator;
 pub mod tpu;
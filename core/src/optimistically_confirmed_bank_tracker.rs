@@ -4,6 +4,7 @@
 
 use crate::rpc_subscriptions::RpcSubscriptions;
 use crossbeam_channel::{Receiver, RecvTimeoutError, Sender};
+use solana_accountsdb_plugin_manager::slot_status_notifier::SlotStatusNotifier;
 use solana_client::rpc_response::SlotUpdate;
 use solana_runtime::{bank::Bank, bank_forks::BankForks};
 use solana_sdk::{clock::Slot, timing::timestamp};
@@ -61,6 +62,7 @@ impl OptimisticallyConfirmedBankTracker {
         bank_forks: Arc<RwLock<BankForks>>,
         optimistically_confirmed_bank: Arc<RwLock<OptimisticallyConfirmedBank>>,
         subscriptions: Arc<RpcSubscriptions>,
+        slot_status_notifier: Option<SlotStatusNotifier>,
     ) -> Self {
         let exit_ = exit.clone();
         let mut pending_optimistically_confirmed_banks = HashSet::new();
@@ -77,6 +79,7 @@ impl OptimisticallyConfirmedBankTracker {
                     &optimistically_confirmed_bank,
                     &subscriptions,
                     &mut pending_optimistically_confirmed_banks,
+                    &slot_status_notifier,
                 ) {
                     break;
                 }
@@ -91,8 +94,12 @@ impl OptimisticallyConfirmedBankTracker {
         optimistically_confirmed_bank: &Arc<RwLock<OptimisticallyConfirmedBank>>,
         subscriptions: &Arc<RpcSubscriptions>,
         mut pending_optimistically_confirmed_banks: &mut HashSet<Slot>,
+        slot_status_notifier: &Option<SlotStatusNotifier>,
     ) -> Result<(), RecvTimeoutError> {
         let notification = receiver.recv_timeout(Duration::from_secs(1))?;
+        if let Some(slot_status_notifier) = slot_status_notifier {
+            Self::notify_slot_status(slot_status_notifier, &notification, bank_forks);
+        }
         Self::process_notification(
             notification,
             bank_forks,
@@ -103,6 +110,31 @@ impl OptimisticallyConfirmedBankTracker {
         Ok(())
     }
 
+    /// Forwards the slot status carried by `notification` to the accountsdb plugins
+    pub(crate) fn notify_slot_status(
+        slot_status_notifier: &SlotStatusNotifier,
+        notification: &BankNotification,
+        bank_forks: &Arc<RwLock<BankForks>>,
+    ) {
+        let slot_status_notifier = slot_status_notifier.read().unwrap();
+        match notification {
+            BankNotification::OptimisticallyConfirmed(slot) => {
+                let parent = bank_forks
+                    .read()
+                    .unwrap()
+                    .get(*slot)
+                    .map(|bank| bank.parent_slot());
+                slot_status_notifier.notify_slot_confirmed(*slot, parent);
+            }
+            BankNotification::Frozen(bank) => {
+                slot_status_notifier.notify_slot_processed(bank.slot(), Some(bank.parent_slot()));
+            }
+            BankNotification::Root(bank) => {
+                slot_status_notifier.notify_slot_rooted(bank.slot(), Some(bank.parent_slot()));
+            }
+        }
+    }
+
     pub(crate) fn process_notification(
         notification: BankNotification,
         bank_forks: &Arc<RwLock<BankForks>>,
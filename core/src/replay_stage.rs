@@ -19,6 +19,7 @@ use crate::{
     rewards_recorder_service::RewardsRecorderSender,
     rpc_subscriptions::RpcSubscriptions,
 };
+use solana_client::rpc_response::RpcEvmBlockReplaced;
 use solana_ledger::{
     block_error::BlockError,
     blockstore::Blockstore,
@@ -335,6 +336,7 @@ impl ReplayStage {
                         &bank_notification_sender,
                         &rewards_recorder_sender,
                         &evm_block_recorder_sender,
+                        &subscriptions,
                     );
                     replay_active_banks_time.stop();
                     Self::report_memory(&allocated, "replay_active_banks", start);
@@ -1318,6 +1320,7 @@ impl ReplayStage {
         bank_notification_sender: &Option<BankNotificationSender>,
         rewards_recorder_sender: &Option<RewardsRecorderSender>,
         evm_block_recorder_sender: &Option<EvmRecorderSender>,
+        subscriptions: &Arc<RpcSubscriptions>,
     ) -> bool {
         let mut did_complete_bank = false;
         let mut tx_count = 0;
@@ -1387,6 +1390,12 @@ impl ReplayStage {
                     did_complete_bank = true;
                     info!("bank frozen: {}", bank.slot());
                     bank.freeze();
+                    if let Some(transaction_status_sender) = &transaction_status_sender {
+                        blockstore_processor::send_transaction_status_freeze_message(
+                            &bank,
+                            transaction_status_sender,
+                        );
+                    }
                     heaviest_subtree_fork_choice
                         .add_new_leaf_slot(bank.slot(), Some(bank.parent_slot()));
                     if let Some(sender) = bank_notification_sender {
@@ -1398,7 +1407,12 @@ impl ReplayStage {
                     }
 
                     Self::record_rewards(&bank, &rewards_recorder_sender);
-                    Self::record_evm_block(&bank, &evm_block_recorder_sender);
+                    Self::record_evm_block(
+                        &bank,
+                        blockstore,
+                        subscriptions,
+                        &evm_block_recorder_sender,
+                    );
                 } else {
                     Self::mark_dead_slot(
                         blockstore,
@@ -1955,10 +1969,16 @@ impl ReplayStage {
         }
     }
 
-    fn record_evm_block(bank: &Bank, evm_block_recorder_sender: &Option<EvmRecorderSender>) {
+    fn record_evm_block(
+        bank: &Bank,
+        blockstore: &Blockstore,
+        subscriptions: &RpcSubscriptions,
+        evm_block_recorder_sender: &Option<EvmRecorderSender>,
+    ) {
         if let Some(evm_block_recorder_sender) = evm_block_recorder_sender {
             let block = bank.evm_block();
             if let Some(block) = block {
+                Self::notify_replaced_evm_block(blockstore, subscriptions, &block.header);
                 evm_block_recorder_sender
                     .send(block)
                     .unwrap_or_else(|err| warn!("evm_block_recorder_sender failed: {:?}", err));
@@ -1966,6 +1986,50 @@ impl ReplayStage {
         }
     }
 
+    /// Notify rpc subscribers, if unconfirmed evm block that was visible through rpc,
+    /// is going to be replaced by block from another fork, produced at higher or lower slot.
+    fn notify_replaced_evm_block(
+        blockstore: &Blockstore,
+        subscriptions: &RpcSubscriptions,
+        header: &evm_state::BlockHeader,
+    ) {
+        let seen = match blockstore.read_evm_block_header(header.block_number) {
+            Ok(Some((seen, false))) => seen,
+            Ok(_) => return,
+            Err(err) => {
+                warn!(
+                    "Failed to read evm block {}: {:?}",
+                    header.block_number, err
+                );
+                return;
+            }
+        };
+        if seen.native_chain_slot == header.native_chain_slot {
+            return;
+        }
+        let (hash, replaced_by_hash) = (seen.hash(), header.hash());
+        if hash == replaced_by_hash {
+            return;
+        }
+        info!(
+            "Evm block {} from slot {} replaced by block from slot {}",
+            header.block_number, seen.native_chain_slot, header.native_chain_slot
+        );
+        datapoint_info!(
+            "evm-block-replaced",
+            ("block_number", header.block_number, i64),
+            ("slot", seen.native_chain_slot, i64),
+            ("replaced_by_slot", header.native_chain_slot, i64)
+        );
+        subscriptions.notify_evm_block_replaced(RpcEvmBlockReplaced {
+            block_number: header.block_number,
+            hash: format!("{:#x}", hash),
+            slot: seen.native_chain_slot,
+            replaced_by_hash: format!("{:#x}", replaced_by_hash),
+            replaced_by_slot: header.native_chain_slot,
+        });
+    }
+
     fn cache_block_times(
         blockstore: &Arc<Blockstore>,
         bank_forks: &Arc<RwLock<BankForks>>,
@@ -2060,7 +2124,7 @@ pub(crate) mod tests {
     use std::{
         fs::remove_dir_all,
         iter,
-        sync::{Arc, RwLock},
+        sync::{atomic::AtomicU64, Arc, RwLock},
     };
     use trees::tr;
 
@@ -2733,6 +2797,9 @@ pub(crate) mod tests {
         let (replay_vote_sender, _replay_vote_receiver) = unbounded();
         let transaction_status_service = TransactionStatusService::new(
             transaction_status_receiver,
+            Arc::new(AtomicU64::default()),
+            true,
+            None,
             blockstore,
             &Arc::new(AtomicBool::new(false)),
         );
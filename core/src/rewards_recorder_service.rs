@@ -55,6 +55,7 @@ impl RewardsRecorderService {
                 lamports: reward_info.lamports,
                 post_balance: reward_info.post_balance,
                 reward_type: Some(reward_info.reward_type),
+                commission: reward_info.commission,
             })
             .collect();
 
@@ -36,13 +36,16 @@ use solana_client::{
     rpc_response::*,
 };
 use solana_faucet::faucet::request_airdrop_transaction;
-use solana_ledger::{blockstore::Blockstore, blockstore_db::BlockstoreError, get_tmp_ledger_path};
+use solana_ledger::{
+    blockstore::Blockstore, blockstore_db::BlockstoreError, get_tmp_ledger_path,
+    leader_schedule_cache::LeaderScheduleCache,
+};
 use solana_metrics::inc_new_counter_info;
 use solana_perf::packet::PACKET_DATA_SIZE;
 use solana_runtime::{
     accounts::AccountAddressFilter,
     accounts_index::{AccountIndex, IndexKey},
-    bank::Bank,
+    bank::{Bank, TransactionSimulationResult},
     bank_forks::{BankForks, SnapshotConfig},
     commitment::{BlockCommitmentArray, BlockCommitmentCache, CommitmentSlots},
     inline_spl_token_v2_0::{SPL_TOKEN_ACCOUNT_MINT_OFFSET, SPL_TOKEN_ACCOUNT_OWNER_OFFSET},
@@ -66,8 +69,8 @@ use solana_sdk::{
 };
 use solana_stake_program::stake_state::StakeState;
 use solana_transaction_status::{
-    EncodedConfirmedBlock, EncodedConfirmedTransaction, TransactionConfirmationStatus,
-    TransactionStatus, UiTransactionEncoding,
+    EncodedConfirmedBlock, EncodedConfirmedTransaction, Reward, RewardType,
+    TransactionConfirmationStatus, TransactionStatus, UiTransactionEncoding,
 };
 use solana_vote_program::vote_state::{VoteState, MAX_LOCKOUT_HISTORY};
 use spl_token_v2_0::{
@@ -140,6 +143,7 @@ pub struct JsonRpcRequestProcessor {
     optimistically_confirmed_bank: Arc<RwLock<OptimisticallyConfirmedBank>>,
     largest_accounts_cache: Arc<RwLock<LargestAccountsCache>>,
     max_slots: Arc<MaxSlots>,
+    leader_schedule_cache: Arc<LeaderScheduleCache>,
 }
 impl Metadata for JsonRpcRequestProcessor {}
 
@@ -225,6 +229,7 @@ impl JsonRpcRequestProcessor {
         optimistically_confirmed_bank: Arc<RwLock<OptimisticallyConfirmedBank>>,
         largest_accounts_cache: Arc<RwLock<LargestAccountsCache>>,
         max_slots: Arc<MaxSlots>,
+        leader_schedule_cache: Arc<LeaderScheduleCache>,
     ) -> (Self, Receiver<TransactionInfo>) {
         let (sender, receiver) = channel();
         (
@@ -244,6 +249,7 @@ impl JsonRpcRequestProcessor {
                 optimistically_confirmed_bank,
                 largest_accounts_cache,
                 max_slots,
+                leader_schedule_cache,
             },
             receiver,
         )
@@ -285,6 +291,7 @@ impl JsonRpcRequestProcessor {
             })),
             largest_accounts_cache: Arc::new(RwLock::new(LargestAccountsCache::new(30))),
             max_slots: Arc::new(MaxSlots::default()),
+            leader_schedule_cache: Arc::new(LeaderScheduleCache::new_from_bank(bank)),
         }
     }
 
@@ -393,6 +400,73 @@ impl JsonRpcRequestProcessor {
         }
     }
 
+    pub fn get_inflation_reward(
+        &self,
+        addresses: Vec<Pubkey>,
+        config: Option<RpcEpochConfig>,
+    ) -> Result<Vec<Option<RpcInflationReward>>> {
+        let config = config.unwrap_or_default();
+        let epoch_schedule = self.get_epoch_schedule();
+        let epoch = config
+            .epoch
+            .unwrap_or_else(|| self.bank(config.commitment).epoch().saturating_sub(1));
+
+        // Rewards for this epoch are found in the first confirmed block of the next epoch
+        let first_slot_in_epoch = epoch_schedule.get_first_slot_in_epoch(epoch.saturating_add(1));
+        let first_available_block = self.get_first_available_block();
+        if first_slot_in_epoch < first_available_block {
+            return Err(RpcCustomError::BlockCleanedUp {
+                slot: first_slot_in_epoch,
+                first_available_block,
+            }
+            .into());
+        }
+
+        let first_confirmed_block_in_epoch = *self
+            .get_confirmed_blocks_with_limit(first_slot_in_epoch, 1)?
+            .get(0)
+            .ok_or(RpcCustomError::BlockNotAvailable {
+                slot: first_slot_in_epoch,
+            })?;
+        let first_confirmed_block = self
+            .get_confirmed_block(
+                first_confirmed_block_in_epoch,
+                Some(UiTransactionEncoding::Base64),
+            )?
+            .ok_or(RpcCustomError::BlockNotAvailable {
+                slot: first_confirmed_block_in_epoch,
+            })?;
+
+        let addresses: Vec<String> = addresses
+            .into_iter()
+            .map(|pubkey| pubkey.to_string())
+            .collect();
+        let rewards: HashMap<String, Reward> = first_confirmed_block
+            .rewards
+            .into_iter()
+            .filter(|reward| {
+                matches!(
+                    reward.reward_type,
+                    Some(RewardType::Staking) | Some(RewardType::Voting)
+                ) && addresses.contains(&reward.pubkey)
+            })
+            .map(|reward| (reward.pubkey.clone(), reward))
+            .collect();
+
+        Ok(addresses
+            .iter()
+            .map(|address| {
+                rewards.get(address).map(|reward| RpcInflationReward {
+                    epoch,
+                    effective_slot: first_confirmed_block_in_epoch,
+                    amount: reward.lamports.abs() as u64,
+                    post_balance: reward.post_balance,
+                    commission: reward.commission,
+                })
+            })
+            .collect())
+    }
+
     pub fn get_epoch_schedule(&self) -> EpochSchedule {
         // Since epoch schedule data comes from the genesis config, any commitment level should be
         // fine
@@ -886,6 +960,100 @@ impl JsonRpcRequestProcessor {
             .collect())
     }
 
+    pub fn get_block_production(
+        &self,
+        config: Option<RpcBlockProductionConfig>,
+    ) -> Result<RpcResponse<RpcBlockProduction>> {
+        let config = config.unwrap_or_default();
+        let filter_by_identity = config.identity.map(verify_pubkey).transpose()?;
+
+        let bank = self.bank(config.commitment);
+        let lowest_blockstore_slot = self.blockstore.lowest_slot();
+        let (first_slot, last_slot) = match config.range {
+            // Ledger may start in the middle of the current epoch, e.g. after boot from a snapshot
+            None => (
+                max(
+                    bank.epoch_schedule().get_first_slot_in_epoch(bank.epoch()),
+                    lowest_blockstore_slot,
+                ),
+                bank.slot(),
+            ),
+            Some(range) => (
+                range.first_slot,
+                range.last_slot.unwrap_or_else(|| bank.slot()),
+            ),
+        };
+        if last_slot < first_slot {
+            return Err(Error::invalid_params(format!(
+                "lastSlot, {}, cannot be less than firstSlot, {}",
+                last_slot, first_slot
+            )));
+        }
+        if last_slot > bank.slot() {
+            return Err(Error::invalid_params(format!(
+                "lastSlot, {}, is too large; max {}",
+                last_slot,
+                bank.slot()
+            )));
+        }
+        if last_slot - first_slot > MAX_GET_CONFIRMED_BLOCKS_RANGE {
+            return Err(Error::invalid_params(format!(
+                "Slot range too large; max {}",
+                MAX_GET_CONFIRMED_BLOCKS_RANGE
+            )));
+        }
+        if first_slot < lowest_blockstore_slot {
+            return Err(Error::invalid_params(format!(
+                "firstSlot, {}, is too small; min {}",
+                first_slot, lowest_blockstore_slot
+            )));
+        }
+
+        // Slots above the root are not in the Root column yet, they are ancestors of the bank.
+        let rooted_blocks: HashSet<Slot> = self
+            .blockstore
+            .rooted_slot_iterator(first_slot)
+            .map_err(|_| Error::internal_error())?
+            .take_while(|&slot| slot <= last_slot)
+            .collect();
+
+        // Skipping slots without a known leader would undercount leader slots, reject the range instead
+        let mut block_production: HashMap<Pubkey, (usize, usize)> = HashMap::new();
+        for slot in first_slot..=last_slot {
+            let identity = self
+                .leader_schedule_cache
+                .slot_leader_at(slot, Some(&bank))
+                .ok_or_else(|| {
+                    Error::invalid_params(format!(
+                        "Leader schedule for slot {} is not available",
+                        slot
+                    ))
+                })?;
+            if filter_by_identity.map_or(false, |filter| filter != identity) {
+                continue;
+            }
+            let entry = block_production.entry(identity).or_default();
+            entry.0 += 1; // leader slots
+            if rooted_blocks.contains(&slot) || bank.ancestors.contains_key(&slot) {
+                entry.1 += 1; // blocks produced
+            }
+        }
+
+        Ok(new_response(
+            &bank,
+            RpcBlockProduction {
+                by_identity: block_production
+                    .into_iter()
+                    .map(|(identity, production)| (identity.to_string(), production))
+                    .collect(),
+                range: RpcBlockProductionRange {
+                    first_slot,
+                    last_slot,
+                },
+            },
+        ))
+    }
+
     pub fn get_block_time(&self, slot: Slot) -> Result<Option<UnixTimestamp>> {
         if slot
             <= self
@@ -1864,6 +2032,14 @@ pub trait RpcSol {
     #[rpc(meta, name = "getInflationRate")]
     fn get_inflation_rate(&self, meta: Self::Metadata) -> Result<RpcInflationRate>;
 
+    #[rpc(meta, name = "getInflationReward")]
+    fn get_inflation_reward(
+        &self,
+        meta: Self::Metadata,
+        address_strs: Vec<String>,
+        config: Option<RpcEpochConfig>,
+    ) -> Result<Vec<Option<RpcInflationReward>>>;
+
     #[rpc(meta, name = "getEpochSchedule")]
     fn get_epoch_schedule(&self, meta: Self::Metadata) -> Result<EpochSchedule>;
 
@@ -1905,6 +2081,13 @@ pub trait RpcSol {
     #[rpc(meta, name = "getHealth")]
     fn get_health(&self, meta: Self::Metadata) -> Result<String>;
 
+    #[rpc(meta, name = "getBlockProduction")]
+    fn get_block_production(
+        &self,
+        meta: Self::Metadata,
+        config: Option<RpcBlockProductionConfig>,
+    ) -> Result<RpcResponse<RpcBlockProduction>>;
+
     #[rpc(meta, name = "getLeaderSchedule")]
     fn get_leader_schedule(
         &self,
@@ -2295,6 +2478,35 @@ impl RpcSol for RpcSolImpl {
         Ok(meta.get_inflation_rate())
     }
 
+    fn get_inflation_reward(
+        &self,
+        meta: Self::Metadata,
+        address_strs: Vec<String>,
+        config: Option<RpcEpochConfig>,
+    ) -> Result<Vec<Option<RpcInflationReward>>> {
+        debug!(
+            "get_inflation_reward rpc request received: {:?}",
+            address_strs.len()
+        );
+
+        let max_multiple_accounts = meta
+            .config
+            .max_multiple_accounts
+            .unwrap_or(MAX_MULTIPLE_ACCOUNTS);
+        if address_strs.len() > max_multiple_accounts {
+            return Err(Error::invalid_params(format!(
+                "Too many inputs provided; max {}",
+                max_multiple_accounts
+            )));
+        }
+        let mut addresses: Vec<Pubkey> = vec![];
+        for address_str in address_strs {
+            addresses.push(verify_pubkey(address_str)?);
+        }
+
+        meta.get_inflation_reward(addresses, config)
+    }
+
     fn get_epoch_schedule(&self, meta: Self::Metadata) -> Result<EpochSchedule> {
         debug!("get_epoch_schedule rpc request received");
         Ok(meta.get_epoch_schedule())
@@ -2417,6 +2629,15 @@ impl RpcSol for RpcSolImpl {
         }
     }
 
+    fn get_block_production(
+        &self,
+        meta: Self::Metadata,
+        config: Option<RpcBlockProductionConfig>,
+    ) -> Result<RpcResponse<RpcBlockProduction>> {
+        debug!("get_block_production rpc request received");
+        meta.get_block_production(config)
+    }
+
     fn get_leader_schedule(
         &self,
         meta: Self::Metadata,
@@ -2695,12 +2916,23 @@ impl RpcSol for RpcSolImpl {
                 }
             }
 
-            if let (Err(err), logs) = preflight_bank.simulate_transaction(transaction.clone()) {
+            if let TransactionSimulationResult {
+                result: Err(err),
+                logs,
+                units_consumed,
+                return_data,
+                ..
+            } = preflight_bank.simulate_transaction(transaction.clone())
+            {
                 return Err(RpcCustomError::SendTransactionPreflightFailure {
                     message: format!("Transaction simulation failed: {}", err),
                     result: RpcSimulateTransactionResult {
                         err: Some(err),
                         logs: Some(logs),
+                        accounts: None,
+                        units_consumed: Some(units_consumed),
+                        evm_receipts: None,
+                        return_data: return_data.map(|return_data| return_data.into()),
                     },
                 }
                 .into());
@@ -2725,22 +2957,101 @@ impl RpcSol for RpcSolImpl {
         debug!("simulate_transaction rpc request received");
         let config = config.unwrap_or_default();
         let encoding = config.encoding.unwrap_or(UiTransactionEncoding::Base58);
-        let (_, transaction) = deserialize_transaction(data, encoding)?;
+        let (_, mut transaction) = deserialize_transaction(data, encoding)?;
 
         if config.sig_verify {
+            if config.replace_recent_blockhash {
+                return Err(Error::invalid_params(
+                    "sigVerify may not be used with replaceRecentBlockhash",
+                ));
+            }
+
             if let Err(e) = verify_transaction(&transaction) {
                 return Err(e);
             }
         }
 
         let bank = &*meta.bank(config.commitment);
-        let (result, logs) = bank.simulate_transaction(transaction);
+        if config.replace_recent_blockhash {
+            transaction.message.recent_blockhash = bank.last_blockhash();
+        }
+
+        let TransactionSimulationResult {
+            result,
+            logs,
+            post_simulation_accounts,
+            units_consumed,
+            evm_receipts,
+            return_data,
+        } = bank.simulate_transaction(transaction);
+
+        let accounts = if let Some(config_accounts) = config.accounts {
+            let accounts_encoding = config_accounts
+                .encoding
+                .unwrap_or(UiAccountEncoding::Base64);
+
+            if accounts_encoding == UiAccountEncoding::Binary
+                || accounts_encoding == UiAccountEncoding::Base58
+            {
+                return Err(Error::invalid_params("base58 encoding not supported"));
+            }
+
+            if config_accounts.addresses.len() > post_simulation_accounts.len() {
+                return Err(Error::invalid_params(format!(
+                    "Too many accounts provided; max {}",
+                    post_simulation_accounts.len()
+                )));
+            }
+
+            let mut accounts = vec![];
+            for address in config_accounts.addresses {
+                let address = verify_pubkey(address)?;
+                accounts.push(
+                    post_simulation_accounts
+                        .iter()
+                        .find(|(pubkey, _account)| *pubkey == address)
+                        .map(|(pubkey, account)| {
+                            UiAccount::encode(
+                                pubkey,
+                                account.clone(),
+                                accounts_encoding.clone(),
+                                None,
+                                None,
+                            )
+                        }),
+                );
+            }
+            Some(accounts)
+        } else {
+            None
+        };
+
+        let evm_receipts = if evm_receipts.is_empty() {
+            None
+        } else {
+            let mut receipts = Vec::with_capacity(evm_receipts.len());
+            for (receipt, return_data) in evm_receipts {
+                receipts.push(RpcSimulateEvmReceipt {
+                    // Simulated transactions are not included in any block.
+                    receipt: evm_rpc::RPCReceipt::new_from_receipt(
+                        receipt,
+                        evm_state::H256::zero(),
+                    )?,
+                    return_data: evm_rpc::Bytes(return_data),
+                });
+            }
+            Some(receipts)
+        };
 
         Ok(new_response(
             &bank,
             RpcSimulateTransactionResult {
                 err: result.err(),
                 logs: Some(logs),
+                accounts,
+                units_consumed: Some(units_consumed),
+                evm_receipts,
+                return_data: return_data.map(|return_data| return_data.into()),
             },
         ))
     }
@@ -3295,6 +3606,9 @@ pub mod tests {
             OptimisticallyConfirmedBank::locked_from_bank_forks_root(&bank_forks),
             Arc::new(RwLock::new(LargestAccountsCache::new(30))),
             max_slots,
+            Arc::new(LeaderScheduleCache::new_from_bank(
+                &bank_forks.read().unwrap().root_bank(),
+            )),
         );
         SendTransactionService::new(tpu_address, &bank_forks, None, receiver, 1000, 1);
 
@@ -4220,10 +4534,17 @@ pub mod tests {
             "jsonrpc": "2.0",
             "result": {
                 "context":{"slot":0},
-                "value":{"err":null, "logs":[
-                    "Program 11111111111111111111111111111111 invoke [1]",
-                    "Program 11111111111111111111111111111111 success"
-                ]}
+                "value":{
+                    "accounts": null,
+                    "err":null,
+                    "evmReceipts": null,
+                    "logs":[
+                        "Program 11111111111111111111111111111111 invoke [1]",
+                        "Program 11111111111111111111111111111111 success"
+                    ],
+                    "returnData": null,
+                    "unitsConsumed":0
+                }
             },
             "id": 1,
         });
@@ -4264,10 +4585,17 @@ pub mod tests {
             "jsonrpc": "2.0",
             "result": {
                 "context":{"slot":0},
-                "value":{"err":null, "logs":[
-                    "Program 11111111111111111111111111111111 invoke [1]",
-                    "Program 11111111111111111111111111111111 success"
-                ]}
+                "value":{
+                    "accounts": null,
+                    "err":null,
+                    "evmReceipts": null,
+                    "logs":[
+                        "Program 11111111111111111111111111111111 invoke [1]",
+                        "Program 11111111111111111111111111111111 success"
+                    ],
+                    "returnData": null,
+                    "unitsConsumed":0
+                }
             },
             "id": 1,
         });
@@ -4277,6 +4605,36 @@ pub mod tests {
             .expect("actual response deserialization");
         assert_eq!(expected, result);
 
+        // Unknown blockhash with replaceRecentBlockhash=true, returning post-simulation accounts
+        let stale_tx = system_transaction::transfer(&alice, &bob_pubkey, 1234, Hash::default());
+        let stale_tx_serialized_encoded = bs58::encode(serialize(&stale_tx).unwrap()).into_string();
+        let req = format!(
+            r#"{{"jsonrpc":"2.0","id":1,"method":"simulateTransaction","params":["{}", {{"replaceRecentBlockhash": true, "accounts": {{"addresses": ["{}"]}}}}]}}"#,
+            stale_tx_serialized_encoded, bob_pubkey,
+        );
+        let res = io.handle_request_sync(&req, meta.clone());
+        let json: Value = serde_json::from_str(&res.unwrap()).unwrap();
+        let result: RpcSimulateTransactionResult =
+            serde_json::from_value(json["result"]["value"].clone())
+                .expect("actual response deserialization");
+        assert_eq!(result.err, None);
+        assert_eq!(result.units_consumed, Some(0));
+        let accounts = result.accounts.unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(
+            accounts[0].as_ref().unwrap().lamports,
+            bank.get_balance(&bob_pubkey) + 1234
+        );
+
+        // replaceRecentBlockhash can't be used together with sigVerify
+        let req = format!(
+            r#"{{"jsonrpc":"2.0","id":1,"method":"simulateTransaction","params":["{}", {{"sigVerify": true, "replaceRecentBlockhash": true}}]}}"#,
+            tx_serialized_encoded,
+        );
+        let res = io.handle_request_sync(&req, meta.clone());
+        let json: Value = serde_json::from_str(&res.unwrap()).unwrap();
+        assert_eq!(json["error"]["code"], -32602);
+
         // Bad signature with default sigVerify setting (false)
         let req = format!(
             r#"{{"jsonrpc":"2.0","id":1,"method":"simulateTransaction","params":["{}"]}}"#,
@@ -4287,10 +4645,17 @@ pub mod tests {
             "jsonrpc": "2.0",
             "result": {
                 "context":{"slot":0},
-                "value":{"err":null, "logs":[
-                    "Program 11111111111111111111111111111111 invoke [1]",
-                    "Program 11111111111111111111111111111111 success"
-                ]}
+                "value":{
+                    "accounts": null,
+                    "err":null,
+                    "evmReceipts": null,
+                    "logs":[
+                        "Program 11111111111111111111111111111111 invoke [1]",
+                        "Program 11111111111111111111111111111111 success"
+                    ],
+                    "returnData": null,
+                    "unitsConsumed":0
+                }
             },
             "id": 1,
         });
@@ -4301,6 +4666,100 @@ pub mod tests {
         assert_eq!(expected, result);
     }
 
+    #[test]
+    fn test_rpc_simulate_evm_transaction() {
+        use evm_state::FromKey;
+
+        let bob_pubkey = solana_sdk::pubkey::new_rand();
+        let RpcHandler {
+            io,
+            meta,
+            blockhash,
+            alice,
+            bank,
+            ..
+        } = start_rpc_handler_with_tx(&bob_pubkey);
+
+        let secret_key = evm_state::SecretKey::from_slice(&[1; 32]).unwrap();
+        let evm_address = secret_key.to_address();
+        let evm_tx = |nonce: u64, action, input| {
+            evm_state::UnsignedTransaction {
+                nonce: nonce.into(),
+                gas_price: 1.into(),
+                gas_limit: 300_000.into(),
+                action,
+                value: 0.into(),
+                input,
+            }
+            .sign(&secret_key, Some(bank.evm_chain_id))
+        };
+        let send_evm_tx = |signed_tx| {
+            Transaction::new_signed_with_payer(
+                &[solana_evm_loader_program::send_raw_tx(
+                    alice.pubkey(),
+                    signed_tx,
+                    None,
+                )],
+                Some(&alice.pubkey()),
+                &[&alice],
+                blockhash,
+            )
+        };
+
+        let fund_tx = Transaction::new_signed_with_payer(
+            &solana_evm_loader_program::transfer_native_to_eth_ixs(
+                alice.pubkey(),
+                1000,
+                evm_address,
+            ),
+            Some(&alice.pubkey()),
+            &[&alice],
+            blockhash,
+        );
+        bank.process_transaction(&fund_tx).unwrap();
+
+        let deploy_tx = evm_tx(
+            0,
+            evm_state::TransactionAction::Create,
+            hex::decode(evm_state::HELLO_WORLD_CODE).unwrap(),
+        );
+        let contract = deploy_tx.address().unwrap();
+        bank.process_transaction(&send_evm_tx(deploy_tx)).unwrap();
+
+        let call_tx = evm_tx(
+            1,
+            evm_state::TransactionAction::Call(contract),
+            hex::decode(evm_state::HELLO_WORLD_ABI).unwrap(),
+        );
+        let call_tx_hash = call_tx.signing_hash();
+        let tx = send_evm_tx(call_tx);
+
+        bank.freeze();
+
+        let req = format!(
+            r#"{{"jsonrpc":"2.0","id":1,"method":"simulateTransaction","params":["{}"]}}"#,
+            bs58::encode(serialize(&tx).unwrap()).into_string(),
+        );
+        let res = io.handle_request_sync(&req, meta);
+        let json: Value = serde_json::from_str(&res.unwrap()).unwrap();
+        let result: RpcSimulateTransactionResult =
+            serde_json::from_value(json["result"]["value"].clone())
+                .expect("actual response deserialization");
+        assert_eq!(result.err, None);
+
+        // Only the simulated transaction is reported, not the deploy from the bank.
+        let evm_receipts = result.evm_receipts.unwrap();
+        assert_eq!(evm_receipts.len(), 1);
+        let evm_receipt = &evm_receipts[0];
+        assert_eq!(evm_receipt.receipt.transaction_hash.0, call_tx_hash);
+        assert_eq!(evm_receipt.receipt.to.map(|to| to.0), Some(contract));
+        assert_eq!(evm_receipt.receipt.status.0, 1);
+        assert_eq!(
+            evm_receipt.return_data.0,
+            hex::decode(evm_state::HELLO_WORLD_RESULT).unwrap()
+        );
+    }
+
     #[test]
     #[should_panic]
     fn test_rpc_simulate_transaction_panic_on_unfrozen_bank() {
@@ -4698,6 +5157,9 @@ pub mod tests {
             OptimisticallyConfirmedBank::locked_from_bank_forks_root(&bank_forks),
             Arc::new(RwLock::new(LargestAccountsCache::new(30))),
             Arc::new(MaxSlots::default()),
+            Arc::new(LeaderScheduleCache::new_from_bank(
+                &bank_forks.read().unwrap().root_bank(),
+            )),
         );
         SendTransactionService::new(tpu_address, &bank_forks, None, receiver, 1000, 1);
 
@@ -4717,7 +5179,7 @@ pub mod tests {
         assert_eq!(
             res,
             Some(
-                r#"{"jsonrpc":"2.0","error":{"code":-32002,"message":"Transaction simulation failed: Blockhash not found","data":{"err":"BlockhashNotFound","logs":[]}},"id":1}"#.to_string(),
+                r#"{"jsonrpc":"2.0","error":{"code":-32002,"message":"Transaction simulation failed: Blockhash not found","data":{"err":"BlockhashNotFound","logs":[],"accounts":null,"unitsConsumed":0,"evmReceipts":null,"returnData":null}},"id":1}"#.to_string(),
             )
         );
 
@@ -4896,6 +5358,9 @@ pub mod tests {
             OptimisticallyConfirmedBank::locked_from_bank_forks_root(&bank_forks),
             Arc::new(RwLock::new(LargestAccountsCache::new(30))),
             Arc::new(MaxSlots::default()),
+            Arc::new(LeaderScheduleCache::new_from_bank(
+                &bank_forks.read().unwrap().root_bank(),
+            )),
         );
         SendTransactionService::new(tpu_address, &bank_forks, None, receiver, 1000, 1);
         assert_eq!(request_processor.validator_exit(), false);
@@ -4931,6 +5396,9 @@ pub mod tests {
             OptimisticallyConfirmedBank::locked_from_bank_forks_root(&bank_forks),
             Arc::new(RwLock::new(LargestAccountsCache::new(30))),
             Arc::new(MaxSlots::default()),
+            Arc::new(LeaderScheduleCache::new_from_bank(
+                &bank_forks.read().unwrap().root_bank(),
+            )),
         );
         SendTransactionService::new(tpu_address, &bank_forks, None, receiver, 1000, 1);
         assert_eq!(request_processor.validator_exit(), true);
@@ -5043,6 +5511,9 @@ pub mod tests {
             OptimisticallyConfirmedBank::locked_from_bank_forks_root(&bank_forks),
             Arc::new(RwLock::new(LargestAccountsCache::new(30))),
             Arc::new(MaxSlots::default()),
+            Arc::new(LeaderScheduleCache::new_from_bank(
+                &bank_forks.read().unwrap().root_bank(),
+            )),
         );
         SendTransactionService::new(tpu_address, &bank_forks, None, receiver, 1000, 1);
         assert_eq!(
@@ -5297,6 +5768,208 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_get_inflation_reward() {
+        let bob_pubkey = solana_sdk::pubkey::new_rand();
+        let stake_pubkey = solana_sdk::pubkey::new_rand();
+        let first_slot_in_epoch_1 = TEST_SLOTS_PER_EPOCH;
+        let roots = vec![0, 1, 3, 4, 8, first_slot_in_epoch_1];
+        let RpcHandler {
+            io,
+            meta,
+            block_commitment_cache,
+            leader_vote_keypair,
+            ..
+        } = start_rpc_handler_with_tx_and_blockstore(&bob_pubkey, roots);
+        block_commitment_cache
+            .write()
+            .unwrap()
+            .set_highest_confirmed_root(first_slot_in_epoch_1);
+
+        let vote_pubkey = leader_vote_keypair.pubkey();
+        meta.blockstore
+            .write_rewards(
+                first_slot_in_epoch_1,
+                vec![
+                    Reward {
+                        pubkey: vote_pubkey.to_string(),
+                        lamports: 10,
+                        post_balance: 100,
+                        reward_type: Some(RewardType::Voting),
+                        commission: Some(5),
+                    },
+                    Reward {
+                        pubkey: stake_pubkey.to_string(),
+                        lamports: 20,
+                        post_balance: 200,
+                        reward_type: Some(RewardType::Staking),
+                        commission: Some(5),
+                    },
+                    Reward {
+                        pubkey: bob_pubkey.to_string(),
+                        lamports: 30,
+                        post_balance: 300,
+                        reward_type: Some(RewardType::Fee),
+                        commission: None,
+                    },
+                ],
+            )
+            .unwrap();
+
+        let req = format!(
+            r#"{{"jsonrpc":"2.0","id":1,"method":"getInflationReward","params":[["{}","{}","{}"],{{"epoch":0}}]}}"#,
+            vote_pubkey, stake_pubkey, bob_pubkey
+        );
+        let res = io.handle_request_sync(&req, meta.clone());
+        let result: Value = serde_json::from_str(&res.expect("actual response"))
+            .expect("actual response deserialization");
+        let rewards: Vec<Option<RpcInflationReward>> =
+            serde_json::from_value(result["result"].clone()).unwrap();
+        assert_eq!(
+            rewards,
+            vec![
+                Some(RpcInflationReward {
+                    epoch: 0,
+                    effective_slot: first_slot_in_epoch_1,
+                    amount: 10,
+                    post_balance: 100,
+                    commission: Some(5),
+                }),
+                Some(RpcInflationReward {
+                    epoch: 0,
+                    effective_slot: first_slot_in_epoch_1,
+                    amount: 20,
+                    post_balance: 200,
+                    commission: Some(5),
+                }),
+                // Fee rewards are not inflation rewards
+                None,
+            ]
+        );
+
+        // Rewards for epoch 1 are not distributed yet
+        let req = format!(
+            r#"{{"jsonrpc":"2.0","id":1,"method":"getInflationReward","params":[["{}"],{{"epoch":1}}]}}"#,
+            vote_pubkey
+        );
+        let res = io.handle_request_sync(&req, meta);
+        let result: Value = serde_json::from_str(&res.expect("actual response"))
+            .expect("actual response deserialization");
+        assert!(result.get("error").is_some());
+    }
+
+    #[test]
+    fn test_get_block_production() {
+        let bob_pubkey = solana_sdk::pubkey::new_rand();
+        let roots = vec![0, 1, 3, 4, 8];
+        let RpcHandler {
+            io,
+            meta,
+            leader_pubkey,
+            ..
+        } = start_rpc_handler_with_tx_and_blockstore(&bob_pubkey, roots);
+
+        // Working bank is 9, ledger starts at slot 1, slots 2, 5, 6 and 7 were skipped
+        let req = r#"{"jsonrpc":"2.0","id":1,"method":"getBlockProduction","params":[{"commitment":"recent"}]}"#;
+        let res = io.handle_request_sync(&req, meta.clone());
+        let result: Value = serde_json::from_str(&res.expect("actual response"))
+            .expect("actual response deserialization");
+        let block_production: RpcBlockProduction =
+            serde_json::from_value(result["result"]["value"].clone()).unwrap();
+        assert_eq!(
+            block_production.range,
+            RpcBlockProductionRange {
+                first_slot: 1,
+                last_slot: 9
+            }
+        );
+        assert_eq!(block_production.by_identity.len(), 1);
+        assert_eq!(
+            block_production.by_identity[&leader_pubkey.to_string()],
+            (9, 5)
+        );
+
+        let req = format!(
+            r#"{{"jsonrpc":"2.0","id":1,"method":"getBlockProduction","params":[{{"identity":"{}","range":{{"firstSlot":3,"lastSlot":7}},"commitment":"recent"}}]}}"#,
+            leader_pubkey
+        );
+        let res = io.handle_request_sync(&req, meta.clone());
+        let result: Value = serde_json::from_str(&res.expect("actual response"))
+            .expect("actual response deserialization");
+        let block_production: RpcBlockProduction =
+            serde_json::from_value(result["result"]["value"].clone()).unwrap();
+        assert_eq!(
+            block_production.by_identity[&leader_pubkey.to_string()],
+            (5, 2)
+        );
+
+        let req = format!(
+            r#"{{"jsonrpc":"2.0","id":1,"method":"getBlockProduction","params":[{{"identity":"{}","commitment":"recent"}}]}}"#,
+            bob_pubkey
+        );
+        let res = io.handle_request_sync(&req, meta.clone());
+        let result: Value = serde_json::from_str(&res.expect("actual response"))
+            .expect("actual response deserialization");
+        let block_production: RpcBlockProduction =
+            serde_json::from_value(result["result"]["value"].clone()).unwrap();
+        assert!(block_production.by_identity.is_empty());
+
+        let req = r#"{"jsonrpc":"2.0","id":1,"method":"getBlockProduction","params":[{"range":{"firstSlot":5,"lastSlot":4},"commitment":"recent"}]}"#;
+        let res = io.handle_request_sync(&req, meta.clone());
+        let result: Value = serde_json::from_str(&res.expect("actual response"))
+            .expect("actual response deserialization");
+        assert!(result.get("error").is_some());
+
+        let req = r#"{"jsonrpc":"2.0","id":1,"method":"getBlockProduction","params":[{"range":{"firstSlot":1,"lastSlot":10},"commitment":"recent"}]}"#;
+        let res = io.handle_request_sync(&req, meta);
+        let result: Value = serde_json::from_str(&res.expect("actual response"))
+            .expect("actual response deserialization");
+        assert!(result.get("error").is_some());
+    }
+
+    #[test]
+    fn test_get_block_production_unknown_leader() {
+        let bob_pubkey = solana_sdk::pubkey::new_rand();
+        let roots = vec![0, 1, 3, 4, 8];
+        let RpcHandler {
+            io,
+            meta,
+            leader_pubkey,
+            ..
+        } = start_rpc_handler_with_tx_and_blockstore(&bob_pubkey, roots);
+
+        // One slot epochs, the leader schedule of the working bank slot 9 is not known from root 8
+        let root_bank = meta.bank_forks.read().unwrap().root_bank();
+        let epoch_schedule = EpochSchedule {
+            slots_per_epoch: 1,
+            leader_schedule_slot_offset: 0,
+            warmup: false,
+            first_normal_epoch: 0,
+            first_normal_slot: 0,
+        };
+        let meta = JsonRpcRequestProcessor {
+            leader_schedule_cache: Arc::new(LeaderScheduleCache::new(epoch_schedule, &root_bank)),
+            ..meta
+        };
+
+        let req = r#"{"jsonrpc":"2.0","id":1,"method":"getBlockProduction","params":[{"commitment":"recent"}]}"#;
+        let res = io.handle_request_sync(&req, meta.clone());
+        let result: Value = serde_json::from_str(&res.expect("actual response"))
+            .expect("actual response deserialization");
+        assert!(result.get("error").is_some());
+
+        let req = r#"{"jsonrpc":"2.0","id":1,"method":"getBlockProduction","params":[{"range":{"firstSlot":1,"lastSlot":8},"commitment":"recent"}]}"#;
+        let res = io.handle_request_sync(&req, meta);
+        let result: Value = serde_json::from_str(&res.expect("actual response"))
+            .expect("actual response deserialization");
+        let block_production: RpcBlockProduction =
+            serde_json::from_value(result["result"]["value"].clone()).unwrap();
+        assert_eq!(
+            block_production.by_identity[&leader_pubkey.to_string()],
+            (8, 4)
+        );
+    }
+
     #[test]
     fn test_get_confirmed_blocks_with_limit() {
         let bob_pubkey = solana_sdk::pubkey::new_rand();
@@ -6281,6 +6954,9 @@ pub mod tests {
             optimistically_confirmed_bank.clone(),
             Arc::new(RwLock::new(LargestAccountsCache::new(30))),
             Arc::new(MaxSlots::default()),
+            Arc::new(LeaderScheduleCache::new_from_bank(
+                &bank_forks.read().unwrap().root_bank(),
+            )),
         );
 
         let mut io = MetaIoHandler::default();
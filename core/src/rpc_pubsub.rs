@@ -7,12 +7,13 @@ use jsonrpc_pubsub::{typed::Subscriber, Session, SubscriptionId};
 use solana_account_decoder::UiAccount;
 use solana_client::{
     rpc_config::{
-        RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcSignatureSubscribeConfig,
-        RpcTransactionLogsConfig, RpcTransactionLogsFilter,
+        RpcAccountInfoConfig, RpcBlockSubscribeConfig, RpcBlockSubscribeFilter,
+        RpcProgramAccountsConfig, RpcSignatureSubscribeConfig, RpcTransactionLogsConfig,
+        RpcTransactionLogsFilter,
     },
     rpc_response::{
-        Response as RpcResponse, RpcKeyedAccount, RpcLogsResponse, RpcSignatureResult, SlotInfo,
-        SlotUpdate,
+        Response as RpcResponse, RpcBlockUpdate, RpcEvmBlockReplaced, RpcKeyedAccount,
+        RpcLogsResponse, RpcSignatureResult, SlotInfo, SlotUpdate,
     },
 };
 #[cfg(test)]
@@ -128,6 +129,24 @@ pub trait RpcSolPubSub {
         id: SubscriptionId,
     ) -> Result<bool>;
 
+    // Get notification when block is confirmed or finalized, with its evm block
+    #[pubsub(subscription = "blockNotification", subscribe, name = "blockSubscribe")]
+    fn block_subscribe(
+        &self,
+        meta: Self::Metadata,
+        subscriber: Subscriber<RpcResponse<RpcBlockUpdate>>,
+        filter: RpcBlockSubscribeFilter,
+        config: Option<RpcBlockSubscribeConfig>,
+    );
+
+    // Unsubscribe from block notification subscription.
+    #[pubsub(
+        subscription = "blockNotification",
+        unsubscribe,
+        name = "blockUnsubscribe"
+    )]
+    fn block_unsubscribe(&self, meta: Option<Self::Metadata>, id: SubscriptionId) -> Result<bool>;
+
     // Get notification when slot is encountered
     #[pubsub(subscription = "slotNotification", subscribe, name = "slotSubscribe")]
     fn slot_subscribe(&self, meta: Self::Metadata, subscriber: Subscriber<SlotInfo>);
@@ -187,6 +206,30 @@ pub trait RpcSolPubSub {
         name = "rootUnsubscribe"
     )]
     fn root_unsubscribe(&self, meta: Option<Self::Metadata>, id: SubscriptionId) -> Result<bool>;
+
+    // Get notification when unconfirmed evm block is replaced by block from another fork
+    #[pubsub(
+        subscription = "evmBlockReplacedNotification",
+        subscribe,
+        name = "evmBlockReplacedSubscribe"
+    )]
+    fn evm_block_replaced_subscribe(
+        &self,
+        meta: Self::Metadata,
+        subscriber: Subscriber<RpcEvmBlockReplaced>,
+    );
+
+    // Unsubscribe from evm block replaced notification subscription.
+    #[pubsub(
+        subscription = "evmBlockReplacedNotification",
+        unsubscribe,
+        name = "evmBlockReplacedUnsubscribe"
+    )]
+    fn evm_block_replaced_unsubscribe(
+        &self,
+        meta: Option<Self::Metadata>,
+        id: SubscriptionId,
+    ) -> Result<bool>;
 }
 
 pub struct RpcSolPubSubImpl {
@@ -428,6 +471,50 @@ impl RpcSolPubSub for RpcSolPubSubImpl {
         }
     }
 
+    fn block_subscribe(
+        &self,
+        _meta: Self::Metadata,
+        subscriber: Subscriber<RpcResponse<RpcBlockUpdate>>,
+        filter: RpcBlockSubscribeFilter,
+        config: Option<RpcBlockSubscribeConfig>,
+    ) {
+        info!("block_subscribe");
+        if let Err(err) = self.check_subscription_count() {
+            subscriber.reject(err).unwrap_or_default();
+            return;
+        }
+        let mentions = match filter {
+            RpcBlockSubscribeFilter::All => None,
+            RpcBlockSubscribeFilter::MentionsAccountOrProgram(address) => {
+                match param::<Pubkey>(&address, "mentionsAccountOrProgram") {
+                    Ok(address) => Some(address),
+                    Err(e) => {
+                        subscriber.reject(e).unwrap_or_default();
+                        return;
+                    }
+                }
+            }
+        };
+        let id = self.uid.fetch_add(1, atomic::Ordering::Relaxed);
+        let sub_id = SubscriptionId::Number(id as u64);
+        info!("block_subscribe: id={:?}", sub_id);
+        self.subscriptions
+            .add_block_subscription(mentions, config, sub_id, subscriber);
+    }
+
+    fn block_unsubscribe(&self, _meta: Option<Self::Metadata>, id: SubscriptionId) -> Result<bool> {
+        info!("block_unsubscribe: id={:?}", id);
+        if self.subscriptions.remove_block_subscription(&id) {
+            Ok(true)
+        } else {
+            Err(Error {
+                code: ErrorCode::InvalidParams,
+                message: "Invalid Request: Subscription id does not exist".into(),
+                data: None,
+            })
+        }
+    }
+
     fn slot_subscribe(&self, _meta: Self::Metadata, subscriber: Subscriber<SlotInfo>) {
         info!("slot_subscribe");
         if let Err(err) = self.check_subscription_count() {
@@ -536,6 +623,43 @@ impl RpcSolPubSub for RpcSolPubSubImpl {
             })
         }
     }
+
+    fn evm_block_replaced_subscribe(
+        &self,
+        _meta: Self::Metadata,
+        subscriber: Subscriber<RpcEvmBlockReplaced>,
+    ) {
+        info!("evm_block_replaced_subscribe");
+        if let Err(err) = self.check_subscription_count() {
+            subscriber.reject(err).unwrap_or_default();
+            return;
+        }
+        let id = self.uid.fetch_add(1, atomic::Ordering::Relaxed);
+        let sub_id = SubscriptionId::Number(id as u64);
+        info!("evm_block_replaced_subscribe: id={:?}", sub_id);
+        self.subscriptions
+            .add_evm_block_replaced_subscription(sub_id, subscriber);
+    }
+
+    fn evm_block_replaced_unsubscribe(
+        &self,
+        _meta: Option<Self::Metadata>,
+        id: SubscriptionId,
+    ) -> Result<bool> {
+        info!("evm_block_replaced_unsubscribe");
+        if self
+            .subscriptions
+            .remove_evm_block_replaced_subscription(&id)
+        {
+            Ok(true)
+        } else {
+            Err(Error {
+                code: ErrorCode::InvalidParams,
+                message: "Invalid Request: Subscription id does not exist".into(),
+                data: None,
+            })
+        }
+    }
 }
 
 #[cfg(test)]
@@ -18,6 +18,7 @@ use std::{
 
 #[derive(Debug, Clone)]
 pub struct PubSubConfig {
+    pub enable_block_subscription: bool,
     pub enable_vote_subscription: bool,
 
     // See the corresponding fields in
@@ -32,6 +33,7 @@ pub struct PubSubConfig {
 impl Default for PubSubConfig {
     fn default() -> Self {
         Self {
+            enable_block_subscription: false,
             enable_vote_subscription: false,
             max_connections: 1000, // Arbitrary, default of 100 is too low
             max_fragment_size: 50 * 1024, // 50KB
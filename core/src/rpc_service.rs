@@ -19,7 +19,7 @@ use jsonrpc_http_server::{
 };
 use regex::Regex;
 use solana_client::rpc_cache::LargestAccountsCache;
-use solana_ledger::blockstore::Blockstore;
+use solana_ledger::{blockstore::Blockstore, leader_schedule_cache::LeaderScheduleCache};
 use solana_metrics::inc_new_counter_info;
 use solana_runtime::{
     bank_forks::{BankForks, SnapshotConfig},
@@ -67,7 +67,7 @@ impl RpcRequestMiddleware {
         Self {
             ledger_path,
             snapshot_archive_path_regex: Regex::new(
-                r"^/snapshot-\d+-[[:alnum:]]+\.(tar|tar\.bz2|tar\.zst|tar\.gz)$",
+                r"^/(snapshot-\d+|incremental-snapshot-\d+-\d+)-[[:alnum:]]+\.(tar|tar\.bz2|tar\.zst|tar\.gz)$",
             )
             .unwrap(),
             snapshot_config,
@@ -84,6 +84,21 @@ impl RpcRequestMiddleware {
             .unwrap()
     }
 
+    fn redirect_to_archive(archive: Option<PathBuf>) -> hyper::Response<hyper::Body> {
+        if let Some(archive) = archive {
+            Self::redirect(&format!(
+                "/{}",
+                archive
+                    .file_name()
+                    .unwrap_or_else(|| std::ffi::OsStr::new(""))
+                    .to_str()
+                    .unwrap_or(&"")
+            ))
+        } else {
+            Self::not_found()
+        }
+    }
+
     fn not_found() -> hyper::Response<hyper::Body> {
         hyper::Response::builder()
             .status(hyper::StatusCode::NOT_FOUND)
@@ -176,29 +191,43 @@ impl RequestMiddleware for RpcRequestMiddleware {
         trace!("request uri: {}", request.uri());
 
         if let Some(ref snapshot_config) = self.snapshot_config {
-            if request.uri().path() == "/snapshot.tar.bz2" {
-                // Convenience redirect to the latest snapshot
-                return RequestMiddlewareAction::Respond {
-                    should_validate_hosts: true,
-                    response: Box::new(jsonrpc_core::futures::future::ok(
-                        if let Some((snapshot_archive, _)) =
-                            snapshot_utils::get_highest_snapshot_archive_path(
+            let snapshot_archive = || {
+                snapshot_utils::get_highest_snapshot_archive_path(
+                    &snapshot_config.snapshot_package_output_path,
+                )
+            };
+            match request.uri().path() {
+                "/snapshot.tar.bz2" => {
+                    // Convenience redirect to the latest snapshot
+                    return RequestMiddlewareAction::Respond {
+                        should_validate_hosts: true,
+                        response: Box::new(jsonrpc_core::futures::future::ok(
+                            RpcRequestMiddleware::redirect_to_archive(
+                                snapshot_archive().map(|(archive, _)| archive),
+                            ),
+                        )),
+                    };
+                }
+                "/incremental-snapshot.tar.bz2" => {
+                    // Convenience redirect to the latest incremental snapshot,
+                    // that is built on top of the latest snapshot
+                    let incremental_snapshot_archive =
+                        snapshot_archive().and_then(|(_, (full_snapshot_slot, ..))| {
+                            snapshot_utils::get_highest_incremental_snapshot_archive_path(
                                 &snapshot_config.snapshot_package_output_path,
+                                full_snapshot_slot,
                             )
-                        {
-                            RpcRequestMiddleware::redirect(&format!(
-                                "/{}",
-                                snapshot_archive
-                                    .file_name()
-                                    .unwrap_or_else(|| std::ffi::OsStr::new(""))
-                                    .to_str()
-                                    .unwrap_or(&"")
-                            ))
-                        } else {
-                            RpcRequestMiddleware::not_found()
-                        },
-                    )),
-                };
+                        });
+                    return RequestMiddlewareAction::Respond {
+                        should_validate_hosts: true,
+                        response: Box::new(jsonrpc_core::futures::future::ok(
+                            RpcRequestMiddleware::redirect_to_archive(
+                                incremental_snapshot_archive.map(|(archive, _)| archive),
+                            ),
+                        )),
+                    };
+                }
+                _ => (),
             }
         }
 
@@ -276,6 +305,7 @@ impl JsonRpcService {
         send_transaction_retry_ms: u64,
         send_transaction_leader_forward_count: u64,
         max_slots: Arc<MaxSlots>,
+        leader_schedule_cache: Arc<LeaderScheduleCache>,
     ) -> Self {
         info!("rpc bound to {:?}", rpc_addr);
         info!("rpc configuration: {:?}", config);
@@ -353,6 +383,7 @@ impl JsonRpcService {
             optimistically_confirmed_bank,
             largest_accounts_cache,
             max_slots,
+            leader_schedule_cache,
         );
 
         let leader_info =
@@ -487,6 +518,7 @@ mod tests {
         let exit = Arc::new(AtomicBool::new(false));
         let validator_exit = create_validator_exit(&exit);
         let bank = Bank::new(&genesis_config);
+        let leader_schedule_cache = Arc::new(LeaderScheduleCache::new_from_bank(&bank));
         let cluster_info = Arc::new(ClusterInfo::default());
         let ip_addr = IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0));
         let rpc_addr = SocketAddr::new(
@@ -517,6 +549,7 @@ mod tests {
             1000,
             1,
             Arc::new(MaxSlots::default()),
+            leader_schedule_cache,
         );
         let thread = rpc_service.thread_hdl.thread();
         assert_eq!(thread.name().unwrap(), "solana-jsonrpc");
@@ -569,6 +602,7 @@ mod tests {
                 snapshot_path: PathBuf::from("/"),
                 archive_format: ArchiveFormat::TarBzip2,
                 snapshot_version: SnapshotVersion::default(),
+                incremental_snapshot_interval_slots: 0,
             }),
             bank_forks,
             RpcHealth::stub(),
@@ -578,6 +612,8 @@ mod tests {
         assert!(!rrm.is_file_get_path("genesis.tar.bz2"));
 
         assert!(!rrm.is_file_get_path("/snapshot.tar.bz2")); // This is a redirect
+        assert!(!rrm_with_snapshot_config.is_file_get_path("/snapshot.tar.bz2"));
+        assert!(!rrm_with_snapshot_config.is_file_get_path("/incremental-snapshot.tar.bz2"));
 
         assert!(!rrm.is_file_get_path(
             "/snapshot-100-AvFf9oS8A8U78HdjT9YG2sTTThLHJZmhaMn2g8vkWYnr.tar.bz2"
@@ -596,6 +632,16 @@ mod tests {
         assert!(rrm_with_snapshot_config
             .is_file_get_path("/snapshot-100-AvFf9oS8A8U78HdjT9YG2sTTThLHJZmhaMn2g8vkWYnr.tar"));
 
+        assert!(rrm_with_snapshot_config.is_file_get_path(
+            "/incremental-snapshot-100-150-AvFf9oS8A8U78HdjT9YG2sTTThLHJZmhaMn2g8vkWYnr.tar.bz2"
+        ));
+        assert!(!rrm_with_snapshot_config.is_file_get_path(
+            "/incremental-snapshot-150-AvFf9oS8A8U78HdjT9YG2sTTThLHJZmhaMn2g8vkWYnr.tar.bz2"
+        ));
+        assert!(!rrm.is_file_get_path(
+            "/incremental-snapshot-100-150-AvFf9oS8A8U78HdjT9YG2sTTThLHJZmhaMn2g8vkWYnr.tar.bz2"
+        ));
+
         assert!(!rrm.is_file_get_path(
             "/snapshot-notaslotnumber-AvFf9oS8A8U78HdjT9YG2sTTThLHJZmhaMn2g8vkWYnr.tar.bz2"
         ));
@@ -3,8 +3,10 @@
 use crate::{
     optimistically_confirmed_bank_tracker::OptimisticallyConfirmedBank,
     rpc::{get_parsed_token_account, get_parsed_token_accounts},
+    rpc_pubsub_service::PubSubConfig,
 };
 use core::hash::Hash;
+use evm_rpc::RPCBlock;
 use jsonrpc_core::futures::Future;
 use jsonrpc_pubsub::{
     typed::{Sink, Subscriber},
@@ -13,13 +15,18 @@ use jsonrpc_pubsub::{
 use serde::Serialize;
 use solana_account_decoder::{parse_token::spl_token_id_v2_0, UiAccount, UiAccountEncoding};
 use solana_client::{
-    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcSignatureSubscribeConfig},
+    rpc_config::{
+        RpcAccountInfoConfig, RpcBlockSubscribeConfig, RpcProgramAccountsConfig,
+        RpcSignatureSubscribeConfig,
+    },
     rpc_filter::RpcFilterType,
     rpc_response::{
-        ProcessedSignatureResult, ReceivedSignatureResult, Response, RpcKeyedAccount,
-        RpcLogsResponse, RpcResponseContext, RpcSignatureResult, SlotInfo, SlotUpdate,
+        ProcessedSignatureResult, ReceivedSignatureResult, Response, RpcBlockUpdate,
+        RpcBlockUpdateError, RpcEvmBlockReplaced, RpcKeyedAccount, RpcLogsResponse,
+        RpcResponseContext, RpcSignatureResult, SlotInfo, SlotUpdate,
     },
 };
+use solana_ledger::blockstore::Blockstore;
 use solana_measure::measure::Measure;
 use solana_runtime::{
     bank::{
@@ -37,12 +44,13 @@ use solana_sdk::{
     timing::timestamp,
     transaction,
 };
+use solana_transaction_status::{ConfirmedBlock, TransactionDetails, UiTransactionEncoding};
 use solana_vote_program::vote_state::Vote;
 use std::{
     collections::{HashMap, HashSet},
     iter,
     sync::{
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicU64, Ordering},
         mpsc::{Receiver, RecvTimeoutError, SendError, Sender},
     },
     sync::{Arc, Mutex, RwLock},
@@ -90,6 +98,7 @@ enum NotificationEntry {
     SlotUpdate(SlotUpdate),
     Vote(Vote),
     Root(Slot),
+    EvmBlockReplaced(RpcEvmBlockReplaced),
     Bank(CommitmentSlots),
     Gossip(Slot),
     SignaturesReceived((Slot, Vec<Signature>)),
@@ -99,6 +108,9 @@ impl std::fmt::Debug for NotificationEntry {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             NotificationEntry::Root(root) => write!(f, "Root({})", root),
+            NotificationEntry::EvmBlockReplaced(replaced) => {
+                write!(f, "EvmBlockReplaced({:?})", replaced)
+            }
             NotificationEntry::Vote(vote) => write!(f, "Vote({:?})", vote),
             NotificationEntry::Slot(slot_info) => write!(f, "Slot({:?})", slot_info),
             NotificationEntry::SlotUpdate(slot_update) => {
@@ -150,10 +162,21 @@ type RpcSignatureSubscriptions = RwLock<
         HashMap<SubscriptionId, SubscriptionData<Response<RpcSignatureResult>, bool>>,
     >,
 >;
+// Block subscriptions are keyed by the mentioned account or program, `None` for all blocks
+type RpcBlockSubscriptions = RwLock<
+    HashMap<
+        Option<Pubkey>,
+        HashMap<
+            SubscriptionId,
+            SubscriptionData<Response<RpcBlockUpdate>, RpcBlockSubscribeConfig>,
+        >,
+    >,
+>;
 type RpcSlotSubscriptions = RwLock<HashMap<SubscriptionId, Sink<SlotInfo>>>;
 type RpcSlotUpdateSubscriptions = RwLock<HashMap<SubscriptionId, Sink<Arc<SlotUpdate>>>>;
 type RpcVoteSubscriptions = RwLock<HashMap<SubscriptionId, Sink<RpcVote>>>;
 type RpcRootSubscriptions = RwLock<HashMap<SubscriptionId, Sink<Slot>>>;
+type RpcEvmBlockReplacedSubscriptions = RwLock<HashMap<SubscriptionId, Sink<RpcEvmBlockReplaced>>>;
 
 fn add_subscription<K, S, T>(
     subscriptions: &mut HashMap<K, HashMap<SubscriptionId, SubscriptionData<S, T>>>,
@@ -377,6 +400,93 @@ fn filter_logs_results(
     }
 }
 
+/// Filter block by mentioned account or program, and encode it for the subscriber.
+/// Returns `None` if no transaction of block mentions the address.
+fn filter_block_result(
+    block: &ConfirmedBlock,
+    evm_block: Option<&(evm_state::Block, bool)>,
+    mentions: &Option<Pubkey>,
+    slot: Slot,
+    config: &RpcBlockSubscribeConfig,
+) -> Option<RpcBlockUpdate> {
+    let mut block = block.clone();
+    if let Some(address) = mentions {
+        block
+            .transactions
+            .retain(|tx| tx.transaction.message.account_keys.contains(address));
+        if block.transactions.is_empty() {
+            return None;
+        }
+    }
+    let transaction_details = config.transaction_details.unwrap_or_default();
+    let full_evm_transactions = transaction_details == TransactionDetails::Full;
+    Some(RpcBlockUpdate {
+        slot,
+        block: Some(block.configure(
+            config.encoding.unwrap_or(UiTransactionEncoding::Json),
+            transaction_details,
+            config.show_rewards.unwrap_or(true),
+        )),
+        evm_block: evm_block.map(|(evm_block, confirmed)| {
+            RPCBlock::new_from_block(evm_block.clone(), *confirmed, full_evm_transactions)
+        }),
+        err: None,
+    })
+}
+
+/// Collect evm blocks, produced by `bank` and its ancestors after `lowest_slot`, keyed by slot.
+/// Unrooted ancestors keep their parents, older blocks are read from blockstore by rooted slots.
+fn collect_evm_blocks(
+    bank: &Arc<Bank>,
+    blockstore: &Blockstore,
+    lowest_slot: Slot,
+) -> HashMap<Slot, (evm_state::Block, bool)> {
+    let mut evm_blocks = HashMap::new();
+    let mut next_block_number = None;
+    for bank in bank.clone().parents_inclusive() {
+        if bank.slot() <= lowest_slot {
+            return evm_blocks;
+        }
+        if let Some(block) = bank.evm_block() {
+            if block.header.native_chain_slot == bank.slot() {
+                next_block_number = block.header.block_number.checked_sub(1);
+                evm_blocks.insert(bank.slot(), (block, blockstore.is_root(bank.slot())));
+            }
+        }
+    }
+    while let Some(block_number) = next_block_number {
+        match blockstore.get_evm_block(block_number) {
+            Ok((block, true)) if block.header.native_chain_slot > lowest_slot => {
+                next_block_number = block_number.checked_sub(1);
+                evm_blocks.insert(block.header.native_chain_slot, (block, true));
+            }
+            _ => break,
+        }
+    }
+    evm_blocks
+}
+
+/// Collect slots of the fork of confirmed `bank` after `last_notified_slot`.
+/// Unrooted ancestors keep their parents, older slots are read from blockstore by rooted slots.
+fn confirmed_fork_slots(
+    bank: &Arc<Bank>,
+    blockstore: &Blockstore,
+    last_notified_slot: Slot,
+) -> Vec<Slot> {
+    let mut slots = vec![];
+    for bank in bank.clone().parents_inclusive() {
+        if bank.slot() <= last_notified_slot {
+            return slots;
+        }
+        slots.push(bank.slot());
+    }
+    let lowest_bank_slot = slots.last().copied().unwrap_or_else(|| bank.slot());
+    if let Ok(roots) = blockstore.rooted_slot_iterator(last_notified_slot + 1) {
+        slots.extend(roots.take_while(|root| *root < lowest_bank_slot));
+    }
+    slots
+}
+
 fn total_nested_subscriptions<K, L, V>(
     subscription_map: &RwLock<HashMap<K, HashMap<L, V>>>,
 ) -> usize {
@@ -397,10 +507,13 @@ struct Subscriptions {
     gossip_logs_subscriptions: Arc<RpcLogsSubscriptions>,
     gossip_program_subscriptions: Arc<RpcProgramSubscriptions>,
     gossip_signature_subscriptions: Arc<RpcSignatureSubscriptions>,
+    block_subscriptions: Arc<RpcBlockSubscriptions>,
+    gossip_block_subscriptions: Arc<RpcBlockSubscriptions>,
     slot_subscriptions: Arc<RpcSlotSubscriptions>,
     slots_updates_subscriptions: Arc<RpcSlotUpdateSubscriptions>,
     vote_subscriptions: Arc<RpcVoteSubscriptions>,
     root_subscriptions: Arc<RpcRootSubscriptions>,
+    evm_block_replaced_subscriptions: Arc<RpcEvmBlockReplacedSubscriptions>,
 }
 
 impl Subscriptions {
@@ -414,9 +527,12 @@ impl Subscriptions {
         total += total_nested_subscriptions(&self.gossip_logs_subscriptions);
         total += total_nested_subscriptions(&self.gossip_program_subscriptions);
         total += total_nested_subscriptions(&self.gossip_signature_subscriptions);
+        total += total_nested_subscriptions(&self.block_subscriptions);
+        total += total_nested_subscriptions(&self.gossip_block_subscriptions);
         total += self.slot_subscriptions.read().unwrap().len();
         total += self.vote_subscriptions.read().unwrap().len();
         total += self.root_subscriptions.read().unwrap().len();
+        total += self.evm_block_replaced_subscriptions.read().unwrap().len();
         total
     }
 }
@@ -431,6 +547,8 @@ pub struct RpcSubscriptions {
     optimistically_confirmed_bank: Arc<RwLock<OptimisticallyConfirmedBank>>,
     exit: Arc<AtomicBool>,
     enable_vote_subscription: bool,
+    // Set only if block subscriptions are enabled
+    blockstore: Option<Arc<Blockstore>>,
 }
 
 impl Drop for RpcSubscriptions {
@@ -463,6 +581,53 @@ impl RpcSubscriptions {
         block_commitment_cache: Arc<RwLock<BlockCommitmentCache>>,
         optimistically_confirmed_bank: Arc<RwLock<OptimisticallyConfirmedBank>>,
         enable_vote_subscription: bool,
+    ) -> Self {
+        Self::new_internal(
+            exit,
+            Arc::new(AtomicU64::default()),
+            None,
+            bank_forks,
+            block_commitment_cache,
+            optimistically_confirmed_bank,
+            enable_vote_subscription,
+        )
+    }
+
+    /// Blocks for `blockSubscribe` are read from `blockstore`, once their transaction statuses
+    /// are written, as reported by `max_complete_transaction_status_slot`.
+    pub fn new_with_config(
+        exit: &Arc<AtomicBool>,
+        max_complete_transaction_status_slot: Arc<AtomicU64>,
+        blockstore: Arc<Blockstore>,
+        bank_forks: Arc<RwLock<BankForks>>,
+        block_commitment_cache: Arc<RwLock<BlockCommitmentCache>>,
+        optimistically_confirmed_bank: Arc<RwLock<OptimisticallyConfirmedBank>>,
+        config: &PubSubConfig,
+    ) -> Self {
+        let blockstore = if config.enable_block_subscription {
+            Some(blockstore)
+        } else {
+            None
+        };
+        Self::new_internal(
+            exit,
+            max_complete_transaction_status_slot,
+            blockstore,
+            bank_forks,
+            block_commitment_cache,
+            optimistically_confirmed_bank,
+            config.enable_vote_subscription,
+        )
+    }
+
+    fn new_internal(
+        exit: &Arc<AtomicBool>,
+        max_complete_transaction_status_slot: Arc<AtomicU64>,
+        blockstore: Option<Arc<Blockstore>>,
+        bank_forks: Arc<RwLock<BankForks>>,
+        block_commitment_cache: Arc<RwLock<BlockCommitmentCache>>,
+        optimistically_confirmed_bank: Arc<RwLock<OptimisticallyConfirmedBank>>,
+        enable_vote_subscription: bool,
     ) -> Self {
         let (notification_sender, notification_receiver): (
             Sender<NotificationEntry>,
@@ -477,10 +642,14 @@ impl RpcSubscriptions {
         let gossip_logs_subscriptions = Arc::new(RpcLogsSubscriptions::default());
         let gossip_program_subscriptions = Arc::new(RpcProgramSubscriptions::default());
         let gossip_signature_subscriptions = Arc::new(RpcSignatureSubscriptions::default());
+        let block_subscriptions = Arc::new(RpcBlockSubscriptions::default());
+        let gossip_block_subscriptions = Arc::new(RpcBlockSubscriptions::default());
         let slot_subscriptions = Arc::new(RpcSlotSubscriptions::default());
         let slots_updates_subscriptions = Arc::new(RpcSlotUpdateSubscriptions::default());
         let vote_subscriptions = Arc::new(RpcVoteSubscriptions::default());
         let root_subscriptions = Arc::new(RpcRootSubscriptions::default());
+        let evm_block_replaced_subscriptions =
+            Arc::new(RpcEvmBlockReplacedSubscriptions::default());
         let notification_sender = Arc::new(Mutex::new(notification_sender));
 
         let _bank_forks = bank_forks.clone();
@@ -495,12 +664,16 @@ impl RpcSubscriptions {
             gossip_logs_subscriptions,
             gossip_program_subscriptions,
             gossip_signature_subscriptions,
+            block_subscriptions,
+            gossip_block_subscriptions,
             slot_subscriptions,
             slots_updates_subscriptions,
             vote_subscriptions,
             root_subscriptions,
+            evm_block_replaced_subscriptions,
         };
         let _subscriptions = subscriptions.clone();
+        let _blockstore = blockstore.clone();
 
         let notifier_runtime = RuntimeBuilder::new()
             .core_threads(1)
@@ -518,6 +691,8 @@ impl RpcSubscriptions {
                     notification_receiver,
                     _subscriptions,
                     _bank_forks,
+                    _blockstore,
+                    max_complete_transaction_status_slot,
                 );
             })
             .unwrap();
@@ -532,6 +707,7 @@ impl RpcSubscriptions {
             optimistically_confirmed_bank,
             exit: exit.clone(),
             enable_vote_subscription,
+            blockstore,
         }
     }
 
@@ -913,6 +1089,76 @@ impl RpcSubscriptions {
         }
     }
 
+    pub fn add_block_subscription(
+        &self,
+        mentions: Option<Pubkey>,
+        config: Option<RpcBlockSubscribeConfig>,
+        sub_id: SubscriptionId,
+        subscriber: Subscriber<Response<RpcBlockUpdate>>,
+    ) {
+        if self.blockstore.is_none() {
+            let _ = subscriber.reject(jsonrpc_core::Error::new(
+                jsonrpc_core::ErrorCode::MethodNotFound,
+            ));
+            return;
+        }
+        let config = config.unwrap_or_default();
+        let commitment = config
+            .commitment
+            .unwrap_or_else(CommitmentConfig::confirmed);
+
+        let (mut subscriptions, last_notified_slot) = if commitment.is_finalized() {
+            (
+                self.subscriptions.block_subscriptions.write().unwrap(),
+                self.block_commitment_cache
+                    .read()
+                    .unwrap()
+                    .highest_confirmed_root(),
+            )
+        } else if commitment.is_confirmed() {
+            (
+                self.subscriptions
+                    .gossip_block_subscriptions
+                    .write()
+                    .unwrap(),
+                self.optimistically_confirmed_bank
+                    .read()
+                    .unwrap()
+                    .bank
+                    .slot(),
+            )
+        } else {
+            let _ = subscriber.reject(jsonrpc_core::Error::invalid_params(
+                "Invalid Request: processed commitment is not supported",
+            ));
+            return;
+        };
+
+        add_subscription(
+            &mut subscriptions,
+            mentions,
+            commitment,
+            sub_id,
+            subscriber,
+            last_notified_slot,
+            Some(config),
+        );
+    }
+
+    pub fn remove_block_subscription(&self, id: &SubscriptionId) -> bool {
+        let mut subscriptions = self.subscriptions.block_subscriptions.write().unwrap();
+        if remove_subscription(&mut subscriptions, id) {
+            true
+        } else {
+            let mut subscriptions = self
+                .subscriptions
+                .gossip_block_subscriptions
+                .write()
+                .unwrap();
+            remove_subscription(&mut subscriptions, id)
+        }
+    }
+
     /// Notify subscribers of changes to any accounts or new signatures since
     /// the bank's last checkpoint.
     pub fn notify_subscribers(&self, commitment_slots: CommitmentSlots) {
@@ -1014,6 +1260,33 @@ impl RpcSubscriptions {
         });
     }
 
+    pub fn add_evm_block_replaced_subscription(
+        &self,
+        sub_id: SubscriptionId,
+        subscriber: Subscriber<RpcEvmBlockReplaced>,
+    ) {
+        let sink = subscriber.assign_id(sub_id.clone()).unwrap();
+        let mut subscriptions = self
+            .subscriptions
+            .evm_block_replaced_subscriptions
+            .write()
+            .unwrap();
+        subscriptions.insert(sub_id, sink);
+    }
+
+    pub fn remove_evm_block_replaced_subscription(&self, id: &SubscriptionId) -> bool {
+        let mut subscriptions = self
+            .subscriptions
+            .evm_block_replaced_subscriptions
+            .write()
+            .unwrap();
+        subscriptions.remove(id).is_some()
+    }
+
+    pub fn notify_evm_block_replaced(&self, replaced: RpcEvmBlockReplaced) {
+        self.enqueue_notification(NotificationEntry::EvmBlockReplaced(replaced));
+    }
+
     fn enqueue_notification(&self, notification_entry: NotificationEntry) {
         match self
             .notification_sender
@@ -1037,6 +1310,8 @@ impl RpcSubscriptions {
         notification_receiver: Receiver<NotificationEntry>,
         subscriptions: Subscriptions,
         bank_forks: Arc<RwLock<BankForks>>,
+        blockstore: Option<Arc<Blockstore>>,
+        max_complete_transaction_status_slot: Arc<AtomicU64>,
     ) {
         loop {
             if exit.load(Ordering::Relaxed) {
@@ -1106,6 +1381,23 @@ impl RpcSubscriptions {
                             notifier.notify(root, sink);
                         }
                     }
+                    NotificationEntry::EvmBlockReplaced(replaced) => {
+                        let subscriptions = subscriptions
+                            .evm_block_replaced_subscriptions
+                            .read()
+                            .unwrap();
+                        let num_subscriptions = subscriptions.len();
+                        if num_subscriptions > 0 {
+                            debug!(
+                                "evm block replaced notify: {:?}, num_subscriptions: {:?}",
+                                replaced, num_subscriptions
+                            );
+                        }
+                        for (_, sink) in subscriptions.iter() {
+                            inc_new_counter_info!("rpc-subscription-notify-evm-block-replaced", 1);
+                            notifier.notify(replaced.clone(), sink);
+                        }
+                    }
                     NotificationEntry::Bank(commitment_slots) => {
                         RpcSubscriptions::notify_accounts_logs_programs_signatures(
                             &subscriptions.account_subscriptions,
@@ -1116,7 +1408,17 @@ impl RpcSubscriptions {
                             &commitment_slots,
                             &notifier,
                             "bank",
-                        )
+                        );
+                        if let Some(blockstore) = &blockstore {
+                            RpcSubscriptions::notify_blocks(
+                                &subscriptions.block_subscriptions,
+                                blockstore,
+                                &max_complete_transaction_status_slot,
+                                &bank_forks,
+                                commitment_slots.highest_confirmed_root,
+                                &notifier,
+                            );
+                        }
                     }
                     NotificationEntry::Gossip(slot) => {
                         Self::process_gossip_notification(
@@ -1125,6 +1427,16 @@ impl RpcSubscriptions {
                             &subscriptions,
                             &bank_forks,
                         );
+                        if let Some(blockstore) = &blockstore {
+                            RpcSubscriptions::notify_blocks(
+                                &subscriptions.gossip_block_subscriptions,
+                                blockstore,
+                                &max_complete_transaction_status_slot,
+                                &bank_forks,
+                                slot,
+                                &notifier,
+                            );
+                        }
                     }
                     NotificationEntry::SignaturesReceived(slot_signatures) => {
                         RpcSubscriptions::process_signatures_received(
@@ -1289,6 +1601,94 @@ impl RpcSubscriptions {
         }
     }
 
+    /// Notify block subscribers of every block up to `slot`, that was not notified yet.
+    /// Finalized subscriptions are notified of rooted slots, confirmed ones of ancestors of `slot`.
+    fn notify_blocks(
+        block_subscriptions: &Arc<RpcBlockSubscriptions>,
+        blockstore: &Blockstore,
+        max_complete_transaction_status_slot: &AtomicU64,
+        bank_forks: &Arc<RwLock<BankForks>>,
+        slot: Slot,
+        notifier: &RpcNotifier,
+    ) {
+        let subscriptions = block_subscriptions.read().unwrap();
+        if subscriptions.is_empty() {
+            return;
+        }
+        let bank = match bank_forks.read().unwrap().get(slot).cloned() {
+            Some(bank) => bank,
+            None => return,
+        };
+        let lowest_notified_slot = subscriptions
+            .values()
+            .flat_map(|hashmap| hashmap.values())
+            .map(|data| *data.last_notified_slot.read().unwrap())
+            .min()
+            .unwrap_or(slot);
+        if lowest_notified_slot >= slot {
+            return;
+        }
+        let evm_blocks = collect_evm_blocks(&bank, blockstore, lowest_notified_slot);
+        let mut blocks: HashMap<Slot, Option<ConfirmedBlock>> = HashMap::new();
+
+        for (mentions, hashmap) in subscriptions.iter() {
+            for (
+                _,
+                SubscriptionData {
+                    sink,
+                    commitment,
+                    last_notified_slot,
+                    config,
+                },
+            ) in hashmap.iter()
+            {
+                let mut w_last_notified_slot = last_notified_slot.write().unwrap();
+                let mut slots: Vec<Slot> = if commitment.is_finalized() {
+                    blockstore
+                        .rooted_slot_iterator(*w_last_notified_slot + 1)
+                        .map(|iter| iter.take_while(|root| *root <= slot).collect())
+                        .unwrap_or_default()
+                } else {
+                    confirmed_fork_slots(&bank, blockstore, *w_last_notified_slot)
+                };
+                slots.sort_unstable();
+
+                let config = config.clone().unwrap_or_default();
+                for s in slots {
+                    // Transaction statuses of `s` are not written yet, retry on next notification
+                    if s > max_complete_transaction_status_slot.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    let block = blocks
+                        .entry(s)
+                        .or_insert_with(|| blockstore.get_complete_block(s, false).ok());
+                    let update = match block {
+                        Some(block) => {
+                            filter_block_result(block, evm_blocks.get(&s), mentions, s, &config)
+                        }
+                        None => Some(RpcBlockUpdate {
+                            slot: s,
+                            block: None,
+                            evm_block: None,
+                            err: Some(RpcBlockUpdateError::BlockStoreError),
+                        }),
+                    };
+                    if let Some(update) = update {
+                        inc_new_counter_info!("rpc-subscription-notify-block", 1);
+                        notifier.notify(
+                            Response {
+                                context: RpcResponseContext { slot: s },
+                                value: update,
+                            },
+                            sink,
+                        );
+                    }
+                    *w_last_notified_slot = s;
+                }
+            }
+        }
+    }
+
     fn process_signatures_received(
         (received_slot, signatures): &(Slot, Vec<Signature>),
         signature_subscriptions: &Arc<RpcSignatureSubscriptions>,
@@ -1352,7 +1752,11 @@ pub(crate) mod tests {
     use jsonrpc_core::futures::{self, stream::Stream};
     use jsonrpc_pubsub::typed::Subscriber;
     use serial_test_derive::serial;
+    use solana_ledger::{
+        blockstore::entries_to_test_shreds, entry::next_entry, get_tmp_ledger_path,
+    };
     use solana_runtime::{
+        accounts_background_service::AbsRequestSender,
         commitment::BlockCommitment,
         genesis_utils::{create_genesis_config, GenesisConfigInfo},
     };
@@ -1937,6 +2341,180 @@ pub(crate) mod tests {
             .contains_key(&sub_id));
     }
 
+    #[test]
+    #[serial]
+    fn test_check_evm_block_replaced_subscribe() {
+        let (subscriber, _id_receiver, transport_receiver) =
+            Subscriber::new_test("evmBlockReplacedNotification");
+        let sub_id = SubscriptionId::Number(0);
+        let exit = Arc::new(AtomicBool::new(false));
+        let GenesisConfigInfo { genesis_config, .. } = create_genesis_config(10_000);
+        let bank = Bank::new(&genesis_config);
+        let bank_forks = Arc::new(RwLock::new(BankForks::new(bank)));
+        let optimistically_confirmed_bank =
+            OptimisticallyConfirmedBank::locked_from_bank_forks_root(&bank_forks);
+        let subscriptions = RpcSubscriptions::new(
+            &exit,
+            bank_forks,
+            Arc::new(RwLock::new(BlockCommitmentCache::new_for_tests())),
+            optimistically_confirmed_bank,
+        );
+        subscriptions.add_evm_block_replaced_subscription(sub_id.clone(), subscriber);
+
+        assert!(subscriptions
+            .subscriptions
+            .evm_block_replaced_subscriptions
+            .read()
+            .unwrap()
+            .contains_key(&sub_id));
+
+        let replaced = RpcEvmBlockReplaced {
+            block_number: 3,
+            hash: format!("{:#x}", evm_state::H256::repeat_byte(1)),
+            slot: 5,
+            replaced_by_hash: format!("{:#x}", evm_state::H256::repeat_byte(2)),
+            replaced_by_slot: 6,
+        };
+        subscriptions.notify_evm_block_replaced(replaced.clone());
+        let (response, _) = robust_poll_or_panic(transport_receiver);
+        let expected_res_str =
+            serde_json::to_string(&serde_json::to_value(replaced).unwrap()).unwrap();
+        let expected = format!(
+            r#"{{"jsonrpc":"2.0","method":"evmBlockReplacedNotification","params":{{"result":{},"subscription":0}}}}"#,
+            expected_res_str
+        );
+        assert_eq!(expected, response);
+
+        subscriptions.remove_evm_block_replaced_subscription(&sub_id);
+        assert!(!subscriptions
+            .subscriptions
+            .evm_block_replaced_subscriptions
+            .read()
+            .unwrap()
+            .contains_key(&sub_id));
+    }
+
+    #[test]
+    #[serial]
+    fn test_check_finalized_block_subscribe() {
+        let GenesisConfigInfo {
+            genesis_config,
+            mint_keypair,
+            ..
+        } = create_genesis_config(100);
+        let bank = Bank::new(&genesis_config);
+        let blockhash = bank.last_blockhash();
+        let bank_forks = Arc::new(RwLock::new(BankForks::new(bank)));
+        let bank0 = bank_forks.read().unwrap().get(0).unwrap().clone();
+        let bank1 = Bank::new_from_parent(&bank0, &Pubkey::default(), 1);
+        bank_forks.write().unwrap().insert(bank1);
+
+        let ledger_path = get_tmp_ledger_path!();
+        let blockstore = Arc::new(Blockstore::open(&ledger_path).unwrap());
+        let alice = Keypair::new();
+        let tx = system_transaction::transfer(&mint_keypair, &alice.pubkey(), 1, blockhash);
+        let signature = tx.signatures[0];
+        let entries = vec![next_entry(&blockhash, 1, vec![tx])];
+        let shreds = entries_to_test_shreds(entries, 1, 0, true, 0);
+        blockstore.insert_shreds(shreds, None, false).unwrap();
+        blockstore.set_roots(&[1]).unwrap();
+
+        let exit = Arc::new(AtomicBool::new(false));
+        let max_complete_transaction_status_slot = Arc::new(AtomicU64::default());
+        let subscriptions = RpcSubscriptions::new_with_config(
+            &exit,
+            max_complete_transaction_status_slot.clone(),
+            blockstore.clone(),
+            bank_forks.clone(),
+            Arc::new(RwLock::new(BlockCommitmentCache::new_for_tests())),
+            OptimisticallyConfirmedBank::locked_from_bank_forks_root(&bank_forks),
+            &PubSubConfig {
+                enable_block_subscription: true,
+                ..PubSubConfig::default()
+            },
+        );
+        let (subscriber, _id_receiver, transport_receiver) =
+            Subscriber::new_test("blockNotification");
+        let sub_id = SubscriptionId::Number(0);
+        subscriptions.add_block_subscription(
+            Some(alice.pubkey()),
+            Some(RpcBlockSubscribeConfig {
+                commitment: Some(CommitmentConfig::finalized()),
+                transaction_details: Some(TransactionDetails::Signatures),
+                show_rewards: Some(false),
+                ..RpcBlockSubscribeConfig::default()
+            }),
+            sub_id.clone(),
+            subscriber,
+        );
+
+        let commitment_slots = CommitmentSlots {
+            slot: 1,
+            root: 1,
+            highest_confirmed_slot: 1,
+            highest_confirmed_root: 1,
+        };
+        // Transaction statuses of slot 1 are not written yet
+        subscriptions.notify_subscribers(commitment_slots);
+        max_complete_transaction_status_slot.store(1, Ordering::SeqCst);
+        subscriptions.notify_subscribers(commitment_slots);
+
+        let (response, _) = robust_poll_or_panic(transport_receiver);
+        let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+        let result = &response["params"]["result"];
+        assert_eq!(result["context"]["slot"], 1);
+        assert_eq!(result["value"]["slot"], 1);
+        assert_eq!(
+            result["value"]["block"]["signatures"],
+            json!([signature.to_string()])
+        );
+        assert!(result["value"]["block"].get("transactions").is_none());
+        assert!(result["value"]["block"].get("rewards").is_none());
+        assert!(result["value"]["err"].is_null());
+
+        assert!(subscriptions.remove_block_subscription(&sub_id));
+        assert!(!subscriptions.remove_block_subscription(&sub_id));
+
+        drop(subscriptions);
+        drop(blockstore);
+        Blockstore::destroy(&ledger_path).unwrap();
+    }
+
+    #[test]
+    #[serial]
+    fn test_confirmed_fork_slots() {
+        let GenesisConfigInfo { genesis_config, .. } = create_genesis_config(100);
+        let bank_forks = Arc::new(RwLock::new(BankForks::new(Bank::new(&genesis_config))));
+        // Fork 0 -> 1 -> 2 -> 4 is confirmed, 2 -> 3 is not
+        for (parent, slot) in &[(0, 1), (1, 2), (2, 3), (2, 4)] {
+            let parent = bank_forks.read().unwrap().get(*parent).unwrap().clone();
+            let bank = Bank::new_from_parent(&parent, &Pubkey::default(), *slot);
+            bank_forks.write().unwrap().insert(bank);
+        }
+        bank_forks
+            .write()
+            .unwrap()
+            .set_root(2, &AbsRequestSender::default(), None);
+
+        let ledger_path = get_tmp_ledger_path!();
+        let blockstore = Blockstore::open(&ledger_path).unwrap();
+        blockstore.set_roots(&[1, 2]).unwrap();
+
+        let bank4 = bank_forks.read().unwrap().get(4).unwrap().clone();
+        let mut slots = confirmed_fork_slots(&bank4, &blockstore, 0);
+        slots.sort_unstable();
+        assert_eq!(slots, vec![1, 2, 4]);
+
+        let mut slots = confirmed_fork_slots(&bank4, &blockstore, 1);
+        slots.sort_unstable();
+        assert_eq!(slots, vec![2, 4]);
+
+        assert!(confirmed_fork_slots(&bank4, &blockstore, 4).is_empty());
+
+        drop(blockstore);
+        Blockstore::destroy(&ledger_path).unwrap();
+    }
+
     #[test]
     #[serial]
     fn test_add_and_remove_subscription() {
@@ -0,0 +1,54 @@
+//! The `slot_status_observer` module forwards bank notifications from replay to the accountsdb
+//! plugins when the rpc services, which otherwise consume them, are disabled
+
+use crate::optimistically_confirmed_bank_tracker::{
+    BankNotificationReceiver, OptimisticallyConfirmedBankTracker,
+};
+use crossbeam_channel::RecvTimeoutError;
+use solana_accountsdb_plugin_manager::slot_status_notifier::SlotStatusNotifier;
+use solana_runtime::bank_forks::BankForks;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, RwLock,
+    },
+    thread::{self, Builder, JoinHandle},
+    time::Duration,
+};
+
+pub struct SlotStatusObserver {
+    thread_hdl: JoinHandle<()>,
+}
+
+impl SlotStatusObserver {
+    pub fn new(
+        bank_notification_receiver: BankNotificationReceiver,
+        bank_forks: Arc<RwLock<BankForks>>,
+        slot_status_notifier: SlotStatusNotifier,
+        exit: &Arc<AtomicBool>,
+    ) -> Self {
+        let exit = exit.clone();
+        let thread_hdl = Builder::new()
+            .name("solana-slot-status-observer".to_string())
+            .spawn(move || loop {
+                if exit.load(Ordering::Relaxed) {
+                    break;
+                }
+                match bank_notification_receiver.recv_timeout(Duration::from_secs(1)) {
+                    Ok(notification) => OptimisticallyConfirmedBankTracker::notify_slot_status(
+                        &slot_status_notifier,
+                        &notification,
+                        &bank_forks,
+                    ),
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => break,
+                }
+            })
+            .unwrap();
+        Self { thread_hdl }
+    }
+
+    pub fn join(self) -> thread::Result<()> {
+        self.thread_hdl.join()
+    }
+}
@@ -45,7 +45,8 @@ impl SnapshotPackagerService {
                             snapshot_utils::archive_snapshot_package(&snapshot_package)
                         {
                             warn!("Failed to create snapshot archive: {}", err);
-                        } else {
+                        } else if !snapshot_package.is_incremental() {
+                            // Only full snapshots can be downloaded by name from gossip
                             hashes.push((snapshot_package.slot, snapshot_package.hash));
                             while hashes.len() > MAX_SNAPSHOT_HASHES {
                                 hashes.remove(0);
@@ -170,6 +171,7 @@ mod tests {
             Hash::default(),
             ArchiveFormat::TarBzip2,
             SnapshotVersion::default(),
+            None,
         );
 
         // Make tarball from packageable snapshot
@@ -398,6 +398,7 @@ impl TestValidator {
                 snapshot_package_output_path: ledger_path.to_path_buf(),
                 archive_format: ArchiveFormat::Tar,
                 snapshot_version: SnapshotVersion::default(),
+                incremental_snapshot_interval_slots: std::u64::MAX,
             }),
             enforce_ulimit_nofile: false,
             warp_slot: config.warp_slot,
@@ -1,6 +1,10 @@
 use crossbeam_channel::{Receiver, RecvTimeoutError};
 use itertools::izip;
-use solana_ledger::{blockstore::Blockstore, blockstore_processor::TransactionStatusBatch};
+use solana_accountsdb_plugin_manager::transaction_notifier::TransactionNotifierLock;
+use solana_ledger::{
+    blockstore::Blockstore,
+    blockstore_processor::{TransactionStatusBatch, TransactionStatusMessage},
+};
 use solana_runtime::{
     bank::{Bank, InnerInstructionsList, NonceRollbackInfo, TransactionLogMessages},
     transaction_utils::OrderedIterator,
@@ -8,7 +12,7 @@ use solana_runtime::{
 use solana_transaction_status::{InnerInstructions, TransactionStatusMeta};
 use std::{
     sync::{
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicU64, Ordering},
         Arc,
     },
     thread::{self, Builder, JoinHandle},
@@ -22,7 +26,10 @@ pub struct TransactionStatusService {
 impl TransactionStatusService {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(
-        write_transaction_status_receiver: Receiver<TransactionStatusBatch>,
+        write_transaction_status_receiver: Receiver<TransactionStatusMessage>,
+        max_complete_transaction_status_slot: Arc<AtomicU64>,
+        enable_rpc_transaction_history: bool,
+        transaction_notifier: Option<TransactionNotifierLock>,
         blockstore: Arc<Blockstore>,
         exit: &Arc<AtomicBool>,
     ) -> Self {
@@ -35,6 +42,9 @@ impl TransactionStatusService {
                 }
                 if let Err(RecvTimeoutError::Disconnected) = Self::write_transaction_status_batch(
                     &write_transaction_status_receiver,
+                    &max_complete_transaction_status_slot,
+                    enable_rpc_transaction_history,
+                    &transaction_notifier,
                     &blockstore,
                 ) {
                     break;
@@ -45,7 +55,10 @@ impl TransactionStatusService {
     }
 
     fn write_transaction_status_batch(
-        write_transaction_status_receiver: &Receiver<TransactionStatusBatch>,
+        write_transaction_status_receiver: &Receiver<TransactionStatusMessage>,
+        max_complete_transaction_status_slot: &Arc<AtomicU64>,
+        enable_rpc_transaction_history: bool,
+        transaction_notifier: &Option<TransactionNotifierLock>,
         blockstore: &Arc<Blockstore>,
     ) -> Result<(), RecvTimeoutError> {
         let TransactionStatusBatch {
@@ -57,7 +70,15 @@ impl TransactionStatusService {
             token_balances,
             inner_instructions,
             transaction_logs,
-        } = write_transaction_status_receiver.recv_timeout(Duration::from_secs(1))?;
+            transaction_return_data,
+        } = match write_transaction_status_receiver.recv_timeout(Duration::from_secs(1))? {
+            TransactionStatusMessage::Batch(batch) => batch,
+            TransactionStatusMessage::Freeze(slot) => {
+                // Statuses of frozen `slot` are written, `blockSubscribe` notifications wait for them
+                max_complete_transaction_status_slot.fetch_max(slot, Ordering::SeqCst);
+                return Ok(());
+            }
+        };
 
         let slot = bank.slot();
         let inner_instructions_iter: Box<dyn Iterator<Item = Option<InnerInstructionsList>>> =
@@ -81,6 +102,7 @@ impl TransactionStatusService {
             post_token_balances,
             inner_instructions,
             log_messages,
+            return_data,
         ) in izip!(
             OrderedIterator::new(&transactions, iteration_order.as_deref()),
             statuses,
@@ -89,7 +111,8 @@ impl TransactionStatusService {
             token_balances.pre_token_balances,
             token_balances.post_token_balances,
             inner_instructions_iter,
-            transaction_logs_iter
+            transaction_logs_iter,
+            transaction_return_data
         ) {
             if Bank::can_commit(&status) && !transaction.signatures.is_empty() {
                 let fee_calculator = nonce_rollback
@@ -118,24 +141,37 @@ impl TransactionStatusService {
                 let pre_token_balances = Some(pre_token_balances);
                 let post_token_balances = Some(post_token_balances);
 
-                blockstore
-                    .write_transaction_status(
+                let transaction_status_meta = TransactionStatusMeta {
+                    status,
+                    fee,
+                    pre_balances,
+                    post_balances,
+                    inner_instructions,
+                    log_messages,
+                    pre_token_balances,
+                    post_token_balances,
+                    return_data,
+                };
+
+                if let Some(transaction_notifier) = transaction_notifier.as_ref() {
+                    transaction_notifier.read().unwrap().notify_transaction(
                         slot,
-                        transaction.signatures[0],
-                        writable_keys,
-                        readonly_keys,
-                        TransactionStatusMeta {
-                            status,
-                            fee,
-                            pre_balances,
-                            post_balances,
-                            inner_instructions,
-                            log_messages,
-                            pre_token_balances,
-                            post_token_balances,
-                        },
-                    )
-                    .expect("Expect database write to succeed");
+                        &transaction.signatures[0],
+                        &transaction_status_meta,
+                        &transaction,
+                    );
+                }
+                if enable_rpc_transaction_history {
+                    blockstore
+                        .write_transaction_status(
+                            slot,
+                            transaction.signatures[0],
+                            writable_keys,
+                            readonly_keys,
+                            transaction_status_meta,
+                        )
+                        .expect("Expect database write to succeed");
+                }
             }
         }
         Ok(())
@@ -216,11 +216,11 @@ impl Tvu {
                     let (snapshot_request_sender, snapshot_request_receiver) = unbounded();
                     (
                         Some(snapshot_request_sender),
-                        Some(SnapshotRequestHandler {
+                        Some(SnapshotRequestHandler::new(
                             snapshot_config,
                             snapshot_request_receiver,
-                            accounts_package_sender: accounts_hash_sender,
-                        }),
+                            accounts_hash_sender,
+                        )),
                     )
                 })
                 .unwrap_or((None, None))
@@ -28,6 +28,7 @@ use crate::{
     serve_repair::ServeRepair,
     serve_repair_service::ServeRepairService,
     sigverify,
+    slot_status_observer::SlotStatusObserver,
     snapshot_packager_service::{PendingSnapshotPackage, SnapshotPackagerService},
     tpu::{Tpu, DEFAULT_TPU_COALESCE_MS},
     transaction_status_service::TransactionStatusService,
@@ -35,6 +36,10 @@ use crate::{
 };
 use crossbeam_channel::{bounded, unbounded};
 use rand::{thread_rng, Rng};
+use solana_accountsdb_plugin_manager::{
+    accountsdb_plugin_service::AccountsDbPluginService,
+    transaction_notifier::TransactionNotifierLock,
+};
 use solana_ledger::{
     bank_forks_utils,
     blockstore::{Blockstore, BlockstoreSignals, CompletedSlotsReceiver, PurgeType},
@@ -70,7 +75,7 @@ use std::{
     net::SocketAddr,
     ops::Deref,
     path::{Path, PathBuf},
-    sync::atomic::{AtomicBool, Ordering},
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
     sync::mpsc::Receiver,
     sync::{Arc, Mutex, RwLock},
     thread::sleep,
@@ -129,6 +134,7 @@ pub struct ValidatorConfig {
     pub accounts_db_test_hash_calculation: bool,
     pub accounts_db_use_index_hash_calculation: bool,
     pub tpu_coalesce_ms: u64,
+    pub accountsdb_plugin_config_files: Option<Vec<PathBuf>>,
 }
 
 impl Default for ValidatorConfig {
@@ -182,6 +188,7 @@ impl Default for ValidatorConfig {
             accounts_db_test_hash_calculation: false,
             accounts_db_use_index_hash_calculation: true,
             tpu_coalesce_ms: DEFAULT_TPU_COALESCE_MS,
+            accountsdb_plugin_config_files: None,
         }
     }
 }
@@ -206,6 +213,7 @@ impl ValidatorExit {
 #[derive(Default)]
 struct TransactionHistoryServices {
     transaction_status_sender: Option<TransactionStatusSender>,
+    max_complete_transaction_status_slot: Arc<AtomicU64>,
     transaction_status_service: Option<TransactionStatusService>,
     rewards_recorder_sender: Option<RewardsRecorderSender>,
     rewards_recorder_service: Option<RewardsRecorderService>,
@@ -236,6 +244,8 @@ pub struct Validator {
     cache_block_time_service: Option<CacheBlockTimeService>,
     sample_performance_service: Option<SamplePerformanceService>,
     evm_block_recorder_service: Option<EvmRecorderService>,
+    accountsdb_plugin_service: Option<AccountsDbPluginService>,
+    slot_status_observer: Option<SlotStatusObserver>,
     gossip_service: GossipService,
     serve_repair_service: ServeRepairService,
     completed_data_sets_service: CompletedDataSetsService,
@@ -331,6 +341,22 @@ impl Validator {
         start.stop();
         info!("done. {}", start);
 
+        let accountsdb_plugin_service =
+            if let Some(accountsdb_plugin_config_files) = &config.accountsdb_plugin_config_files {
+                match AccountsDbPluginService::new(accountsdb_plugin_config_files) {
+                    Ok(accountsdb_plugin_service) => Some(accountsdb_plugin_service),
+                    Err(err) => {
+                        error!("Failed to load the AccountsDb plugin: {:?}", err);
+                        abort();
+                    }
+                }
+            } else {
+                None
+            };
+        let transaction_notifier = accountsdb_plugin_service
+            .as_ref()
+            .and_then(|service| service.get_transaction_notifier());
+
         let mut validator_exit = ValidatorExit::default();
         let exit = Arc::new(AtomicBool::new(false));
         let exit_ = exit.clone();
@@ -348,6 +374,7 @@ impl Validator {
             snapshot_hash,
             TransactionHistoryServices {
                 transaction_status_sender,
+                max_complete_transaction_status_slot,
                 transaction_status_service,
                 rewards_recorder_sender,
                 rewards_recorder_service,
@@ -365,10 +392,19 @@ impl Validator {
             config.poh_verify,
             &exit,
             config.enforce_ulimit_nofile,
+            transaction_notifier,
         );
 
         let leader_schedule_cache = Arc::new(leader_schedule_cache);
         let bank = bank_forks.working_bank();
+        if let Some(accountsdb_plugin_service) = &accountsdb_plugin_service {
+            let accounts_db = &bank.rc.accounts.accounts_db;
+            accounts_db.set_accounts_update_notifier(
+                accountsdb_plugin_service.get_accounts_update_notifier(),
+            );
+            accounts_db.notify_account_restore_from_snapshot(&bank.ancestors);
+            accountsdb_plugin_service.notify_end_of_startup();
+        }
         if let Some(ref shrink_paths) = config.account_shrink_paths {
             bank.set_shrink_paths(shrink_paths.clone());
         }
@@ -423,12 +459,14 @@ impl Validator {
         let optimistically_confirmed_bank =
             OptimisticallyConfirmedBank::locked_from_bank_forks_root(&bank_forks);
 
-        let subscriptions = Arc::new(RpcSubscriptions::new_with_vote_subscription(
+        let subscriptions = Arc::new(RpcSubscriptions::new_with_config(
             &exit,
+            max_complete_transaction_status_slot,
+            blockstore.clone(),
             bank_forks.clone(),
             block_commitment_cache.clone(),
             optimistically_confirmed_bank.clone(),
-            config.pubsub_config.enable_vote_subscription,
+            &config.pubsub_config,
         ));
 
         let max_slots = Arc::new(MaxSlots::default());
@@ -505,6 +543,7 @@ impl Validator {
                         config.send_transaction_retry_ms,
                         config.send_transaction_leader_forward_count,
                         max_slots.clone(),
+                        leader_schedule_cache.clone(),
                     ),
                     pubsub_service: PubSubService::new(
                         config.pubsub_config.clone(),
@@ -518,6 +557,9 @@ impl Validator {
                         bank_forks.clone(),
                         optimistically_confirmed_bank,
                         subscriptions.clone(),
+                        accountsdb_plugin_service
+                            .as_ref()
+                            .map(|service| service.get_slot_status_notifier()),
                     ),
                 }),
                 Some(bank_notification_sender),
@@ -526,6 +568,23 @@ impl Validator {
             (None, None)
         };
 
+        // Without rpc services nobody consumes bank notifications, observe them for the plugins
+        let (slot_status_observer, bank_notification_sender) = match &accountsdb_plugin_service {
+            Some(accountsdb_plugin_service) if bank_notification_sender.is_none() => {
+                let (bank_notification_sender, bank_notification_receiver) = unbounded();
+                (
+                    Some(SlotStatusObserver::new(
+                        bank_notification_receiver,
+                        bank_forks.clone(),
+                        accountsdb_plugin_service.get_slot_status_notifier(),
+                        &exit,
+                    )),
+                    Some(bank_notification_sender),
+                )
+            }
+            _ => (None, bank_notification_sender),
+        };
+
         if config.dev_halt_at_slot.is_some() {
             // Simulate a confirmed root to avoid RPC errors with CommitmentConfig::finalized() and
             // to ensure RPC endpoints like getConfirmedBlock, which require a confirmed root, work
@@ -715,6 +774,8 @@ impl Validator {
             snapshot_packager_service,
             completed_data_sets_service,
             evm_block_recorder_service,
+            accountsdb_plugin_service,
+            slot_status_observer,
             tpu,
             tvu,
             poh_service,
@@ -806,6 +867,10 @@ impl Validator {
                 .expect("evm_block_recorder_service");
         }
 
+        if let Some(slot_status_observer) = self.slot_status_observer {
+            slot_status_observer.join().expect("slot_status_observer");
+        }
+
         if let Some(s) = self.snapshot_packager_service {
             s.join().expect("snapshot_packager_service");
         }
@@ -820,6 +885,12 @@ impl Validator {
             .join()
             .expect("completed_data_sets_service");
         self.ip_echo_server.shutdown_background();
+
+        if let Some(accountsdb_plugin_service) = self.accountsdb_plugin_service {
+            accountsdb_plugin_service
+                .join()
+                .expect("accountsdb_plugin_service");
+        }
     }
 }
 
@@ -961,6 +1032,7 @@ fn new_banks_from_ledger(
     poh_verify: bool,
     exit: &Arc<AtomicBool>,
     enforce_ulimit_nofile: bool,
+    transaction_notifier: Option<TransactionNotifierLock>,
 ) -> (
     GenesisConfig,
     BankForks,
@@ -1027,12 +1099,16 @@ fn new_banks_from_ledger(
     };
 
     let blockstore = Arc::new(blockstore);
+    let enable_rpc_transaction_history =
+        config.rpc_addrs.is_some() && config.rpc_config.enable_rpc_transaction_history;
     let transaction_history_services =
-        if config.rpc_addrs.is_some() && config.rpc_config.enable_rpc_transaction_history {
+        if enable_rpc_transaction_history || transaction_notifier.is_some() {
             initialize_rpc_transaction_history_services(
                 blockstore.clone(),
                 exit,
+                enable_rpc_transaction_history,
                 config.rpc_config.enable_cpi_and_log_storage,
+                transaction_notifier,
             )
         } else {
             TransactionHistoryServices::default()
@@ -1209,19 +1285,47 @@ fn backup_and_clear_blockstore(ledger_path: &Path, start_slot: Slot, shred_versi
 fn initialize_rpc_transaction_history_services(
     blockstore: Arc<Blockstore>,
     exit: &Arc<AtomicBool>,
+    enable_rpc_transaction_history: bool,
     enable_cpi_and_log_storage: bool,
+    transaction_notifier: Option<TransactionNotifierLock>,
 ) -> TransactionHistoryServices {
     let (transaction_status_sender, transaction_status_receiver) = unbounded();
     let transaction_status_sender = Some(TransactionStatusSender {
         sender: transaction_status_sender,
         enable_cpi_and_log_storage,
     });
+    let max_complete_transaction_status_slot = Arc::new(AtomicU64::default());
     let transaction_status_service = Some(TransactionStatusService::new(
         transaction_status_receiver,
+        max_complete_transaction_status_slot.clone(),
+        enable_rpc_transaction_history,
+        transaction_notifier.clone(),
         blockstore.clone(),
         exit,
     ));
 
+    let (evm_block_recorder_sender, evm_block_recorder_receiver) = unbounded();
+    let evm_block_recorder_sender = Some(evm_block_recorder_sender);
+    let evm_block_recorder_service = Some(EvmRecorderService::new(
+        evm_block_recorder_receiver,
+        enable_rpc_transaction_history,
+        transaction_notifier,
+        blockstore.clone(),
+        exit,
+    ));
+
+    // Plugins are only notified of transactions, rewards and block times are rpc-only
+    if !enable_rpc_transaction_history {
+        return TransactionHistoryServices {
+            transaction_status_sender,
+            max_complete_transaction_status_slot,
+            transaction_status_service,
+            evm_block_recorder_sender,
+            evm_block_recorder_service,
+            ..TransactionHistoryServices::default()
+        };
+    }
+
     let (rewards_recorder_sender, rewards_receiver) = unbounded();
     let rewards_recorder_sender = Some(rewards_recorder_sender);
     let rewards_recorder_service = Some(RewardsRecorderService::new(
@@ -1234,20 +1338,13 @@ fn initialize_rpc_transaction_history_services(
     let cache_block_time_sender = Some(cache_block_time_sender);
     let cache_block_time_service = Some(CacheBlockTimeService::new(
         cache_block_time_receiver,
-        blockstore.clone(),
-        exit,
-    ));
-
-    let (evm_block_recorder_sender, evm_block_recorder_receiver) = unbounded();
-    let evm_block_recorder_sender = Some(evm_block_recorder_sender);
-    let evm_block_recorder_service = Some(EvmRecorderService::new(
-        evm_block_recorder_receiver,
         blockstore,
         exit,
     ));
 
     TransactionHistoryServices {
         transaction_status_sender,
+        max_complete_transaction_status_slot,
         transaction_status_service,
         rewards_recorder_sender,
         rewards_recorder_service,
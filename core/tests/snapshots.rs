@@ -147,6 +147,7 @@ mod tests {
                 snapshot_path: PathBuf::from(snapshot_dir.path()),
                 archive_format: ArchiveFormat::TarBzip2,
                 snapshot_version,
+                incremental_snapshot_interval_slots: std::u64::MAX,
             };
             bank_forks.set_snapshot_config(Some(snapshot_config.clone()));
             SnapshotTestConfig {
@@ -192,6 +193,7 @@ mod tests {
                 ArchiveFormat::TarBzip2,
             ),
             ArchiveFormat::TarBzip2,
+            None,
             old_genesis_config,
             None,
             None,
@@ -236,11 +238,11 @@ mod tests {
         let (s, snapshot_request_receiver) = unbounded();
         let (accounts_package_sender, _r) = channel();
         let request_sender = AbsRequestSender::new(Some(s));
-        let snapshot_request_handler = SnapshotRequestHandler {
-            snapshot_config: snapshot_test_config.snapshot_config.clone(),
+        let snapshot_request_handler = SnapshotRequestHandler::new(
+            snapshot_test_config.snapshot_config.clone(),
             snapshot_request_receiver,
             accounts_package_sender,
-        };
+        );
         for slot in 0..last_slot {
             let mut bank = Bank::new_from_parent(&bank_forks[slot], &Pubkey::default(), slot + 1);
             f(&mut bank, &mint_keypair);
@@ -273,6 +275,7 @@ mod tests {
             ArchiveFormat::TarBzip2,
             snapshot_version,
             None,
+            None,
         )
         .unwrap();
         let snapshot_package = snapshot_utils::process_accounts_package_pre(
@@ -404,6 +407,7 @@ mod tests {
                 snapshot_config.snapshot_version,
                 &snapshot_config.archive_format,
                 None,
+                None,
             )
             .unwrap();
 
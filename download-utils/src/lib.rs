@@ -210,3 +210,59 @@ pub fn download_snapshot(
     }
     Err("Snapshot couldn't be downloaded".to_string())
 }
+
+/// Downloads the latest incremental snapshot of the node at `rpc_addr`, if it's built
+/// on top of the full snapshot at `full_snapshot_slot`.
+pub fn download_incremental_snapshot(
+    rpc_addr: &SocketAddr,
+    ledger_path: &Path,
+    full_snapshot_slot: Slot,
+    use_progress_bar: bool,
+) -> Result<(), String> {
+    // Resolve the archive name, without following the redirect
+    let response = reqwest::blocking::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .and_then(|client| {
+            client
+                .get(&format!("http://{}/incremental-snapshot.tar.bz2", rpc_addr))
+                .send()
+        })
+        .map_err(|err| err.to_string())?;
+    if !response.status().is_redirection() {
+        return Err(format!(
+            "Incremental snapshot is not available: {}",
+            response.status()
+        ));
+    }
+    let archive_filename = response
+        .headers()
+        .get(reqwest::header::LOCATION)
+        .and_then(|location| location.to_str().ok())
+        .map(|location| location.trim_start_matches('/'))
+        .ok_or_else(|| "Invalid incremental snapshot redirect".to_string())?;
+    let (base_slot, slot, hash, archive_format) =
+        snapshot_utils::incremental_snapshot_hash_of(archive_filename)
+            .ok_or_else(|| format!("Invalid incremental snapshot name: {}", archive_filename))?;
+    if base_slot != full_snapshot_slot {
+        return Err(format!(
+            "Incremental snapshot {} is not built on top of snapshot at slot {}",
+            archive_filename, full_snapshot_slot
+        ));
+    }
+
+    let desired_snapshot_package = snapshot_utils::get_incremental_snapshot_archive_path(
+        ledger_path.to_path_buf(),
+        base_slot,
+        &(slot, hash),
+        archive_format,
+    );
+    if desired_snapshot_package.is_file() {
+        return Ok(());
+    }
+    download_file(
+        &format!("http://{}/{}", rpc_addr, archive_filename),
+        &desired_snapshot_package,
+        use_progress_bar,
+    )
+}
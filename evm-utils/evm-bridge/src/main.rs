@@ -40,7 +40,7 @@ use solana_transaction_status::{
 };
 
 use solana_client::{
-    client_error::{ClientError, ClientErrorKind},
+    client_error::{ClientError, ClientErrorKind, Result as ClientResult},
     rpc_client::RpcClient,
     rpc_config::*,
     rpc_request::RpcRequest,
@@ -167,15 +167,46 @@ impl EvmBridge {
 }
 
 macro_rules! proxy_evm_rpc {
-    ($rpc: expr, $rpc_call:ident $(, $calls:expr)*) => (
-        {
-            debug!("evm proxy received {}", stringify!($rpc_call));
-            match RpcClient::send(&$rpc, RpcRequest::$rpc_call, json!([$($calls,)*])) {
-                Err(e) => Err(from_client_error(e).into()),
-                Ok(o) => Ok(o)
-            }
+    ($rpc_call:expr) => {{
+        debug!("evm proxy received {}", stringify!($rpc_call));
+        $rpc_call.map_err(from_client_error)
+    }};
+}
+
+/// Block selector of proxied `eth_*` requests: either EVM block id,
+/// or commitment of the native chain bank.
+enum BlockRef {
+    Id(BlockId),
+    Commitment(CommitmentConfig),
+}
+
+impl BlockRef {
+    fn parse(block: Option<String>) -> EvmResult<Self> {
+        let block = match block {
+            Some(block) => block,
+            // Node uses its default commitment, when block isn't set
+            None => return Ok(BlockRef::Commitment(CommitmentConfig::default())),
+        };
+        if let Ok(commitment) = block.parse::<CommitmentLevel>() {
+            return Ok(BlockRef::Commitment(CommitmentConfig { commitment }));
         }
-    )
+        block
+            .parse()
+            .map(BlockRef::Id)
+            .map_err(|_| Error::StateNotFoundForBlock { block })
+    }
+
+    /// Proxy request at this block, using `at_block` or `with_commitment` request of `RpcClient`.
+    fn proxy<T>(
+        self,
+        at_block: impl FnOnce(BlockId) -> ClientResult<T>,
+        with_commitment: impl FnOnce(CommitmentConfig) -> ClientResult<T>,
+    ) -> EvmResult<T> {
+        match self {
+            BlockRef::Id(block) => proxy_evm_rpc!(at_block(block)),
+            BlockRef::Commitment(commitment) => proxy_evm_rpc!(with_commitment(commitment)),
+        }
+    }
 }
 
 pub struct BridgeErpcImpl;
@@ -334,7 +365,10 @@ impl ChainMockERPC for ChainMockErpcProxy {
         block: String,
         full: bool,
     ) -> EvmResult<Option<RPCBlock>> {
-        proxy_evm_rpc!(meta.rpc_client, EthGetBlockByNumber, block, full)
+        let block = block
+            .parse()
+            .map_err(|_| Error::StateNotFoundForBlock { block })?;
+        proxy_evm_rpc!(meta.rpc_client.get_evm_block_by_number(block, full))
     }
 
     fn block_transaction_count_by_number(
@@ -412,7 +446,7 @@ impl BasicERPC for BasicErpcProxy {
 
     // The same as get_slot
     fn block_number(&self, meta: Self::Metadata) -> EvmResult<Hex<usize>> {
-        proxy_evm_rpc!(meta.rpc_client, EthBlockNumber)
+        proxy_evm_rpc!(meta.rpc_client.get_evm_block_number()).map(|num| Hex(num as usize))
     }
 
     fn balance(
@@ -421,7 +455,15 @@ impl BasicERPC for BasicErpcProxy {
         address: Hex<Address>,
         block: Option<String>,
     ) -> EvmResult<Hex<U256>> {
-        proxy_evm_rpc!(meta.rpc_client, EthGetBalance, address, block)
+        BlockRef::parse(block)?
+            .proxy(
+                |block| meta.rpc_client.get_evm_balance_at_block(&address.0, block),
+                |commitment| {
+                    meta.rpc_client
+                        .get_evm_balance_with_commitment(&address.0, commitment)
+                },
+            )
+            .map(Hex)
     }
 
     fn storage_at(
@@ -431,7 +473,18 @@ impl BasicERPC for BasicErpcProxy {
         data: Hex<H256>,
         block: Option<String>,
     ) -> EvmResult<Hex<H256>> {
-        proxy_evm_rpc!(meta.rpc_client, EthGetStorageAt, address, data, block)
+        BlockRef::parse(block)?
+            .proxy(
+                |block| {
+                    meta.rpc_client
+                        .get_evm_storage_at_block(&address.0, &data.0, block)
+                },
+                |commitment| {
+                    meta.rpc_client
+                        .get_evm_storage_at_with_commitment(&address.0, &data.0, commitment)
+                },
+            )
+            .map(Hex)
     }
 
     fn transaction_count(
@@ -440,7 +493,18 @@ impl BasicERPC for BasicErpcProxy {
         address: Hex<Address>,
         block: Option<String>,
     ) -> EvmResult<Hex<U256>> {
-        proxy_evm_rpc!(meta.rpc_client, EthGetTransactionCount, address, block)
+        BlockRef::parse(block)?
+            .proxy(
+                |block| {
+                    meta.rpc_client
+                        .get_evm_transaction_count_at_block(&address.0, block)
+                },
+                |commitment| {
+                    meta.rpc_client
+                        .get_evm_transaction_count_with_commitment(&address.0, commitment)
+                },
+            )
+            .map(Hex)
     }
 
     fn code(
@@ -449,7 +513,15 @@ impl BasicERPC for BasicErpcProxy {
         address: Hex<Address>,
         block: Option<String>,
     ) -> EvmResult<Bytes> {
-        proxy_evm_rpc!(meta.rpc_client, EthGetCode, address, block)
+        BlockRef::parse(block)?
+            .proxy(
+                |block| meta.rpc_client.get_evm_code_at_block(&address.0, block),
+                |commitment| {
+                    meta.rpc_client
+                        .get_evm_code_with_commitment(&address.0, commitment)
+                },
+            )
+            .map(Bytes)
     }
 
     fn transaction_by_hash(
@@ -457,7 +529,7 @@ impl BasicERPC for BasicErpcProxy {
         meta: Self::Metadata,
         tx_hash: Hex<H256>,
     ) -> EvmResult<Option<RPCTransaction>> {
-        proxy_evm_rpc!(meta.rpc_client, EthGetTransactionByHash, tx_hash)
+        proxy_evm_rpc!(meta.rpc_client.get_evm_transaction_by_hash(&tx_hash.0))
     }
 
     fn transaction_receipt(
@@ -465,7 +537,7 @@ impl BasicERPC for BasicErpcProxy {
         meta: Self::Metadata,
         tx_hash: Hex<H256>,
     ) -> EvmResult<Option<RPCReceipt>> {
-        proxy_evm_rpc!(meta.rpc_client, EthGetTransactionReceipt, tx_hash)
+        proxy_evm_rpc!(meta.rpc_client.get_evm_transaction_receipt(&tx_hash.0))
     }
 
     fn call(
@@ -474,7 +546,12 @@ impl BasicERPC for BasicErpcProxy {
         tx: RPCTransaction,
         block: Option<String>,
     ) -> EvmResult<Bytes> {
-        proxy_evm_rpc!(meta.rpc_client, EthCall, tx, block)
+        BlockRef::parse(block)?
+            .proxy(
+                |block| meta.rpc_client.evm_call_at_block(&tx, block),
+                |commitment| meta.rpc_client.evm_call_with_commitment(&tx, commitment),
+            )
+            .map(Bytes)
     }
 
     fn estimate_gas(
@@ -483,11 +560,19 @@ impl BasicERPC for BasicErpcProxy {
         tx: RPCTransaction,
         block: Option<String>,
     ) -> EvmResult<Hex<Gas>> {
-        proxy_evm_rpc!(meta.rpc_client, EthEstimateGas, tx, block)
+        BlockRef::parse(block)?
+            .proxy(
+                |block| meta.rpc_client.evm_estimate_gas_at_block(&tx, block),
+                |commitment| {
+                    meta.rpc_client
+                        .evm_estimate_gas_with_commitment(&tx, commitment)
+                },
+            )
+            .map(Hex)
     }
 
     fn logs(&self, meta: Self::Metadata, log_filter: RPCLogFilter) -> EvmResult<Vec<RPCLog>> {
-        proxy_evm_rpc!(meta.rpc_client, EthGetLogs, log_filter)
+        proxy_evm_rpc!(meta.rpc_client.get_evm_logs(&log_filter))
     }
 }
 
@@ -95,7 +95,7 @@ pub struct RPCBlock {
     pub uncles: Vec<Hex<H256>>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct RPCTransaction {
     pub from: Option<Hex<Address>>,
@@ -510,6 +510,53 @@ pub mod bridge {
 //     server.wait();
 // }
 
+impl RPCBlock {
+    /// Convert evm block, `full` selects transactions objects instead of hashes.
+    pub fn new_from_block(block: evm_state::Block, confirmed: bool, full: bool) -> Self {
+        let block_hash = block.header.hash();
+        let transactions = if full {
+            let txs = block
+                .transactions
+                .into_iter()
+                .map(|(_k, v)| v)
+                .filter_map(|receipt| RPCTransaction::new_from_receipt(receipt, block_hash).ok())
+                .collect();
+            Either::Right(txs)
+        } else {
+            let txs = block
+                .transactions
+                .into_iter()
+                .map(|(k, _v)| Hex(k))
+                .collect();
+            Either::Left(txs)
+        };
+
+        RPCBlock {
+            number: U256::from(block.header.block_number).into(),
+            hash: block_hash.into(),
+            parent_hash: block.header.parent_hash.into(),
+            size: 0x100.into(),
+            gas_limit: Hex(block.header.gas_limit.into()),
+            gas_used: Hex(block.header.gas_used.into()),
+            timestamp: Hex(block.header.timestamp),
+            transactions,
+            nonce: block.header.native_chain_slot,
+            mix_hash: block.header.native_chain_hash.into(),
+            logs_bloom: block.header.logs_bloom, // H2048
+            transactions_root: Hex(block.header.transactions_root),
+            state_root: Hex(block.header.state_root),
+            receipts_root: Hex(block.header.receipts_root),
+            is_finalized: confirmed,
+            miner: Address::zero().into(),
+            difficulty: U256::zero().into(),
+            total_difficulty: U256::zero().into(),
+            extra_data: b"Velas EVM compatibility layer...".to_vec().into(),
+            sha3_uncles: H256::zero().into(),
+            uncles: vec![],
+        }
+    }
+}
+
 impl RPCTransaction {
     pub fn new_from_receipt(
         receipt: evm_state::transactions::TransactionReceipt,
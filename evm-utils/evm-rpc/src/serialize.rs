@@ -22,7 +22,7 @@ fn format_hex_trimmed<T: LowerHex>(val: &T) -> String {
 
 impl<T: FormatHex> Hex<T> {
     pub fn from_hex(data: &str) -> Result<Self, Error> {
-        if &data[0..2] != "0x" {
+        if !data.starts_with("0x") {
             return InvalidHexPrefix {
                 input_data: data.to_string(),
             }
@@ -269,6 +269,54 @@ impl<T: FormatHex + FromStr> From<T> for Hex<T> {
     }
 }
 
+/// Block selector for the `block` parameter of `eth_*` calls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockId {
+    Num(u64),
+    Earliest,
+    Latest,
+    Pending,
+}
+
+impl Default for BlockId {
+    fn default() -> Self {
+        BlockId::Latest
+    }
+}
+
+impl From<u64> for BlockId {
+    fn from(num: u64) -> Self {
+        BlockId::Num(num)
+    }
+}
+
+impl FromStr for BlockId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "earliest" => Ok(BlockId::Earliest),
+            "latest" => Ok(BlockId::Latest),
+            "pending" => Ok(BlockId::Pending),
+            num => Hex::<u64>::from_hex(num).map(|num| BlockId::Num(num.0)),
+        }
+    }
+}
+
+impl Serialize for BlockId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            BlockId::Num(num) => Hex(*num).serialize(serializer),
+            BlockId::Earliest => serializer.serialize_str("earliest"),
+            BlockId::Latest => serializer.serialize_str("latest"),
+            BlockId::Pending => serializer.serialize_str("pending"),
+        }
+    }
+}
+
 // The starting of removing Hex type in favour of #[serde(with)] atribute
 // Currently used only for nonce, because its u64, but should be serialized as HASH
 pub mod hex_serde {
@@ -373,6 +421,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn block_id_serialize() {
+        assert_eq!("\"0x0\"", serde_json::to_string(&BlockId::Num(0)).unwrap());
+        assert_eq!(
+            "\"0x2a\"",
+            serde_json::to_string(&BlockId::from(42)).unwrap()
+        );
+        assert_eq!(
+            "\"earliest\"",
+            serde_json::to_string(&BlockId::Earliest).unwrap()
+        );
+        assert_eq!(
+            "\"latest\"",
+            serde_json::to_string(&BlockId::default()).unwrap()
+        );
+        assert_eq!(
+            "\"pending\"",
+            serde_json::to_string(&BlockId::Pending).unwrap()
+        );
+    }
+
+    #[test]
+    fn block_id_from_str() {
+        assert_eq!("0x2a".parse::<BlockId>().unwrap(), BlockId::Num(42));
+        assert_eq!("earliest".parse::<BlockId>().unwrap(), BlockId::Earliest);
+        assert_eq!("latest".parse::<BlockId>().unwrap(), BlockId::Latest);
+        assert_eq!("pending".parse::<BlockId>().unwrap(), BlockId::Pending);
+        assert!("finalized".parse::<BlockId>().is_err());
+    }
+
     #[test]
     fn bytes_single_digit() {
         assert_eq!("\"0x01\"", serde_json::to_string(&Bytes(vec![1])).unwrap());
@@ -0,0 +1,163 @@
+//! Runner of `BlockchainTests`.
+use std::path::Path;
+
+use evm_state::{
+    ChainContext, EvmBackend, EvmConfig, Executor, HardforkConfig, Incomming, Transaction,
+    TransactionSignature, UnsignedTransaction, H256, U256,
+};
+
+use crate::fixture::{
+    memory_accounts, parse_action, u256_to_h256, Block, BlockchainTest, BlockchainTestFile,
+    SignedTransaction,
+};
+use crate::{execute_transaction, hardfork_config, to_u64, Outcome, Report, TestResult};
+
+/// Run all tests from fixture file, only for `fork` if it was set.
+pub fn run_file(path: &Path, fork: Option<&str>) -> anyhow::Result<Report> {
+    let file: BlockchainTestFile = crate::read_fixture(path)?;
+    let mut report = Report::default();
+    for (name, test) in &file {
+        if let Some(result) = run_test(name, test, fork) {
+            report.push(result)
+        }
+    }
+    Ok(report)
+}
+
+/// Returns `None` if test is filtered out by fork.
+pub fn run_test(
+    name: &str,
+    test: &BlockchainTest,
+    fork_filter: Option<&str>,
+) -> Option<TestResult> {
+    if fork_filter.map_or(false, |filter| filter != test.network) {
+        return None;
+    }
+    let outcome = match hardfork_config(&test.network) {
+        None => Outcome::Skipped(format!("Fork {} is not supported", test.network)),
+        Some(hardfork) => match execute(test, hardfork) {
+            Ok(()) => Outcome::Passed,
+            Err(e) => Outcome::Failed(format!("{:#}", e)),
+        },
+    };
+    Some(TestResult::new(name, test.network.as_str(), outcome))
+}
+
+/// Import all valid blocks, and check state root after each of them.
+pub fn execute(test: &BlockchainTest, hardfork: HardforkConfig) -> anyhow::Result<()> {
+    let genesis = &test.genesis_block_header;
+    let mut backend = EvmBackend::default();
+    backend.state.block_number = to_u64(genesis.number.0)?;
+    backend.state.timestamp = to_u64(genesis.timestamp.0)?;
+    backend.set_initial(memory_accounts(&test.pre));
+    let mut committed = backend.commit_block(0, genesis.hash.0);
+    anyhow::ensure!(
+        committed.last_root() == genesis.state_root.0,
+        "Genesis state root {:?}, expected {:?}",
+        committed.last_root(),
+        genesis.state_root.0
+    );
+
+    // Newest block hash is first.
+    let mut last_hashes = vec![genesis.hash.0];
+    for (slot, block) in test
+        .blocks
+        .iter()
+        .filter(|block| block.is_valid())
+        .enumerate()
+    {
+        let header = block.block_header.as_ref().expect("valid block has header");
+        let mut backend = committed.next_incomming(to_u64(header.timestamp.0)?);
+        backend.state.block_number = to_u64(header.number.0)?;
+
+        let backend = execute_block(backend, block, hardfork, &last_hashes)?;
+        committed = backend.commit_block(slot as u64 + 1, header.hash.0);
+        anyhow::ensure!(
+            committed.last_root() == header.state_root.0,
+            "Block {} state root {:?}, expected {:?}",
+            header.number.0,
+            committed.last_root(),
+            header.state_root.0
+        );
+        last_hashes.insert(0, header.hash.0);
+    }
+    Ok(())
+}
+
+fn execute_block(
+    backend: EvmBackend<Incomming>,
+    block: &Block,
+    hardfork: HardforkConfig,
+    last_hashes: &[H256],
+) -> anyhow::Result<EvmBackend<Incomming>> {
+    let header = block.block_header.as_ref().expect("valid block has header");
+    let mut hashes = [H256::zero(); 256];
+    for (hash, last_hash) in hashes.iter_mut().zip(last_hashes) {
+        *hash = *last_hash;
+    }
+    let config = EvmConfig {
+        executor_config: hardfork,
+        gas_limit: to_u64(header.gas_limit.0)?,
+        ..EvmConfig::default()
+    };
+    let mut executor = Executor::with_config(backend, ChainContext::new(hashes), config);
+
+    for tx in &block.transactions {
+        let tx = signed_transaction(tx)?;
+        let caller = tx.caller().map_err(|e| anyhow::anyhow!("{}", e))?;
+        execute_transaction(
+            &mut executor,
+            caller,
+            UnsignedTransaction::from(tx),
+            header.coinbase.0,
+        );
+    }
+
+    let reward = block_reward(hardfork);
+    let number = header.number.0;
+    for uncle in &block.uncle_headers {
+        let uncle_reward = (uncle.number.0 + U256::from(8) - number) * reward / U256::from(8);
+        executor.deposit(uncle.coinbase.0, uncle_reward);
+    }
+    let miner_reward = reward + reward / U256::from(32) * U256::from(block.uncle_headers.len());
+    executor.deposit(header.coinbase.0, miner_reward);
+
+    Ok(executor.deconstruct())
+}
+
+/// Mining reward, that is expected by fixtures.
+fn block_reward(hardfork: HardforkConfig) -> U256 {
+    let ether = U256::exp10(18);
+    match hardfork {
+        HardforkConfig::Frontier => ether * U256::from(5),
+        HardforkConfig::Istanbul => ether * U256::from(2),
+    }
+}
+
+fn signed_transaction(tx: &SignedTransaction) -> anyhow::Result<Transaction> {
+    Ok(Transaction {
+        nonce: tx.nonce.0,
+        gas_price: tx.gas_price.0,
+        gas_limit: tx.gas_limit.0,
+        action: parse_action(&tx.to)?,
+        value: tx.value.0,
+        signature: TransactionSignature {
+            v: to_u64(tx.v.0)?,
+            r: u256_to_h256(tx.r.0),
+            s: u256_to_h256(tx.s.0),
+        },
+        input: tx.data.0.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rewards() {
+        let reward = block_reward(HardforkConfig::Istanbul);
+        assert_eq!(reward, U256::from(2_000_000_000_000_000_000u64));
+        assert!(block_reward(HardforkConfig::Frontier) > reward);
+    }
+}
@@ -0,0 +1,243 @@
+//! Json layout of `GeneralStateTests` and `BlockchainTests` fixtures.
+use std::collections::BTreeMap;
+
+use evm_rpc::{Bytes, Hex};
+use evm_state::{MemoryAccount, TransactionAction, H160, H256, U256};
+use serde::Deserialize;
+
+/// File with state tests, indexed by test name.
+pub type StateTestFile = BTreeMap<String, StateTest>;
+
+/// File with blockchain tests, indexed by test name.
+pub type BlockchainTestFile = BTreeMap<String, BlockchainTest>;
+
+/// Accounts state, indexed by address.
+pub type State = BTreeMap<Hex<H160>, AccountFixture>;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccountFixture {
+    pub balance: Hex<U256>,
+    pub code: Bytes,
+    pub nonce: Hex<U256>,
+    pub storage: BTreeMap<Hex<U256>, Hex<U256>>,
+}
+
+impl AccountFixture {
+    pub fn to_memory_account(&self) -> MemoryAccount {
+        MemoryAccount {
+            nonce: self.nonce.0,
+            balance: self.balance.0,
+            storage: self
+                .storage
+                .iter()
+                .map(|(index, value)| (u256_to_h256(index.0), u256_to_h256(value.0)))
+                .collect(),
+            code: self.code.0.clone(),
+        }
+    }
+}
+
+/// Convert state into iterator, that can be used in `EvmBackend::set_initial`.
+pub fn memory_accounts(state: &State) -> impl Iterator<Item = (H160, MemoryAccount)> + '_ {
+    state
+        .iter()
+        .map(|(address, account)| (address.0, account.to_memory_account()))
+}
+
+pub fn u256_to_h256(value: U256) -> H256 {
+    let mut bytes = [0; 32];
+    value.to_big_endian(&mut bytes);
+    H256::from(bytes)
+}
+
+/// Empty `to` field means contract creation.
+pub fn parse_action(to: &str) -> Result<TransactionAction, anyhow::Error> {
+    if to.is_empty() {
+        Ok(TransactionAction::Create)
+    } else {
+        let address = to
+            .parse::<Hex<H160>>()
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+        Ok(TransactionAction::Call(address.0))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateTest {
+    pub env: Env,
+    pub pre: State,
+    pub transaction: MultiTransaction,
+    /// Expected results, indexed by fork name.
+    pub post: BTreeMap<String, Vec<PostState>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Env {
+    pub current_coinbase: Hex<H160>,
+    pub current_difficulty: Hex<U256>,
+    pub current_gas_limit: Hex<U256>,
+    pub current_number: Hex<U256>,
+    pub current_timestamp: Hex<U256>,
+    pub previous_hash: Hex<H256>,
+}
+
+/// Transaction template, concrete transaction is selected by `PostState::indexes`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiTransaction {
+    pub data: Vec<Bytes>,
+    pub gas_limit: Vec<Hex<U256>>,
+    pub gas_price: Hex<U256>,
+    pub nonce: Hex<U256>,
+    pub secret_key: Hex<H256>,
+    pub to: String,
+    pub value: Vec<Hex<U256>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PostState {
+    /// Expected state root.
+    pub hash: Hex<H256>,
+    /// Expected keccak hash of rlp encoded logs.
+    pub logs: Hex<H256>,
+    pub indexes: Indexes,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Indexes {
+    pub data: usize,
+    pub gas: usize,
+    pub value: usize,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockchainTest {
+    pub genesis_block_header: BlockHeader,
+    pub blocks: Vec<Block>,
+    pub pre: State,
+    /// Fork name.
+    pub network: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Block {
+    /// Missing for blocks that can't be decoded.
+    pub block_header: Option<BlockHeader>,
+    #[serde(default)]
+    pub transactions: Vec<SignedTransaction>,
+    #[serde(default)]
+    pub uncle_headers: Vec<BlockHeader>,
+    /// Set for invalid blocks, that should be rejected.
+    pub expect_exception: Option<String>,
+}
+
+impl Block {
+    pub fn is_valid(&self) -> bool {
+        self.block_header.is_some() && self.expect_exception.is_none()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockHeader {
+    pub coinbase: Hex<H160>,
+    pub difficulty: Hex<U256>,
+    pub gas_limit: Hex<U256>,
+    pub hash: Hex<H256>,
+    pub number: Hex<U256>,
+    pub state_root: Hex<H256>,
+    pub timestamp: Hex<U256>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedTransaction {
+    pub data: Bytes,
+    pub gas_limit: Hex<U256>,
+    pub gas_price: Hex<U256>,
+    pub nonce: Hex<U256>,
+    pub to: String,
+    pub value: Hex<U256>,
+    pub v: Hex<U256>,
+    pub r: Hex<U256>,
+    pub s: Hex<U256>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATE_TEST: &str = r#"{
+        "add11": {
+            "_info": { "comment": "" },
+            "env": {
+                "currentCoinbase": "0x2adc25665018aa1fe0e6bc666dac8fc2697ff9ba",
+                "currentDifficulty": "0x020000",
+                "currentGasLimit": "0xff112233445566",
+                "currentNumber": "0x01",
+                "currentTimestamp": "0x03e8",
+                "previousHash": "0x5e20a0453cecd065ea59c37ac63e079ee08998b6045136a8ce6635c7912ec0b6"
+            },
+            "post": {
+                "Istanbul": [{
+                    "hash": "0x17454a767e5f04461256f3812ffca930443c04a47d05ce3f38940c4a14b8c479",
+                    "indexes": { "data": 0, "gas": 0, "value": 0 },
+                    "logs": "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347"
+                }]
+            },
+            "pre": {
+                "0x095e7baea6a6c7c4c2dfeb977efac326af552d87": {
+                    "balance": "0x0de0b6b3a7640000",
+                    "code": "0x600160010160005500",
+                    "nonce": "0x00",
+                    "storage": { "0x01": "0x01" }
+                }
+            },
+            "transaction": {
+                "data": ["0x"],
+                "gasLimit": ["0x061a80"],
+                "gasPrice": "0x01",
+                "nonce": "0x00",
+                "secretKey": "0x45a915e4d060149eb4365960e6a7a45f334393093061116b197e3240065ff2d8",
+                "to": "0x095e7baea6a6c7c4c2dfeb977efac326af552d87",
+                "value": ["0x0186a0"]
+            }
+        }
+    }"#;
+
+    #[test]
+    fn parse_state_test() {
+        let file: StateTestFile = serde_json::from_str(STATE_TEST).unwrap();
+        let test = &file["add11"];
+        assert_eq!(test.env.current_number.0, U256::one());
+        assert_eq!(test.transaction.data[0].0, Vec::<u8>::new());
+        assert_eq!(test.post["Istanbul"][0].indexes.gas, 0);
+
+        let (address, account) = memory_accounts(&test.pre).next().unwrap();
+        assert_eq!(
+            address,
+            "095e7baea6a6c7c4c2dfeb977efac326af552d87".parse().unwrap()
+        );
+        assert_eq!(
+            account.storage[&u256_to_h256(U256::one())],
+            u256_to_h256(U256::one())
+        );
+        assert_eq!(
+            account.code,
+            vec![0x60, 0x01, 0x60, 0x01, 0x01, 0x60, 0x00, 0x55, 0x00]
+        );
+    }
+
+    #[test]
+    fn parse_create_action() {
+        assert_eq!(parse_action("").unwrap(), TransactionAction::Create);
+        assert!(matches!(
+            parse_action("0x095e7baea6a6c7c4c2dfeb977efac326af552d87").unwrap(),
+            TransactionAction::Call(_)
+        ));
+    }
+}
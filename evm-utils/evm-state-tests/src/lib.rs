@@ -0,0 +1,121 @@
+//! Runner of Ethereum `GeneralStateTests` and `BlockchainTests` fixtures against `evm_state::Executor`.
+//!
+//! Velas burns transaction fees and has no mining rewards,
+//! so runner credits coinbase with fees and rewards itself, to make state roots comparable with fixtures.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use evm_state::{Executor, ExitReason, HardforkConfig, Log, UnsignedTransaction, H160, H256, U256};
+use log::*;
+use rlp::RlpStream;
+use sha3::{Digest, Keccak256};
+
+pub mod blockchain_test;
+pub mod fixture;
+pub mod report;
+pub mod state_test;
+
+pub use report::{Outcome, Report, TestResult};
+
+/// Returns executor config for fork name used in fixtures, `None` if fork is not supported.
+pub fn hardfork_config(fork: &str) -> Option<HardforkConfig> {
+    match fork {
+        "Frontier" => Some(HardforkConfig::Frontier),
+        "Istanbul" => Some(HardforkConfig::Istanbul),
+        _ => None,
+    }
+}
+
+pub(crate) fn to_u64(value: U256) -> anyhow::Result<u64> {
+    anyhow::ensure!(
+        value <= U256::from(u64::MAX),
+        "value {} overflows u64",
+        value
+    );
+    Ok(value.as_u64())
+}
+
+/// Execute transaction, and pay fee to coinbase.
+/// Returns logs of transaction, or empty list if transaction was rejected.
+pub(crate) fn execute_transaction(
+    executor: &mut Executor,
+    caller: H160,
+    tx: UnsignedTransaction,
+    coinbase: H160,
+) -> Vec<Log> {
+    let gas_price = tx.gas_price;
+    match executor.transaction_execute_unsinged(caller, tx, |_, _, _, _| None) {
+        Ok(result) => {
+            // Executor withdraw fee only from succeed transactions.
+            let fee = U256::from(result.used_gas) * gas_price;
+            if matches!(result.exit_reason, ExitReason::Succeed(_)) && !fee.is_zero() {
+                executor.deposit(coinbase, fee);
+            }
+            result.tx_logs
+        }
+        Err(e) => {
+            debug!("Transaction rejected: {}", e);
+            vec![]
+        }
+    }
+}
+
+/// Keccak hash of rlp encoded logs list, the same as `logs` field in state tests.
+pub fn logs_hash(logs: &[Log]) -> H256 {
+    let mut stream = RlpStream::new_list(logs.len());
+    for log in logs {
+        stream.begin_list(3);
+        stream.append(&log.address);
+        stream.append_list::<H256, _>(&log.topics);
+        stream.append(&log.data);
+    }
+    H256::from_slice(Keccak256::digest(&stream.out()).as_slice())
+}
+
+/// Collect all json files from paths, directories are traversed recursively.
+pub fn collect_fixtures(paths: &[PathBuf]) -> anyhow::Result<Vec<PathBuf>> {
+    fn visit(path: &Path, files: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+        if path.is_dir() {
+            let mut entries = fs::read_dir(path)
+                .with_context(|| format!("Unable to read dir {}", path.display()))?
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<Result<Vec<_>, _>>()?;
+            entries.sort();
+            for entry in entries {
+                visit(&entry, files)?;
+            }
+        } else if path.extension().map_or(false, |ext| ext == "json") {
+            files.push(path.to_path_buf());
+        }
+        Ok(())
+    }
+
+    let mut files = vec![];
+    for path in paths {
+        visit(path, &mut files)?;
+    }
+    Ok(files)
+}
+
+pub(crate) fn read_fixture<T: serde::de::DeserializeOwned>(path: &Path) -> anyhow::Result<T> {
+    let file =
+        fs::File::open(path).with_context(|| format!("Unable to open {}", path.display()))?;
+    serde_json::from_reader(std::io::BufReader::new(file))
+        .with_context(|| format!("Unable to parse {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_logs_hash() {
+        assert_eq!(
+            logs_hash(&[]),
+            "1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347"
+                .parse()
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use std::path::PathBuf;
+
+use env_logger::Env;
+use evm_state_tests::{blockchain_test, collect_fixtures, state_test, Outcome, Report};
+use log::*;
+
+// With the "paw" feature enabled in structopt
+#[derive(Debug, structopt::StructOpt)]
+enum SubCommands {
+    /// Run `GeneralStateTests` fixtures.
+    State {
+        /// Fixture files, or directories with fixtures.
+        paths: Vec<PathBuf>,
+    },
+    /// Run `BlockchainTests` fixtures.
+    Blockchain {
+        /// Fixture files, or directories with fixtures.
+        paths: Vec<PathBuf>,
+    },
+}
+
+#[derive(Debug, structopt::StructOpt)]
+struct Args {
+    /// Run tests only for specific fork (e.g. "Istanbul").
+    #[structopt(long = "fork")]
+    fork: Option<String>,
+    /// Print reason of every failed test.
+    #[structopt(short = "v", long = "verbose")]
+    verbose: bool,
+    #[structopt(subcommand)]
+    subcommand: SubCommands,
+}
+
+#[paw::main]
+fn main(args: Args) -> anyhow::Result<()> {
+    let env = Env::new().default_filter_or("info");
+    env_logger::init_from_env(env);
+
+    let fork = args.fork.as_deref();
+    let (paths, run_file): (
+        _,
+        fn(&std::path::Path, Option<&str>) -> anyhow::Result<Report>,
+    ) = match args.subcommand {
+        SubCommands::State { paths } => (paths, state_test::run_file),
+        SubCommands::Blockchain { paths } => (paths, blockchain_test::run_file),
+    };
+
+    let mut report = Report::default();
+    for path in collect_fixtures(&paths)? {
+        info!("Running {}", path.display());
+        report.extend(run_file(&path, fork)?);
+    }
+
+    if args.verbose {
+        for result in report.failures() {
+            if let Outcome::Failed(reason) = &result.outcome {
+                println!("FAILED {} ({}): {}", result.name, result.fork, reason);
+            }
+        }
+    }
+    print!("{}", report);
+
+    if !report.is_success() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
@@ -0,0 +1,128 @@
+//! Pass/fail report of executed fixtures, grouped by fork.
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    Failed(String),
+    Skipped(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestResult {
+    pub name: String,
+    pub fork: String,
+    pub outcome: Outcome,
+}
+
+impl TestResult {
+    pub fn new(name: impl Into<String>, fork: impl Into<String>, outcome: Outcome) -> Self {
+        Self {
+            name: name.into(),
+            fork: fork.into(),
+            outcome,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ForkSummary {
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Report {
+    results: Vec<TestResult>,
+}
+
+impl Report {
+    pub fn push(&mut self, result: TestResult) {
+        self.results.push(result)
+    }
+
+    pub fn extend(&mut self, other: Report) {
+        self.results.extend(other.results)
+    }
+
+    pub fn results(&self) -> &[TestResult] {
+        &self.results
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &TestResult> {
+        self.results
+            .iter()
+            .filter(|result| matches!(result.outcome, Outcome::Failed(_)))
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    pub fn summary(&self) -> BTreeMap<&str, ForkSummary> {
+        let mut summary = BTreeMap::<_, ForkSummary>::new();
+        for result in &self.results {
+            let fork = summary.entry(result.fork.as_str()).or_default();
+            match result.outcome {
+                Outcome::Passed => fork.passed += 1,
+                Outcome::Failed(_) => fork.failed += 1,
+                Outcome::Skipped(_) => fork.skipped += 1,
+            }
+        }
+        summary
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<20} {:>8} {:>8} {:>8}",
+            "Fork", "Passed", "Failed", "Skipped"
+        )?;
+        for (fork, summary) in self.summary() {
+            writeln!(
+                f,
+                "{:<20} {:>8} {:>8} {:>8}",
+                fork, summary.passed, summary.failed, summary.skipped
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_by_fork() {
+        let mut report = Report::default();
+        report.push(TestResult::new("a", "Istanbul", Outcome::Passed));
+        report.push(TestResult::new(
+            "b",
+            "Istanbul",
+            Outcome::Failed("state root mismatch".into()),
+        ));
+        report.push(TestResult::new(
+            "a",
+            "Berlin",
+            Outcome::Skipped("unsupported fork".into()),
+        ));
+
+        let summary = report.summary();
+        assert_eq!(
+            summary["Istanbul"],
+            ForkSummary {
+                passed: 1,
+                failed: 1,
+                skipped: 0
+            }
+        );
+        assert_eq!(summary["Berlin"].skipped, 1);
+        assert!(!report.is_success());
+        assert_eq!(report.failures().count(), 1);
+    }
+}
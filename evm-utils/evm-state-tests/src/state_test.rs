@@ -0,0 +1,155 @@
+//! Runner of `GeneralStateTests`.
+use std::path::Path;
+
+use evm_state::{
+    ChainContext, EvmBackend, EvmConfig, Executor, FromKey, HardforkConfig, SecretKey,
+    UnsignedTransaction, H256,
+};
+
+use crate::fixture::{memory_accounts, parse_action, Indexes, StateTest, StateTestFile};
+use crate::{execute_transaction, hardfork_config, logs_hash, to_u64, Outcome, Report, TestResult};
+
+/// Run all tests from fixture file, only for `fork` if it was set.
+pub fn run_file(path: &Path, fork: Option<&str>) -> anyhow::Result<Report> {
+    let file: StateTestFile = crate::read_fixture(path)?;
+    let mut report = Report::default();
+    for (name, test) in &file {
+        report.extend(run_test(name, test, fork));
+    }
+    Ok(report)
+}
+
+/// Run every post state of test, each post state is a separate result in report.
+pub fn run_test(name: &str, test: &StateTest, fork_filter: Option<&str>) -> Report {
+    let mut report = Report::default();
+    for (fork, posts) in &test.post {
+        if fork_filter.map_or(false, |filter| filter != fork) {
+            continue;
+        }
+        let hardfork = hardfork_config(fork);
+        for post in posts {
+            let indexes = post.indexes;
+            let test_name = format!(
+                "{}[d{},g{},v{}]",
+                name, indexes.data, indexes.gas, indexes.value
+            );
+            let outcome = match hardfork {
+                None => Outcome::Skipped(format!("Fork {} is not supported", fork)),
+                Some(hardfork) => match execute(test, hardfork, indexes) {
+                    Ok((state_root, _)) if state_root != post.hash.0 => Outcome::Failed(format!(
+                        "State root {:?}, expected {:?}",
+                        state_root, post.hash.0
+                    )),
+                    Ok((_, logs)) if logs != post.logs.0 => {
+                        Outcome::Failed(format!("Logs hash {:?}, expected {:?}", logs, post.logs.0))
+                    }
+                    Ok(_) => Outcome::Passed,
+                    Err(e) => Outcome::Failed(format!("{:#}", e)),
+                },
+            };
+            report.push(TestResult::new(test_name, fork.as_str(), outcome));
+        }
+    }
+    report
+}
+
+/// Execute transaction selected by `indexes` on pre state.
+/// Returns state root and logs hash.
+pub fn execute(
+    test: &StateTest,
+    hardfork: HardforkConfig,
+    indexes: Indexes,
+) -> anyhow::Result<(H256, H256)> {
+    let tx = &test.transaction;
+    let input = tx
+        .data
+        .get(indexes.data)
+        .ok_or_else(|| anyhow::anyhow!("No data with index {}", indexes.data))?;
+    let gas_limit = tx
+        .gas_limit
+        .get(indexes.gas)
+        .ok_or_else(|| anyhow::anyhow!("No gas limit with index {}", indexes.gas))?;
+    let value = tx
+        .value
+        .get(indexes.value)
+        .ok_or_else(|| anyhow::anyhow!("No value with index {}", indexes.value))?;
+
+    let secret_key = SecretKey::from_slice(tx.secret_key.0.as_bytes())?;
+    let unsigned_tx = UnsignedTransaction {
+        nonce: tx.nonce.0,
+        gas_price: tx.gas_price.0,
+        gas_limit: gas_limit.0,
+        action: parse_action(&tx.to)?,
+        value: value.0,
+        input: input.0.clone(),
+    };
+
+    let env = &test.env;
+    let mut backend = EvmBackend::default();
+    backend.state.block_number = to_u64(env.current_number.0)?;
+    backend.state.timestamp = to_u64(env.current_timestamp.0)?;
+    backend.set_initial(memory_accounts(&test.pre));
+
+    let config = EvmConfig {
+        executor_config: hardfork,
+        gas_limit: to_u64(env.current_gas_limit.0)?,
+        ..EvmConfig::default()
+    };
+    let mut last_hashes = [H256::zero(); 256];
+    last_hashes[0] = env.previous_hash.0;
+
+    let mut executor = Executor::with_config(backend, ChainContext::new(last_hashes), config);
+    let logs = execute_transaction(
+        &mut executor,
+        secret_key.to_address(),
+        unsigned_tx,
+        env.current_coinbase.0,
+    );
+    let committed = executor.deconstruct().commit_block(0, H256::zero());
+    Ok((committed.last_root(), logs_hash(&logs)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn fixture(name: &str) -> StateTestFile {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join(name);
+        crate::read_fixture(&path).unwrap()
+    }
+
+    fn assert_post_state(file: &str, test_name: &str, fork: &str) {
+        let file = fixture(file);
+        let test = &file[test_name];
+        let hardfork = hardfork_config(fork).unwrap();
+        for post in &test.post[fork] {
+            let (state_root, logs) = execute(test, hardfork, post.indexes).unwrap();
+            assert_eq!(state_root, post.hash.0);
+            assert_eq!(logs, post.logs.0);
+        }
+    }
+
+    #[test]
+    fn add11_frontier() {
+        assert_post_state("add11.json", "add11", "Frontier");
+    }
+
+    #[test]
+    fn add11_istanbul() {
+        assert_post_state("add11.json", "add11", "Istanbul");
+    }
+
+    #[test]
+    fn sstore_dirty_slot_frontier() {
+        assert_post_state("sstoreDirtySlot.json", "sstoreDirtySlot", "Frontier");
+    }
+
+    #[test]
+    fn sstore_dirty_slot_istanbul() {
+        assert_post_state("sstoreDirtySlot.json", "sstoreDirtySlot", "Istanbul");
+    }
+}
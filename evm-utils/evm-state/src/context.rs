@@ -63,6 +63,12 @@ pub struct EvmConfig {
     pub force_chain_id: bool,
     /// Executor should be called with estimate purposes (count transaction in worst scenario).
     pub estimate: bool,
+    /// Transactions with lower gas price will be rejected (ignored in estimate mode).
+    pub min_gas_price: u64,
+    /// Percent of each transaction fee that should be burned instead of refunded to the sender.
+    pub fee_burn_percent: u8,
+    /// Percent of each transaction fee that should be paid to the block leader instead of refunded to the sender.
+    pub fee_leader_percent: u8,
 }
 
 impl Default for EvmConfig {
@@ -73,6 +79,9 @@ impl Default for EvmConfig {
             chain_id: crate::TEST_CHAIN_ID,
             force_chain_id: true,
             estimate: false,
+            min_gas_price: 0,
+            fee_burn_percent: 0,
+            fee_leader_percent: 0,
         }
     }
 }
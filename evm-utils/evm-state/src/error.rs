@@ -83,6 +83,13 @@ pub enum Error {
     ))]
     GasPriceOutOfBounds { gas_price: U256 },
 
+    #[snafu(display(
+        "Gas price should be at least min_gas_price={}, provided_gas_price={}",
+        min_gas_price,
+        gas_price,
+    ))]
+    GasPriceTooLow { gas_price: U256, min_gas_price: u64 },
+
     #[snafu(display("Duplicate transaction have found={:?}", tx_hash,))]
     DuplicateTx { tx_hash: H256 },
 
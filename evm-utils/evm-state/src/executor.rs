@@ -119,6 +119,14 @@ impl Executor {
             GasPriceOutOfBounds { gas_price }
         );
 
+        ensure!(
+            self.config.estimate || gas_price >= U256::from(self.config.min_gas_price),
+            GasPriceTooLow {
+                gas_price,
+                min_gas_price: self.config.min_gas_price,
+            }
+        );
+
         ensure!(
             gas_limit <= U256::from(u64::MAX),
             GasLimitOutOfBounds { gas_limit }
@@ -327,6 +335,9 @@ impl Executor {
 
         assert!(!tx_hashes.contains(&tx_hash));
 
+        self.evm_backend
+            .set_transaction_output(tx_hash, &result.exit_data);
+
         let receipt = TransactionReceipt::new(
             tx,
             result.used_gas,
@@ -385,6 +396,16 @@ impl Executor {
 
     // }
 
+    pub fn config(&self) -> &EvmConfig {
+        &self.config
+    }
+
+    /// Register part of transaction fee that was not refunded to the sender,
+    /// and should be burned or paid to the leader, when block is finalized.
+    pub fn withhold_fee(&mut self, burned: u64, leader: u64) {
+        self.evm_backend.withhold_fee(burned, leader)
+    }
+
     pub fn get_tx_receipt_by_hash(&mut self, tx: H256) -> Option<&TransactionReceipt> {
         self.evm_backend.find_transaction_receipt(tx)
     }
@@ -634,6 +655,47 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn it_rejects_txs_with_gas_price_below_min() {
+        let _logger = simple_logger::SimpleLogger::new().init();
+
+        let chain_id = 0xeba;
+        let mut evm_config = EvmConfig::default();
+        evm_config.chain_id = chain_id;
+        evm_config.min_gas_price = 1;
+        let mut executor =
+            Executor::with_config(EvmBackend::default(), Default::default(), evm_config);
+
+        let code = hex::decode(METACOIN_CODE).unwrap();
+
+        let alice = Persona::new();
+        let create_tx = alice.unsigned(TransactionAction::Create, &code);
+
+        let cheap_tx = create_tx.clone().sign(&alice.secret, Some(chain_id));
+        assert!(matches!(
+            executor
+                .transaction_execute(cheap_tx, noop_precompile)
+                .unwrap_err(),
+            Error::GasPriceTooLow {
+                gas_price,
+                min_gas_price: 1,
+            } if gas_price == U256::zero()
+        ));
+
+        let mut estimate_config = evm_config;
+        estimate_config.estimate = true;
+        let mut executor =
+            Executor::with_config(EvmBackend::default(), Default::default(), estimate_config);
+        let create_tx = create_tx.sign(&alice.secret, Some(chain_id));
+        assert!(matches!(
+            executor
+                .transaction_execute(create_tx, noop_precompile)
+                .unwrap()
+                .exit_reason,
+            ExitReason::Succeed(ExitSucceed::Returned)
+        ));
+    }
+
     #[test]
     fn it_handles_metacoin() {
         use ethabi::Token;
@@ -15,7 +15,7 @@ pub mod types;
 pub use transactions::*;
 pub use types::*;
 pub use {
-    context::{ChainContext, EvmConfig},
+    context::{ChainContext, EvmConfig, HardforkConfig},
     state::{
         AccountProvider, Committed, EvmBackend, EvmPersistState, EvmState, Incomming,
         DEFAULT_GAS_LIMIT, MAX_IN_MEMORY_EVM_ACCOUNTS,
@@ -33,6 +33,7 @@ mod context;
 mod executor;
 mod state;
 mod storage;
+mod walker;
 
 pub trait FromKey {
     fn to_public_key(&self) -> secp256k1::PublicKey;
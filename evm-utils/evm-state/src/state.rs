@@ -60,6 +60,18 @@ pub struct Incomming {
     /// Transactions that was processed but wasn't committed.
     /// Transactions should be ordered by execution order order on all validators.
     executed_transactions: Vec<(H256, TransactionReceipt)>,
+
+    /// Return data of processed transactions, kept in memory only, to be shown in simulation results.
+    /// Collected only after `collect_transactions_output` was called.
+    #[serde(skip)]
+    executed_transactions_output: Option<HashMap<H256, Vec<u8>>>,
+
+    /// Part of transaction fees (in lamports) that should be burned at the end of block.
+    #[serde(skip)]
+    withheld_burn_fees: u64,
+    /// Part of transaction fees (in lamports) that should be paid to the block leader.
+    #[serde(skip)]
+    withheld_leader_fees: u64,
 }
 
 impl Incomming {
@@ -264,6 +276,41 @@ impl EvmBackend<Incomming> {
             .push((transaction, receipt));
     }
 
+    /// Start collecting return data of transactions, used only by simulation.
+    pub fn collect_transactions_output(&mut self) {
+        self.state
+            .executed_transactions_output
+            .get_or_insert_with(HashMap::new);
+    }
+
+    pub fn find_transaction_output(&self, transaction: H256) -> Option<&[u8]> {
+        self.state
+            .executed_transactions_output
+            .as_ref()?
+            .get(&transaction)
+            .map(Vec::as_slice)
+    }
+
+    /// Save return data of transaction, if collecting was enabled.
+    pub fn set_transaction_output(&mut self, transaction: H256, output: &[u8]) {
+        if let Some(outputs) = &mut self.state.executed_transactions_output {
+            outputs.insert(transaction, output.to_vec());
+        }
+    }
+
+    pub fn withhold_fee(&mut self, burned: u64, leader: u64) {
+        self.state.withheld_burn_fees += burned;
+        self.state.withheld_leader_fees += leader;
+    }
+
+    /// Return fees withheld by transactions in this block, as `(burned, leader)` pair.
+    pub fn withheld_fees(&self) -> (u64, u64) {
+        (
+            self.state.withheld_burn_fees,
+            self.state.withheld_leader_fees,
+        )
+    }
+
     pub fn get_executed_transactions(&self) -> Vec<H256> {
         self.state
             .executed_transactions
@@ -550,6 +597,36 @@ impl EvmState {
         Ok(self.kvs().backup()?)
     }
 
+    /// Save only the current state root (with codes) into compact database at `target`.
+    /// Returns root of the saved state.
+    pub fn make_state_copy(&self, target: impl AsRef<Path>) -> Result<H256, anyhow::Error> {
+        let root = self.last_root();
+        self.kvs().copy_state(root, target)?;
+        Ok(root)
+    }
+
+    /// Save only the nodes of current state root, that are not reachable from `base_root`,
+    /// into compact database at `target`. Returns root of the saved state.
+    pub fn make_state_diff_copy(
+        &self,
+        base_root: H256,
+        target: impl AsRef<Path>,
+    ) -> Result<H256, anyhow::Error> {
+        let root = self.last_root();
+        self.kvs().copy_state_diff(base_root, root, target)?;
+        Ok(root)
+    }
+
+    /// Check that current state root is present in the underlying database.
+    pub fn check_root_exist(&self) -> bool {
+        self.kvs().check_root_exist(self.last_root())
+    }
+
+    /// Check that state `root` is present in the underlying database.
+    pub fn check_state_root_exist(&self, root: H256) -> bool {
+        self.kvs().check_root_exist(root)
+    }
+
     /// Convert current state into persist one.
     /// With persist state and database, one can load evm state back to memory.
     /// Consume self, so outer code should call `clone()`.
@@ -601,6 +678,14 @@ impl EvmState {
         }
     }
 
+    /// Return fees withheld by transactions in current block, as `(burned, leader)` pair.
+    pub fn withheld_fees(&self) -> (u64, u64) {
+        match self {
+            EvmState::Incomming(incomming) => incomming.withheld_fees(),
+            EvmState::Committed(_) => (0, 0),
+        }
+    }
+
     /// Return block header if this state was committed before.
     pub fn get_block(&self) -> Option<Block> {
         match self {
@@ -655,8 +740,11 @@ impl Default for Incomming {
             last_block_hash: H256::zero(),
             state_updates: HashMap::new(),
             executed_transactions: Vec::new(),
+            executed_transactions_output: None,
             used_gas: 0,
             timestamp: 0,
+            withheld_burn_fees: 0,
+            withheld_leader_fees: 0,
         }
     }
 }
@@ -970,6 +1058,116 @@ mod tests {
         assert_state(&evm_state, &accounts_state_diff, &accounts_storage_diff);
     }
 
+    #[test]
+    fn state_copy_contains_only_last_root() {
+        let _ = simple_logger::SimpleLogger::new().init();
+
+        let accounts = generate_accounts_addresses(SEED, 20);
+        let accounts_state = generate_accounts_state(SEED, &accounts);
+        let accounts_storage = generate_storage(SEED, &accounts);
+        let accounts_state_diff = to_state_diff(accounts_state, BTreeSet::new());
+        let accounts_storage_diff = to_state_diff(accounts_storage, BTreeSet::new());
+
+        let mut evm_state = EvmBackend::default();
+        save_state(&mut evm_state, &accounts_state_diff, &accounts_storage_diff);
+        let committed = evm_state.commit_block(0, Default::default());
+        let old_root = committed.last_root();
+
+        let removed_accounts: BTreeSet<_> = accounts[0..5].iter().copied().collect();
+        let removed_diff = to_state_diff(BTreeMap::new(), removed_accounts);
+        let mut evm_state = committed.next_incomming(0);
+        save_state(&mut evm_state, &removed_diff, &BTreeMap::new());
+        let committed = evm_state.commit_block(1, Default::default());
+        let new_root = committed.last_root();
+        assert_ne!(old_root, new_root);
+
+        let evm_state = EvmState::from(committed);
+        let copy_dir = tempfile::TempDir::new().unwrap();
+        let copy_path = copy_dir.path().join("copy");
+        assert_eq!(evm_state.make_state_copy(&copy_path).unwrap(), new_root);
+
+        let target_dir = tempfile::TempDir::new().unwrap();
+        KVS::restore_from_copy(&copy_path, target_dir.path()).unwrap();
+        let kvs = KVS::open_persistent(target_dir.path()).unwrap();
+        assert!(kvs.check_root_exist(new_root));
+        assert!(!kvs.check_root_exist(old_root));
+
+        let restored = EvmBackend::new(Incomming::new(2, new_root, H256::zero(), 0), kvs);
+        assert_state(&restored, &removed_diff, &BTreeMap::new());
+        let (_, remaining_storage): (BTreeMap<_, _>, BTreeMap<_, _>) = accounts_storage_diff
+            .into_iter()
+            .partition(|((address, _), _)| removed_diff.contains_key(address));
+        let remaining_accounts: BTreeMap<_, _> = accounts_state_diff
+            .into_iter()
+            .filter(|(address, _)| !removed_diff.contains_key(address))
+            .collect();
+        assert_state(&restored, &remaining_accounts, &remaining_storage);
+    }
+
+    #[test]
+    fn state_diff_copy_applies_on_top_of_base_copy() {
+        let _ = simple_logger::SimpleLogger::new().init();
+
+        let accounts = generate_accounts_addresses(SEED, 20);
+        let accounts_state = generate_accounts_state(SEED, &accounts);
+        let accounts_storage = generate_storage(SEED, &accounts);
+        let accounts_state_diff = to_state_diff(accounts_state, BTreeSet::new());
+        let accounts_storage_diff = to_state_diff(accounts_storage, BTreeSet::new());
+
+        let mut evm_state = EvmBackend::default();
+        save_state(&mut evm_state, &accounts_state_diff, &accounts_storage_diff);
+        let committed = evm_state.commit_block(0, Default::default());
+        let base_root = committed.last_root();
+
+        let copy_dir = tempfile::TempDir::new().unwrap();
+        let base_path = copy_dir.path().join("base");
+        committed.kvs().copy_state(base_root, &base_path).unwrap();
+
+        let removed_accounts: BTreeSet<_> = accounts[0..5].iter().copied().collect();
+        let removed_diff = to_state_diff(BTreeMap::new(), removed_accounts);
+        let mut evm_state = committed.next_incomming(0);
+        save_state(&mut evm_state, &removed_diff, &BTreeMap::new());
+        let committed = evm_state.commit_block(1, Default::default());
+        let new_root = committed.last_root();
+        assert_ne!(base_root, new_root);
+
+        let evm_state = EvmState::from(committed);
+        let diff_path = copy_dir.path().join("diff");
+        assert_eq!(
+            evm_state
+                .make_state_diff_copy(base_root, &diff_path)
+                .unwrap(),
+            new_root
+        );
+        {
+            let diff_kvs = KVS::open_persistent(&diff_path).unwrap();
+            assert!(diff_kvs.check_root_exist(new_root));
+            assert!(!diff_kvs.check_root_exist(base_root));
+        }
+
+        let target_dir = tempfile::TempDir::new().unwrap();
+        KVS::restore_from_copy(&base_path, target_dir.path()).unwrap();
+        assert!(matches!(
+            KVS::restore_diff_from_copy(copy_dir.path().join("missing"), target_dir.path()),
+            Err(crate::storage::Error::MissingStorageDir(_))
+        ));
+        KVS::restore_diff_from_copy(&diff_path, target_dir.path()).unwrap();
+        let kvs = KVS::open_persistent(target_dir.path()).unwrap();
+        assert!(kvs.check_root_exist(base_root));
+        assert!(kvs.check_root_exist(new_root));
+
+        let restored = EvmBackend::new(Incomming::new(2, new_root, H256::zero(), 0), kvs);
+        assert_state(&restored, &removed_diff, &BTreeMap::new());
+        let (_, remaining_storage): (BTreeMap<_, _>, BTreeMap<_, _>) = accounts_storage_diff
+            .into_iter()
+            .partition(|((address, _), _)| removed_diff.contains_key(address));
+        let remaining_accounts: BTreeMap<_, _> = accounts_state_diff
+            .into_iter()
+            .filter(|(address, _)| !removed_diff.contains_key(address))
+            .collect();
+        assert_state(&restored, &remaining_accounts, &remaining_storage);
+    }
+
     #[test]
     fn lookups_thru_forks() {
         let _ = simple_logger::SimpleLogger::new().init();
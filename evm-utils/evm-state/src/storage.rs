@@ -14,7 +14,7 @@ use rlp::{Decodable, Encodable};
 use rocksdb::{
     self,
     backup::{BackupEngine, BackupEngineOptions, RestoreOptions},
-    ColumnFamily, ColumnFamilyDescriptor, Options, DB,
+    ColumnFamily, ColumnFamilyDescriptor, IteratorMode, Options, DB,
 };
 use serde::{de::DeserializeOwned, Serialize};
 use tempfile::TempDir;
@@ -22,6 +22,7 @@ use tempfile::TempDir;
 use crate::{
     transactions::{Transaction, TransactionReceipt},
     types::*,
+    walker::Walker,
 };
 use triedb::{empty_trie_hash, rocksdb::RocksMemoryTrieMut, FixedSecureTrieMut};
 
@@ -42,6 +43,14 @@ pub enum Error {
     KeyErr(#[from] TryFromSliceError),
     #[error("Internal IO error: {0:?}")]
     InternalErr(#[from] IoError),
+    #[error("Unable to decode trie node: {0}")]
+    DecoderErr(#[from] rlp::DecoderError),
+    #[error("Trie node {0:?} not found in storage")]
+    MissingTrieNode(H256),
+    #[error("Code {0:?} not found in storage")]
+    MissingCode(H256),
+    #[error("Storage directory {0:?} not found")]
+    MissingStorageDir(PathBuf),
 }
 
 const BACKUP_SUBDIR: &str = "backup";
@@ -139,6 +148,99 @@ impl Storage {
         Ok(())
     }
 
+    /// Copy only the nodes reachable from `root` (accounts, storages and codes) into a new
+    /// database at `target`. Unlike `backup`, history of other states is not copied.
+    pub fn copy_state(&self, root: H256, target: impl AsRef<Path>) -> Result<()> {
+        let target = target.as_ref();
+        info!(
+            "copy storage data of state {:?} into {}",
+            root,
+            target.display()
+        );
+        let target = Self::open_persistent(target)?;
+        let nodes = Walker::new(self, &target).copy_state(root)?;
+        info!("copied {} trie nodes of state {:?}", nodes, root);
+        Ok(())
+    }
+
+    /// Copy only the nodes reachable from `root`, but not from `base_root`, into a new
+    /// database at `target`. Applied with `restore_diff_from_copy` on top of `base_root` state copy,
+    /// it gives full state at `root`.
+    pub fn copy_state_diff(
+        &self,
+        base_root: H256,
+        root: H256,
+        target: impl AsRef<Path>,
+    ) -> Result<()> {
+        let target = target.as_ref();
+        info!(
+            "copy storage data of state {:?} changed since {:?} into {}",
+            root,
+            base_root,
+            target.display()
+        );
+        let target = Self::open_persistent(target)?;
+        let mut walker = Walker::new(self, &target);
+        walker.exclude_state(base_root)?;
+        let nodes = walker.copy_state(root)?;
+        info!(
+            "copied {} trie nodes of state {:?} changed since {:?}",
+            nodes, root, base_root
+        );
+        Ok(())
+    }
+
+    /// Apply storage, that was created by `copy_state_diff`, on top of storage at `target`.
+    pub fn restore_diff_from_copy(path: impl AsRef<Path>, target: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let target = target.as_ref();
+        // Opening missing `path` would create an empty database, and silently apply nothing
+        if !path.is_dir() {
+            return Err(Error::MissingStorageDir(path.to_owned()));
+        }
+        info!(
+            "Loading storage data from {} into {} (apply state diff copy)",
+            path.display(),
+            target.display()
+        );
+
+        let source = Self::open_persistent(path)?;
+        let target = Self::open_persistent(target)?;
+        let mut nodes = 0;
+        for (key, value) in source.db.iterator(IteratorMode::Start) {
+            target.db.put(key, value)?;
+            nodes += 1;
+        }
+        let (source_codes, target_codes) = (source.cf::<Codes>(), target.cf::<Codes>());
+        for (key, value) in source.db.iterator_cf(source_codes, IteratorMode::Start) {
+            target.db.put_cf(target_codes, key, value)?;
+        }
+        info!("applied {} trie nodes from {}", nodes, path.display());
+        Ok(())
+    }
+
+    /// Load storage, that was created by `copy_state`.
+    pub fn restore_from_copy(path: impl AsRef<Path>, target: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let target = target.as_ref();
+        assert!(
+            path.is_dir() && path.exists(),
+            "Storage can be loaded only from existing directory"
+        );
+        info!(
+            "Loading storage data from {} into {} (restore from state copy)",
+            path.display(),
+            target.display()
+        );
+
+        fs::create_dir_all(target)?;
+        for entry in fs::read_dir(path)? {
+            let entry = entry?;
+            fs::copy(entry.path(), target.join(entry.file_name()))?;
+        }
+        Ok(())
+    }
+
     /// Temporary solution to check if anything was purged from bd.
     pub fn check_root_exist(&self, root: H256) -> bool {
         if root == empty_trie_hash() {
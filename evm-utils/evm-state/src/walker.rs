@@ -0,0 +1,152 @@
+use log::*;
+use primitive_types::H256;
+use rlp::Rlp;
+use triedb::{
+    empty_trie_hash,
+    merkle::{MerkleNode, MerkleValue},
+};
+
+use crate::{
+    storage::{Codes, Error, Result, Storage},
+    types::{Account, Code},
+};
+
+/// Traverse account trie (and all of its storage tries) starting from single root,
+/// and copy every reachable node into target storage.
+/// Used to create compact snapshots of evm state, without historical states.
+pub(crate) struct Walker<'a> {
+    source: &'a Storage,
+    target: &'a Storage,
+    /// When false, only keys of visited nodes and codes are stored into target.
+    copy_data: bool,
+    /// Hashes of nodes and codes, that are reachable from excluded state.
+    /// Kept on disk, because state can have more nodes than fit into memory.
+    excluded: Option<Storage>,
+    nodes_copied: usize,
+}
+
+impl<'a> Walker<'a> {
+    pub fn new(source: &'a Storage, target: &'a Storage) -> Self {
+        Self {
+            source,
+            target,
+            copy_data: true,
+            excluded: None,
+            nodes_copied: 0,
+        }
+    }
+
+    /// Mark every node and code reachable from `root` as already present in target,
+    /// so later `copy_state` will skip them. Trie nodes are addressed by hash of its content,
+    /// so subtree of skipped node is skipped too.
+    pub fn exclude_state(&mut self, root: H256) -> Result<()> {
+        let excluded = Storage::create_temporary()?;
+        Walker {
+            copy_data: false,
+            ..Walker::new(self.source, &excluded)
+        }
+        .walk_state(root)?;
+        self.excluded = Some(excluded);
+        Ok(())
+    }
+
+    /// Copy state at `root`: account trie nodes, storage trie nodes and contract codes.
+    /// Returns count of copied trie nodes.
+    pub fn copy_state(mut self, root: H256) -> Result<usize> {
+        self.walk_state(root)?;
+        Ok(self.nodes_copied)
+    }
+
+    fn walk_state(&mut self, root: H256) -> Result<()> {
+        let mut accounts = vec![];
+        self.traverse(root, &mut |data| {
+            accounts.push(rlp::decode::<Account>(data)?);
+            Ok(())
+        })?;
+        debug!("Found {} accounts at root {:?}", accounts.len(), root);
+
+        for account in accounts {
+            self.traverse(account.storage_root, &mut |_| Ok(()))?;
+            if account.code_hash == Code::empty().hash() || self.is_code_visited(account.code_hash)
+            {
+                continue;
+            }
+            let code = if self.copy_data {
+                self.source
+                    .get::<Codes>(account.code_hash)
+                    .ok_or(Error::MissingCode(account.code_hash))?
+            } else {
+                Code::empty()
+            };
+            self.target.set::<Codes>(account.code_hash, code);
+        }
+        Ok(())
+    }
+
+    // Trie nodes are addressed by hash of its content, so if node is already in target
+    // (or excluded), its whole subtree is there too, and can be skipped.
+    fn is_node_visited(&self, hash: H256) -> Result<bool> {
+        for storage in self.excluded.iter().chain(Some(self.target)) {
+            if storage.db.get_pinned(hash.as_ref())?.is_some() {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn is_code_visited(&self, hash: H256) -> bool {
+        self.excluded
+            .iter()
+            .chain(Some(self.target))
+            .any(|storage| storage.get::<Codes>(hash).is_some())
+    }
+
+    fn traverse(&mut self, hash: H256, on_data: &mut dyn FnMut(&[u8]) -> Result<()>) -> Result<()> {
+        if hash == empty_trie_hash() || self.is_node_visited(hash)? {
+            return Ok(());
+        }
+        let bytes = self
+            .source
+            .db
+            .get(hash.as_ref())?
+            .ok_or(Error::MissingTrieNode(hash))?;
+        let data: &[u8] = if self.copy_data { &bytes } else { &[] };
+        self.target.db.put(hash.as_ref(), data)?;
+        self.nodes_copied += 1;
+
+        let node = MerkleNode::decode(&Rlp::new(&bytes))?;
+        self.process_node(&node, on_data)
+    }
+
+    fn process_node(
+        &mut self,
+        node: &MerkleNode,
+        on_data: &mut dyn FnMut(&[u8]) -> Result<()>,
+    ) -> Result<()> {
+        match node {
+            MerkleNode::Leaf(_, data) => on_data(data),
+            MerkleNode::Extension(_, value) => self.process_value(value, on_data),
+            MerkleNode::Branch(values, data) => {
+                for value in values.iter() {
+                    self.process_value(value, on_data)?;
+                }
+                if let Some(data) = data {
+                    on_data(data)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn process_value(
+        &mut self,
+        value: &MerkleValue,
+        on_data: &mut dyn FnMut(&[u8]) -> Result<()>,
+    ) -> Result<()> {
+        match value {
+            MerkleValue::Empty => Ok(()),
+            MerkleValue::Full(node) => self.process_node(node, on_data),
+            MerkleValue::Hash(hash) => self.traverse(*hash, on_data),
+        }
+    }
+}
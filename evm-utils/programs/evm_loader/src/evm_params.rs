@@ -0,0 +1,150 @@
+//! Governance controlled parameters of the evm chain.
+//!  Parameters are stored in a config program account, that can be updated only
+//!  with the signature of the governance authority, and are applied by the bank at epoch boundaries.
+use bincode::{deserialize, serialized_size};
+use evm_state::EvmConfig;
+use serde::{Deserialize, Serialize};
+use solana_config_program::{config_instruction, get_config_data, ConfigKeys, ConfigState};
+use solana_sdk::{account::Account, instruction::Instruction, pubkey::Pubkey};
+
+// evm params config ID
+solana_sdk::declare_id!("71urRSN8TmUSFbJa2QydN5Z3Gr9DF9otssAYNLAEetzu");
+
+/// Copy of the params that are in effect for the current epoch, maintained by the bank.
+pub mod active {
+    solana_sdk::declare_id!("3QeRubZzbi5KPxvdhiFzWtG7JCCEx5UfHtgNJDR79eit");
+}
+
+/// Authority that has to sign every update of the params.
+pub mod governance {
+    solana_sdk::declare_id!("CNh8oae5b4jMatywaYGNBRhM7EYqN7EgrZrsoqaRjr15");
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+pub struct EvmParams {
+    /// transactions with lower gas price are rejected
+    pub min_gas_price: u64,
+    /// how much gas all transactions of a single block can use
+    pub block_gas_limit: u64,
+    /// percent of transaction fee that is burned
+    pub fee_burn_percent: u8,
+    /// percent of transaction fee that is paid to the block leader,
+    ///  the rest (after burn) is refunded to the transaction sender
+    pub fee_leader_percent: u8,
+}
+
+impl EvmParams {
+    pub fn from(account: &Account) -> Option<Self> {
+        if account.owner != solana_config_program::id() {
+            return None;
+        }
+        get_config_data(&account.data)
+            .ok()
+            .and_then(|data| deserialize(data).ok())
+    }
+
+    /// Read params from the governance config account,
+    ///  params that can be stored without the governance authority signature are ignored.
+    pub fn from_governance_account(account: &Account) -> Option<Self> {
+        let keys = deserialize::<ConfigKeys>(&account.data).ok()?;
+        if keys.keys != governance_keys() {
+            return None;
+        }
+        Self::from(account)
+    }
+
+    pub fn is_valid(&self) -> bool {
+        u16::from(self.fee_burn_percent) + u16::from(self.fee_leader_percent) <= 100
+    }
+
+    pub fn evm_config(&self, chain_id: u64) -> EvmConfig {
+        EvmConfig {
+            gas_limit: self.block_gas_limit,
+            min_gas_price: self.min_gas_price,
+            fee_burn_percent: self.fee_burn_percent,
+            fee_leader_percent: self.fee_leader_percent,
+            ..EvmConfig::new(chain_id)
+        }
+    }
+}
+
+impl Default for EvmParams {
+    fn default() -> Self {
+        Self {
+            min_gas_price: 0,
+            block_gas_limit: evm_state::DEFAULT_GAS_LIMIT,
+            fee_burn_percent: 0,
+            fee_leader_percent: 0,
+        }
+    }
+}
+
+impl ConfigState for EvmParams {
+    fn max_space() -> u64 {
+        serialized_size(&EvmParams::default()).unwrap()
+    }
+}
+
+fn governance_keys() -> Vec<(Pubkey, bool)> {
+    vec![(governance::id(), true)]
+}
+
+/// Create the evm params account, it should be initialized with `store` signed by the account itself.
+pub fn create_account(from_pubkey: &Pubkey, lamports: u64) -> Vec<Instruction> {
+    config_instruction::create_account::<EvmParams>(from_pubkey, &id(), lamports, governance_keys())
+}
+
+/// Store new params, the governance authority should sign the transaction.
+pub fn store(is_config_signer: bool, params: &EvmParams) -> Instruction {
+    config_instruction::store(&id(), is_config_signer, governance_keys(), params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_config_program::create_config_account;
+
+    #[test]
+    fn test_evm_params_from_account() {
+        let params = EvmParams {
+            min_gas_price: 2_000_000_000,
+            block_gas_limit: 100_000_000,
+            fee_burn_percent: 50,
+            fee_leader_percent: 25,
+        };
+        let mut account = create_config_account(governance_keys(), &params, 1);
+        assert_eq!(EvmParams::from(&account), Some(params));
+        assert_eq!(EvmParams::from_governance_account(&account), Some(params));
+        assert!(params.is_valid());
+
+        let config = params.evm_config(0xdead);
+        assert_eq!(config.chain_id, 0xdead);
+        assert_eq!(config.gas_limit, 100_000_000);
+        assert_eq!(config.min_gas_price, 2_000_000_000);
+        assert_eq!(config.fee_burn_percent, 50);
+        assert_eq!(config.fee_leader_percent, 25);
+
+        account.owner = Pubkey::new_unique();
+        assert_eq!(EvmParams::from(&account), None);
+        assert_eq!(EvmParams::from_governance_account(&account), None);
+
+        // params stored without the governance authority are ignored
+        for keys in vec![
+            vec![],
+            vec![(Pubkey::new_unique(), true)],
+            vec![(governance::id(), false)],
+            vec![(governance::id(), true), (Pubkey::new_unique(), true)],
+        ] {
+            let account = create_config_account(keys, &params, 1);
+            assert_eq!(EvmParams::from(&account), Some(params));
+            assert_eq!(EvmParams::from_governance_account(&account), None);
+        }
+
+        let params = EvmParams {
+            fee_burn_percent: 60,
+            fee_leader_percent: 50,
+            ..EvmParams::default()
+        };
+        assert!(!params.is_valid());
+    }
+}
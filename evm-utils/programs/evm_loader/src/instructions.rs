@@ -72,6 +72,19 @@ pub enum EvmInstruction {
     },
 }
 
+impl EvmInstruction {
+    /// Scope that vaccount operational key should have, to sign this instruction.
+    pub fn scope(&self) -> u8 {
+        match self {
+            EvmInstruction::EvmTransaction { .. } => 0,
+            EvmInstruction::SwapNativeToEther { .. } => 1,
+            EvmInstruction::FreeOwnership {} => 2,
+            EvmInstruction::EvmBigTransaction(_) => 3,
+            EvmInstruction::EvmAuthorizedTransaction { .. } => 4,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
 
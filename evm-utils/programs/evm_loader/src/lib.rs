@@ -1,4 +1,5 @@
 mod account_structure;
+pub mod evm_params;
 pub mod tx_chunks;
 
 pub mod instructions;
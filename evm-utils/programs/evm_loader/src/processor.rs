@@ -5,6 +5,7 @@ use super::scope::*;
 use log::*;
 
 use evm::{gweis_to_lamports, Executor, ExitReason};
+use solana_sdk::feature_set::velas_accounts_program;
 use solana_sdk::ic_msg;
 use solana_sdk::instruction::InstructionError;
 use solana_sdk::process_instruction::InvokeContext;
@@ -20,6 +21,18 @@ pub fn next_account_info<'a, 'b, I: Iterator<Item = &'a KeyedAccount<'b>>>(
     iter.next().ok_or(InstructionError::NotEnoughAccountKeys)
 }
 
+/// Withhold burned and leader parts of transaction fee (in lamports), according to executor config.
+/// Withheld lamports stays on evm state account until the bank settles them,
+/// return the part that should be refunded to the transaction sender.
+fn withhold_fee(executor: &mut Executor, fee: u64) -> u64 {
+    let config = executor.config();
+    let share = |percent: u8| (u128::from(fee) * u128::from(percent) / 100) as u64;
+    let burned = share(config.fee_burn_percent);
+    let leader = share(config.fee_leader_percent);
+    executor.withhold_fee(burned, leader);
+    fee.saturating_sub(burned).saturating_sub(leader)
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct EvmProcessor {}
 
@@ -43,15 +56,22 @@ impl EvmProcessor {
 
         let accounts = AccountStructure::new(evm_state_account, keyed_accounts);
 
-        let ix = limited_deserialize(data)?;
+        let ix: EvmInstruction = limited_deserialize(data)?;
         trace!("Run evm exec with ix = {:?}.", ix);
+        let scope = ix.scope();
         match ix {
             EvmInstruction::EvmTransaction { evm_tx } => {
                 self.process_raw_tx(executor, invoke_context, accounts, evm_tx)
             }
-            EvmInstruction::EvmAuthorizedTransaction { from, unsigned_tx } => {
-                self.process_authorized_tx(executor, invoke_context, accounts, from, unsigned_tx)
-            }
+            EvmInstruction::EvmAuthorizedTransaction { from, unsigned_tx } => self
+                .process_authorized_tx(
+                    executor,
+                    invoke_context,
+                    accounts,
+                    from,
+                    unsigned_tx,
+                    scope,
+                ),
             EvmInstruction::FreeOwnership {} => {
                 self.process_free_ownership(executor, invoke_context, accounts)
             }
@@ -108,8 +128,9 @@ impl EvmProcessor {
             return Err(InstructionError::InvalidError);
         }
         let fee = tx_gas_price * result.used_gas;
+        let (fee, _) = gweis_to_lamports(fee);
+        let fee = withhold_fee(executor, fee);
         if let Some(payer) = accounts.users.first() {
-            let (fee, _) = gweis_to_lamports(fee);
             ic_msg!(
                 invoke_context,
                 "EvmTransaction: Refunding transaction fee to transaction sender fee:{:?}, sender:{}",
@@ -127,6 +148,40 @@ impl EvmProcessor {
         Ok(())
     }
 
+    /// Check that vaccount, passed as first account, is signed by one of its operational keys,
+    /// which is allowed to run evm instruction with `scope`.
+    fn verify_vaccount_signer(
+        invoke_context: &mut dyn InvokeContext,
+        accounts: &AccountStructure,
+        scope: u8,
+    ) -> Result<(), InstructionError> {
+        let (vaccount, storage, signer) = match accounts.users {
+            [vaccount, storage, signer, ..] => (vaccount, storage, signer),
+            _ => {
+                ic_msg!(
+                    invoke_context,
+                    "EvmAuthorizedTransaction: Not enough accounts, expected vaccount, its storage and operational signer."
+                );
+                return Err(InstructionError::NotEnoughAccountKeys);
+            }
+        };
+        solana_velas_account_program::vaccount_processor::verify_operational_signer(
+            vaccount,
+            storage,
+            signer,
+            &crate::ID,
+            Some(scope),
+        )
+        .map_err(|e| {
+            ic_msg!(
+                invoke_context,
+                "EvmAuthorizedTransaction: Vaccount operational key is not allowed to sign: {:?}",
+                e
+            );
+            e
+        })
+    }
+
     fn process_authorized_tx(
         &self,
         executor: &mut Executor,
@@ -134,6 +189,7 @@ impl EvmProcessor {
         accounts: AccountStructure,
         from: evm::Address,
         unsigned_tx: evm::UnsignedTransaction,
+        scope: u8,
     ) -> Result<(), InstructionError> {
         // TODO: Check that it is from program?
         // TODO: Gas limit?
@@ -146,6 +202,12 @@ impl EvmProcessor {
         })?;
         let key = if let Some(key) = program_account.signer_key() {
             key
+        } else if invoke_context.is_feature_active(&velas_accounts_program::id())
+            && program_account.owner()? == solana_velas_account_program::id()
+        {
+            // Vaccount is signed by its operational key: [vaccount, storage, operational signer]
+            Self::verify_vaccount_signer(invoke_context, &accounts, scope)?;
+            program_account.unsigned_key()
         } else {
             ic_msg!(
                 invoke_context,
@@ -199,6 +261,7 @@ impl EvmProcessor {
             .first()
             .expect("Payer is program account, and was checked before");
         let (fee, _) = gweis_to_lamports(fee);
+        let fee = withhold_fee(executor, fee);
         ic_msg!(
             invoke_context,
             "EvmAuthorizedTransaction: Refunding transaction fee to transaction sender fee:{:?}, sender:{}",
@@ -394,8 +457,9 @@ impl EvmProcessor {
                     return Err(InstructionError::InvalidError);
                 }
                 let fee = tx_gas_price * result.used_gas;
+                let (fee, _) = gweis_to_lamports(fee);
+                let fee = withhold_fee(executor, fee);
                 if let Some(payer) = accounts.users.get(1) {
-                    let (fee, _) = gweis_to_lamports(fee);
                     ic_msg!(
                         invoke_context,
                         "BigTransaction::EvmTransactionExecute: Refunding transaction fee to transaction sender fee:{:?}, sender:{}",
@@ -633,6 +697,69 @@ mod test {
         );
     }
 
+    #[test]
+    fn deploy_tx_withholds_fee() {
+        let _logger = simple_logger::SimpleLogger::new().init();
+        let evm_config = evm::EvmConfig {
+            fee_burn_percent: 10,
+            fee_leader_percent: 20,
+            ..Default::default()
+        };
+        let mut executor =
+            evm_state::Executor::with_config(Default::default(), Default::default(), evm_config);
+        let mut executor = Some(&mut executor);
+        let processor = EvmProcessor::default();
+        let user_id = Pubkey::new_unique();
+        let first_user_account = RefCell::new(solana_sdk::account::Account {
+            lamports: 0,
+            data: vec![],
+            owner: crate::ID,
+            executable: false,
+            rent_epoch: 0,
+        });
+        let user_keyed_account = KeyedAccount::new(&user_id, true, &first_user_account);
+
+        let init_evm_balance = 1000000;
+        let evm_account = RefCell::new(crate::create_state_account(init_evm_balance));
+        let evm_keyed_account = KeyedAccount::new(&solana::evm_state::ID, false, &evm_account);
+        let keyed_accounts = [evm_keyed_account, user_keyed_account];
+        let secret_key = evm::SecretKey::from_slice(&SECRET_KEY_DUMMY).unwrap();
+
+        let address = secret_key.to_address();
+        executor.as_mut().unwrap().deposit(
+            address,
+            U256::from(crate::evm::LAMPORTS_TO_GWEI_PRICE) * 300000,
+        );
+        let tx_create = evm::UnsignedTransaction {
+            nonce: 0.into(),
+            gas_price: crate::evm::LAMPORTS_TO_GWEI_PRICE.into(),
+            gas_limit: 300000.into(),
+            action: TransactionAction::Create,
+            value: 0.into(),
+            input: hex::decode(evm_state::HELLO_WORLD_CODE).unwrap().to_vec(),
+        };
+        let tx_create = tx_create.sign(&secret_key, Some(CHAIN_ID));
+        let mut mock = MockInvokeContext::default();
+        assert!(processor
+            .process_instruction(
+                &crate::ID,
+                &keyed_accounts,
+                &bincode::serialize(&EvmInstruction::EvmTransaction { evm_tx: tx_create }).unwrap(),
+                executor.as_deref_mut(),
+                &mut mock,
+            )
+            .is_ok());
+        let fee = 114985; // used gas for hello world deploy, price is 1lamport
+        let (burned, leader) = (11498, 22997);
+        assert_eq!(
+            executor.as_ref().unwrap().evm_backend.withheld_fees(),
+            (burned, leader)
+        );
+        let refund = fee - burned - leader;
+        assert_eq!(first_user_account.borrow().lamports, refund);
+        assert_eq!(evm_account.borrow().lamports, init_evm_balance + 1 - refund);
+    }
+
     #[test]
     fn tx_preserve_nonce() {
         let mut executor = evm_state::Executor::testing();
@@ -1543,6 +1670,100 @@ mod test {
             .unwrap();
     }
 
+    fn vaccount_authorized_tx(
+        executor: &mut evm_state::Executor,
+        operational_scopes: Vec<u8>,
+    ) -> Result<(), InstructionError> {
+        use solana_velas_account_program::{
+            Operational, OperationalState, VAccountInfo, VAccountStorage, Whitelist,
+        };
+
+        let genesis_key = Pubkey::new_unique();
+        let vaccount_id = Pubkey::new_unique();
+        let storage_id = Pubkey::new_unique();
+        let operational_id = Pubkey::new_unique();
+
+        let mut vaccount_data = vec![0; VAccountInfo::LEN];
+        VAccountInfo::new(genesis_key, storage_id)
+            .pack(&mut vaccount_data)
+            .unwrap();
+        let mut storage_data = vec![0; 512];
+        VAccountStorage {
+            owners: vec![genesis_key],
+            operationals: vec![Operational {
+                pubkey: operational_id,
+                state: OperationalState::Initialized,
+                whitelist_programs: vec![Whitelist {
+                    program_id: crate::ID,
+                    scopes: operational_scopes,
+                }],
+                ..Operational::default()
+            }],
+        }
+        .pack(&mut storage_data)
+        .unwrap();
+        let velas_account = |lamports, data| {
+            RefCell::new(solana_sdk::account::Account {
+                lamports,
+                data,
+                owner: solana_velas_account_program::id(),
+                executable: false,
+                rent_epoch: 0,
+            })
+        };
+        let vaccount = velas_account(1000, vaccount_data);
+        let storage = velas_account(1, storage_data);
+        let operational = RefCell::new(solana_sdk::account::Account::default());
+        let evm_account = RefCell::new(crate::create_state_account(0));
+
+        let keyed_accounts = [
+            KeyedAccount::new(&solana::evm_state::ID, false, &evm_account),
+            KeyedAccount::new(&vaccount_id, false, &vaccount),
+            KeyedAccount::new_readonly(&storage_id, false, &storage),
+            KeyedAccount::new_readonly(&operational_id, true, &operational),
+        ];
+
+        executor.deposit(
+            crate::evm_address_for_program(vaccount_id),
+            U256::from(2) * 300000,
+        );
+        let (_, unsigned_tx) = dummy_call(0);
+        let ix = crate::authorized_tx(vaccount_id, unsigned_tx);
+        EvmProcessor::default().process_instruction(
+            &crate::ID,
+            &keyed_accounts,
+            &ix.data,
+            Some(executor),
+            &mut MockInvokeContext::default(),
+        )
+    }
+
+    #[test]
+    fn authorized_tx_from_vaccount_operational_signer() {
+        let _ = simple_logger::SimpleLogger::new().init();
+        let mut executor = evm_state::Executor::testing();
+        let scope = EvmInstruction::EvmAuthorizedTransaction {
+            from: evm::Address::zero(),
+            unsigned_tx: dummy_call(0).1,
+        }
+        .scope();
+
+        vaccount_authorized_tx(&mut executor, vec![scope]).unwrap();
+    }
+
+    #[test]
+    fn authorized_tx_from_vaccount_rejects_operational_signer_without_scope() {
+        let _ = simple_logger::SimpleLogger::new().init();
+        let mut executor = evm_state::Executor::testing();
+        let scope = EvmInstruction::FreeOwnership {}.scope();
+
+        let err = vaccount_authorized_tx(&mut executor, vec![scope]).unwrap_err();
+        assert_eq!(
+            err,
+            solana_velas_account_program::vaccount_instruction::VAccountError::Unauthorized.into()
+        );
+    }
+
     #[test]
     fn big_tx_allocation_error() {
         let mut executor = evm_state::Executor::testing();
@@ -171,8 +171,11 @@ fn main(args: Args) -> Result<(), Box<dyn std::error::Error>> {
             contract_code,
         } => {
             let secret_key = evm::SecretKey::from_slice(&SECRET_KEY_DUMMY).unwrap();
+            let nonce = rpc_client
+                .get_evm_transaction_count(&secret_key.to_address())
+                .expect("Cannot get nonce of dummy account");
             let tx_create = evm::UnsignedTransaction {
-                nonce: 0.into(),
+                nonce,
                 gas_price: 0.into(),
                 gas_limit: 300000.into(),
                 action: evm::TransactionAction::Create,
@@ -203,8 +206,11 @@ fn main(args: Args) -> Result<(), Box<dyn std::error::Error>> {
             let tx_address = evm_tx.address().unwrap();
 
             let secret_key = evm::SecretKey::from_slice(&SECRET_KEY_DUMMY).unwrap();
+            let nonce = rpc_client
+                .get_evm_transaction_count(&secret_key.to_address())
+                .expect("Cannot get nonce of dummy account");
             let tx_call = evm::UnsignedTransaction {
-                nonce: 0.into(),
+                nonce,
                 gas_price: 0.into(),
                 gas_limit: 300000.into(),
                 action: evm::TransactionAction::Call(tx_address),
@@ -674,6 +674,7 @@ fn load_bank_forks(
             snapshot_path,
             archive_format: ArchiveFormat::TarBzip2,
             snapshot_version: SnapshotVersion::default(),
+            incremental_snapshot_interval_slots: 0, // Value doesn't matter
         })
     };
     let account_paths = if let Some(account_paths) = arg_matches.value_of("account_paths") {
@@ -54,6 +54,17 @@ pub fn load(
         ) {
             Some((archive_filename, (archive_slot, archive_snapshot_hash, compression))) => {
                 info!("Loading snapshot package: {:?}", archive_filename);
+                let incremental_snapshot_archive =
+                    snapshot_utils::get_highest_incremental_snapshot_archive_path(
+                        &snapshot_config.snapshot_package_output_path,
+                        archive_slot,
+                    );
+                if let Some((incremental_archive_filename, _)) = &incremental_snapshot_archive {
+                    info!(
+                        "Loading incremental snapshot package: {:?}",
+                        incremental_archive_filename
+                    );
+                }
                 // Fail hard here if snapshot fails to load, don't silently continue
 
                 if account_paths.is_empty() {
@@ -68,6 +79,14 @@ pub fn load(
                     &snapshot_config.snapshot_path,
                     &archive_filename,
                     compression,
+                    incremental_snapshot_archive.as_ref().map(
+                        |(incremental_archive_filename, (_, _, _, incremental_compression))| {
+                            (
+                                incremental_archive_filename.as_path(),
+                                *incremental_compression,
+                            )
+                        },
+                    ),
                     genesis_config,
                     process_options.debug_keys.clone(),
                     Some(&crate::builtins::get(process_options.bpf_jit)),
@@ -84,10 +103,17 @@ pub fn load(
                     deserialized_bank.get_accounts_hash(),
                 );
 
-                if deserialized_snapshot_hash != (archive_slot, archive_snapshot_hash) {
+                // Bank is restored at the slot of the incremental snapshot, if there is one
+                let expected_snapshot_hash = incremental_snapshot_archive.as_ref().map_or(
+                    (archive_slot, archive_snapshot_hash),
+                    |(_, (_, incremental_slot, incremental_hash, _))| {
+                        (*incremental_slot, *incremental_hash)
+                    },
+                );
+                if deserialized_snapshot_hash != expected_snapshot_hash {
                     error!(
                         "Snapshot has mismatch:\narchive: {:?}\ndeserialized: {:?}",
-                        archive_snapshot_hash, deserialized_snapshot_hash
+                        expected_snapshot_hash, deserialized_snapshot_hash
                     );
                     process::exit(1);
                 }
@@ -100,7 +126,8 @@ pub fn load(
                         &VerifyRecyclers::default(),
                         transaction_status_sender,
                     ),
-                    Some(deserialized_snapshot_hash),
+                    // Only full snapshots are advertised to the cluster
+                    Some((archive_slot, archive_snapshot_hash)),
                 );
             }
             None => info!("No snapshot package available"),
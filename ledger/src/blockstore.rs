@@ -4,8 +4,8 @@
 pub use crate::{blockstore_db::BlockstoreError, blockstore_meta::SlotMeta};
 use crate::{
     blockstore_db::{
-        columns as cf, AccessType, BlockstoreRecoveryMode, Column, Database, IteratorDirection,
-        IteratorMode, LedgerColumn, Result, WriteBatch,
+        columns as cf, AccessType, BlockstoreRecoveryMode, Column, Database, EvmReceiptKey,
+        IteratorDirection, IteratorMode, LedgerColumn, Result, WriteBatch,
     },
     blockstore_meta::*,
     entry::{create_ticks, Entry},
@@ -1713,7 +1713,7 @@ impl Blockstore {
         Ok(self
             .evm_blocks_cf
             .iter(IteratorMode::Start)?
-            .map(|(block, _)| block)
+            .map(|((block, _slot), _)| block)
             .next()
             .unwrap_or(evm::BlockNum::MAX))
     }
@@ -1722,7 +1722,7 @@ impl Blockstore {
         Ok(self
             .evm_blocks_cf
             .iter(IteratorMode::End)?
-            .map(|(block, _)| block)
+            .map(|((block, _slot), _)| block)
             .next()
             .unwrap_or(evm::BlockNum::MIN))
     }
@@ -1765,15 +1765,36 @@ impl Blockstore {
             return Err(BlockstoreError::SlotCleanedUp);
         }
         if self.is_root(slot) {
-            let slot_meta_cf = self.db.column::<cf::SlotMeta>();
-            let slot_meta = match slot_meta_cf.get(slot)? {
-                Some(slot_meta) => slot_meta,
-                None => {
-                    info!("SlotMeta not found for rooted slot {}", slot);
-                    return Err(BlockstoreError::SlotCleanedUp);
-                }
-            };
+            if self.meta(slot)?.is_none() {
+                info!("SlotMeta not found for rooted slot {}", slot);
+                return Err(BlockstoreError::SlotCleanedUp);
+            }
+            // Rooted slot without entries, eg. snapshot root slot
+            return self
+                .get_complete_block(slot, require_previous_blockhash)
+                .map_err(|err| match err {
+                    BlockstoreError::SlotUnavailable => BlockstoreError::SlotNotRooted,
+                    err => err,
+                });
+        }
+        Err(BlockstoreError::SlotNotRooted)
+    }
 
+    /// Returns block of full `slot`, regardless of whether the slot was rooted.
+    pub fn get_complete_block(
+        &self,
+        slot: Slot,
+        require_previous_blockhash: bool,
+    ) -> Result<ConfirmedBlock> {
+        let slot_meta_cf = self.db.column::<cf::SlotMeta>();
+        let slot_meta = match slot_meta_cf.get(slot)? {
+            Some(slot_meta) => slot_meta,
+            None => {
+                info!("SlotMeta not found for slot {}", slot);
+                return Err(BlockstoreError::SlotUnavailable);
+            }
+        };
+        if slot_meta.is_full() {
             let slot_entries = self.get_slot_entries(slot, 0)?;
             if !slot_entries.is_empty() {
                 let slot_transaction_iterator = slot_entries
@@ -1804,7 +1825,7 @@ impl Blockstore {
                 };
 
                 let blockhash = get_last_hash(slot_entries.iter())
-                    .unwrap_or_else(|| panic!("Rooted slot {:?} must have blockhash", slot));
+                    .unwrap_or_else(|| panic!("Full slot {:?} must have blockhash", slot));
 
                 let rewards = self
                     .rewards_cf
@@ -1825,7 +1846,7 @@ impl Blockstore {
                 return Ok(block);
             }
         }
-        Err(BlockstoreError::SlotNotRooted)
+        Err(BlockstoreError::SlotUnavailable)
     }
 
     /// Returns block, and flag if that block was rooted (confirmed)
@@ -1841,14 +1862,15 @@ impl Blockstore {
         // if *lowest_cleanup_slot > 0 && *lowest_cleanup_slot >= slot {
         //     return Err(BlockstoreError::SlotCleanedUp);
         // }
-        let block_header = if let Some(block_header) = self.read_evm_block_header(block_number)? {
-            block_header
-        } else {
-            return Err(BlockstoreError::SlotCleanedUp);
-        };
+        let (block_header, confirmed) =
+            if let Some(block) = self.read_evm_block_header(block_number)? {
+                block
+            } else {
+                return Err(BlockstoreError::SlotCleanedUp);
+            };
         let mut txs = Vec::new();
         for hash in &block_header.transactions {
-            let tx = self.read_evm_transaction((*hash, block_header.block_number))?;
+            let tx = self.read_evm_block_transaction(&block_header, *hash)?;
             if let Some(tx) = tx {
                 txs.push((*hash, tx))
             } else {
@@ -1859,7 +1881,6 @@ impl Blockstore {
             };
         }
 
-        let confirmed = self.is_root(block_header.native_chain_slot);
         Ok((
             evm::Block {
                 header: block_header,
@@ -2468,32 +2489,101 @@ impl Blockstore {
 
     // EVM scope
 
-    pub fn write_evm_block_header(
+    /// Write evm block header, produced by bank at `block_slot`.
+    /// Headers with the same number from different forks are stored side by side,
+    /// and resolved by `read_evm_block_header`.
+    pub fn write_evm_block_header(&self, block_slot: Slot, block: &evm::BlockHeader) -> Result<()> {
+        self.evm_blocks_cf
+            .put((block.block_number, Some(block_slot)), block)
+    }
+
+    /// Returns all stored headers for evm block number, from every fork.
+    pub fn read_evm_block_headers(
         &self,
-        _block_slot: Slot,
-        block: &evm::BlockHeader,
-    ) -> Result<()> {
-        self.evm_blocks_cf.put(block.block_number, block)
+        block_number: evm::BlockNum,
+    ) -> Result<Vec<evm::BlockHeader>> {
+        let mut headers = Vec::new();
+        for ((block, _slot), data) in self.evm_blocks_cf.iter(IteratorMode::From(
+            (block_number, None),
+            IteratorDirection::Forward,
+        ))? {
+            if block != block_number {
+                break;
+            }
+            headers.push(deserialize(&data)?);
+        }
+        Ok(headers)
     }
 
+    /// Returns canonical header for evm block number, and flag if that block was rooted (confirmed).
     pub fn read_evm_block_header(
         &self,
-        block_index: evm::BlockNum,
-    ) -> Result<Option<evm::BlockHeader>> {
-        self.evm_blocks_cf.get(block_index)
+        block_number: evm::BlockNum,
+    ) -> Result<Option<(evm::BlockHeader, bool)>> {
+        let headers = self.read_evm_block_headers(block_number)?;
+        Ok(self.select_canonical_evm_block(headers))
+    }
+
+    /// Choose canonical header from headers of different forks with the same block number.
+    ///
+    /// Header from rooted slot is always canonical.
+    /// Otherwise header from the latest slot is used, headers from slots older than last root
+    /// belong to abandoned forks, and are never returned.
+    fn select_canonical_evm_block(
+        &self,
+        headers: Vec<evm::BlockHeader>,
+    ) -> Option<(evm::BlockHeader, bool)> {
+        let last_root = self.last_root();
+        let mut unconfirmed: Option<evm::BlockHeader> = None;
+        for header in headers {
+            if self.is_root(header.native_chain_slot) {
+                return Some((header, true));
+            }
+            if header.native_chain_slot <= last_root {
+                continue;
+            }
+            match &unconfirmed {
+                Some(current) if current.native_chain_slot >= header.native_chain_slot => {}
+                _ => unconfirmed = Some(header),
+            }
+        }
+        unconfirmed.map(|header| (header, false))
     }
 
-    #[allow(clippy::useless_conversion)] // to keep code the same when evm_transaction_cf will change type.
+    /// Read receipt of transaction included in evm block.
+    fn read_evm_block_transaction(
+        &self,
+        block: &evm::BlockHeader,
+        hash: H256,
+    ) -> Result<Option<evm::TransactionReceipt>> {
+        if let Some(receipt) = self.read_evm_transaction((hash, block.native_chain_slot))? {
+            return Ok(Some(receipt));
+        }
+        Ok(self
+            .read_evm_transaction_receipt(hash, EvmReceiptKey::BlockNumber(block.block_number))?
+            .filter(|receipt| receipt.block_number == block.block_number))
+    }
+
+    /// Read receipt of transaction included in evm block produced at native slot.
     pub fn read_evm_transaction(
         &self,
         index: (H256, Slot),
     ) -> Result<Option<evm::TransactionReceipt>> {
-        let (signature, slot) = index;
-        let result = self.evm_transactions_cf.get((0, signature, slot))?;
+        let (hash, slot) = index;
+        self.read_evm_transaction_receipt(hash, EvmReceiptKey::Slot(slot))
+    }
+
+    #[allow(clippy::useless_conversion)] // to keep code the same when evm_transaction_cf will change type.
+    fn read_evm_transaction_receipt(
+        &self,
+        hash: H256,
+        receipt_key: EvmReceiptKey,
+    ) -> Result<Option<evm::TransactionReceipt>> {
+        let result = self.evm_transactions_cf.get((0, hash, receipt_key))?;
         if result.is_none() {
             Ok(self
                 .evm_transactions_cf
-                .get((1, signature, slot))?
+                .get((1, hash, receipt_key))?
                 .and_then(|meta| meta.try_into().ok()))
         } else {
             Ok(result.and_then(|meta| meta.try_into().ok()))
@@ -2504,15 +2594,24 @@ impl Blockstore {
         let mut logs = Vec::new();
         let masks = filter.bloom_possibilities();
         info!("Starting search for logs with filter = {:?}", filter);
-        for (block_num, data) in self.evm_blocks_cf.iter(IteratorMode::From(
-            filter.from_block,
+        let mut last_block_num = None;
+        for ((block_num, _slot), _data) in self.evm_blocks_cf.iter(IteratorMode::From(
+            (filter.from_block, None),
             IteratorDirection::Forward,
         ))? {
-            trace!("Searching block = {}", block_num);
             if block_num > filter.to_block {
                 break;
             }
-            let block: evm::BlockHeader = deserialize(&data)?;
+            // Headers from different forks are stored one after another, search only in canonical one.
+            if last_block_num == Some(block_num) {
+                continue;
+            }
+            last_block_num = Some(block_num);
+            trace!("Searching block = {}", block_num);
+            let block = match self.read_evm_block_header(block_num)? {
+                Some((block, _confirmed)) => block,
+                None => continue,
+            };
             // First filterout all blocks that not contain ALL topic + addresses
             if !masks
                 .iter()
@@ -2527,7 +2626,7 @@ impl Blockstore {
             }
 
             for (id, hash) in block.transactions.iter().enumerate() {
-                let tx = self.read_evm_transaction((*hash, block.block_number))?;
+                let tx = self.read_evm_block_transaction(&block, *hash)?;
                 let tx = if let Some(tx) = tx {
                     tx
                 } else {
@@ -2566,23 +2665,33 @@ impl Blockstore {
         Ok(logs)
     }
 
+    /// Find receipt of transaction by its hash.
+    /// Only receipt from canonical block is returned, receipts from blocks of abandoned forks are skipped.
     pub fn find_evm_transaction(&self, hash: H256) -> Result<Option<evm::TransactionReceipt>> {
-        if let Some(((_p, found_hash, _block), data)) = self
-            .evm_transactions_cf
-            .iter(IteratorMode::From((0, hash, 0), IteratorDirection::Forward))?
-            .next()
-        {
-            if found_hash == hash {
-                return Ok(Some(deserialize(&data)?));
-            }
-        }
-        if let Some(((_p, found_hash, _block), data)) = self
-            .evm_transactions_cf
-            .iter(IteratorMode::From((1, hash, 0), IteratorDirection::Forward))?
-            .next()
-        {
-            if found_hash == hash {
-                return Ok(Some(deserialize(&data)?));
+        for primary_index in 0..=1 {
+            for ((_p, found_hash, receipt_key), data) in
+                self.evm_transactions_cf.iter(IteratorMode::From(
+                    (primary_index, hash, EvmReceiptKey::BlockNumber(0)),
+                    IteratorDirection::Forward,
+                ))?
+            {
+                if found_hash != hash {
+                    break;
+                }
+                let receipt: evm::TransactionReceipt = deserialize(&data)?;
+                let block = match self.read_evm_block_header(receipt.block_number)? {
+                    Some((block, _confirmed)) => block,
+                    None => continue,
+                };
+                let canonical = match receipt_key {
+                    EvmReceiptKey::Slot(slot) => slot == block.native_chain_slot,
+                    EvmReceiptKey::BlockNumber(block_number) => {
+                        block_number == block.block_number && block.transactions.contains(&hash)
+                    }
+                };
+                if canonical {
+                    return Ok(Some(receipt));
+                }
             }
         }
         Ok(None)
@@ -2599,7 +2708,7 @@ impl Blockstore {
             self.active_transaction_status_index.write().unwrap();
         let primary_index = self.get_primary_index(slot, &mut w_active_transaction_status_index)?;
         self.evm_transactions_cf
-            .put((primary_index, hash, slot), &status)?;
+            .put((primary_index, hash, EvmReceiptKey::Slot(slot)), &status)?;
         Ok(())
     }
     /// Returns the entry vector for the slot starting with `shred_start_index`
@@ -3791,6 +3900,7 @@ pub mod tests {
         hash::{self, hash, Hash},
         instruction::CompiledInstruction,
         packet::PACKET_DATA_SIZE,
+        process_instruction::TransactionReturnData,
         pubkey::Pubkey,
         signature::Signature,
         transaction::TransactionError,
@@ -5737,6 +5847,145 @@ pub mod tests {
         Blockstore::destroy(&blockstore_path).expect("Expected successful database destruction");
     }
 
+    #[test]
+    fn test_evm_block_forks() {
+        let blockstore_path = get_tmp_ledger_path!();
+        let blockstore = Blockstore::open(&blockstore_path).unwrap();
+        let header = |block_number, slot| {
+            evm::BlockHeader::new(
+                H256::zero(),
+                0,
+                H256::zero(),
+                block_number,
+                0,
+                0,
+                slot,
+                H256::repeat_byte(slot as u8),
+                std::iter::empty(),
+            )
+        };
+
+        // Two forks produced block 1 at slots 2 and 3, latest fork is visible until rooted.
+        blockstore.write_evm_block_header(2, &header(1, 2)).unwrap();
+        blockstore.write_evm_block_header(3, &header(1, 3)).unwrap();
+        blockstore.write_evm_block_header(4, &header(2, 4)).unwrap();
+        assert_eq!(blockstore.read_evm_block_headers(1).unwrap().len(), 2);
+        assert_eq!(
+            blockstore.read_evm_block_header(1).unwrap(),
+            Some((header(1, 3), false))
+        );
+        assert_eq!(blockstore.get_first_available_evm_block().unwrap(), 1);
+        assert_eq!(blockstore.get_last_available_evm_block().unwrap(), 2);
+
+        // Rooted fork is canonical, blocks of abandoned fork are never returned.
+        blockstore.set_roots(&[2]).unwrap();
+        assert_eq!(
+            blockstore.read_evm_block_header(1).unwrap(),
+            Some((header(1, 2), true))
+        );
+        blockstore.set_roots(&[5]).unwrap();
+        assert_eq!(
+            blockstore.read_evm_block_header(1).unwrap(),
+            Some((header(1, 2), true))
+        );
+        assert_eq!(blockstore.read_evm_block_header(2).unwrap(), None);
+
+        drop(blockstore);
+        Blockstore::destroy(&blockstore_path).expect("Expected successful database destruction");
+    }
+
+    #[test]
+    fn test_find_evm_transaction_forks() {
+        let blockstore_path = get_tmp_ledger_path!();
+        let blockstore = Blockstore::open(&blockstore_path).unwrap();
+        let receipt = |block_number| {
+            let tx = evm::UnsignedTransaction {
+                nonce: evm::U256::zero(),
+                gas_price: evm::U256::zero(),
+                gas_limit: evm::U256::zero(),
+                action: evm::TransactionAction::Create,
+                value: evm::U256::zero(),
+                input: vec![],
+            };
+            evm::TransactionReceipt::new(
+                evm::TransactionInReceipt::Unsigned(evm::UnsignedTransactionWithCaller {
+                    unsigned_tx: tx,
+                    caller: evm::H160::zero(),
+                    chain_id: None,
+                }),
+                0,
+                block_number,
+                0,
+                vec![],
+                (evm::ExitReason::Succeed(evm::ExitSucceed::Stopped), vec![]),
+            )
+        };
+        let header = |block_number, slot, hash: H256| {
+            evm::BlockHeader::new(
+                H256::zero(),
+                0,
+                H256::zero(),
+                block_number,
+                0,
+                0,
+                slot,
+                H256::repeat_byte(slot as u8),
+                [(hash, receipt(block_number))].iter(),
+            )
+        };
+        let dead_fork_tx = H256::repeat_byte(1);
+        let rooted_tx = H256::repeat_byte(2);
+        let legacy_tx = H256::repeat_byte(3);
+
+        // Block 1 from a dead fork at slot 2, and from rooted slot 3
+        blockstore
+            .write_evm_block_header(2, &header(1, 2, dead_fork_tx))
+            .unwrap();
+        blockstore
+            .write_evm_transaction(2, dead_fork_tx, receipt(1))
+            .unwrap();
+        blockstore
+            .write_evm_block_header(3, &header(1, 3, rooted_tx))
+            .unwrap();
+        blockstore
+            .write_evm_transaction(3, rooted_tx, receipt(1))
+            .unwrap();
+        blockstore.set_roots(&[3]).unwrap();
+
+        // The only receipt of transaction is not returned, if it comes from a dead fork
+        assert_eq!(blockstore.find_evm_transaction(dead_fork_tx).unwrap(), None);
+        assert_eq!(
+            blockstore.find_evm_transaction(rooted_tx).unwrap(),
+            Some(receipt(1))
+        );
+        assert_eq!(
+            blockstore.read_evm_transaction((rooted_tx, 3)).unwrap(),
+            Some(receipt(1))
+        );
+
+        // Receipts written before blocks were stored per fork are keyed by block number,
+        // and never confused with receipts keyed by slot
+        blockstore
+            .write_evm_block_header(5, &header(5, 5, legacy_tx))
+            .unwrap();
+        blockstore
+            .evm_transactions_cf
+            .put((0, legacy_tx, EvmReceiptKey::BlockNumber(5)), &receipt(5))
+            .unwrap();
+        blockstore.set_roots(&[5]).unwrap();
+        assert_eq!(
+            blockstore.read_evm_transaction((legacy_tx, 5)).unwrap(),
+            None
+        );
+        assert_eq!(
+            blockstore.find_evm_transaction(legacy_tx).unwrap(),
+            Some(receipt(5))
+        );
+
+        drop(blockstore);
+        Blockstore::destroy(&blockstore_path).expect("Expected successful database destruction");
+    }
+
     #[test]
     fn test_is_skipped() {
         let blockstore_path = get_tmp_ledger_path!();
@@ -5970,6 +6219,7 @@ pub mod tests {
                     log_messages: Some(vec![]),
                     pre_token_balances: Some(vec![]),
                     post_token_balances: Some(vec![]),
+                    return_data: None,
                 }
                 .into();
                 ledger
@@ -5985,6 +6235,7 @@ pub mod tests {
                     log_messages: Some(vec![]),
                     pre_token_balances: Some(vec![]),
                     post_token_balances: Some(vec![]),
+                    return_data: None,
                 }
                 .into();
                 ledger
@@ -6002,6 +6253,7 @@ pub mod tests {
                         log_messages: Some(vec![]),
                         pre_token_balances: Some(vec![]),
                         post_token_balances: Some(vec![]),
+                        return_data: None,
                     }),
                 }
             })
@@ -6076,6 +6328,10 @@ pub mod tests {
             let log_messages_vec = vec![String::from("Test message\n")];
             let pre_token_balances_vec = vec![];
             let post_token_balances_vec = vec![];
+            let return_data_value = TransactionReturnData {
+                program_id: Pubkey::new_unique(),
+                data: vec![1, 2, 3],
+            };
 
             // result not found
             assert!(transaction_status_cf
@@ -6099,6 +6355,7 @@ pub mod tests {
                 log_messages: Some(log_messages_vec.clone()),
                 pre_token_balances: Some(pre_token_balances_vec.clone()),
                 post_token_balances: Some(post_token_balances_vec.clone()),
+                return_data: Some(return_data_value.clone()),
             }
             .into();
             assert!(transaction_status_cf
@@ -6115,6 +6372,7 @@ pub mod tests {
                 log_messages,
                 pre_token_balances,
                 post_token_balances,
+                return_data,
             } = transaction_status_cf
                 .get_protobuf_or_bincode::<StoredTransactionStatusMeta>((
                     0,
@@ -6133,6 +6391,7 @@ pub mod tests {
             assert_eq!(log_messages.unwrap(), log_messages_vec);
             assert_eq!(pre_token_balances.unwrap(), pre_token_balances_vec);
             assert_eq!(post_token_balances.unwrap(), post_token_balances_vec);
+            assert_eq!(return_data.unwrap(), return_data_value);
 
             // insert value
             let status = TransactionStatusMeta {
@@ -6144,6 +6403,7 @@ pub mod tests {
                 log_messages: Some(log_messages_vec.clone()),
                 pre_token_balances: Some(pre_token_balances_vec.clone()),
                 post_token_balances: Some(post_token_balances_vec.clone()),
+                return_data: Some(return_data_value.clone()),
             }
             .into();
             assert!(transaction_status_cf
@@ -6160,6 +6420,7 @@ pub mod tests {
                 log_messages,
                 pre_token_balances,
                 post_token_balances,
+                return_data,
             } = transaction_status_cf
                 .get_protobuf_or_bincode::<StoredTransactionStatusMeta>((
                     0,
@@ -6180,6 +6441,7 @@ pub mod tests {
             assert_eq!(log_messages.unwrap(), log_messages_vec);
             assert_eq!(pre_token_balances.unwrap(), pre_token_balances_vec);
             assert_eq!(post_token_balances.unwrap(), post_token_balances_vec);
+            assert_eq!(return_data.unwrap(), return_data_value);
         }
         Blockstore::destroy(&blockstore_path).expect("Expected successful database destruction");
     }
@@ -6410,6 +6672,7 @@ pub mod tests {
                 log_messages: Some(vec![]),
                 pre_token_balances: Some(vec![]),
                 post_token_balances: Some(vec![]),
+                return_data: None,
             }
             .into();
 
@@ -6557,6 +6820,7 @@ pub mod tests {
                     log_messages: log_messages.clone(),
                     pre_token_balances: pre_token_balances.clone(),
                     post_token_balances: post_token_balances.clone(),
+                    return_data: None,
                 }
                 .into();
                 blockstore
@@ -6574,6 +6838,7 @@ pub mod tests {
                         log_messages,
                         pre_token_balances,
                         post_token_balances,
+                        return_data: None,
                     }),
                 }
             })
@@ -7018,6 +7283,7 @@ pub mod tests {
                     log_messages: Some(vec![]),
                     pre_token_balances: Some(vec![]),
                     post_token_balances: Some(vec![]),
+                    return_data: None,
                 }
                 .into();
                 transaction_status_cf
@@ -7501,6 +7767,7 @@ pub mod tests {
                     lamports: 42 + i,
                     post_balance: std::u64::MAX,
                     reward_type: Some(RewardType::Fee),
+                    commission: None,
                 })
                 .collect();
             let protobuf_rewards: generated::Rewards = rewards.into();
@@ -7562,6 +7829,7 @@ pub mod tests {
                         ui_amount_string: "1.1".to_string(),
                     },
                 }]),
+                return_data: None,
             };
             let deprecated_status: StoredTransactionStatusMeta = status.clone().into();
             let protobuf_status: generated::TransactionStatusMeta = status.into();
@@ -80,6 +80,7 @@ pub enum BlockstoreError {
     ProtobufEncodeError(#[from] prost::EncodeError),
     ProtobufDecodeError(#[from] prost::DecodeError),
     ParentEntriesUnavailable,
+    SlotUnavailable,
 }
 pub type Result<T> = std::result::Result<T, BlockstoreError>;
 
@@ -731,24 +732,35 @@ impl TypedColumn for columns::ErasureMeta {
 // EVM blockstore
 
 impl Column for columns::EvmBlockHeader {
-    type Index = u64;
-
-    fn key(index: u64) -> Vec<u8> {
-        let mut key = vec![0; 8];
-        BigEndian::write_u64(&mut key[..], index);
+    /// Evm block number, and native slot of the fork that produced the block.
+    /// Headers written before blocks were keyed by slot have no slot in key.
+    type Index = (evm_state::BlockNum, Option<Slot>);
+
+    fn key((block, slot): Self::Index) -> Vec<u8> {
+        let mut key = vec![0; if slot.is_some() { 16 } else { 8 }];
+        BigEndian::write_u64(&mut key[0..8], block);
+        if let Some(slot) = slot {
+            BigEndian::write_u64(&mut key[8..16], slot);
+        }
         key
     }
 
-    fn index(key: &[u8]) -> u64 {
-        BigEndian::read_u64(&key[..8])
+    fn index(key: &[u8]) -> Self::Index {
+        let block = BigEndian::read_u64(&key[0..8]);
+        let slot = if key.len() >= 16 {
+            Some(BigEndian::read_u64(&key[8..16]))
+        } else {
+            None
+        };
+        (block, slot)
     }
 
-    fn primary_index(index: u64) -> u64 {
-        index
+    fn primary_index(index: Self::Index) -> u64 {
+        index.0
     }
 
-    fn as_index(block: u64) -> u64 {
-        block
+    fn as_index(block: u64) -> Self::Index {
+        (block, None)
     }
 }
 
@@ -760,26 +772,48 @@ impl TypedColumn for columns::EvmBlockHeader {
     type Type = evm_state::BlockHeader;
 }
 
+/// Position of an evm transaction receipt in the ledger.
+/// Receipts written before blocks were stored per fork are keyed by evm block number,
+/// newer receipts are keyed by native slot and their key ends with a version byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvmReceiptKey {
+    BlockNumber(evm_state::BlockNum),
+    Slot(Slot),
+}
+
+const EVM_RECEIPT_SLOT_KEY_VERSION: u8 = 1;
+
 impl Column for columns::EvmTransactionReceipts {
-    type Index = (u64, H256, Slot);
+    type Index = (u64, H256, EvmReceiptKey);
 
-    fn key((index, hash, slot): (u64, H256, Slot)) -> Vec<u8> {
-        let mut key = vec![0; 8 + 32 + 8]; // size_of u64 + size_of HASH + size_of Slot
+    fn key((index, hash, receipt_key): Self::Index) -> Vec<u8> {
+        // size_of u64 + size_of HASH + size_of Slot, and a version byte for slot keys
+        let mut key = vec![0; 8 + 32 + 8];
         BigEndian::write_u64(&mut key[0..8], index);
         key[8..40].clone_from_slice(&hash.as_bytes()[0..32]);
-        BigEndian::write_u64(&mut key[40..48], slot);
+        match receipt_key {
+            EvmReceiptKey::BlockNumber(block_number) => {
+                BigEndian::write_u64(&mut key[40..48], block_number)
+            }
+            EvmReceiptKey::Slot(slot) => {
+                BigEndian::write_u64(&mut key[40..48], slot);
+                key.push(EVM_RECEIPT_SLOT_KEY_VERSION);
+            }
+        }
         key
     }
 
-    fn index(key: &[u8]) -> (u64, H256, Slot) {
-        if key.len() != 48 {
-            Self::as_index(0)
-        } else {
-            let index = BigEndian::read_u64(&key[0..8]);
-            let hash = H256::from_slice(&key[8..40]);
-            let slot = BigEndian::read_u64(&key[40..48]);
-            (index, hash, slot)
-        }
+    fn index(key: &[u8]) -> Self::Index {
+        let receipt_key = match key.len() {
+            48 => EvmReceiptKey::BlockNumber(BigEndian::read_u64(&key[40..48])),
+            49 if key[48] == EVM_RECEIPT_SLOT_KEY_VERSION => {
+                EvmReceiptKey::Slot(BigEndian::read_u64(&key[40..48]))
+            }
+            _ => return Self::as_index(0),
+        };
+        let index = BigEndian::read_u64(&key[0..8]);
+        let hash = H256::from_slice(&key[8..40]);
+        (index, hash, receipt_key)
     }
 
     fn primary_index(index: Self::Index) -> u64 {
@@ -787,7 +821,7 @@ impl Column for columns::EvmTransactionReceipts {
     }
 
     fn as_index(index: u64) -> Self::Index {
-        (index, H256::default(), 0)
+        (index, H256::default(), EvmReceiptKey::BlockNumber(0))
     }
 }
 
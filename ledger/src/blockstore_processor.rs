@@ -33,6 +33,7 @@ use solana_sdk::{
     clock::{Slot, MAX_PROCESSING_AGE},
     genesis_config::GenesisConfig,
     hash::Hash,
+    process_instruction::TransactionReturnData,
     pubkey::Pubkey,
     signature::{Keypair, Signature},
     transaction::{Result, Transaction, TransactionError},
@@ -118,7 +119,7 @@ fn execute_batch(
         vec![]
     };
 
-    let (tx_results, balances, inner_instructions, transaction_logs) =
+    let (tx_results, balances, inner_instructions, transaction_logs, transaction_return_data) =
         batch.bank().load_execute_and_commit_transactions(
             batch,
             MAX_PROCESSING_AGE,
@@ -155,6 +156,7 @@ fn execute_batch(
             token_balances,
             inner_instructions,
             transaction_logs,
+            transaction_return_data,
             transaction_status_sender,
         );
     }
@@ -1077,7 +1079,7 @@ fn process_single_slot(
 ) -> result::Result<(), BlockstoreProcessorError> {
     // Mark corrupt slots as dead so validators don't replay this slot and
     // see DuplicateSignature errors later in ReplayStage
-    confirm_full_slot(blockstore, bank, opts, recyclers, progress, transaction_status_sender, replay_vote_sender).map_err(|err| {
+    confirm_full_slot(blockstore, bank, opts, recyclers, progress, transaction_status_sender.clone(), replay_vote_sender).map_err(|err| {
         let slot = bank.slot();
         warn!("slot {} failed to verify: {}", slot, err);
         if blockstore.is_primary_access() {
@@ -1091,6 +1093,9 @@ fn process_single_slot(
     })?;
 
     bank.freeze(); // all banks handled by this routine are created from complete slots
+    if let Some(transaction_status_sender) = transaction_status_sender {
+        send_transaction_status_freeze_message(bank, &transaction_status_sender);
+    }
 
     Ok(())
 }
@@ -1104,11 +1109,18 @@ pub struct TransactionStatusBatch {
     pub token_balances: TransactionTokenBalancesSet,
     pub inner_instructions: Option<Vec<Option<InnerInstructionsList>>>,
     pub transaction_logs: Option<Vec<TransactionLogMessages>>,
+    pub transaction_return_data: Vec<Option<TransactionReturnData>>,
+}
+
+pub enum TransactionStatusMessage {
+    Batch(TransactionStatusBatch),
+    /// All batches of the slot were sent, and its bank is frozen
+    Freeze(Slot),
 }
 
 #[derive(Clone)]
 pub struct TransactionStatusSender {
-    pub sender: Sender<TransactionStatusBatch>,
+    pub sender: Sender<TransactionStatusMessage>,
     pub enable_cpi_and_log_storage: bool,
 }
 
@@ -1121,6 +1133,7 @@ pub fn send_transaction_status_batch(
     token_balances: TransactionTokenBalancesSet,
     inner_instructions: Vec<Option<InnerInstructionsList>>,
     transaction_logs: Vec<TransactionLogMessages>,
+    transaction_return_data: Vec<Option<TransactionReturnData>>,
     transaction_status_sender: TransactionStatusSender,
 ) {
     let slot = bank.slot();
@@ -1132,7 +1145,7 @@ pub fn send_transaction_status_batch(
         };
     if let Err(e) = transaction_status_sender
         .sender
-        .send(TransactionStatusBatch {
+        .send(TransactionStatusMessage::Batch(TransactionStatusBatch {
             bank,
             transactions: transactions.to_vec(),
             iteration_order,
@@ -1141,7 +1154,8 @@ pub fn send_transaction_status_batch(
             token_balances,
             inner_instructions,
             transaction_logs,
-        })
+            transaction_return_data,
+        }))
     {
         trace!(
             "Slot {} transaction_status send batch failed: {:?}",
@@ -1151,6 +1165,23 @@ pub fn send_transaction_status_batch(
     }
 }
 
+pub fn send_transaction_status_freeze_message(
+    bank: &Bank,
+    transaction_status_sender: &TransactionStatusSender,
+) {
+    let slot = bank.slot();
+    if let Err(e) = transaction_status_sender
+        .sender
+        .send(TransactionStatusMessage::Freeze(slot))
+    {
+        trace!(
+            "Slot {} transaction_status send freeze message failed: {:?}",
+            slot,
+            e
+        );
+    }
+}
+
 // used for tests only
 pub fn fill_blockstore_slot_with_ticks(
     blockstore: &Blockstore,
@@ -3233,6 +3264,7 @@ pub mod tests {
             _balances,
             _inner_instructions,
             _log_messages,
+            _return_data,
         ) = batch.bank().load_execute_and_commit_transactions(
             &batch,
             MAX_PROCESSING_AGE,
@@ -2499,6 +2499,7 @@ fn setup_snapshot_validator_config(
         snapshot_path: PathBuf::from(snapshot_dir.path()),
         archive_format: ArchiveFormat::TarBzip2,
         snapshot_version: snapshot_utils::SnapshotVersion::default(),
+        incremental_snapshot_interval_slots: std::u64::MAX,
     };
 
     // Create the account paths
@@ -2,4 +2,7 @@
 pub mod counter;
 pub mod datapoint;
 mod metrics;
-pub use crate::metrics::{flush, query, set_host_id, set_panic_hook, submit};
+mod prometheus;
+pub use crate::metrics::{
+    flush, query, set_host_id, set_panic_hook, set_prometheus_address, submit,
+};
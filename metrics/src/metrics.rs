@@ -1,6 +1,7 @@
-//! The `metrics` module enables sending measurements to an `InfluxDB` instance
+//! The `metrics` module enables sending measurements to an `InfluxDB` instance or exposing them
+//! to a Prometheus server
 
-use crate::{counter::CounterPoint, datapoint::DataPoint};
+use crate::{counter::CounterPoint, datapoint::DataPoint, prometheus::PrometheusMetricsWriter};
 use gethostname::gethostname;
 use lazy_static::lazy_static;
 use log::*;
@@ -8,6 +9,7 @@ use solana_sdk::hash::hash;
 use std::{
     collections::HashMap,
     convert::Into,
+    net::SocketAddr,
     sync::{
         mpsc::{channel, Receiver, RecvTimeoutError, Sender},
         Arc, Barrier, Mutex, Once, RwLock,
@@ -39,10 +41,10 @@ struct MetricsAgent {
     sender: Sender<MetricsCommand>,
 }
 
-trait MetricsWriter {
-    // Write the points and empty the vector.  Called on the internal
+pub(crate) trait MetricsWriter {
+    // Write the points and counters and empty the vectors.  Called on the internal
     // MetricsAgent worker thread.
-    fn write(&self, points: Vec<DataPoint>);
+    fn write(&self, points: Vec<DataPoint>, counters: Vec<CounterPoint>);
 }
 
 struct InfluxDbMetricsWriter {
@@ -77,14 +79,17 @@ impl InfluxDbMetricsWriter {
 }
 
 impl MetricsWriter for InfluxDbMetricsWriter {
-    fn write(&self, points: Vec<DataPoint>) {
+    fn write(&self, points: Vec<DataPoint>, counters: Vec<CounterPoint>) {
         if let Some(ref write_url) = self.write_url {
-            info!("submitting {} points", points.len());
+            info!("submitting {} points", points.len() + counters.len());
 
             let host_id = HOST_ID.read().unwrap();
 
             let mut line = String::new();
-            for point in points {
+            for point in points
+                .into_iter()
+                .chain(counters.into_iter().map(DataPoint::from))
+            {
                 line.push_str(&format!("{},host_id={}", &point.name, &host_id));
 
                 let mut first = true;
@@ -130,11 +135,21 @@ impl Default for MetricsAgent {
             })
             .unwrap_or(4000);
 
-        Self::new(
-            Arc::new(InfluxDbMetricsWriter::new()),
-            Duration::from_secs(10),
-            max_points_per_sec,
-        )
+        let writer: Arc<dyn MetricsWriter + Send + Sync> = match get_prometheus_address() {
+            Some(address) => match PrometheusMetricsWriter::new(address) {
+                Ok(writer) => {
+                    info!("serving prometheus metrics on {}", writer.local_addr());
+                    Arc::new(writer)
+                }
+                Err(err) => {
+                    warn!("failed to serve prometheus metrics on {}: {}", address, err);
+                    Arc::new(InfluxDbMetricsWriter::new())
+                }
+            },
+            None => Arc::new(InfluxDbMetricsWriter::new()),
+        };
+
+        Self::new(writer, Duration::from_secs(10), max_points_per_sec)
     }
 }
 
@@ -152,38 +167,38 @@ impl MetricsAgent {
 
     fn collect_points(
         points_map: &mut HashMap<log::Level, (CounterMap, Vec<DataPoint>)>,
-    ) -> Vec<DataPoint> {
-        let points: Vec<DataPoint> = [
+    ) -> (Vec<DataPoint>, Vec<CounterPoint>) {
+        let mut all_points = vec![];
+        let mut all_counters = vec![];
+        for level in &[
             Level::Error,
             Level::Warn,
             Level::Info,
             Level::Debug,
             Level::Trace,
-        ]
-        .iter()
-        .filter_map(|level| points_map.remove(level))
-        .flat_map(|(counters, points)| {
-            let counter_points = counters.into_iter().map(|(_, v)| v.into());
-            points.into_iter().chain(counter_points)
-        })
-        .collect();
+        ] {
+            if let Some((counters, points)) = points_map.remove(level) {
+                all_points.extend(points);
+                all_counters.extend(counters.into_iter().map(|(_, v)| v));
+            }
+        }
         points_map.clear();
-        points
+        (all_points, all_counters)
     }
 
     fn write(
         writer: &Arc<dyn MetricsWriter + Send + Sync>,
-        mut points: Vec<DataPoint>,
+        (mut points, mut counters): (Vec<DataPoint>, Vec<CounterPoint>),
         max_points: usize,
         max_points_per_sec: usize,
         last_write_time: Instant,
     ) {
-        if points.is_empty() {
+        if points.is_empty() && counters.is_empty() {
             return;
         }
 
         let now = Instant::now();
-        let num_points = points.len();
+        let num_points = points.len() + counters.len();
         debug!("run: attempting to write {} points", num_points);
         if num_points > max_points {
             warn!(
@@ -194,6 +209,7 @@ impl MetricsAgent {
         }
         let points_written = cmp::min(num_points, max_points - 1);
         points.truncate(points_written);
+        counters.truncate(points_written - points.len());
         points.push(
             DataPoint::new("metrics")
                 .add_field_i64("points_written", points_written as i64)
@@ -206,7 +222,7 @@ impl MetricsAgent {
                 .to_owned(),
         );
 
-        writer.write(points);
+        writer.write(points, counters);
     }
     fn run(
         receiver: &Receiver<MetricsCommand>,
@@ -336,6 +352,32 @@ pub fn set_host_id(host_id: String) {
     *HOST_ID.write().unwrap() = host_id;
 }
 
+pub(crate) fn get_host_id() -> String {
+    HOST_ID.read().unwrap().clone()
+}
+
+lazy_static! {
+    static ref PROMETHEUS_ADDRESS: RwLock<Option<SocketAddr>> = RwLock::new(None);
+}
+
+/// Serve metrics over HTTP in the Prometheus text format instead of submitting them to InfluxDB.
+/// Must be called before the first point is submitted, the `SOLANA_METRICS_PROMETHEUS_ADDRESS`
+/// environment variable is used otherwise.
+pub fn set_prometheus_address(address: SocketAddr) {
+    *PROMETHEUS_ADDRESS.write().unwrap() = Some(address);
+}
+
+fn get_prometheus_address() -> Option<SocketAddr> {
+    if let Some(address) = *PROMETHEUS_ADDRESS.read().unwrap() {
+        return Some(address);
+    }
+    let address = env::var("SOLANA_METRICS_PROMETHEUS_ADDRESS").ok()?;
+    address
+        .parse()
+        .map_err(|err| warn!("SOLANA_METRICS_PROMETHEUS_ADDRESS is invalid: {}", err))
+        .ok()
+}
+
 /// Submits a new point from any thread.  Note that points are internally queued
 /// and transmitted periodically in batches.
 pub fn submit(point: DataPoint, level: log::Level) {
@@ -471,14 +513,16 @@ mod test {
     }
 
     impl MetricsWriter for MockMetricsWriter {
-        fn write(&self, points: Vec<DataPoint>) {
+        fn write(&self, points: Vec<DataPoint>, counters: Vec<CounterPoint>) {
             assert!(!points.is_empty());
 
-            let new_points = points.len();
-            self.points_written
-                .lock()
-                .unwrap()
-                .extend(points.into_iter());
+            let new_points = points.len() + counters.len();
+            self.points_written.lock().unwrap().extend(
+                counters
+                    .into_iter()
+                    .map(DataPoint::from)
+                    .chain(points.into_iter()),
+            );
 
             info!(
                 "Writing {} points ({} total)",
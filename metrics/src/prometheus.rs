@@ -0,0 +1,222 @@
+//! The `prometheus` module aggregates the submitted points in process and serves them over HTTP
+//! in the Prometheus text exposition format
+
+use crate::{
+    counter::CounterPoint,
+    datapoint::DataPoint,
+    metrics::{get_host_id, MetricsWriter},
+};
+use log::*;
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    io::{self, BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{Arc, RwLock},
+    thread::Builder,
+    time::Duration,
+};
+
+const METRIC_PREFIX: &str = "solana_";
+
+#[derive(Debug, Default)]
+struct PrometheusMetrics {
+    /// `inc_new_counter_*` counters, accumulated since the process start
+    counters: BTreeMap<String, i64>,
+    /// numeric fields of `datapoint_*` points, the last submitted value wins
+    gauges: BTreeMap<String, f64>,
+}
+
+pub(crate) struct PrometheusMetricsWriter {
+    metrics: Arc<RwLock<PrometheusMetrics>>,
+    local_addr: SocketAddr,
+}
+
+impl PrometheusMetricsWriter {
+    pub(crate) fn new(address: SocketAddr) -> io::Result<Self> {
+        let listener = TcpListener::bind(address)?;
+        let local_addr = listener.local_addr()?;
+        let metrics = Arc::new(RwLock::new(PrometheusMetrics::default()));
+        let metrics_ = metrics.clone();
+        Builder::new()
+            .name("solana-metrics-prometheus".to_string())
+            .spawn(move || {
+                for stream in listener.incoming() {
+                    match stream {
+                        Ok(stream) => {
+                            if let Err(err) = Self::serve(stream, &metrics_) {
+                                debug!("prometheus scrape failed: {}", err);
+                            }
+                        }
+                        Err(err) => warn!("prometheus connection failed: {}", err),
+                    }
+                }
+            })?;
+        Ok(Self {
+            metrics,
+            local_addr,
+        })
+    }
+
+    pub(crate) fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    fn serve(mut stream: TcpStream, metrics: &RwLock<PrometheusMetrics>) -> io::Result<()> {
+        stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+        // Every request is answered with the metrics, only the request head has to be consumed
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut line = String::new();
+        while reader.read_line(&mut line)? > 0 && line != "\r\n" && line != "\n" {
+            line.clear();
+        }
+
+        let body = Self::render(&metrics.read().unwrap(), &get_host_id());
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )?;
+        stream.flush()
+    }
+
+    fn render(metrics: &PrometheusMetrics, host_id: &str) -> String {
+        let mut body = String::new();
+        for (name, value) in &metrics.counters {
+            let _ = writeln!(body, "# TYPE {} counter", name);
+            let _ = writeln!(body, "{}{{host_id=\"{}\"}} {}", name, host_id, value);
+        }
+        for (name, value) in &metrics.gauges {
+            let _ = writeln!(body, "# TYPE {} gauge", name);
+            let _ = writeln!(body, "{}{{host_id=\"{}\"}} {}", name, host_id, value);
+        }
+        body
+    }
+}
+
+impl MetricsWriter for PrometheusMetricsWriter {
+    fn write(&self, points: Vec<DataPoint>, counters: Vec<CounterPoint>) {
+        let mut metrics = self.metrics.write().unwrap();
+        for counter in counters {
+            *metrics
+                .counters
+                .entry(metric_name(&[counter.name]))
+                .or_default() += counter.count;
+        }
+        for point in points {
+            for (field, value) in point.fields {
+                if let Some(value) = parse_field_value(&value) {
+                    metrics
+                        .gauges
+                        .insert(metric_name(&[point.name, field]), value);
+                }
+            }
+        }
+    }
+}
+
+/// Joins the datapoint and field names with `_`, replacing the characters Prometheus does not
+/// accept in metric names, e.g. `replay-slot-stats` / `total_entries` becomes
+/// `solana_replay_slot_stats_total_entries`
+fn metric_name(parts: &[&str]) -> String {
+    let mut name = METRIC_PREFIX.to_string();
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            name.push('_');
+        }
+        name.extend(part.chars().map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        }));
+    }
+    name
+}
+
+/// Parses a field value formatted by `DataPoint`, string fields have no numeric value
+fn parse_field_value(value: &str) -> Option<f64> {
+    match value {
+        "true" => Some(1.0),
+        "false" => Some(0.0),
+        _ if value.starts_with('"') => None,
+        _ if value.ends_with('i') => value[..value.len() - 1]
+            .parse::<i64>()
+            .ok()
+            .map(|v| v as f64),
+        _ => value.parse().ok(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn test_metric_name() {
+        assert_eq!(
+            metric_name(&["replay-slot-stats", "total_entries"]),
+            "solana_replay_slot_stats_total_entries"
+        );
+        assert_eq!(
+            metric_name(&["bank-process_transactions-txs"]),
+            "solana_bank_process_transactions_txs"
+        );
+    }
+
+    #[test]
+    fn test_parse_field_value() {
+        assert_eq!(parse_field_value("42i"), Some(42.0));
+        assert_eq!(parse_field_value("-1i"), Some(-1.0));
+        assert_eq!(parse_field_value("1.5"), Some(1.5));
+        assert_eq!(parse_field_value("true"), Some(1.0));
+        assert_eq!(parse_field_value("\"text\""), None);
+    }
+
+    #[test]
+    fn test_prometheus_writer() {
+        let writer = PrometheusMetricsWriter::new("127.0.0.1:0".parse().unwrap()).unwrap();
+        for _ in 0..2 {
+            writer.write(
+                vec![DataPoint::new("replay-slot-stats")
+                    .add_field_i64("slot", 7)
+                    .add_field_str("leader", "abc")
+                    .add_field_bool("is_leader", true)
+                    .to_owned()],
+                vec![CounterPoint {
+                    name: "bank-process_transactions-txs",
+                    count: 5,
+                    timestamp: 0,
+                }],
+            );
+        }
+
+        let mut stream = TcpStream::connect(writer.local_addr()).unwrap();
+        stream
+            .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        let host_id = get_host_id();
+        assert!(response.contains("# TYPE solana_bank_process_transactions_txs counter\n"));
+        assert!(response.contains(&format!(
+            "solana_bank_process_transactions_txs{{host_id=\"{}\"}} 10\n",
+            host_id
+        )));
+        assert!(response.contains("# TYPE solana_replay_slot_stats_slot gauge\n"));
+        assert!(response.contains(&format!(
+            "solana_replay_slot_stats_slot{{host_id=\"{}\"}} 7\n",
+            host_id
+        )));
+        assert!(response.contains(&format!(
+            "solana_replay_slot_stats_is_leader{{host_id=\"{}\"}} 1\n",
+            host_id
+        )));
+        assert!(!response.contains("solana_replay_slot_stats_leader"));
+    }
+}
@@ -17,10 +17,152 @@
 /// ```bash
 /// export TWILIO_CONFIG='ACCOUNT=<account>,TOKEN=<securityToken>,TO=<receivingNumber>,FROM=<sendingNumber>'
 /// ```
+///
+/// Alerts sent with `Notifier::notify` are additionally delivered to a generic JSON webhook
+/// and to a PagerDuty Events API v2 integration, which opens and resolves incidents by alert key:
+/// ```bash
+/// export ALERT_WEBHOOK=...
+/// export PAGERDUTY_ROUTING_KEY=...
+/// ```
+///
+/// A firing alert is repeated at most once per `NOTIFIER_REPEAT_INTERVAL_SECS` (1 hour by default)
+/// unless its severity or summary changes.
 use log::*;
 use reqwest::{blocking::Client, StatusCode};
 use serde_json::json;
-use std::{env, thread::sleep, time::Duration};
+use std::{
+    collections::HashMap,
+    env, fmt,
+    sync::Mutex,
+    thread::sleep,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+const DEFAULT_REPEAT_INTERVAL: Duration = Duration::from_secs(60 * 60);
+const PAGERDUTY_EVENTS_URL: &str = "https://events.pagerduty.com/v2/enqueue";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+    Critical,
+}
+
+impl Severity {
+    /// Severity names as accepted by the PagerDuty Events API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+            Severity::Critical => "critical",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str().to_uppercase())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlertState {
+    Firing,
+    Resolved,
+}
+
+impl AlertState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertState::Firing => "firing",
+            AlertState::Resolved => "resolved",
+        }
+    }
+}
+
+/// A structured notification, alerts with the same `key` refer to the same incident
+#[derive(Clone, Debug, PartialEq)]
+pub struct Alert {
+    pub key: String,
+    pub severity: Severity,
+    pub state: AlertState,
+    pub summary: String,
+}
+
+impl Alert {
+    pub fn firing(key: &str, severity: Severity, summary: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            severity,
+            state: AlertState::Firing,
+            summary: summary.to_string(),
+        }
+    }
+
+    pub fn resolved(key: &str, summary: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            severity: Severity::Info,
+            state: AlertState::Resolved,
+            summary: summary.to_string(),
+        }
+    }
+
+    fn text(&self) -> String {
+        match self.state {
+            AlertState::Firing => format!("[{}] {}", self.severity, self.summary),
+            AlertState::Resolved => format!("[RESOLVED] {}", self.summary),
+        }
+    }
+}
+
+struct FiringAlert {
+    severity: Severity,
+    summary: String,
+    last_sent: Instant,
+}
+
+/// Suppresses duplicates of firing alerts and resolutions of alerts that never fired
+struct AlertDeduplicator {
+    repeat_interval: Duration,
+    firing: HashMap<String, FiringAlert>,
+}
+
+impl AlertDeduplicator {
+    fn new(repeat_interval: Duration) -> Self {
+        Self {
+            repeat_interval,
+            firing: HashMap::new(),
+        }
+    }
+
+    fn should_send(&mut self, alert: &Alert, now: Instant) -> bool {
+        match alert.state {
+            AlertState::Firing => {
+                if let Some(firing) = self.firing.get(&alert.key) {
+                    if firing.severity == alert.severity
+                        && firing.summary == alert.summary
+                        && now.duration_since(firing.last_sent) < self.repeat_interval
+                    {
+                        return false;
+                    }
+                }
+                self.firing.insert(
+                    alert.key.clone(),
+                    FiringAlert {
+                        severity: alert.severity,
+                        summary: alert.summary.clone(),
+                        last_sent: now,
+                    },
+                );
+                true
+            }
+            AlertState::Resolved => self.firing.remove(&alert.key).is_some(),
+        }
+    }
+}
 
 struct TelegramWebHook {
     bot_token: String,
@@ -77,10 +219,15 @@ fn get_twilio_config() -> Result<Option<TwilioWebHook>, String> {
 
 pub struct Notifier {
     client: Client,
+    source: String,
     discord_webhook: Option<String>,
     slack_webhook: Option<String>,
     telegram_webhook: Option<TelegramWebHook>,
     twilio_webhook: Option<TwilioWebHook>,
+    alert_webhook: Option<String>,
+    pagerduty_routing_key: Option<String>,
+    pagerduty_url: String,
+    deduplicator: Mutex<AlertDeduplicator>,
 }
 
 impl Notifier {
@@ -114,16 +261,111 @@ impl Notifier {
         let twilio_webhook = get_twilio_config()
             .map_err(|err| panic!("Twilio config error: {}", err))
             .unwrap();
+        let alert_webhook = env::var(format!("{}ALERT_WEBHOOK", env_prefix))
+            .map_err(|_| {
+                info!("Alert webhook notifications disabled");
+            })
+            .ok();
+        let pagerduty_routing_key = env::var(format!("{}PAGERDUTY_ROUTING_KEY", env_prefix))
+            .map_err(|_| {
+                info!("PagerDuty notifications disabled");
+            })
+            .ok();
+        let pagerduty_url = env::var(format!("{}PAGERDUTY_URL", env_prefix))
+            .unwrap_or_else(|_| PAGERDUTY_EVENTS_URL.to_string());
+        let repeat_interval = env::var(format!("{}NOTIFIER_REPEAT_INTERVAL_SECS", env_prefix))
+            .map(|secs| {
+                Duration::from_secs(
+                    secs.parse()
+                        .expect("Failed to parse NOTIFIER_REPEAT_INTERVAL_SECS"),
+                )
+            })
+            .unwrap_or(DEFAULT_REPEAT_INTERVAL);
 
         Notifier {
             client: Client::new(),
+            source: "solana".to_string(),
             discord_webhook,
             slack_webhook,
             telegram_webhook,
             twilio_webhook,
+            alert_webhook,
+            pagerduty_routing_key,
+            pagerduty_url,
+            deduplicator: Mutex::new(AlertDeduplicator::new(repeat_interval)),
+        }
+    }
+
+    /// Names the program raising the alerts, e.g. `solana-watchtower`
+    pub fn with_source(mut self, source: &str) -> Self {
+        self.source = source.to_string();
+        self
+    }
+
+    /// Sends an alert to every configured sink, unless it duplicates the last
+    /// notification sent for `alert.key`
+    pub fn notify(&self, alert: Alert) {
+        if !self
+            .deduplicator
+            .lock()
+            .unwrap()
+            .should_send(&alert, Instant::now())
+        {
+            debug!("Suppressing duplicate alert {:?}", alert);
+            return;
+        }
+
+        self.send(&format!("{}: {}", self.source, alert.text()));
+
+        if let Some(webhook) = &self.alert_webhook {
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or_default();
+            let data = json!({
+                "key": alert.key,
+                "severity": alert.severity.as_str(),
+                "state": alert.state.as_str(),
+                "summary": alert.summary,
+                "source": self.source,
+                "timestamp": timestamp,
+            });
+            if let Err(err) = self.client.post(webhook).json(&data).send() {
+                warn!("Failed to send alert webhook: {:?}", err);
+            }
+        }
+
+        if let Some(routing_key) = &self.pagerduty_routing_key {
+            let data = match alert.state {
+                AlertState::Firing => json!({
+                    "routing_key": routing_key,
+                    "event_action": "trigger",
+                    "dedup_key": alert.key,
+                    "payload": {
+                        "summary": alert.summary,
+                        "source": self.source,
+                        "severity": alert.severity.as_str(),
+                    },
+                }),
+                AlertState::Resolved => json!({
+                    "routing_key": routing_key,
+                    "event_action": "resolve",
+                    "dedup_key": alert.key,
+                }),
+            };
+            match self.client.post(&self.pagerduty_url).json(&data).send() {
+                Ok(response) if !response.status().is_success() => warn!(
+                    "PagerDuty event rejected: {} {:?}",
+                    response.status(),
+                    response.text()
+                ),
+                Ok(_) => {}
+                Err(err) => warn!("Failed to send PagerDuty event: {:?}", err),
+            }
         }
     }
 
+    /// Sends a plain text message to the Discord, Slack, Telegram and Twilio sinks
     pub fn send(&self, msg: &str) {
         if let Some(webhook) = &self.discord_webhook {
             for line in msg.split('\n') {
@@ -187,3 +429,51 @@ impl Notifier {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alert_deduplicator() {
+        let mut deduplicator = AlertDeduplicator::new(Duration::from_secs(60));
+        let now = Instant::now();
+
+        let alert = Alert::firing(
+            "sanity",
+            Severity::Error,
+            "Transaction count is not advancing",
+        );
+        assert!(deduplicator.should_send(&alert, now));
+        assert!(!deduplicator.should_send(&alert, now + Duration::from_secs(30)));
+        // Repeated once the repeat interval passed
+        assert!(deduplicator.should_send(&alert, now + Duration::from_secs(61)));
+
+        // Severity and summary changes are sent right away
+        let escalated = Alert::firing("sanity", Severity::Critical, &alert.summary);
+        assert!(deduplicator.should_send(&escalated, now + Duration::from_secs(62)));
+        let changed = Alert::firing("sanity", Severity::Critical, "Current stake is 50%");
+        assert!(deduplicator.should_send(&changed, now + Duration::from_secs(63)));
+
+        // Other keys are independent
+        let other = Alert::firing("balance", Severity::Warning, "Low balance");
+        assert!(deduplicator.should_send(&other, now + Duration::from_secs(63)));
+
+        let resolved = Alert::resolved("sanity", "All clear");
+        assert!(deduplicator.should_send(&resolved, now + Duration::from_secs(64)));
+        assert!(!deduplicator.should_send(&resolved, now + Duration::from_secs(65)));
+        assert!(deduplicator.should_send(&alert, now + Duration::from_secs(66)));
+    }
+
+    #[test]
+    fn test_alert_text() {
+        assert_eq!(
+            Alert::firing("sanity", Severity::Warning, "Low balance").text(),
+            "[WARNING] Low balance"
+        );
+        assert_eq!(
+            Alert::resolved("sanity", "All clear after 5m").text(),
+            "[RESOLVED] All clear after 5m"
+        );
+    }
+}
@@ -8,10 +8,21 @@ use {
     solana_banks_client::start_client,
     solana_banks_server::banks_server::start_local_server,
     solana_program::{
-        account_info::AccountInfo, entrypoint::ProgramResult, fee_calculator::FeeCalculator,
-        hash::Hash, instruction::Instruction, instruction::InstructionError, message::Message,
-        native_token::sol_to_lamports, program_error::ProgramError, program_stubs, pubkey::Pubkey,
+        account_info::AccountInfo,
+        clock::Clock,
+        entrypoint::{ProgramResult, SUCCESS},
+        epoch_schedule::EpochSchedule,
+        fee_calculator::FeeCalculator,
+        hash::Hash,
+        instruction::Instruction,
+        instruction::InstructionError,
+        message::Message,
+        native_token::sol_to_lamports,
+        program_error::{ProgramError, UNSUPPORTED_SYSVAR},
+        program_stubs,
+        pubkey::Pubkey,
         rent::Rent,
+        sysvar::{self, fees::Fees, recent_evm_blockhashes::RecentBlockhashes, Sysvar},
     },
     solana_runtime::{
         bank::{Bank, Builtin, ExecuteTimings},
@@ -25,7 +36,7 @@ use {
         genesis_config::GenesisConfig,
         keyed_account::KeyedAccount,
         process_instruction::{
-            stable_log, BpfComputeBudget, InvokeContext, ProcessInstructionWithContext,
+            self, stable_log, BpfComputeBudget, InvokeContext, ProcessInstructionWithContext,
         },
         signature::{Keypair, Signer},
     },
@@ -74,6 +85,7 @@ pub fn to_instruction_error(error: ProgramError) -> InstructionError {
         ProgramError::InvalidSeeds => InstructionError::InvalidSeeds,
         ProgramError::BorshIoError(err) => InstructionError::BorshIoError(err),
         ProgramError::AccountNotRentExempt => InstructionError::AccountNotRentExempt,
+        ProgramError::UnsupportedSysvar => InstructionError::UnsupportedSysvar,
     }
 }
 
@@ -101,6 +113,32 @@ fn get_invoke_context<'a>() -> &'a mut dyn InvokeContext {
     unsafe { transmute::<(usize, usize), &mut dyn InvokeContext>(fat) }
 }
 
+fn get_sysvar<T: Sysvar>(id: &Pubkey, var_addr: *mut u8) -> u64 {
+    let invoke_context = get_invoke_context();
+    let sysvar_cost = invoke_context
+        .get_bpf_compute_budget()
+        .sysvar_base_cost
+        .saturating_add(std::mem::size_of::<T>() as u64);
+    if invoke_context
+        .get_compute_meter()
+        .borrow_mut()
+        .consume(sysvar_cost)
+        .is_err()
+    {
+        panic!("Exceeded compute budget");
+    }
+
+    match process_instruction::get_sysvar::<T>(invoke_context, id) {
+        Ok(var) => {
+            unsafe {
+                *(var_addr as *mut _ as *mut T) = var;
+            }
+            SUCCESS
+        }
+        Err(_) => UNSUPPORTED_SYSVAR,
+    }
+}
+
 pub fn builtin_process_instruction(
     process_instruction: solana_program::entrypoint::ProcessInstruction,
     program_id: &Pubkey,
@@ -336,6 +374,26 @@ impl program_stubs::SyscallStubs for SyscallStubs {
         stable_log::program_success(&logger, &program_id);
         Ok(())
     }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        get_sysvar::<Clock>(&sysvar::clock::id(), var_addr)
+    }
+
+    fn sol_get_epoch_schedule_sysvar(&self, var_addr: *mut u8) -> u64 {
+        get_sysvar::<EpochSchedule>(&sysvar::epoch_schedule::id(), var_addr)
+    }
+
+    fn sol_get_fees_sysvar(&self, var_addr: *mut u8) -> u64 {
+        get_sysvar::<Fees>(&sysvar::fees::id(), var_addr)
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        get_sysvar::<Rent>(&sysvar::rent::id(), var_addr)
+    }
+
+    fn sol_get_recent_evm_blockhashes_sysvar(&self, var_addr: *mut u8) -> u64 {
+        get_sysvar::<RecentBlockhashes>(&sysvar::recent_evm_blockhashes::id(), var_addr)
+    }
 }
 
 pub fn find_file(filename: &str) -> Option<PathBuf> {
@@ -0,0 +1,76 @@
+use {
+    solana_program::{
+        account_info::{next_account_info, AccountInfo},
+        clock::Clock,
+        entrypoint::ProgramResult,
+        instruction::{AccountMeta, Instruction},
+        msg,
+        program_error::ProgramError,
+        pubkey::Pubkey,
+        rent::Rent,
+        sysvar::{self, recent_evm_blockhashes::RecentBlockhashes, Sysvar},
+    },
+    solana_program_test::{processor, ProgramTest},
+    solana_sdk::{signature::Signer, transaction::Transaction},
+};
+
+// Process instruction to compare sysvars read by getters with their accounts
+fn sysvar_getter_process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _input: &[u8],
+) -> ProgramResult {
+    msg!("sysvar_getter");
+    let account_info_iter = &mut accounts.iter();
+
+    let clock = Clock::from_account_info(next_account_info(account_info_iter)?)?;
+    assert_eq!(clock, Clock::get()?);
+
+    let rent = Rent::from_account_info(next_account_info(account_info_iter)?)?;
+    assert_eq!(rent, Rent::get()?);
+
+    let blockhashes = RecentBlockhashes::from_account_info(next_account_info(account_info_iter)?)?;
+    assert_eq!(blockhashes, *RecentBlockhashes::get_boxed()?);
+
+    // RecentBlockhashes is read only into the heap, `Sysvar::get()` is unsupported for it
+    assert_eq!(
+        <RecentBlockhashes as Sysvar>::get(),
+        Err(ProgramError::UnsupportedSysvar)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+#[cfg_attr(not(feature = "multiple-db-in-thread"), ignore)]
+async fn get_sysvar() {
+    let program_id = Pubkey::new_unique();
+    let program_test = ProgramTest::new(
+        "program-test-sysvar-getter",
+        program_id,
+        processor!(sysvar_getter_process_instruction),
+    );
+
+    let mut context = program_test.start_with_context().await;
+    let instructions = vec![Instruction::new(
+        program_id,
+        &[0],
+        vec![
+            AccountMeta::new_readonly(sysvar::clock::id(), false),
+            AccountMeta::new_readonly(sysvar::rent::id(), false),
+            AccountMeta::new_readonly(sysvar::recent_evm_blockhashes::id(), false),
+        ],
+    )];
+
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+}
@@ -264,14 +264,15 @@ fn process_transaction_and_record_inner(
     let signature = tx.signatures.get(0).unwrap().clone();
     let txs = vec![tx];
     let tx_batch = bank.prepare_batch(&txs, None);
-    let (mut results, _, mut inner, _transaction_logs) = bank.load_execute_and_commit_transactions(
-        &tx_batch,
-        MAX_PROCESSING_AGE,
-        false,
-        true,
-        false,
-        &mut ExecuteTimings::default(),
-    );
+    let (mut results, _, mut inner, _transaction_logs, _return_data) = bank
+        .load_execute_and_commit_transactions(
+            &tx_batch,
+            MAX_PROCESSING_AGE,
+            false,
+            true,
+            false,
+            &mut ExecuteTimings::default(),
+        );
     let inner_instructions = if inner.is_empty() {
         Some(vec![vec![]])
     } else {
@@ -303,6 +304,7 @@ fn execute_transactions(bank: &Bank, txs: &[Transaction]) -> Vec<ConfirmedTransa
         },
         mut inner_instructions,
         mut transaction_logs,
+        mut transaction_return_data,
     ) = bank.load_execute_and_commit_transactions(
         &batch,
         std::usize::MAX,
@@ -319,6 +321,9 @@ fn execute_transactions(bank: &Bank, txs: &[Transaction]) -> Vec<ConfirmedTransa
     for _ in 0..(txs.len() - inner_instructions.len()) {
         inner_instructions.push(None);
     }
+    for _ in 0..(txs.len() - transaction_return_data.len()) {
+        transaction_return_data.push(None);
+    }
 
     izip!(
         txs.iter(),
@@ -329,6 +334,7 @@ fn execute_transactions(bank: &Bank, txs: &[Transaction]) -> Vec<ConfirmedTransa
         tx_pre_token_balances.into_iter(),
         tx_post_token_balances.into_iter(),
         transaction_logs.into_iter(),
+        transaction_return_data.into_iter(),
     )
     .map(
         |(
@@ -340,6 +346,7 @@ fn execute_transactions(bank: &Bank, txs: &[Transaction]) -> Vec<ConfirmedTransa
             pre_token_balances,
             post_token_balances,
             log_messages,
+            return_data,
         )| {
             let fee_calculator = nonce_rollback
                 .map(|nonce_rollback| nonce_rollback.fee_calculator())
@@ -368,6 +375,7 @@ fn execute_transactions(bank: &Bank, txs: &[Transaction]) -> Vec<ConfirmedTransa
                 post_token_balances: Some(post_token_balances),
                 inner_instructions,
                 log_messages: Some(log_messages),
+                return_data,
             };
 
             ConfirmedTransaction {
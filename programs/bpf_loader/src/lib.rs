@@ -150,7 +150,11 @@ pub fn create_vm<'a>(
     parameter_accounts: &'a [KeyedAccount<'a>],
     invoke_context: &'a mut dyn InvokeContext,
 ) -> Result<EbpfVm<'a, BpfError, ThisInstructionMeter>, EbpfError<BpfError>> {
-    let heap = vec![0_u8; DEFAULT_HEAP_SIZE];
+    let heap_size = invoke_context
+        .get_bpf_compute_budget()
+        .heap_size
+        .unwrap_or(DEFAULT_HEAP_SIZE);
+    let heap = vec![0_u8; heap_size];
     let heap_region = MemoryRegion::new_from_slice(&heap, MM_HEAP_START, 0, true);
     let mut vm = EbpfVm::new(program, parameter_bytes, &[heap_region])?;
     syscalls::bind_syscall_context_objects(
@@ -1105,6 +1109,7 @@ mod tests {
             vec![],
             &[],
             &[],
+            &[],
             None,
             BpfComputeBudget {
                 max_units: 1,
@@ -1115,14 +1120,23 @@ mod tests {
                 max_invoke_depth: 2,
                 sha256_base_cost: 85,
                 sha256_byte_cost: 1,
+                keccak256_base_cost: 85,
+                keccak256_byte_cost: 1,
+                secp256k1_recover_cost: 25_000,
+                evm_state_read_units: 1_000,
+                return_data_units: 100,
+                return_data_bytes_per_unit: 250,
+                sysvar_base_cost: 100,
                 max_call_depth: 20,
                 stack_frame_size: 4096,
                 log_pubkey_units: 100,
                 max_cpi_instruction_size: usize::MAX,
+                heap_size: None,
             },
             Rc::new(RefCell::new(Executors::default())),
             None,
             Arc::new(FeatureSet::default()),
+            None,
         );
         assert_eq!(
             Err(InstructionError::ProgramFailedToComplete),
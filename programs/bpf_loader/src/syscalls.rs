@@ -1,6 +1,7 @@
 use crate::{alloc, BpfError};
 use alloc::Alloc;
 use curve25519_dalek::{ristretto::RistrettoPoint, scalar::Scalar};
+use evm_state::{H160, H256};
 use solana_rbpf::{
     ebpf::MM_HEAP_START,
     error::EbpfError,
@@ -16,20 +17,33 @@ use solana_sdk::{
     bpf_loader, bpf_loader_deprecated,
     bpf_loader_upgradeable::{self, UpgradeableLoaderState},
     entrypoint::{MAX_PERMITTED_DATA_INCREASE, SUCCESS},
+    evm_state::{EvmAccount, EVM_ADDRESS_BYTES, EVM_STATE_NOT_AVAILABLE, EVM_WORD_BYTES},
     feature_set::{
-        abort_on_all_cpi_failures, limit_cpi_loader_invoke, per_byte_logging_cost,
+        abort_on_all_cpi_failures, cpi_return_data_syscalls, evm_state_read_syscalls,
+        keccak256_secp256k1_recover_syscalls, limit_cpi_loader_invoke, per_byte_logging_cost,
         pubkey_log_syscall_enabled, ristretto_mul_syscall_enabled, sha256_syscall_enabled,
-        sol_log_compute_units_syscall, try_find_program_address_syscall_enabled,
-        use_loaded_executables, use_loaded_program_accounts,
+        sol_log_compute_units_syscall, sysvar_getter_syscalls,
+        try_find_program_address_syscall_enabled, use_loaded_executables,
+        use_loaded_program_accounts,
     },
     hash::{Hasher, HASH_BYTES},
     ic_msg,
     instruction::{AccountMeta, Instruction, InstructionError},
+    keccak,
     keyed_account::KeyedAccount,
     native_loader,
-    process_instruction::{stable_log, ComputeMeter, InvokeContext, Logger},
-    program_error::ProgramError,
+    process_instruction::{self, stable_log, ComputeMeter, InvokeContext, Logger},
+    program::MAX_RETURN_DATA,
+    program_error::{ProgramError, UNSUPPORTED_SYSVAR},
     pubkey::{Pubkey, PubkeyError, MAX_SEEDS},
+    secp256k1_recover::{
+        Secp256k1RecoverError, SECP256K1_HASH_LENGTH, SECP256K1_PUBLIC_KEY_LENGTH,
+        SECP256K1_SIGNATURE_LENGTH,
+    },
+    sysvar::{
+        self, clock::Clock, epoch_schedule::EpochSchedule, fees::Fees,
+        recent_evm_blockhashes::RecentBlockhashes, rent::Rent, Sysvar,
+    },
 };
 use std::{
     alloc::Layout,
@@ -72,6 +86,8 @@ pub enum SyscallError {
     InstructionTooLarge(usize, usize),
     #[error("Too many accounts passed to inner instruction")]
     TooManyAccounts,
+    #[error("Return data too large ({0} > {1})")]
+    ReturnDataTooLarge(u64, u64),
 }
 impl From<SyscallError> for EbpfError<BpfError> {
     fn from(error: SyscallError) -> Self {
@@ -128,6 +144,12 @@ pub fn register_syscalls(
             .register_syscall_by_name(b"sol_ristretto_mul", SyscallRistrettoMul::call)?;
     }
 
+    if invoke_context.is_feature_active(&keccak256_secp256k1_recover_syscalls::id()) {
+        syscall_registry.register_syscall_by_name(b"sol_keccak256", SyscallKeccak256::call)?;
+        syscall_registry
+            .register_syscall_by_name(b"sol_secp256k1_recover", SyscallSecp256k1Recover::call)?;
+    }
+
     syscall_registry.register_syscall_by_name(
         b"sol_create_program_address",
         SyscallCreateProgramAddress::call,
@@ -138,6 +160,34 @@ pub fn register_syscalls(
             SyscallTryFindProgramAddress::call,
         )?;
     }
+    if invoke_context.is_feature_active(&evm_state_read_syscalls::id()) {
+        syscall_registry
+            .register_syscall_by_name(b"sol_evm_get_account", SyscallEvmGetAccount::call)?;
+        syscall_registry
+            .register_syscall_by_name(b"sol_evm_get_storage", SyscallEvmGetStorage::call)?;
+    }
+    if invoke_context.is_feature_active(&cpi_return_data_syscalls::id()) {
+        syscall_registry
+            .register_syscall_by_name(b"sol_set_return_data", SyscallSetReturnData::call)?;
+        syscall_registry
+            .register_syscall_by_name(b"sol_get_return_data", SyscallGetReturnData::call)?;
+    }
+    if invoke_context.is_feature_active(&sysvar_getter_syscalls::id()) {
+        syscall_registry
+            .register_syscall_by_name(b"sol_get_clock_sysvar", SyscallGetClockSysvar::call)?;
+        syscall_registry.register_syscall_by_name(
+            b"sol_get_epoch_schedule_sysvar",
+            SyscallGetEpochScheduleSysvar::call,
+        )?;
+        syscall_registry
+            .register_syscall_by_name(b"sol_get_fees_sysvar", SyscallGetFeesSysvar::call)?;
+        syscall_registry
+            .register_syscall_by_name(b"sol_get_rent_sysvar", SyscallGetRentSysvar::call)?;
+        syscall_registry.register_syscall_by_name(
+            b"sol_get_recent_evm_blockhashes_sysvar",
+            SyscallGetRecentEvmBlockhashesSysvar::call,
+        )?;
+    }
     syscall_registry
         .register_syscall_by_name(b"sol_invoke_signed_c", SyscallInvokeSignedC::call)?;
     syscall_registry
@@ -248,6 +298,29 @@ pub fn bind_syscall_context_objects<'a>(
         }),
     );
 
+    bind_feature_gated_syscall_context_object!(
+        vm,
+        invoke_context,
+        &keccak256_secp256k1_recover_syscalls::id(),
+        Box::new(SyscallKeccak256 {
+            base_cost: bpf_compute_budget.keccak256_base_cost,
+            byte_cost: bpf_compute_budget.keccak256_byte_cost,
+            compute_meter: invoke_context.get_compute_meter(),
+            loader_id,
+        }),
+    );
+
+    bind_feature_gated_syscall_context_object!(
+        vm,
+        invoke_context,
+        &keccak256_secp256k1_recover_syscalls::id(),
+        Box::new(SyscallSecp256k1Recover {
+            cost: bpf_compute_budget.secp256k1_recover_cost,
+            compute_meter: invoke_context.get_compute_meter(),
+            loader_id,
+        }),
+    );
+
     vm.bind_syscall_context_object(
         Box::new(SyscallCreateProgramAddress {
             cost: bpf_compute_budget.create_program_address_units,
@@ -288,6 +361,108 @@ pub fn bind_syscall_context_objects<'a>(
         None,
     )?;
 
+    // Evm state syscalls
+
+    bind_feature_gated_syscall_context_object!(
+        vm,
+        invoke_context.borrow(),
+        &evm_state_read_syscalls::id(),
+        Box::new(SyscallEvmGetAccount {
+            cost: invoke_context
+                .borrow()
+                .get_bpf_compute_budget()
+                .evm_state_read_units,
+            compute_meter: invoke_context.borrow().get_compute_meter(),
+            invoke_context: invoke_context.clone(),
+            loader_id,
+        }),
+    );
+    bind_feature_gated_syscall_context_object!(
+        vm,
+        invoke_context.borrow(),
+        &evm_state_read_syscalls::id(),
+        Box::new(SyscallEvmGetStorage {
+            cost: invoke_context
+                .borrow()
+                .get_bpf_compute_budget()
+                .evm_state_read_units,
+            compute_meter: invoke_context.borrow().get_compute_meter(),
+            invoke_context: invoke_context.clone(),
+            loader_id,
+        }),
+    );
+
+    // Return data syscalls
+
+    bind_feature_gated_syscall_context_object!(
+        vm,
+        invoke_context.borrow(),
+        &cpi_return_data_syscalls::id(),
+        Box::new(SyscallSetReturnData {
+            compute_meter: invoke_context.borrow().get_compute_meter(),
+            invoke_context: invoke_context.clone(),
+            loader_id,
+        }),
+    );
+    bind_feature_gated_syscall_context_object!(
+        vm,
+        invoke_context.borrow(),
+        &cpi_return_data_syscalls::id(),
+        Box::new(SyscallGetReturnData {
+            compute_meter: invoke_context.borrow().get_compute_meter(),
+            invoke_context: invoke_context.clone(),
+            loader_id,
+        }),
+    );
+
+    // Sysvar getters
+
+    bind_feature_gated_syscall_context_object!(
+        vm,
+        invoke_context.borrow(),
+        &sysvar_getter_syscalls::id(),
+        Box::new(SyscallGetClockSysvar {
+            invoke_context: invoke_context.clone(),
+            loader_id,
+        }),
+    );
+    bind_feature_gated_syscall_context_object!(
+        vm,
+        invoke_context.borrow(),
+        &sysvar_getter_syscalls::id(),
+        Box::new(SyscallGetEpochScheduleSysvar {
+            invoke_context: invoke_context.clone(),
+            loader_id,
+        }),
+    );
+    bind_feature_gated_syscall_context_object!(
+        vm,
+        invoke_context.borrow(),
+        &sysvar_getter_syscalls::id(),
+        Box::new(SyscallGetFeesSysvar {
+            invoke_context: invoke_context.clone(),
+            loader_id,
+        }),
+    );
+    bind_feature_gated_syscall_context_object!(
+        vm,
+        invoke_context.borrow(),
+        &sysvar_getter_syscalls::id(),
+        Box::new(SyscallGetRentSysvar {
+            invoke_context: invoke_context.clone(),
+            loader_id,
+        }),
+    );
+    bind_feature_gated_syscall_context_object!(
+        vm,
+        invoke_context.borrow(),
+        &sysvar_getter_syscalls::id(),
+        Box::new(SyscallGetRecentEvmBlockhashesSysvar {
+            invoke_context: invoke_context.clone(),
+            loader_id,
+        }),
+    );
+
     // Memory allocator
 
     vm.bind_syscall_context_object(
@@ -827,111 +1002,572 @@ impl<'a> SyscallObject<BpfError> for SyscallSha256<'a> {
     }
 }
 
-/// Ristretto point multiply
-pub struct SyscallRistrettoMul<'a> {
-    cost: u64,
+/// Keccak256
+pub struct SyscallKeccak256<'a> {
+    base_cost: u64,
+    byte_cost: u64,
     compute_meter: Rc<RefCell<dyn ComputeMeter>>,
     loader_id: &'a Pubkey,
 }
-impl<'a> SyscallObject<BpfError> for SyscallRistrettoMul<'a> {
+impl<'a> SyscallObject<BpfError> for SyscallKeccak256<'a> {
     fn call(
         &mut self,
-        point_addr: u64,
-        scalar_addr: u64,
+        vals_addr: u64,
+        vals_len: u64,
         result_addr: u64,
         _arg4: u64,
         _arg5: u64,
         memory_mapping: &MemoryMapping,
         result: &mut Result<u64, EbpfError<BpfError>>,
+    ) {
+        question_mark!(self.compute_meter.consume(self.base_cost), result);
+        let hash_result = question_mark!(
+            translate_slice_mut::<u8>(
+                memory_mapping,
+                result_addr,
+                keccak::HASH_BYTES as u64,
+                self.loader_id
+            ),
+            result
+        );
+        let mut hasher = keccak::Hasher::default();
+        if vals_len > 0 {
+            let vals = question_mark!(
+                translate_slice::<&[u8]>(memory_mapping, vals_addr, vals_len, self.loader_id),
+                result
+            );
+            for val in vals.iter() {
+                let bytes = question_mark!(
+                    translate_slice::<u8>(
+                        memory_mapping,
+                        val.as_ptr() as u64,
+                        val.len() as u64,
+                        self.loader_id
+                    ),
+                    result
+                );
+                question_mark!(
+                    self.compute_meter
+                        .consume(self.byte_cost * (val.len() as u64 / 2)),
+                    result
+                );
+                hasher.hash(bytes);
+            }
+        }
+        hash_result.copy_from_slice(&hasher.result().to_bytes());
+        *result = Ok(0);
+    }
+}
+
+/// secp256k1 public key recovery, returns a `Secp256k1RecoverError` code
+/// instead of failing the program on malformed input
+pub struct SyscallSecp256k1Recover<'a> {
+    cost: u64,
+    compute_meter: Rc<RefCell<dyn ComputeMeter>>,
+    loader_id: &'a Pubkey,
+}
+impl<'a> SyscallObject<BpfError> for SyscallSecp256k1Recover<'a> {
+    fn call(
+        &mut self,
+        hash_addr: u64,
+        recovery_id_val: u64,
+        signature_addr: u64,
+        result_addr: u64,
+        _arg5: u64,
+        memory_mapping: &MemoryMapping,
+        result: &mut Result<u64, EbpfError<BpfError>>,
     ) {
         question_mark!(self.compute_meter.consume(self.cost), result);
 
-        let point = question_mark!(
-            translate_type::<RistrettoPoint>(memory_mapping, point_addr, self.loader_id),
+        let hash = question_mark!(
+            translate_slice::<u8>(
+                memory_mapping,
+                hash_addr,
+                SECP256K1_HASH_LENGTH as u64,
+                self.loader_id
+            ),
             result
         );
-        let scalar = question_mark!(
-            translate_type::<Scalar>(memory_mapping, scalar_addr, self.loader_id),
+        let signature = question_mark!(
+            translate_slice::<u8>(
+                memory_mapping,
+                signature_addr,
+                SECP256K1_SIGNATURE_LENGTH as u64,
+                self.loader_id
+            ),
             result
         );
-        let output = question_mark!(
-            translate_type_mut::<RistrettoPoint>(memory_mapping, result_addr, self.loader_id),
+        let secp256k1_recover_result = question_mark!(
+            translate_slice_mut::<u8>(
+                memory_mapping,
+                result_addr,
+                SECP256K1_PUBLIC_KEY_LENGTH as u64,
+                self.loader_id
+            ),
             result
         );
-        *output = point * scalar;
-
-        *result = Ok(0);
-    }
-}
-
-// Cross-program invocation syscalls
 
-struct AccountReferences<'a> {
-    lamports: &'a mut u64,
-    owner: &'a mut Pubkey,
-    data: &'a mut [u8],
-    vm_data_addr: u64,
-    ref_to_len_in_vm: &'a mut u64,
-    serialized_len_ptr: &'a mut u64,
-}
-type TranslatedAccount<'a> = (Rc<RefCell<Account>>, Option<AccountReferences<'a>>);
-type TranslatedAccounts<'a> = (
-    Vec<Rc<RefCell<Account>>>,
-    Vec<Option<AccountReferences<'a>>>,
-);
+        let message = match secp256k1::Message::parse_slice(hash) {
+            Ok(message) => message,
+            Err(_) => {
+                *result = Ok(Secp256k1RecoverError::InvalidHash.into());
+                return;
+            }
+        };
+        let recovery_id = match u8::try_from(recovery_id_val)
+            .ok()
+            .and_then(|recovery_id| secp256k1::RecoveryId::parse(recovery_id).ok())
+        {
+            Some(recovery_id) => recovery_id,
+            None => {
+                *result = Ok(Secp256k1RecoverError::InvalidRecoveryId.into());
+                return;
+            }
+        };
+        let public_key = match secp256k1::Signature::parse_slice(signature)
+            .and_then(|signature| secp256k1::recover(&message, &signature, &recovery_id))
+        {
+            Ok(public_key) => public_key,
+            Err(_) => {
+                *result = Ok(Secp256k1RecoverError::InvalidSignature.into());
+                return;
+            }
+        };
 
-/// Implemented by language specific data structure translators
-trait SyscallInvokeSigned<'a> {
-    fn get_context_mut(&self) -> Result<RefMut<&'a mut dyn InvokeContext>, EbpfError<BpfError>>;
-    fn get_context(&self) -> Result<Ref<&'a mut dyn InvokeContext>, EbpfError<BpfError>>;
-    fn get_callers_keyed_accounts(&self) -> &'a [KeyedAccount<'a>];
-    fn translate_instruction(
-        &self,
-        addr: u64,
-        memory_mapping: &MemoryMapping,
-    ) -> Result<Instruction, EbpfError<BpfError>>;
-    fn translate_accounts(
-        &self,
-        account_keys: &[Pubkey],
-        program_account_index: usize,
-        account_infos_addr: u64,
-        account_infos_len: u64,
-        memory_mapping: &MemoryMapping,
-    ) -> Result<TranslatedAccounts<'a>, EbpfError<BpfError>>;
-    fn translate_signers(
-        &self,
-        program_id: &Pubkey,
-        signers_seeds_addr: u64,
-        signers_seeds_len: u64,
-        memory_mapping: &MemoryMapping,
-    ) -> Result<Vec<Pubkey>, EbpfError<BpfError>>;
+        secp256k1_recover_result.copy_from_slice(&public_key.serialize()[1..]);
+        *result = Ok(SUCCESS);
+    }
 }
 
-/// Cross-program invocation called from Rust
-pub struct SyscallInvokeSignedRust<'a> {
-    callers_keyed_accounts: &'a [KeyedAccount<'a>],
+/// Read balance, nonce and code hash of an evm account from the incoming evm state
+pub struct SyscallEvmGetAccount<'a> {
+    cost: u64,
+    compute_meter: Rc<RefCell<dyn ComputeMeter>>,
     invoke_context: Rc<RefCell<&'a mut dyn InvokeContext>>,
     loader_id: &'a Pubkey,
 }
-impl<'a> SyscallInvokeSigned<'a> for SyscallInvokeSignedRust<'a> {
-    fn get_context_mut(&self) -> Result<RefMut<&'a mut dyn InvokeContext>, EbpfError<BpfError>> {
-        self.invoke_context
-            .try_borrow_mut()
-            .map_err(|_| SyscallError::InvokeContextBorrowFailed.into())
-    }
-    fn get_context(&self) -> Result<Ref<&'a mut dyn InvokeContext>, EbpfError<BpfError>> {
-        self.invoke_context
-            .try_borrow()
-            .map_err(|_| SyscallError::InvokeContextBorrowFailed.into())
-    }
-    fn get_callers_keyed_accounts(&self) -> &'a [KeyedAccount<'a>] {
-        self.callers_keyed_accounts
-    }
-    fn translate_instruction(
-        &self,
-        addr: u64,
+impl<'a> SyscallObject<BpfError> for SyscallEvmGetAccount<'a> {
+    fn call(
+        &mut self,
+        address_addr: u64,
+        account_addr: u64,
+        _arg3: u64,
+        _arg4: u64,
+        _arg5: u64,
         memory_mapping: &MemoryMapping,
-    ) -> Result<Instruction, EbpfError<BpfError>> {
+        result: &mut Result<u64, EbpfError<BpfError>>,
+    ) {
+        question_mark!(self.compute_meter.consume(self.cost), result);
+        let address = question_mark!(
+            translate_slice::<u8>(
+                memory_mapping,
+                address_addr,
+                EVM_ADDRESS_BYTES as u64,
+                self.loader_id
+            ),
+            result
+        );
+        let account = question_mark!(
+            translate_type_mut::<EvmAccount>(memory_mapping, account_addr, self.loader_id),
+            result
+        );
+        let invoke_context = question_mark!(
+            self.invoke_context
+                .try_borrow()
+                .map_err(|_| SyscallError::InvokeContextBorrowFailed),
+            result
+        );
+        let evm_state = match invoke_context.get_evm_state() {
+            Some(evm_state) => evm_state,
+            None => {
+                *result = Ok(EVM_STATE_NOT_AVAILABLE);
+                return;
+            }
+        };
+
+        *account = EvmAccount::default();
+        // Like EXTCODEHASH, empty accounts are reported as not existing
+        if let Some(state) = evm_state
+            .get_account_state(H160::from_slice(address))
+            .filter(|state| !state.is_empty())
+        {
+            state.balance.to_big_endian(&mut account.balance);
+            state.nonce.to_big_endian(&mut account.nonce);
+            account.code_hash = state.code.hash().to_fixed_bytes();
+        }
+        *result = Ok(SUCCESS);
+    }
+}
+
+/// Read a storage slot of an evm account from the incoming evm state
+pub struct SyscallEvmGetStorage<'a> {
+    cost: u64,
+    compute_meter: Rc<RefCell<dyn ComputeMeter>>,
+    invoke_context: Rc<RefCell<&'a mut dyn InvokeContext>>,
+    loader_id: &'a Pubkey,
+}
+impl<'a> SyscallObject<BpfError> for SyscallEvmGetStorage<'a> {
+    fn call(
+        &mut self,
+        address_addr: u64,
+        index_addr: u64,
+        value_addr: u64,
+        _arg4: u64,
+        _arg5: u64,
+        memory_mapping: &MemoryMapping,
+        result: &mut Result<u64, EbpfError<BpfError>>,
+    ) {
+        question_mark!(self.compute_meter.consume(self.cost), result);
+        let address = question_mark!(
+            translate_slice::<u8>(
+                memory_mapping,
+                address_addr,
+                EVM_ADDRESS_BYTES as u64,
+                self.loader_id
+            ),
+            result
+        );
+        let index = question_mark!(
+            translate_slice::<u8>(
+                memory_mapping,
+                index_addr,
+                EVM_WORD_BYTES as u64,
+                self.loader_id
+            ),
+            result
+        );
+        let value = question_mark!(
+            translate_slice_mut::<u8>(
+                memory_mapping,
+                value_addr,
+                EVM_WORD_BYTES as u64,
+                self.loader_id
+            ),
+            result
+        );
+        let invoke_context = question_mark!(
+            self.invoke_context
+                .try_borrow()
+                .map_err(|_| SyscallError::InvokeContextBorrowFailed),
+            result
+        );
+        let evm_state = match invoke_context.get_evm_state() {
+            Some(evm_state) => evm_state,
+            None => {
+                *result = Ok(EVM_STATE_NOT_AVAILABLE);
+                return;
+            }
+        };
+
+        let storage_value = evm_state
+            .get_storage(H160::from_slice(address), H256::from_slice(index))
+            .unwrap_or_default();
+        value.copy_from_slice(storage_value.as_bytes());
+        *result = Ok(SUCCESS);
+    }
+}
+
+/// Set the return data of the currently executing instruction
+pub struct SyscallSetReturnData<'a> {
+    compute_meter: Rc<RefCell<dyn ComputeMeter>>,
+    invoke_context: Rc<RefCell<&'a mut dyn InvokeContext>>,
+    loader_id: &'a Pubkey,
+}
+impl<'a> SyscallObject<BpfError> for SyscallSetReturnData<'a> {
+    fn call(
+        &mut self,
+        addr: u64,
+        len: u64,
+        _arg3: u64,
+        _arg4: u64,
+        _arg5: u64,
+        memory_mapping: &MemoryMapping,
+        result: &mut Result<u64, EbpfError<BpfError>>,
+    ) {
+        let mut invoke_context = question_mark!(
+            self.invoke_context
+                .try_borrow_mut()
+                .map_err(|_| SyscallError::InvokeContextBorrowFailed),
+            result
+        );
+        let bpf_compute_budget = invoke_context.get_bpf_compute_budget();
+        let cost = (len / bpf_compute_budget.return_data_bytes_per_unit)
+            .saturating_add(bpf_compute_budget.return_data_units);
+        question_mark!(self.compute_meter.consume(cost), result);
+
+        if len > MAX_RETURN_DATA as u64 {
+            *result = Err(SyscallError::ReturnDataTooLarge(len, MAX_RETURN_DATA as u64).into());
+            return;
+        }
+        let return_data = if len == 0 {
+            Vec::new()
+        } else {
+            question_mark!(
+                translate_slice::<u8>(memory_mapping, addr, len, self.loader_id),
+                result
+            )
+            .to_vec()
+        };
+        question_mark!(
+            invoke_context
+                .set_return_data(return_data)
+                .map_err(SyscallError::InstructionError),
+            result
+        );
+        *result = Ok(0);
+    }
+}
+
+/// Get the return data set by the last invoked instruction, returns its full length
+pub struct SyscallGetReturnData<'a> {
+    compute_meter: Rc<RefCell<dyn ComputeMeter>>,
+    invoke_context: Rc<RefCell<&'a mut dyn InvokeContext>>,
+    loader_id: &'a Pubkey,
+}
+impl<'a> SyscallObject<BpfError> for SyscallGetReturnData<'a> {
+    fn call(
+        &mut self,
+        return_data_addr: u64,
+        len: u64,
+        program_id_addr: u64,
+        _arg4: u64,
+        _arg5: u64,
+        memory_mapping: &MemoryMapping,
+        result: &mut Result<u64, EbpfError<BpfError>>,
+    ) {
+        let invoke_context = question_mark!(
+            self.invoke_context
+                .try_borrow()
+                .map_err(|_| SyscallError::InvokeContextBorrowFailed),
+            result
+        );
+        let bpf_compute_budget = invoke_context.get_bpf_compute_budget();
+        question_mark!(
+            self.compute_meter
+                .consume(bpf_compute_budget.return_data_units),
+            result
+        );
+
+        let (program_id, return_data) = invoke_context.get_return_data();
+        let length = len.min(return_data.len() as u64);
+        if length != 0 {
+            let cost = length / bpf_compute_budget.return_data_bytes_per_unit;
+            question_mark!(self.compute_meter.consume(cost), result);
+
+            let return_data_result = question_mark!(
+                translate_slice_mut::<u8>(memory_mapping, return_data_addr, length, self.loader_id),
+                result
+            );
+            return_data_result.copy_from_slice(&return_data[..length as usize]);
+
+            let program_id_result = question_mark!(
+                translate_type_mut::<Pubkey>(memory_mapping, program_id_addr, self.loader_id),
+                result
+            );
+            *program_id_result = program_id;
+        }
+
+        // Return the full length, which may be larger than the length copied
+        *result = Ok(return_data.len() as u64);
+    }
+}
+
+fn get_sysvar<T: Sysvar>(
+    id: &Pubkey,
+    var_addr: u64,
+    loader_id: &Pubkey,
+    memory_mapping: &MemoryMapping,
+    invoke_context: &Rc<RefCell<&mut dyn InvokeContext>>,
+) -> Result<u64, EbpfError<BpfError>> {
+    let invoke_context = invoke_context
+        .try_borrow()
+        .map_err(|_| SyscallError::InvokeContextBorrowFailed)?;
+
+    invoke_context.get_compute_meter().consume(
+        invoke_context
+            .get_bpf_compute_budget()
+            .sysvar_base_cost
+            .saturating_add(size_of::<T>() as u64),
+    )?;
+    let var = translate_type_mut::<T>(memory_mapping, var_addr, loader_id)?;
+
+    // Missing sysvar is reported to the program, same as by the program-test stubs,
+    // so `Sysvar::get()` returns `ProgramError::UnsupportedSysvar` instead of aborting
+    match process_instruction::get_sysvar::<T>(&**invoke_context, id) {
+        Ok(sysvar) => {
+            *var = sysvar;
+            Ok(SUCCESS)
+        }
+        Err(_) => Ok(UNSUPPORTED_SYSVAR),
+    }
+}
+
+macro_rules! declare_sysvar_getter_syscall {
+    ($(#[$attr:meta])* $name:ident, $sysvar_type:ty, $sysvar_id:expr) => {
+        $(#[$attr])*
+        pub struct $name<'a> {
+            invoke_context: Rc<RefCell<&'a mut dyn InvokeContext>>,
+            loader_id: &'a Pubkey,
+        }
+        impl<'a> SyscallObject<BpfError> for $name<'a> {
+            fn call(
+                &mut self,
+                var_addr: u64,
+                _arg2: u64,
+                _arg3: u64,
+                _arg4: u64,
+                _arg5: u64,
+                memory_mapping: &MemoryMapping,
+                result: &mut Result<u64, EbpfError<BpfError>>,
+            ) {
+                *result = get_sysvar::<$sysvar_type>(
+                    &$sysvar_id,
+                    var_addr,
+                    self.loader_id,
+                    memory_mapping,
+                    &self.invoke_context,
+                );
+            }
+        }
+    };
+}
+
+declare_sysvar_getter_syscall!(
+    /// Get the Clock sysvar
+    SyscallGetClockSysvar,
+    Clock,
+    sysvar::clock::id()
+);
+declare_sysvar_getter_syscall!(
+    /// Get the EpochSchedule sysvar
+    SyscallGetEpochScheduleSysvar,
+    EpochSchedule,
+    sysvar::epoch_schedule::id()
+);
+declare_sysvar_getter_syscall!(
+    /// Get the Fees sysvar
+    SyscallGetFeesSysvar,
+    Fees,
+    sysvar::fees::id()
+);
+declare_sysvar_getter_syscall!(
+    /// Get the Rent sysvar
+    SyscallGetRentSysvar,
+    Rent,
+    sysvar::rent::id()
+);
+declare_sysvar_getter_syscall!(
+    /// Get the RecentBlockhashes sysvar of the EVM
+    SyscallGetRecentEvmBlockhashesSysvar,
+    RecentBlockhashes,
+    sysvar::recent_evm_blockhashes::id()
+);
+
+/// Ristretto point multiply
+pub struct SyscallRistrettoMul<'a> {
+    cost: u64,
+    compute_meter: Rc<RefCell<dyn ComputeMeter>>,
+    loader_id: &'a Pubkey,
+}
+impl<'a> SyscallObject<BpfError> for SyscallRistrettoMul<'a> {
+    fn call(
+        &mut self,
+        point_addr: u64,
+        scalar_addr: u64,
+        result_addr: u64,
+        _arg4: u64,
+        _arg5: u64,
+        memory_mapping: &MemoryMapping,
+        result: &mut Result<u64, EbpfError<BpfError>>,
+    ) {
+        question_mark!(self.compute_meter.consume(self.cost), result);
+
+        let point = question_mark!(
+            translate_type::<RistrettoPoint>(memory_mapping, point_addr, self.loader_id),
+            result
+        );
+        let scalar = question_mark!(
+            translate_type::<Scalar>(memory_mapping, scalar_addr, self.loader_id),
+            result
+        );
+        let output = question_mark!(
+            translate_type_mut::<RistrettoPoint>(memory_mapping, result_addr, self.loader_id),
+            result
+        );
+        *output = point * scalar;
+
+        *result = Ok(0);
+    }
+}
+
+// Cross-program invocation syscalls
+
+struct AccountReferences<'a> {
+    lamports: &'a mut u64,
+    owner: &'a mut Pubkey,
+    data: &'a mut [u8],
+    vm_data_addr: u64,
+    ref_to_len_in_vm: &'a mut u64,
+    serialized_len_ptr: &'a mut u64,
+}
+type TranslatedAccount<'a> = (Rc<RefCell<Account>>, Option<AccountReferences<'a>>);
+type TranslatedAccounts<'a> = (
+    Vec<Rc<RefCell<Account>>>,
+    Vec<Option<AccountReferences<'a>>>,
+);
+
+/// Implemented by language specific data structure translators
+trait SyscallInvokeSigned<'a> {
+    fn get_context_mut(&self) -> Result<RefMut<&'a mut dyn InvokeContext>, EbpfError<BpfError>>;
+    fn get_context(&self) -> Result<Ref<&'a mut dyn InvokeContext>, EbpfError<BpfError>>;
+    fn get_callers_keyed_accounts(&self) -> &'a [KeyedAccount<'a>];
+    fn translate_instruction(
+        &self,
+        addr: u64,
+        memory_mapping: &MemoryMapping,
+    ) -> Result<Instruction, EbpfError<BpfError>>;
+    fn translate_accounts(
+        &self,
+        account_keys: &[Pubkey],
+        program_account_index: usize,
+        account_infos_addr: u64,
+        account_infos_len: u64,
+        memory_mapping: &MemoryMapping,
+    ) -> Result<TranslatedAccounts<'a>, EbpfError<BpfError>>;
+    fn translate_signers(
+        &self,
+        program_id: &Pubkey,
+        signers_seeds_addr: u64,
+        signers_seeds_len: u64,
+        memory_mapping: &MemoryMapping,
+    ) -> Result<Vec<Pubkey>, EbpfError<BpfError>>;
+}
+
+/// Cross-program invocation called from Rust
+pub struct SyscallInvokeSignedRust<'a> {
+    callers_keyed_accounts: &'a [KeyedAccount<'a>],
+    invoke_context: Rc<RefCell<&'a mut dyn InvokeContext>>,
+    loader_id: &'a Pubkey,
+}
+impl<'a> SyscallInvokeSigned<'a> for SyscallInvokeSignedRust<'a> {
+    fn get_context_mut(&self) -> Result<RefMut<&'a mut dyn InvokeContext>, EbpfError<BpfError>> {
+        self.invoke_context
+            .try_borrow_mut()
+            .map_err(|_| SyscallError::InvokeContextBorrowFailed.into())
+    }
+    fn get_context(&self) -> Result<Ref<&'a mut dyn InvokeContext>, EbpfError<BpfError>> {
+        self.invoke_context
+            .try_borrow()
+            .map_err(|_| SyscallError::InvokeContextBorrowFailed.into())
+    }
+    fn get_callers_keyed_accounts(&self) -> &'a [KeyedAccount<'a>] {
+        self.callers_keyed_accounts
+    }
+    fn translate_instruction(
+        &self,
+        addr: u64,
+        memory_mapping: &MemoryMapping,
+    ) -> Result<Instruction, EbpfError<BpfError>> {
         let ix = translate_type::<Instruction>(memory_mapping, addr, self.loader_id)?;
 
         check_instruction_size(
@@ -1755,8 +2391,8 @@ mod tests {
     use solana_rbpf::{memory_region::MemoryRegion, vm::Config};
     use solana_sdk::{
         bpf_loader,
-        hash::hashv,
-        process_instruction::{MockComputeMeter, MockLogger},
+        hash::{hashv, Hash},
+        process_instruction::{MockComputeMeter, MockInvokeContext, MockLogger},
     };
     use std::str::FromStr;
 
@@ -2363,54 +2999,415 @@ mod tests {
     }
 
     #[test]
-    fn test_syscall_sha256() {
-        let bytes1 = "Gaggablaghblagh!";
-        let bytes2 = "flurbos";
-
-        struct MockSlice {
-            pub addr: u64,
-            pub len: usize,
-        }
-        let mock_slice1 = MockSlice {
-            addr: 4096,
-            len: bytes1.len(),
-        };
-        let mock_slice2 = MockSlice {
-            addr: 8192,
-            len: bytes2.len(),
+    fn test_syscall_sha256() {
+        let bytes1 = "Gaggablaghblagh!";
+        let bytes2 = "flurbos";
+
+        struct MockSlice {
+            pub addr: u64,
+            pub len: usize,
+        }
+        let mock_slice1 = MockSlice {
+            addr: 4096,
+            len: bytes1.len(),
+        };
+        let mock_slice2 = MockSlice {
+            addr: 8192,
+            len: bytes2.len(),
+        };
+        let bytes_to_hash = [mock_slice1, mock_slice2];
+        let hash_result = [0; HASH_BYTES];
+        let ro_len = bytes_to_hash.len() as u64;
+        let ro_va = 96;
+        let rw_va = 192;
+        let memory_mapping = MemoryMapping::new(
+            vec![
+                MemoryRegion {
+                    host_addr: bytes1.as_ptr() as *const _ as u64,
+                    vm_addr: 4096,
+                    len: bytes1.len() as u64,
+                    vm_gap_shift: 63,
+                    is_writable: false,
+                },
+                MemoryRegion {
+                    host_addr: bytes2.as_ptr() as *const _ as u64,
+                    vm_addr: 8192,
+                    len: bytes2.len() as u64,
+                    vm_gap_shift: 63,
+                    is_writable: false,
+                },
+                MemoryRegion {
+                    host_addr: bytes_to_hash.as_ptr() as *const _ as u64,
+                    vm_addr: 96,
+                    len: 32,
+                    vm_gap_shift: 63,
+                    is_writable: false,
+                },
+                MemoryRegion {
+                    host_addr: hash_result.as_ptr() as *const _ as u64,
+                    vm_addr: rw_va,
+                    len: HASH_BYTES as u64,
+                    vm_gap_shift: 63,
+                    is_writable: true,
+                },
+            ],
+            &DEFAULT_CONFIG,
+        );
+        let compute_meter: Rc<RefCell<dyn ComputeMeter>> =
+            Rc::new(RefCell::new(MockComputeMeter {
+                remaining: (bytes1.len() + bytes2.len()) as u64,
+            }));
+        let mut syscall = SyscallSha256 {
+            sha256_base_cost: 0,
+            sha256_byte_cost: 2,
+            compute_meter,
+            loader_id: &bpf_loader_deprecated::id(),
+        };
+
+        let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+        syscall.call(ro_va, ro_len, rw_va, 0, 0, &memory_mapping, &mut result);
+        result.unwrap();
+
+        let hash_local = hashv(&[bytes1.as_ref(), bytes2.as_ref()]).to_bytes();
+        assert_eq!(hash_result, hash_local);
+        let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+        syscall.call(
+            ro_va - 1, // AccessViolation
+            ro_len,
+            rw_va,
+            0,
+            0,
+            &memory_mapping,
+            &mut result,
+        );
+        assert_access_violation!(result, ro_va - 1, ro_len);
+        let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+        syscall.call(
+            ro_va,
+            ro_len + 1, // AccessViolation
+            rw_va,
+            0,
+            0,
+            &memory_mapping,
+            &mut result,
+        );
+        assert_access_violation!(result, ro_va, ro_len + 1);
+        let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+        syscall.call(
+            ro_va,
+            ro_len,
+            rw_va - 1, // AccessViolation
+            0,
+            0,
+            &memory_mapping,
+            &mut result,
+        );
+        assert_access_violation!(result, rw_va - 1, HASH_BYTES as u64);
+
+        syscall.call(ro_va, ro_len, rw_va, 0, 0, &memory_mapping, &mut result);
+        assert_eq!(
+            Err(EbpfError::UserError(BpfError::SyscallError(
+                SyscallError::InstructionError(InstructionError::ComputationalBudgetExceeded)
+            ))),
+            result
+        );
+    }
+
+    #[test]
+    fn test_syscall_keccak256() {
+        let bytes1 = "Gaggablaghblagh!";
+        let bytes2 = "flurbos";
+
+        struct MockSlice {
+            pub addr: u64,
+            pub len: usize,
+        }
+        let mock_slice1 = MockSlice {
+            addr: 4096,
+            len: bytes1.len(),
+        };
+        let mock_slice2 = MockSlice {
+            addr: 8192,
+            len: bytes2.len(),
+        };
+        let bytes_to_hash = [mock_slice1, mock_slice2];
+        let hash_result = [0; keccak::HASH_BYTES];
+        let ro_len = bytes_to_hash.len() as u64;
+        let ro_va = 96;
+        let rw_va = 192;
+        let memory_mapping = MemoryMapping::new(
+            vec![
+                MemoryRegion {
+                    host_addr: bytes1.as_ptr() as *const _ as u64,
+                    vm_addr: 4096,
+                    len: bytes1.len() as u64,
+                    vm_gap_shift: 63,
+                    is_writable: false,
+                },
+                MemoryRegion {
+                    host_addr: bytes2.as_ptr() as *const _ as u64,
+                    vm_addr: 8192,
+                    len: bytes2.len() as u64,
+                    vm_gap_shift: 63,
+                    is_writable: false,
+                },
+                MemoryRegion {
+                    host_addr: bytes_to_hash.as_ptr() as *const _ as u64,
+                    vm_addr: 96,
+                    len: 32,
+                    vm_gap_shift: 63,
+                    is_writable: false,
+                },
+                MemoryRegion {
+                    host_addr: hash_result.as_ptr() as *const _ as u64,
+                    vm_addr: rw_va,
+                    len: keccak::HASH_BYTES as u64,
+                    vm_gap_shift: 63,
+                    is_writable: true,
+                },
+            ],
+            &DEFAULT_CONFIG,
+        );
+        let compute_meter: Rc<RefCell<dyn ComputeMeter>> =
+            Rc::new(RefCell::new(MockComputeMeter {
+                remaining: (bytes1.len() + bytes2.len()) as u64,
+            }));
+        let mut syscall = SyscallKeccak256 {
+            base_cost: 0,
+            byte_cost: 2,
+            compute_meter,
+            loader_id: &bpf_loader_deprecated::id(),
+        };
+
+        let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+        syscall.call(ro_va, ro_len, rw_va, 0, 0, &memory_mapping, &mut result);
+        result.unwrap();
+
+        let hash_local = keccak::hashv(&[bytes1.as_ref(), bytes2.as_ref()]).to_bytes();
+        assert_eq!(hash_result, hash_local);
+        let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+        syscall.call(
+            ro_va - 1, // AccessViolation
+            ro_len,
+            rw_va,
+            0,
+            0,
+            &memory_mapping,
+            &mut result,
+        );
+        assert_access_violation!(result, ro_va - 1, ro_len);
+        let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+        syscall.call(
+            ro_va,
+            ro_len,
+            rw_va - 1, // AccessViolation
+            0,
+            0,
+            &memory_mapping,
+            &mut result,
+        );
+        assert_access_violation!(result, rw_va - 1, keccak::HASH_BYTES as u64);
+
+        syscall.call(ro_va, ro_len, rw_va, 0, 0, &memory_mapping, &mut result);
+        assert_eq!(
+            Err(EbpfError::UserError(BpfError::SyscallError(
+                SyscallError::InstructionError(InstructionError::ComputationalBudgetExceeded)
+            ))),
+            result
+        );
+    }
+
+    #[test]
+    fn test_syscall_secp256k1_recover() {
+        let secret_key = secp256k1::SecretKey::parse(&[1; 32]).unwrap();
+        let public_key = secp256k1::PublicKey::from_secret_key(&secret_key);
+        let hash = keccak::hash(b"hello world").to_bytes();
+        let message = secp256k1::Message::parse(&hash);
+        let (signature, recovery_id) = secp256k1::sign(&message, &secret_key);
+        let signature = signature.serialize();
+        let mut bad_signature = signature;
+        bad_signature[..32].copy_from_slice(&[0xff; 32]);
+        let pubkey_result = [0u8; SECP256K1_PUBLIC_KEY_LENGTH];
+
+        let hash_va = 4096;
+        let signature_va = 8192;
+        let bad_signature_va = 12288;
+        let rw_va = 16384;
+        let memory_mapping = MemoryMapping::new(
+            vec![
+                MemoryRegion {
+                    host_addr: hash.as_ptr() as *const _ as u64,
+                    vm_addr: hash_va,
+                    len: SECP256K1_HASH_LENGTH as u64,
+                    vm_gap_shift: 63,
+                    is_writable: false,
+                },
+                MemoryRegion {
+                    host_addr: signature.as_ptr() as *const _ as u64,
+                    vm_addr: signature_va,
+                    len: SECP256K1_SIGNATURE_LENGTH as u64,
+                    vm_gap_shift: 63,
+                    is_writable: false,
+                },
+                MemoryRegion {
+                    host_addr: bad_signature.as_ptr() as *const _ as u64,
+                    vm_addr: bad_signature_va,
+                    len: SECP256K1_SIGNATURE_LENGTH as u64,
+                    vm_gap_shift: 63,
+                    is_writable: false,
+                },
+                MemoryRegion {
+                    host_addr: pubkey_result.as_ptr() as *const _ as u64,
+                    vm_addr: rw_va,
+                    len: SECP256K1_PUBLIC_KEY_LENGTH as u64,
+                    vm_gap_shift: 63,
+                    is_writable: true,
+                },
+            ],
+            &DEFAULT_CONFIG,
+        );
+        let compute_meter: Rc<RefCell<dyn ComputeMeter>> =
+            Rc::new(RefCell::new(MockComputeMeter { remaining: 4 }));
+        let mut syscall = SyscallSecp256k1Recover {
+            cost: 1,
+            compute_meter,
+            loader_id: &bpf_loader::id(),
+        };
+        let recovery_id = recovery_id.serialize() as u64;
+
+        let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+        syscall.call(
+            hash_va,
+            recovery_id,
+            signature_va,
+            rw_va,
+            0,
+            &memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result, Ok(SUCCESS));
+        assert_eq!(pubkey_result[..], public_key.serialize()[1..]);
+
+        let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+        syscall.call(
+            hash_va,
+            4, // InvalidRecoveryId
+            signature_va,
+            rw_va,
+            0,
+            &memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result, Ok(Secp256k1RecoverError::InvalidRecoveryId.into()));
+
+        let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+        syscall.call(
+            hash_va,
+            recovery_id,
+            bad_signature_va, // InvalidSignature
+            rw_va,
+            0,
+            &memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result, Ok(Secp256k1RecoverError::InvalidSignature.into()));
+
+        let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+        syscall.call(
+            hash_va,
+            recovery_id,
+            signature_va,
+            rw_va - 1, // AccessViolation
+            0,
+            &memory_mapping,
+            &mut result,
+        );
+        assert_access_violation!(result, rw_va - 1, SECP256K1_PUBLIC_KEY_LENGTH as u64);
+
+        syscall.call(
+            hash_va,
+            recovery_id,
+            signature_va,
+            rw_va,
+            0,
+            &memory_mapping,
+            &mut result,
+        );
+        assert_eq!(
+            Err(EbpfError::UserError(BpfError::SyscallError(
+                SyscallError::InstructionError(InstructionError::ComputationalBudgetExceeded)
+            ))),
+            result
+        );
+    }
+
+    #[test]
+    fn test_syscall_evm_state() {
+        let address = [1u8; EVM_ADDRESS_BYTES];
+        let empty_address = [2u8; EVM_ADDRESS_BYTES];
+        let index = [3u8; EVM_WORD_BYTES];
+        let code = evm_state::Code::from(vec![0x60, 0x00]);
+        let mut evm_backend = evm_state::EvmBackend::default();
+        evm_backend.set_account_state(
+            H160::from_slice(&address),
+            evm_state::AccountState {
+                nonce: 7.into(),
+                balance: 1_000_000.into(),
+                code: code.clone(),
+            },
+        );
+        evm_backend.ext_storage(
+            H160::from_slice(&address),
+            vec![(H256::from_slice(&index), H256::repeat_byte(4))],
+        );
+        let mut mock_invoke_context = MockInvokeContext {
+            evm_state: Some(evm_backend),
+            ..MockInvokeContext::default()
         };
-        let bytes_to_hash = [mock_slice1, mock_slice2];
-        let hash_result = [0; HASH_BYTES];
-        let ro_len = bytes_to_hash.len() as u64;
-        let ro_va = 96;
-        let rw_va = 192;
+        let invoke_context: Rc<RefCell<&mut dyn InvokeContext>> =
+            Rc::new(RefCell::new(&mut mock_invoke_context));
+
+        let account = EvmAccount::default();
+        let value = [0u8; EVM_WORD_BYTES];
+        let ro_va = 4096;
+        let empty_va = 8192;
+        let index_va = 12288;
+        let rw_va = 16384;
+        let value_va = 20480;
         let memory_mapping = MemoryMapping::new(
             vec![
                 MemoryRegion {
-                    host_addr: bytes1.as_ptr() as *const _ as u64,
-                    vm_addr: 4096,
-                    len: bytes1.len() as u64,
+                    host_addr: address.as_ptr() as *const _ as u64,
+                    vm_addr: ro_va,
+                    len: EVM_ADDRESS_BYTES as u64,
                     vm_gap_shift: 63,
                     is_writable: false,
                 },
                 MemoryRegion {
-                    host_addr: bytes2.as_ptr() as *const _ as u64,
-                    vm_addr: 8192,
-                    len: bytes2.len() as u64,
+                    host_addr: empty_address.as_ptr() as *const _ as u64,
+                    vm_addr: empty_va,
+                    len: EVM_ADDRESS_BYTES as u64,
                     vm_gap_shift: 63,
                     is_writable: false,
                 },
                 MemoryRegion {
-                    host_addr: bytes_to_hash.as_ptr() as *const _ as u64,
-                    vm_addr: 96,
-                    len: 32,
+                    host_addr: index.as_ptr() as *const _ as u64,
+                    vm_addr: index_va,
+                    len: EVM_WORD_BYTES as u64,
                     vm_gap_shift: 63,
                     is_writable: false,
                 },
                 MemoryRegion {
-                    host_addr: hash_result.as_ptr() as *const _ as u64,
+                    host_addr: &account as *const _ as u64,
                     vm_addr: rw_va,
-                    len: HASH_BYTES as u64,
+                    len: size_of::<EvmAccount>() as u64,
+                    vm_gap_shift: 63,
+                    is_writable: true,
+                },
+                MemoryRegion {
+                    host_addr: value.as_ptr() as *const _ as u64,
+                    vm_addr: value_va,
+                    len: EVM_WORD_BYTES as u64,
                     vm_gap_shift: 63,
                     is_writable: true,
                 },
@@ -2418,57 +3415,214 @@ mod tests {
             &DEFAULT_CONFIG,
         );
         let compute_meter: Rc<RefCell<dyn ComputeMeter>> =
-            Rc::new(RefCell::new(MockComputeMeter {
-                remaining: (bytes1.len() + bytes2.len()) as u64,
-            }));
-        let mut syscall = SyscallSha256 {
-            sha256_base_cost: 0,
-            sha256_byte_cost: 2,
-            compute_meter,
-            loader_id: &bpf_loader_deprecated::id(),
+            Rc::new(RefCell::new(MockComputeMeter { remaining: 500 }));
+
+        let mut syscall = SyscallEvmGetAccount {
+            cost: 100,
+            compute_meter: compute_meter.clone(),
+            invoke_context: invoke_context.clone(),
+            loader_id: &bpf_loader::id(),
         };
+        let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+        syscall.call(ro_va, rw_va, 0, 0, 0, &memory_mapping, &mut result);
+        assert_eq!(result, Ok(SUCCESS));
+        let mut expected = EvmAccount::default();
+        evm_state::U256::from(1_000_000).to_big_endian(&mut expected.balance);
+        evm_state::U256::from(7).to_big_endian(&mut expected.nonce);
+        expected.code_hash = code.hash().to_fixed_bytes();
+        assert_eq!(account, expected);
 
         let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
-        syscall.call(ro_va, ro_len, rw_va, 0, 0, &memory_mapping, &mut result);
-        result.unwrap();
+        syscall.call(empty_va, rw_va, 0, 0, 0, &memory_mapping, &mut result);
+        assert_eq!(result, Ok(SUCCESS));
+        assert_eq!(account, EvmAccount::default());
 
-        let hash_local = hashv(&[bytes1.as_ref(), bytes2.as_ref()]).to_bytes();
-        assert_eq!(hash_result, hash_local);
         let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
         syscall.call(
-            ro_va - 1, // AccessViolation
-            ro_len,
-            rw_va,
+            ro_va,
+            rw_va - 1, // AccessViolation
+            0,
             0,
             0,
             &memory_mapping,
             &mut result,
         );
-        assert_access_violation!(result, ro_va - 1, ro_len);
+        assert_access_violation!(result, rw_va - 1, size_of::<EvmAccount>() as u64);
+
+        let mut syscall = SyscallEvmGetStorage {
+            cost: 100,
+            compute_meter,
+            invoke_context,
+            loader_id: &bpf_loader::id(),
+        };
         let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
         syscall.call(
             ro_va,
-            ro_len + 1, // AccessViolation
-            rw_va,
+            index_va,
+            value_va,
             0,
             0,
             &memory_mapping,
             &mut result,
         );
-        assert_access_violation!(result, ro_va, ro_len + 1);
+        assert_eq!(result, Ok(SUCCESS));
+        assert_eq!(value, [4u8; EVM_WORD_BYTES]);
+
+        // Evm state is not available to the transaction
+        let mut mock_invoke_context = MockInvokeContext::default();
+        let mut syscall = SyscallEvmGetStorage {
+            cost: 100,
+            compute_meter: syscall.compute_meter.clone(),
+            invoke_context: Rc::new(RefCell::new(&mut mock_invoke_context)),
+            loader_id: &bpf_loader::id(),
+        };
         let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
         syscall.call(
             ro_va,
-            ro_len,
-            rw_va - 1, // AccessViolation
+            index_va,
+            value_va,
             0,
             0,
             &memory_mapping,
             &mut result,
         );
-        assert_access_violation!(result, rw_va - 1, HASH_BYTES as u64);
+        assert_eq!(result, Ok(EVM_STATE_NOT_AVAILABLE));
 
-        syscall.call(ro_va, ro_len, rw_va, 0, 0, &memory_mapping, &mut result);
+        let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+        syscall.call(
+            ro_va,
+            index_va,
+            value_va,
+            0,
+            0,
+            &memory_mapping,
+            &mut result,
+        );
+        assert_eq!(
+            Err(EbpfError::UserError(BpfError::SyscallError(
+                SyscallError::InstructionError(InstructionError::ComputationalBudgetExceeded)
+            ))),
+            result
+        );
+    }
+
+    #[test]
+    fn test_syscall_return_data() {
+        let program_id = Pubkey::new_unique();
+        let data = [7u8; 300];
+        let result_data = [0u8; 300];
+        let result_program_id = Pubkey::default();
+        let data_va = 4096;
+        let result_data_va = 8192;
+        let program_id_va = 12288;
+        let memory_mapping = MemoryMapping::new(
+            vec![
+                MemoryRegion {
+                    host_addr: data.as_ptr() as *const _ as u64,
+                    vm_addr: data_va,
+                    len: data.len() as u64,
+                    vm_gap_shift: 63,
+                    is_writable: false,
+                },
+                MemoryRegion {
+                    host_addr: result_data.as_ptr() as *const _ as u64,
+                    vm_addr: result_data_va,
+                    len: result_data.len() as u64,
+                    vm_gap_shift: 63,
+                    is_writable: true,
+                },
+                MemoryRegion {
+                    host_addr: &result_program_id as *const _ as u64,
+                    vm_addr: program_id_va,
+                    len: size_of::<Pubkey>() as u64,
+                    vm_gap_shift: 63,
+                    is_writable: true,
+                },
+            ],
+            &DEFAULT_CONFIG,
+        );
+        let mut mock_invoke_context = MockInvokeContext {
+            key: program_id,
+            ..MockInvokeContext::default()
+        };
+        let invoke_context: Rc<RefCell<&mut dyn InvokeContext>> =
+            Rc::new(RefCell::new(&mut mock_invoke_context));
+        let compute_meter: Rc<RefCell<dyn ComputeMeter>> =
+            Rc::new(RefCell::new(MockComputeMeter { remaining: 355 }));
+
+        let mut syscall = SyscallSetReturnData {
+            compute_meter: compute_meter.clone(),
+            invoke_context: invoke_context.clone(),
+            loader_id: &bpf_loader::id(),
+        };
+        let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+        syscall.call(
+            data_va,
+            data.len() as u64,
+            0,
+            0,
+            0,
+            &memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result, Ok(0));
+        assert_eq!(
+            invoke_context.borrow().get_return_data(),
+            (program_id, &data[..])
+        );
+
+        let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+        syscall.call(
+            data_va,
+            MAX_RETURN_DATA as u64 + 1,
+            0,
+            0,
+            0,
+            &memory_mapping,
+            &mut result,
+        );
+        assert_eq!(
+            Err(EbpfError::UserError(BpfError::SyscallError(
+                SyscallError::ReturnDataTooLarge(
+                    MAX_RETURN_DATA as u64 + 1,
+                    MAX_RETURN_DATA as u64
+                )
+            ))),
+            result
+        );
+
+        let mut syscall = SyscallGetReturnData {
+            compute_meter,
+            invoke_context,
+            loader_id: &bpf_loader::id(),
+        };
+
+        // Only the requested length is copied, the full length is returned
+        let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+        syscall.call(
+            result_data_va,
+            100,
+            program_id_va,
+            0,
+            0,
+            &memory_mapping,
+            &mut result,
+        );
+        assert_eq!(result, Ok(data.len() as u64));
+        assert_eq!(result_data[..100], data[..100]);
+        assert_eq!(result_data[100..], [0u8; 200][..]);
+        assert_eq!(result_program_id, program_id);
+
+        let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+        syscall.call(
+            result_data_va,
+            data.len() as u64,
+            program_id_va,
+            0,
+            0,
+            &memory_mapping,
+            &mut result,
+        );
         assert_eq!(
             Err(EbpfError::UserError(BpfError::SyscallError(
                 SyscallError::InstructionError(InstructionError::ComputationalBudgetExceeded)
@@ -2476,4 +3630,162 @@ mod tests {
             result
         );
     }
+
+    #[test]
+    fn test_syscall_get_sysvar() {
+        let src_clock = Clock {
+            slot: 1,
+            epoch_start_timestamp: 2,
+            epoch: 3,
+            leader_schedule_epoch: 4,
+            unix_timestamp: 5,
+        };
+        let src_rent = Rent {
+            lamports_per_byte_year: 1,
+            exemption_threshold: 2.0,
+            burn_percent: 3,
+        };
+        let mut src_blockhashes = RecentBlockhashes::default();
+        for (i, hash) in src_blockhashes.0.iter_mut().enumerate() {
+            *hash = Hash::new_from_array([i as u8; 32]);
+        }
+        let mut invoke_context = MockInvokeContext {
+            sysvars: vec![
+                (sysvar::clock::id(), bincode::serialize(&src_clock).unwrap()),
+                (sysvar::rent::id(), bincode::serialize(&src_rent).unwrap()),
+                (
+                    sysvar::recent_evm_blockhashes::id(),
+                    bincode::serialize(&src_blockhashes).unwrap(),
+                ),
+            ],
+            ..MockInvokeContext::default()
+        };
+
+        // Clock
+        {
+            let got_clock = Clock::default();
+            let got_clock_va = 2048;
+            let memory_mapping = MemoryMapping::new(
+                vec![MemoryRegion {
+                    host_addr: &got_clock as *const _ as u64,
+                    vm_addr: got_clock_va,
+                    len: size_of::<Clock>() as u64,
+                    vm_gap_shift: 63,
+                    is_writable: true,
+                }],
+                &DEFAULT_CONFIG,
+            );
+            let mut syscall = SyscallGetClockSysvar {
+                invoke_context: Rc::new(RefCell::new(&mut invoke_context)),
+                loader_id: &bpf_loader::id(),
+            };
+            let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+            syscall.call(got_clock_va, 0, 0, 0, 0, &memory_mapping, &mut result);
+            assert_eq!(result, Ok(SUCCESS));
+            assert_eq!(got_clock, src_clock);
+        }
+
+        // Rent
+        {
+            let got_rent = Rent::default();
+            let got_rent_va = 2048;
+            let memory_mapping = MemoryMapping::new(
+                vec![MemoryRegion {
+                    host_addr: &got_rent as *const _ as u64,
+                    vm_addr: got_rent_va,
+                    len: size_of::<Rent>() as u64,
+                    vm_gap_shift: 63,
+                    is_writable: true,
+                }],
+                &DEFAULT_CONFIG,
+            );
+            let mut syscall = SyscallGetRentSysvar {
+                invoke_context: Rc::new(RefCell::new(&mut invoke_context)),
+                loader_id: &bpf_loader::id(),
+            };
+            let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+            syscall.call(got_rent_va, 0, 0, 0, 0, &memory_mapping, &mut result);
+            assert_eq!(result, Ok(SUCCESS));
+            assert_eq!(got_rent, src_rent);
+        }
+
+        // RecentBlockhashes, doesn't fit into the stack and is read into the heap
+        {
+            let got_blockhashes = Box::new(RecentBlockhashes::default());
+            let got_blockhashes_va = 2048;
+            let memory_mapping = MemoryMapping::new(
+                vec![MemoryRegion {
+                    host_addr: &*got_blockhashes as *const _ as u64,
+                    vm_addr: got_blockhashes_va,
+                    len: size_of::<RecentBlockhashes>() as u64,
+                    vm_gap_shift: 63,
+                    is_writable: true,
+                }],
+                &DEFAULT_CONFIG,
+            );
+            let mut syscall = SyscallGetRecentEvmBlockhashesSysvar {
+                invoke_context: Rc::new(RefCell::new(&mut invoke_context)),
+                loader_id: &bpf_loader::id(),
+            };
+            let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+            syscall.call(got_blockhashes_va, 0, 0, 0, 0, &memory_mapping, &mut result);
+            assert_eq!(result, Ok(SUCCESS));
+            assert_eq!(*got_blockhashes, src_blockhashes);
+        }
+
+        // Sysvar not available to the program
+        {
+            let got_fees = Fees::default();
+            let got_fees_va = 2048;
+            let memory_mapping = MemoryMapping::new(
+                vec![MemoryRegion {
+                    host_addr: &got_fees as *const _ as u64,
+                    vm_addr: got_fees_va,
+                    len: size_of::<Fees>() as u64,
+                    vm_gap_shift: 63,
+                    is_writable: true,
+                }],
+                &DEFAULT_CONFIG,
+            );
+            let mut syscall = SyscallGetFeesSysvar {
+                invoke_context: Rc::new(RefCell::new(&mut invoke_context)),
+                loader_id: &bpf_loader::id(),
+            };
+            let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+            syscall.call(got_fees_va, 0, 0, 0, 0, &memory_mapping, &mut result);
+            assert_eq!(result, Ok(UNSUPPORTED_SYSVAR));
+            assert_eq!(got_fees, Fees::default());
+        }
+
+        // Not enough compute units
+        {
+            invoke_context.compute_meter.remaining =
+                invoke_context.bpf_compute_budget.sysvar_base_cost;
+            let got_clock = Clock::default();
+            let got_clock_va = 2048;
+            let memory_mapping = MemoryMapping::new(
+                vec![MemoryRegion {
+                    host_addr: &got_clock as *const _ as u64,
+                    vm_addr: got_clock_va,
+                    len: size_of::<Clock>() as u64,
+                    vm_gap_shift: 63,
+                    is_writable: true,
+                }],
+                &DEFAULT_CONFIG,
+            );
+            let mut syscall = SyscallGetClockSysvar {
+                invoke_context: Rc::new(RefCell::new(&mut invoke_context)),
+                loader_id: &bpf_loader::id(),
+            };
+            let mut result: Result<u64, EbpfError<BpfError>> = Ok(0);
+            syscall.call(got_clock_va, 0, 0, 0, 0, &memory_mapping, &mut result);
+            assert_eq!(
+                result,
+                Err(
+                    SyscallError::InstructionError(InstructionError::ComputationalBudgetExceeded)
+                        .into()
+                )
+            );
+        }
+    }
 }
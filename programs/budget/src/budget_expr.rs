@@ -5,6 +5,7 @@
 
 use chrono::prelude::*;
 use serde_derive::{Deserialize, Serialize};
+use solana_sdk::clock::{Epoch, Slot};
 use solana_sdk::hash::Hash;
 use solana_sdk::pubkey::Pubkey;
 
@@ -19,6 +20,9 @@ pub enum Witness {
 
     /// Account snapshot.
     AccountData(Hash, Pubkey),
+
+    /// The slot and epoch of the bank, as read from the Clock sysvar.
+    Clock { slot: Slot, epoch: Epoch },
 }
 
 /// Some amount of lamports that should be sent to the `to` `Pubkey`.
@@ -59,9 +63,49 @@ pub enum Condition {
 
     /// Wait for the account with the given constraints.
     AccountData(AccountConstraints),
+
+    /// Wait for `Signature` `Witness`es from at least `threshold` of `signers`.
+    /// `witnessed` records which signers have signed so far and is always the same
+    /// length as `signers`, so the serialized size of the condition never changes.
+    MultiSig {
+        threshold: u8,
+        signers: Vec<Pubkey>,
+        witnessed: Vec<bool>,
+    },
+
+    /// Wait for a `Clock` `Witness` at or after the given slot.
+    Slot(Slot),
+
+    /// Wait for a `Clock` `Witness` at or after the given epoch.
+    Epoch(Epoch),
 }
 
 impl Condition {
+    /// Create a condition that waits on signatures from `threshold` of `signers`.
+    pub fn new_multisig(threshold: u8, signers: &[Pubkey]) -> Self {
+        Condition::MultiSig {
+            threshold,
+            signers: signers.to_vec(),
+            witnessed: vec![false; signers.len()],
+        }
+    }
+
+    /// Return true if this Condition can ever be satisfied.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Condition::MultiSig {
+                threshold,
+                signers,
+                witnessed,
+            } => {
+                *threshold > 0
+                    && usize::from(*threshold) <= signers.len()
+                    && witnessed.len() == signers.len()
+            }
+            _ => true,
+        }
+    }
+
     /// Return true if the given Witness satisfies this Condition.
     pub fn is_satisfied(&self, witness: &Witness, from: &Pubkey) -> bool {
         match (self, witness) {
@@ -77,9 +121,49 @@ impl Condition {
                     && constraints.key == *from
                     && constraints.data_hash == *actual_hash
             }
+            (
+                Condition::MultiSig {
+                    threshold,
+                    signers,
+                    witnessed,
+                },
+                Witness::Signature,
+            ) => {
+                let signed = signers
+                    .iter()
+                    .zip(witnessed)
+                    .filter(|(signer, witnessed)| **witnessed || *signer == from)
+                    .count();
+                signed >= usize::from(*threshold)
+            }
+            (Condition::Slot(slot), Witness::Clock { slot: current, .. }) => slot <= current,
+            (Condition::Epoch(epoch), Witness::Clock { epoch: current, .. }) => epoch <= current,
             _ => false,
         }
     }
+
+    /// Apply a witness to this Condition and return true if it is now satisfied.
+    /// Unlike `is_satisfied`, this records partial progress, so that a `MultiSig`
+    /// condition can collect its signatures across several instructions.
+    pub fn apply_witness(&mut self, witness: &Witness, from: &Pubkey) -> bool {
+        if self.is_satisfied(witness, from) {
+            return true;
+        }
+        if let (
+            Condition::MultiSig {
+                signers, witnessed, ..
+            },
+            Witness::Signature,
+        ) = (self, witness)
+        {
+            for (signer, witnessed) in signers.iter().zip(witnessed.iter_mut()) {
+                if signer == from {
+                    *witnessed = true;
+                }
+            }
+        }
+        false
+    }
 }
 
 /// A data type representing a payment plan.
@@ -170,6 +254,36 @@ impl BudgetExpr {
         )
     }
 
+    /// Create a budget that pays `lamports` to `to` after being witnessed by `threshold`
+    /// of `signers`.
+    pub fn new_multisig_payment(
+        threshold: u8,
+        signers: &[Pubkey],
+        lamports: u64,
+        to: &Pubkey,
+    ) -> Self {
+        BudgetExpr::After(
+            Condition::new_multisig(threshold, signers),
+            Box::new(Self::new_payment(lamports, to)),
+        )
+    }
+
+    /// Create a budget that pays `lamports` to `to` once the bank reaches `slot`.
+    pub fn new_payment_after_slot(slot: Slot, lamports: u64, to: &Pubkey) -> Self {
+        BudgetExpr::After(
+            Condition::Slot(slot),
+            Box::new(Self::new_payment(lamports, to)),
+        )
+    }
+
+    /// Create a budget that pays `lamports` to `to` once the bank reaches `epoch`.
+    pub fn new_payment_after_epoch(epoch: Epoch, lamports: u64, to: &Pubkey) -> Self {
+        BudgetExpr::After(
+            Condition::Epoch(epoch),
+            Box::new(Self::new_payment(lamports, to)),
+        )
+    }
+
     /// Create a budget that pays `lamports` to `to` after the given DateTime signed
     /// by `dt_pubkey`.
     pub fn new_future_payment(
@@ -217,15 +331,37 @@ impl BudgetExpr {
         }
     }
 
-    /// Return true if the budget spends exactly `spendable_lamports`.
+    /// Return true if every condition in the budget can be satisfied.
+    pub fn has_valid_conditions(&self) -> bool {
+        match self {
+            BudgetExpr::Pay(_) => true,
+            BudgetExpr::After(cond, sub_expr) => cond.is_valid() && sub_expr.has_valid_conditions(),
+            BudgetExpr::Or(a, b) => {
+                a.0.is_valid()
+                    && a.1.has_valid_conditions()
+                    && b.0.is_valid()
+                    && b.1.has_valid_conditions()
+            }
+            BudgetExpr::And(cond0, cond1, sub_expr) => {
+                cond0.is_valid() && cond1.is_valid() && sub_expr.has_valid_conditions()
+            }
+        }
+    }
+
+    /// Return true if the budget spends exactly `spendable_lamports` and all of its
+    /// conditions can be satisfied.
     pub fn verify(&self, spendable_lamports: u64) -> bool {
+        self.has_valid_conditions() && self.verify_lamports(spendable_lamports)
+    }
+
+    fn verify_lamports(&self, spendable_lamports: u64) -> bool {
         match self {
             BudgetExpr::Pay(payment) => payment.lamports == spendable_lamports,
             BudgetExpr::After(_, sub_expr) | BudgetExpr::And(_, _, sub_expr) => {
-                sub_expr.verify(spendable_lamports)
+                sub_expr.verify_lamports(spendable_lamports)
             }
             BudgetExpr::Or(a, b) => {
-                a.1.verify(spendable_lamports) && b.1.verify(spendable_lamports)
+                a.1.verify_lamports(spendable_lamports) && b.1.verify_lamports(spendable_lamports)
             }
         }
     }
@@ -234,19 +370,26 @@ impl BudgetExpr {
     /// If so, modify the budget in-place.
     pub fn apply_witness(&mut self, witness: &Witness, from: &Pubkey) {
         let new_expr = match self {
-            BudgetExpr::After(cond, sub_expr) if cond.is_satisfied(witness, from) => {
-                Some(sub_expr.clone())
-            }
-            BudgetExpr::Or((cond, sub_expr), _) if cond.is_satisfied(witness, from) => {
-                Some(sub_expr.clone())
+            BudgetExpr::After(cond, sub_expr) => {
+                if cond.apply_witness(witness, from) {
+                    Some(sub_expr.clone())
+                } else {
+                    None
+                }
             }
-            BudgetExpr::Or(_, (cond, sub_expr)) if cond.is_satisfied(witness, from) => {
-                Some(sub_expr.clone())
+            BudgetExpr::Or((cond0, sub_expr0), (cond1, sub_expr1)) => {
+                if cond0.apply_witness(witness, from) {
+                    Some(sub_expr0.clone())
+                } else if cond1.apply_witness(witness, from) {
+                    Some(sub_expr1.clone())
+                } else {
+                    None
+                }
             }
             BudgetExpr::And(cond0, cond1, sub_expr) => {
-                if cond0.is_satisfied(witness, from) {
+                if cond0.apply_witness(witness, from) {
                     Some(Box::new(BudgetExpr::After(cond1.clone(), sub_expr.clone())))
-                } else if cond1.is_satisfied(witness, from) {
+                } else if cond1.apply_witness(witness, from) {
                     Some(Box::new(BudgetExpr::After(cond0.clone(), sub_expr.clone())))
                 } else {
                     None
@@ -387,4 +530,64 @@ mod tests {
         expr.apply_witness(&Witness::Signature, &from0);
         assert_eq!(expr, BudgetExpr::new_authorized_payment(&from1, 42, &to));
     }
+
+    #[test]
+    fn test_m_of_n_multisig_payment() {
+        let signers: Vec<_> = (0..3).map(|_| solana_sdk::pubkey::new_rand()).collect();
+        let mallory = solana_sdk::pubkey::new_rand();
+        let to = Pubkey::default();
+
+        let mut expr = BudgetExpr::new_multisig_payment(2, &signers, 42, &to);
+        assert!(expr.verify(42));
+
+        // Unknown and repeated signatures don't count toward the threshold
+        expr.apply_witness(&Witness::Signature, &mallory);
+        expr.apply_witness(&Witness::Signature, &signers[2]);
+        expr.apply_witness(&Witness::Signature, &signers[2]);
+        assert_eq!(expr.final_payment(), None);
+        assert_eq!(
+            expr,
+            BudgetExpr::After(
+                Condition::MultiSig {
+                    threshold: 2,
+                    signers: signers.clone(),
+                    witnessed: vec![false, false, true],
+                },
+                Box::new(BudgetExpr::new_payment(42, &to)),
+            )
+        );
+
+        expr.apply_witness(&Witness::Signature, &signers[0]);
+        assert_eq!(expr, BudgetExpr::new_payment(42, &to));
+    }
+
+    #[test]
+    fn test_invalid_multisig() {
+        let signers: Vec<_> = (0..2).map(|_| solana_sdk::pubkey::new_rand()).collect();
+        let to = Pubkey::default();
+        assert!(!BudgetExpr::new_multisig_payment(0, &signers, 42, &to).verify(42));
+        assert!(!BudgetExpr::new_multisig_payment(3, &signers, 42, &to).verify(42));
+        assert!(BudgetExpr::new_multisig_payment(2, &signers, 42, &to).verify(42));
+
+        let expr = BudgetExpr::After(
+            Condition::MultiSig {
+                threshold: 1,
+                signers,
+                witnessed: vec![],
+            },
+            Box::new(BudgetExpr::new_payment(42, &to)),
+        );
+        assert!(!expr.verify(42));
+    }
+
+    #[test]
+    fn test_slot_and_epoch_satisfied() {
+        let from = Pubkey::default();
+        let clock = |slot, epoch| Witness::Clock { slot, epoch };
+        assert!(!Condition::Slot(10).is_satisfied(&clock(9, 5), &from));
+        assert!(Condition::Slot(10).is_satisfied(&clock(10, 0), &from));
+        assert!(!Condition::Epoch(2).is_satisfied(&clock(100, 1), &from));
+        assert!(Condition::Epoch(2).is_satisfied(&clock(0, 3), &from));
+        assert!(!Condition::Slot(0).is_satisfied(&Witness::Signature, &from));
+    }
 }
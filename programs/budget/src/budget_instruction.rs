@@ -4,11 +4,12 @@ use chrono::prelude::{DateTime, Utc};
 use num_derive::{FromPrimitive, ToPrimitive};
 use serde_derive::{Deserialize, Serialize};
 use solana_sdk::{
+    clock::{Epoch, Slot},
     decode_error::DecodeError,
     hash::Hash,
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
-    system_instruction,
+    system_instruction, sysvar,
 };
 use thiserror::Error;
 
@@ -16,6 +17,9 @@ use thiserror::Error;
 pub enum BudgetError {
     #[error("destination missing")]
     DestinationMissing,
+
+    #[error("budget contains a condition that can never be satisfied")]
+    InvalidCondition,
 }
 
 impl<T> DecodeError<T> for BudgetError {
@@ -39,6 +43,9 @@ pub enum BudgetInstruction {
 
     /// Load an account and pass its data to the budget for inspection.
     ApplyAccountData,
+
+    /// Load the Clock sysvar and pass the current slot and epoch to the budget.
+    ApplyClock,
 }
 
 fn initialize_account(contract: &Pubkey, expr: BudgetExpr) -> Instruction {
@@ -103,6 +110,43 @@ pub fn when_signed(
     create_account(from, contract, lamports, expr)
 }
 
+/// Create an M-of-N multisig payment script.
+pub fn when_multisig(
+    from: &Pubkey,
+    to: &Pubkey,
+    contract: &Pubkey,
+    threshold: u8,
+    signers: &[Pubkey],
+    lamports: u64,
+) -> Vec<Instruction> {
+    let expr = BudgetExpr::new_multisig_payment(threshold, signers, lamports, to);
+    create_account(from, contract, lamports, expr)
+}
+
+/// Create a payment script that pays out once the bank reaches `slot`.
+pub fn after_slot(
+    from: &Pubkey,
+    to: &Pubkey,
+    contract: &Pubkey,
+    slot: Slot,
+    lamports: u64,
+) -> Vec<Instruction> {
+    let expr = BudgetExpr::new_payment_after_slot(slot, lamports, to);
+    create_account(from, contract, lamports, expr)
+}
+
+/// Create a payment script that pays out once the bank reaches `epoch`.
+pub fn after_epoch(
+    from: &Pubkey,
+    to: &Pubkey,
+    contract: &Pubkey,
+    epoch: Epoch,
+    lamports: u64,
+) -> Vec<Instruction> {
+    let expr = BudgetExpr::new_payment_after_epoch(epoch, lamports, to);
+    create_account(from, contract, lamports, expr)
+}
+
 /// Make a payment when an account has the given data
 pub fn when_account_data(
     from: &Pubkey,
@@ -160,6 +204,16 @@ pub fn apply_account_data(witness_pubkey: &Pubkey, contract: &Pubkey, to: &Pubke
     Instruction::new(id(), &BudgetInstruction::ApplyAccountData, account_metas)
 }
 
+/// Apply the current slot and epoch to a contract waiting on a Slot or Epoch condition.
+pub fn apply_clock(contract: &Pubkey, to: &Pubkey) -> Instruction {
+    let account_metas = vec![
+        AccountMeta::new_readonly(sysvar::clock::id(), false),
+        AccountMeta::new(*contract, false),
+        AccountMeta::new(*to, false),
+    ];
+    Instruction::new(id(), &BudgetInstruction::ApplyClock, account_metas)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -192,4 +246,20 @@ mod tests {
         let expr = BudgetExpr::new_payment(1, &bob_pubkey);
         create_account(&alice_pubkey, &budget_pubkey, 2, expr);
     }
+
+    #[test]
+    #[should_panic]
+    fn test_budget_instruction_unreachable_multisig() {
+        let alice_pubkey = solana_sdk::pubkey::new_rand();
+        let bob_pubkey = solana_sdk::pubkey::new_rand();
+        let budget_pubkey = solana_sdk::pubkey::new_rand();
+        when_multisig(
+            &alice_pubkey,
+            &bob_pubkey,
+            &budget_pubkey,
+            2,
+            &[alice_pubkey],
+            1,
+        );
+    }
 }
@@ -7,9 +7,10 @@ use crate::{
 use chrono::prelude::{DateTime, Utc};
 use log::*;
 use solana_sdk::{
+    clock::Clock,
     hash::hash,
     instruction::InstructionError,
-    keyed_account::{next_keyed_account, KeyedAccount},
+    keyed_account::{from_keyed_account, next_keyed_account, KeyedAccount},
     process_instruction::InvokeContext,
     program_utils::limited_deserialize,
     pubkey::Pubkey,
@@ -113,6 +114,38 @@ fn apply_account_data(
     Ok(())
 }
 
+/// Process a Clock Witness and any payment waiting on the current slot or epoch.
+fn apply_clock(
+    budget_state: &mut BudgetState,
+    clock_keyed_account: &KeyedAccount,
+    contract_keyed_account: &KeyedAccount,
+    to_keyed_account: Result<&KeyedAccount, InstructionError>,
+) -> Result<(), InstructionError> {
+    let mut final_payment = None;
+
+    if let Some(ref mut expr) = budget_state.pending_budget {
+        let clock = from_keyed_account::<Clock>(clock_keyed_account)?;
+        let witness = Witness::Clock {
+            slot: clock.slot,
+            epoch: clock.epoch,
+        };
+        expr.apply_witness(&witness, clock_keyed_account.unsigned_key());
+        final_payment = expr.final_payment();
+    }
+
+    if let Some(payment) = final_payment {
+        let to_keyed_account = to_keyed_account?;
+        if &payment.to != to_keyed_account.unsigned_key() {
+            trace!("destination missing");
+            return Err(BudgetError::DestinationMissing.into());
+        }
+        budget_state.pending_budget = None;
+        contract_keyed_account.try_account_ref_mut()?.lamports -= payment.lamports;
+        to_keyed_account.try_account_ref_mut()?.lamports += payment.lamports;
+    }
+    Ok(())
+}
+
 pub fn process_instruction(
     _program_id: &Pubkey,
     keyed_accounts: &[KeyedAccount],
@@ -141,6 +174,10 @@ pub fn process_instruction(
                 trace!("contract already exists");
                 return Err(InstructionError::AccountAlreadyInitialized);
             }
+            if !expr.has_valid_conditions() {
+                trace!("contract has unsatisfiable conditions");
+                return Err(BudgetError::InvalidCondition.into());
+            }
             let budget_state = BudgetState {
                 pending_budget: Some(*expr),
                 initialized: true,
@@ -219,24 +256,49 @@ pub fn process_instruction(
             trace!("apply account data committed");
             budget_state.serialize(&mut contract_keyed_account.try_account_ref_mut()?.data)
         }
+        BudgetInstruction::ApplyClock => {
+            let clock_keyed_account = next_keyed_account(keyed_accounts_iter)?;
+            let contract_keyed_account = next_keyed_account(keyed_accounts_iter)?;
+            let mut budget_state =
+                BudgetState::deserialize(&contract_keyed_account.try_account_ref()?.data)?;
+            if !budget_state.is_pending() {
+                return Ok(()); // Nothing to do here.
+            }
+            if !budget_state.initialized {
+                trace!("contract is uninitialized");
+                return Err(InstructionError::UninitializedAccount);
+            }
+            apply_clock(
+                &mut budget_state,
+                clock_keyed_account,
+                contract_keyed_account,
+                next_keyed_account(keyed_accounts_iter),
+            )?;
+            trace!("apply clock committed");
+            budget_state.serialize(&mut contract_keyed_account.try_account_ref_mut()?.data)
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::budget_expr::BudgetExpr;
     use crate::budget_instruction;
     use crate::id;
     use solana_runtime::bank::Bank;
     use solana_runtime::bank_client::BankClient;
     use solana_sdk::account::Account;
     use solana_sdk::client::SyncClient;
+    use solana_sdk::clock::Slot;
     use solana_sdk::genesis_config::create_genesis_config;
     use solana_sdk::hash::hash;
-    use solana_sdk::instruction::InstructionError;
+    use solana_sdk::instruction::{AccountMeta, Instruction, InstructionError};
     use solana_sdk::message::Message;
     use solana_sdk::signature::{Keypair, Signer};
+    use solana_sdk::system_instruction;
     use solana_sdk::transaction::TransactionError;
+    use std::sync::Arc;
 
     fn create_bank(lamports: u64) -> (Bank, Keypair) {
         let (genesis_config, mint_keypair) = create_genesis_config(lamports);
@@ -245,6 +307,15 @@ mod tests {
         (bank, mint_keypair)
     }
 
+    fn new_bank_at_slot(parent: &Arc<Bank>, slot: Slot) -> Arc<Bank> {
+        // Complete the parent so that the child has a new blockhash and repeated
+        // ApplyClock transactions don't have duplicate signatures.
+        while !parent.is_complete() {
+            parent.register_tick(&hash(&parent.slot().to_le_bytes()));
+        }
+        Arc::new(Bank::new_from_parent(parent, &Pubkey::default(), slot))
+    }
+
     #[test]
     fn test_initialize_no_panic() {
         let (bank, alice_keypair) = create_bank(1);
@@ -583,4 +654,175 @@ mod tests {
         assert_eq!(bank_client.get_balance(&bob_pubkey).unwrap(), 42);
         assert_eq!(bank_client.get_account_data(&budget_pubkey).unwrap(), None);
     }
+
+    #[test]
+    fn test_pay_when_multisig() {
+        let (bank, alice_keypair) = create_bank(2);
+        let bank_client = BankClient::new(bank);
+        let alice_pubkey = alice_keypair.pubkey();
+        let budget_keypair = Keypair::new();
+        let budget_pubkey = budget_keypair.pubkey();
+        let bob_pubkey = solana_sdk::pubkey::new_rand();
+        let signer_keypairs: Vec<_> = (0..3).map(|_| Keypair::new()).collect();
+        let signers: Vec<_> = signer_keypairs.iter().map(|k| k.pubkey()).collect();
+
+        let instructions = budget_instruction::when_multisig(
+            &alice_pubkey,
+            &bob_pubkey,
+            &budget_pubkey,
+            2,
+            &signers,
+            1,
+        );
+        let message = Message::new(&instructions, Some(&alice_pubkey));
+        bank_client
+            .send_and_confirm_message(&[&alice_keypair, &budget_keypair], message)
+            .unwrap();
+
+        // The first signature is recorded, but doesn't release the payment
+        let instruction =
+            budget_instruction::apply_signature(&signers[1], &budget_pubkey, &bob_pubkey);
+        let message = Message::new(&[instruction], Some(&alice_pubkey));
+        bank_client
+            .send_and_confirm_message(&[&alice_keypair, &signer_keypairs[1]], message)
+            .unwrap();
+        assert_eq!(bank_client.get_balance(&budget_pubkey).unwrap(), 1);
+        assert_eq!(bank_client.get_balance(&bob_pubkey).unwrap(), 0);
+
+        let contract_account = bank_client
+            .get_account_data(&budget_pubkey)
+            .unwrap()
+            .unwrap();
+        let budget_state = BudgetState::deserialize(&contract_account).unwrap();
+        assert!(budget_state.is_pending());
+
+        // A second signer completes the payment
+        let instruction =
+            budget_instruction::apply_signature(&signers[2], &budget_pubkey, &bob_pubkey);
+        let message = Message::new(&[instruction], Some(&alice_pubkey));
+        bank_client
+            .send_and_confirm_message(&[&alice_keypair, &signer_keypairs[2]], message)
+            .unwrap();
+        assert_eq!(bank_client.get_balance(&budget_pubkey).unwrap(), 0);
+        assert_eq!(bank_client.get_balance(&bob_pubkey).unwrap(), 1);
+        assert_eq!(bank_client.get_account_data(&budget_pubkey).unwrap(), None);
+    }
+
+    #[test]
+    fn test_initialize_invalid_multisig() {
+        let (bank, alice_keypair) = create_bank(2);
+        let bank_client = BankClient::new(bank);
+        let alice_pubkey = alice_keypair.pubkey();
+        let budget_keypair = Keypair::new();
+        let budget_pubkey = budget_keypair.pubkey();
+        let bob_pubkey = solana_sdk::pubkey::new_rand();
+
+        // Bypass the client-side check in budget_instruction::create_account
+        let expr = BudgetExpr::new_multisig_payment(2, &[alice_pubkey], 1, &bob_pubkey);
+        let space = bincode::serialized_size(&BudgetState::new(expr.clone())).unwrap();
+        let instructions = vec![
+            system_instruction::create_account(&alice_pubkey, &budget_pubkey, 1, space, &id()),
+            Instruction::new(
+                id(),
+                &BudgetInstruction::InitializeAccount(Box::new(expr)),
+                vec![AccountMeta::new(budget_pubkey, false)],
+            ),
+        ];
+        let message = Message::new(&instructions, Some(&alice_pubkey));
+        assert_eq!(
+            bank_client
+                .send_and_confirm_message(&[&alice_keypair, &budget_keypair], message)
+                .unwrap_err()
+                .unwrap(),
+            TransactionError::InstructionError(
+                1,
+                InstructionError::Custom(BudgetError::InvalidCondition as u32)
+            )
+        );
+    }
+
+    #[test]
+    fn test_pay_after_slot() {
+        let (bank, alice_keypair) = create_bank(2);
+        let bank = Arc::new(bank);
+        let bank_client = BankClient::new_shared(&bank);
+        let alice_pubkey = alice_keypair.pubkey();
+        let budget_keypair = Keypair::new();
+        let budget_pubkey = budget_keypair.pubkey();
+        let bob_pubkey = solana_sdk::pubkey::new_rand();
+
+        let instructions =
+            budget_instruction::after_slot(&alice_pubkey, &bob_pubkey, &budget_pubkey, 2, 1);
+        let message = Message::new(&instructions, Some(&alice_pubkey));
+        bank_client
+            .send_and_confirm_message(&[&alice_keypair, &budget_keypair], message)
+            .unwrap();
+
+        // Too early; nothing happens
+        let instruction = budget_instruction::apply_clock(&budget_pubkey, &bob_pubkey);
+        bank_client
+            .send_and_confirm_instruction(&alice_keypair, instruction)
+            .unwrap();
+        assert_eq!(bank_client.get_balance(&budget_pubkey).unwrap(), 1);
+        assert_eq!(bank_client.get_balance(&bob_pubkey).unwrap(), 0);
+
+        // Only the real Clock sysvar is accepted as a witness
+        let mallory_pubkey = solana_sdk::pubkey::new_rand();
+        let mut instruction = budget_instruction::apply_clock(&budget_pubkey, &bob_pubkey);
+        instruction.accounts[0] = AccountMeta::new_readonly(mallory_pubkey, false);
+        assert_eq!(
+            bank_client
+                .send_and_confirm_instruction(&alice_keypair, instruction)
+                .unwrap_err()
+                .unwrap(),
+            TransactionError::InstructionError(0, InstructionError::InvalidArgument)
+        );
+
+        let bank = new_bank_at_slot(&bank, 2);
+        let bank_client = BankClient::new_shared(&bank);
+        let instruction = budget_instruction::apply_clock(&budget_pubkey, &bob_pubkey);
+        bank_client
+            .send_and_confirm_instruction(&alice_keypair, instruction)
+            .unwrap();
+        assert_eq!(bank_client.get_balance(&budget_pubkey).unwrap(), 0);
+        assert_eq!(bank_client.get_balance(&bob_pubkey).unwrap(), 1);
+        assert_eq!(bank_client.get_account_data(&budget_pubkey).unwrap(), None);
+    }
+
+    #[test]
+    fn test_pay_after_epoch() {
+        let (bank, alice_keypair) = create_bank(2);
+        let bank = Arc::new(bank);
+        let bank_client = BankClient::new_shared(&bank);
+        let alice_pubkey = alice_keypair.pubkey();
+        let budget_keypair = Keypair::new();
+        let budget_pubkey = budget_keypair.pubkey();
+        let bob_pubkey = solana_sdk::pubkey::new_rand();
+
+        let instructions =
+            budget_instruction::after_epoch(&alice_pubkey, &bob_pubkey, &budget_pubkey, 1, 1);
+        let message = Message::new(&instructions, Some(&alice_pubkey));
+        bank_client
+            .send_and_confirm_message(&[&alice_keypair, &budget_keypair], message)
+            .unwrap();
+
+        // The last slot of epoch 0 is still too early
+        let first_slot = bank.epoch_schedule().get_first_slot_in_epoch(1);
+        let bank = new_bank_at_slot(&bank, first_slot - 1);
+        let bank_client = BankClient::new_shared(&bank);
+        let instruction = budget_instruction::apply_clock(&budget_pubkey, &bob_pubkey);
+        bank_client
+            .send_and_confirm_instruction(&alice_keypair, instruction)
+            .unwrap();
+        assert_eq!(bank_client.get_balance(&bob_pubkey).unwrap(), 0);
+
+        let bank = new_bank_at_slot(&bank, first_slot);
+        let bank_client = BankClient::new_shared(&bank);
+        let instruction = budget_instruction::apply_clock(&budget_pubkey, &bob_pubkey);
+        bank_client
+            .send_and_confirm_instruction(&alice_keypair, instruction)
+            .unwrap();
+        assert_eq!(bank_client.get_balance(&budget_pubkey).unwrap(), 0);
+        assert_eq!(bank_client.get_balance(&bob_pubkey).unwrap(), 1);
+    }
 }
@@ -48,6 +48,12 @@ pub enum StakeError {
 
     #[error("custodian signature not present")]
     CustodianSignatureMissing,
+
+    #[error("only fully active stake can be redelegated")]
+    RedelegateTransientOrInactiveStake,
+
+    #[error("stake is already delegated to this vote account")]
+    RedelegateToSameVoteAccount,
 }
 
 impl<E> DecodeError<E> for StakeError {
@@ -166,6 +172,52 @@ pub enum StakeInstruction {
     ///   3. Optional: [SIGNER] Lockup authority, if updating StakeAuthorize::Withdrawer before
     ///      lockup expiration
     AuthorizeWithSeed(AuthorizeWithSeedArgs),
+
+    /// Redelegate the active stake of a stake account to another vote account
+    ///
+    /// # Account references
+    ///   0. [WRITE] Fully active stake account to be redelegated
+    ///   1. [WRITE] Uninitialized stake account that will hold the redelegated stake
+    ///   2. [] Vote account to which the stake will be redelegated
+    ///   3. [] Clock sysvar
+    ///   4. [] Stake history sysvar that carries stake warmup/cooldown history
+    ///   5. [] Address of config account that carries stake config
+    ///   6. [SIGNER] Stake authority
+    ///
+    /// The effective stake is moved into the uninitialized account and delegated to the
+    ///   new vote account in the current epoch. The source delegation is deactivated, so
+    ///   the stake warms up on the new validator while it cools down on the old one,
+    ///   rather than sitting idle for a full cooldown. The source account keeps its
+    ///   rent-exempt reserve and any undelegated lamports.
+    Redelegate,
+
+    /// Authorize a key to manage stake or withdrawal
+    ///
+    /// This instruction behaves like `Authorize` with the additional requirement that the new
+    /// stake or withdraw authority must also be a signer.
+    ///
+    /// # Account references
+    ///   0. [WRITE] Stake account to be updated
+    ///   1. [] Clock sysvar
+    ///   2. [SIGNER] The stake or withdraw authority
+    ///   3. [SIGNER] The new stake or withdraw authority
+    ///   4. Optional: [SIGNER] Lockup authority, if updating StakeAuthorize::Withdrawer before
+    ///      lockup expiration
+    AuthorizeChecked(StakeAuthorize),
+
+    /// Authorize a key to manage stake or withdrawal with a derived key
+    ///
+    /// This instruction behaves like `AuthorizeWithSeed` with the additional requirement that
+    /// the new stake or withdraw authority must also be a signer.
+    ///
+    /// # Account references
+    ///   0. [WRITE] Stake account to be updated
+    ///   1. [SIGNER] Base key of stake or withdraw authority
+    ///   2. [] Clock sysvar
+    ///   3. [SIGNER] The new stake or withdraw authority
+    ///   4. Optional: [SIGNER] Lockup authority, if updating StakeAuthorize::Withdrawer before
+    ///      lockup expiration
+    AuthorizeCheckedWithSeed(AuthorizeCheckedWithSeedArgs),
 }
 
 #[derive(Default, Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
@@ -183,6 +235,13 @@ pub struct AuthorizeWithSeedArgs {
     pub authority_owner: Pubkey,
 }
 
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct AuthorizeCheckedWithSeedArgs {
+    pub stake_authorize: StakeAuthorize,
+    pub authority_seed: String,
+    pub authority_owner: Pubkey,
+}
+
 fn initialize(stake_pubkey: &Pubkey, authorized: &Authorized, lockup: &Lockup) -> Instruction {
     Instruction::new(
         id(),
@@ -422,6 +481,64 @@ pub fn authorize_with_seed(
     )
 }
 
+pub fn authorize_checked(
+    stake_pubkey: &Pubkey,
+    authorized_pubkey: &Pubkey,
+    new_authorized_pubkey: &Pubkey,
+    stake_authorize: StakeAuthorize,
+    custodian_pubkey: Option<&Pubkey>,
+) -> Instruction {
+    let mut account_metas = vec![
+        AccountMeta::new(*stake_pubkey, false),
+        AccountMeta::new_readonly(sysvar::clock::id(), false),
+        AccountMeta::new_readonly(*authorized_pubkey, true),
+        AccountMeta::new_readonly(*new_authorized_pubkey, true),
+    ];
+
+    if let Some(custodian_pubkey) = custodian_pubkey {
+        account_metas.push(AccountMeta::new_readonly(*custodian_pubkey, true));
+    }
+
+    Instruction::new(
+        id(),
+        &StakeInstruction::AuthorizeChecked(stake_authorize),
+        account_metas,
+    )
+}
+
+pub fn authorize_checked_with_seed(
+    stake_pubkey: &Pubkey,
+    authority_base: &Pubkey,
+    authority_seed: String,
+    authority_owner: &Pubkey,
+    new_authorized_pubkey: &Pubkey,
+    stake_authorize: StakeAuthorize,
+    custodian_pubkey: Option<&Pubkey>,
+) -> Instruction {
+    let mut account_metas = vec![
+        AccountMeta::new(*stake_pubkey, false),
+        AccountMeta::new_readonly(*authority_base, true),
+        AccountMeta::new_readonly(sysvar::clock::id(), false),
+        AccountMeta::new_readonly(*new_authorized_pubkey, true),
+    ];
+
+    if let Some(custodian_pubkey) = custodian_pubkey {
+        account_metas.push(AccountMeta::new_readonly(*custodian_pubkey, true));
+    }
+
+    let args = AuthorizeCheckedWithSeedArgs {
+        stake_authorize,
+        authority_seed,
+        authority_owner: *authority_owner,
+    };
+
+    Instruction::new(
+        id(),
+        &StakeInstruction::AuthorizeCheckedWithSeed(args),
+        account_metas,
+    )
+}
+
 pub fn delegate_stake(
     stake_pubkey: &Pubkey,
     authorized_pubkey: &Pubkey,
@@ -438,6 +555,74 @@ pub fn delegate_stake(
     Instruction::new(id(), &StakeInstruction::DelegateStake, account_metas)
 }
 
+fn _redelegate(
+    stake_pubkey: &Pubkey,
+    authorized_pubkey: &Pubkey,
+    vote_pubkey: &Pubkey,
+    uninitialized_stake_pubkey: &Pubkey,
+) -> Instruction {
+    let account_metas = vec![
+        AccountMeta::new(*stake_pubkey, false),
+        AccountMeta::new(*uninitialized_stake_pubkey, false),
+        AccountMeta::new_readonly(*vote_pubkey, false),
+        AccountMeta::new_readonly(sysvar::clock::id(), false),
+        AccountMeta::new_readonly(sysvar::stake_history::id(), false),
+        AccountMeta::new_readonly(crate::config::id(), false),
+        AccountMeta::new_readonly(*authorized_pubkey, true),
+    ];
+    Instruction::new(id(), &StakeInstruction::Redelegate, account_metas)
+}
+
+pub fn redelegate(
+    stake_pubkey: &Pubkey,
+    authorized_pubkey: &Pubkey,
+    vote_pubkey: &Pubkey,
+    uninitialized_stake_pubkey: &Pubkey,
+) -> Vec<Instruction> {
+    vec![
+        system_instruction::create_account(
+            authorized_pubkey, // Sending 0, so any signer will suffice
+            uninitialized_stake_pubkey,
+            0,
+            std::mem::size_of::<StakeState>() as u64,
+            &id(),
+        ),
+        _redelegate(
+            stake_pubkey,
+            authorized_pubkey,
+            vote_pubkey,
+            uninitialized_stake_pubkey,
+        ),
+    ]
+}
+
+pub fn redelegate_with_seed(
+    stake_pubkey: &Pubkey,
+    authorized_pubkey: &Pubkey,
+    vote_pubkey: &Pubkey,
+    uninitialized_stake_pubkey: &Pubkey, // derived using create_with_seed()
+    base: &Pubkey,                       // base
+    seed: &str,                          // seed
+) -> Vec<Instruction> {
+    vec![
+        system_instruction::create_account_with_seed(
+            authorized_pubkey, // Sending 0, so any signer will suffice
+            uninitialized_stake_pubkey,
+            base,
+            seed,
+            0,
+            std::mem::size_of::<StakeState>() as u64,
+            &id(),
+        ),
+        _redelegate(
+            stake_pubkey,
+            authorized_pubkey,
+            vote_pubkey,
+            uninitialized_stake_pubkey,
+        ),
+    ]
+}
+
 pub fn withdraw(
     stake_pubkey: &Pubkey,
     withdrawer_pubkey: &Pubkey,
@@ -614,17 +799,79 @@ pub fn process_instruction(
         ),
 
         StakeInstruction::SetLockup(lockup) => me.set_lockup(&lockup, &signers),
+        StakeInstruction::Redelegate => {
+            if !invoke_context.is_feature_active(&feature_set::stake_redelegate_instruction::id()) {
+                return Err(InstructionError::InvalidInstructionData);
+            }
+            let uninitialized_stake = &next_keyed_account(keyed_accounts)?;
+            let vote = next_keyed_account(keyed_accounts)?;
+
+            me.redelegate(
+                uninitialized_stake,
+                &vote,
+                &from_keyed_account::<Clock>(next_keyed_account(keyed_accounts)?)?,
+                &from_keyed_account::<StakeHistory>(next_keyed_account(keyed_accounts)?)?,
+                &config::from_keyed_account(next_keyed_account(keyed_accounts)?)?,
+                &signers,
+            )
+        }
+        StakeInstruction::AuthorizeChecked(stake_authorize) => {
+            if !invoke_context.is_feature_active(&feature_set::authorize_checked_instructions::id())
+            {
+                return Err(InstructionError::InvalidInstructionData);
+            }
+            let clock = from_keyed_account::<Clock>(next_keyed_account(keyed_accounts)?)?;
+            let _current_authority = next_keyed_account(keyed_accounts)?;
+            let authorized_pubkey = next_keyed_account(keyed_accounts)?
+                .signer_key()
+                .ok_or(InstructionError::MissingRequiredSignature)?;
+            let custodian = keyed_accounts.next().map(|ka| ka.unsigned_key());
+
+            me.authorize(
+                &signers,
+                authorized_pubkey,
+                stake_authorize,
+                true,
+                &clock,
+                custodian,
+            )
+        }
+        StakeInstruction::AuthorizeCheckedWithSeed(args) => {
+            if !invoke_context.is_feature_active(&feature_set::authorize_checked_instructions::id())
+            {
+                return Err(InstructionError::InvalidInstructionData);
+            }
+            let authority_base = next_keyed_account(keyed_accounts)?;
+            let clock = from_keyed_account::<Clock>(next_keyed_account(keyed_accounts)?)?;
+            let authorized_pubkey = next_keyed_account(keyed_accounts)?
+                .signer_key()
+                .ok_or(InstructionError::MissingRequiredSignature)?;
+            let custodian = keyed_accounts.next().map(|ka| ka.unsigned_key());
+
+            me.authorize_with_seed(
+                &authority_base,
+                &args.authority_seed,
+                &args.authority_owner,
+                authorized_pubkey,
+                args.stake_authorize,
+                true,
+                &clock,
+                custodian,
+            )
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::stake_state::Meta;
     use bincode::serialize;
     use solana_sdk::{
         account::{self, Account},
         process_instruction::MockInvokeContext,
         rent::Rent,
+        system_program,
         sysvar::stake_history::StakeHistory,
     };
     use std::cell::RefCell;
@@ -774,6 +1021,17 @@ mod tests {
             )),
             Err(InstructionError::InvalidAccountData),
         );
+        assert_eq!(
+            process_instruction(
+                &redelegate(
+                    &Pubkey::default(),
+                    &Pubkey::default(),
+                    &invalid_vote_state_pubkey(),
+                    &invalid_stake_state_pubkey(),
+                )[1]
+            ),
+            Err(InstructionError::InvalidAccountData),
+        );
         assert_eq!(
             process_instruction(&withdraw(
                 &Pubkey::default(),
@@ -1125,6 +1383,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_authorize_checked() {
+        let stake_address = solana_sdk::pubkey::new_rand();
+        let authorized_address = solana_sdk::pubkey::new_rand();
+        let new_authorized_address = solana_sdk::pubkey::new_rand();
+        let clock_address = sysvar::clock::id();
+        let clock_account = RefCell::new(account::create_account(&Clock::default(), 1));
+        let authorized_account = create_default_account();
+        let new_authorized_account = create_default_account();
+        let stake_account = RefCell::new(
+            Account::new_data_with_space(
+                42,
+                &StakeState::Initialized(Meta {
+                    authorized: Authorized::auto(&authorized_address),
+                    ..Meta::default()
+                }),
+                std::mem::size_of::<StakeState>(),
+                &id(),
+            )
+            .unwrap(),
+        );
+
+        // Test AuthorizeChecked with non-signing new authority
+        let mut instruction = authorize_checked(
+            &stake_address,
+            &authorized_address,
+            &new_authorized_address,
+            StakeAuthorize::Staker,
+            None,
+        );
+        instruction.accounts[3] = AccountMeta::new_readonly(new_authorized_address, false);
+        assert_eq!(
+            process_instruction(&instruction),
+            Err(InstructionError::MissingRequiredSignature),
+        );
+
+        // Test AuthorizeCheckedWithSeed with non-signing new authority
+        let mut instruction = authorize_checked_with_seed(
+            &stake_address,
+            &authorized_address,
+            "test".to_string(),
+            &system_program::id(),
+            &new_authorized_address,
+            StakeAuthorize::Staker,
+            None,
+        );
+        instruction.accounts[3] = AccountMeta::new_readonly(new_authorized_address, false);
+        assert_eq!(
+            process_instruction(&instruction),
+            Err(InstructionError::MissingRequiredSignature),
+        );
+
+        // Test AuthorizeChecked with signing new authority
+        let keyed_accounts = vec![
+            KeyedAccount::new(&stake_address, false, &stake_account),
+            KeyedAccount::new(&clock_address, false, &clock_account),
+            KeyedAccount::new(&authorized_address, true, &authorized_account),
+            KeyedAccount::new(&new_authorized_address, true, &new_authorized_account),
+        ];
+        assert_eq!(
+            super::process_instruction(
+                &Pubkey::default(),
+                &keyed_accounts,
+                &serialize(&StakeInstruction::AuthorizeChecked(
+                    StakeAuthorize::Withdrawer
+                ))
+                .unwrap(),
+                &mut MockInvokeContext::default()
+            ),
+            Ok(()),
+        );
+        assert_eq!(
+            StakeState::authorized_from(&stake_account.borrow())
+                .unwrap()
+                .withdrawer,
+            new_authorized_address
+        );
+    }
+
     #[test]
     fn test_custom_error_decode() {
         use num_traits::FromPrimitive;
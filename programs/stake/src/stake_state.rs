@@ -865,6 +865,15 @@ pub trait StakeAccount {
         signers: &HashSet<Pubkey>,
     ) -> Result<(), InstructionError>;
     fn deactivate(&self, clock: &Clock, signers: &HashSet<Pubkey>) -> Result<(), InstructionError>;
+    fn redelegate(
+        &self,
+        uninitialized_stake: &KeyedAccount,
+        vote_account: &KeyedAccount,
+        clock: &Clock,
+        stake_history: &StakeHistory,
+        config: &Config,
+        signers: &HashSet<Pubkey>,
+    ) -> Result<(), InstructionError>;
     fn set_lockup(
         &self,
         lockup: &LockupArgs,
@@ -1041,6 +1050,83 @@ impl<'a> StakeAccount for KeyedAccount<'a> {
             Err(InstructionError::InvalidAccountData)
         }
     }
+    /// Move the active stake of this account into `uninitialized_stake`, delegated to
+    /// `vote_account`. This account keeps its rent-exempt reserve and any undelegated
+    /// lamports, and its delegation is deactivated in the current epoch, so stake history
+    /// sees the old delegation cool down while the new one warms up.
+    fn redelegate(
+        &self,
+        uninitialized_stake: &KeyedAccount,
+        vote_account: &KeyedAccount,
+        clock: &Clock,
+        stake_history: &StakeHistory,
+        config: &Config,
+        signers: &HashSet<Pubkey>,
+    ) -> Result<(), InstructionError> {
+        if vote_account.owner()? != solana_vote_program::id() {
+            return Err(InstructionError::IncorrectProgramId);
+        }
+        if uninitialized_stake.owner()? != id() {
+            return Err(InstructionError::IncorrectProgramId);
+        }
+        if uninitialized_stake.unsigned_key() == self.unsigned_key() {
+            return Err(InstructionError::InvalidArgument);
+        }
+        if uninitialized_stake.data_len()? != std::mem::size_of::<StakeState>() {
+            return Err(InstructionError::InvalidAccountData);
+        }
+        if StakeState::Uninitialized != uninitialized_stake.state()? {
+            return Err(InstructionError::InvalidAccountData);
+        }
+
+        let (meta, mut stake) = match self.state()? {
+            StakeState::Stake(meta, stake) => (meta, stake),
+            _ => return Err(InstructionError::InvalidAccountData),
+        };
+        meta.authorized.check(signers, StakeAuthorize::Staker)?;
+
+        let (effective_stake, activating_stake, deactivating_stake) = stake
+            .delegation
+            .stake_activating_and_deactivating(clock.epoch, Some(stake_history), true);
+        if effective_stake == 0 || activating_stake != 0 || deactivating_stake != 0 {
+            return Err(StakeError::RedelegateTransientOrInactiveStake.into());
+        }
+        if stake.delegation.voter_pubkey == *vote_account.unsigned_key() {
+            return Err(StakeError::RedelegateToSameVoteAccount.into());
+        }
+        if self.lamports()? < effective_stake + meta.rent_exempt_reserve {
+            return Err(InstructionError::InsufficientFunds);
+        }
+
+        let redelegated_rent_exempt_reserve = calculate_split_rent_exempt_reserve(
+            meta.rent_exempt_reserve,
+            self.data_len()? as u64,
+            uninitialized_stake.data_len()? as u64,
+        );
+        let redelegated_lamports = uninitialized_stake.lamports()? + effective_stake;
+        if redelegated_lamports < redelegated_rent_exempt_reserve + MIN_DELEGATE_STAKE_AMOUNT {
+            return Err(InstructionError::InsufficientFunds);
+        }
+        let redelegated_meta = Meta {
+            rent_exempt_reserve: redelegated_rent_exempt_reserve,
+            ..meta
+        };
+        let redelegated_stake = Stake::new(
+            redelegated_lamports - redelegated_rent_exempt_reserve, // can't stake the rent ;)
+            vote_account.unsigned_key(),
+            &State::<VoteStateVersions>::state(vote_account)?.convert_to_current(),
+            clock.epoch,
+            config,
+        );
+
+        stake.deactivate(clock.epoch)?;
+        self.set_state(&StakeState::Stake(meta, stake))?;
+        uninitialized_stake.set_state(&StakeState::Stake(redelegated_meta, redelegated_stake))?;
+
+        self.try_account_ref_mut()?.lamports -= effective_stake;
+        uninitialized_stake.try_account_ref_mut()?.lamports += effective_stake;
+        Ok(())
+    }
     fn set_lockup(
         &self,
         lockup: &LockupArgs,
@@ -2087,6 +2173,201 @@ mod tests {
             .is_err());
     }
 
+    #[test]
+    fn test_redelegate() {
+        let clock = Clock {
+            epoch: 1,
+            ..Clock::default()
+        };
+        let stake_history = StakeHistory::default();
+        let config = Config::default();
+
+        let create_vote_account = |vote_pubkey: &Pubkey| {
+            RefCell::new(vote_state::create_account(
+                vote_pubkey,
+                &solana_sdk::pubkey::new_rand(),
+                0,
+                100,
+            ))
+        };
+        let vote_pubkey = solana_sdk::pubkey::new_rand();
+        let vote_account = create_vote_account(&vote_pubkey);
+        let vote_keyed_account = KeyedAccount::new(&vote_pubkey, false, &vote_account);
+        let new_vote_pubkey = solana_sdk::pubkey::new_rand();
+        let new_vote_account = create_vote_account(&new_vote_pubkey);
+        let new_vote_keyed_account = KeyedAccount::new(&new_vote_pubkey, false, &new_vote_account);
+
+        let stake_pubkey = solana_sdk::pubkey::new_rand();
+        let rent_exempt_reserve = 2_282_880;
+        let stake_lamports = 1_000_000_000;
+        let undelegated_lamports = 42;
+        let meta = Meta {
+            rent_exempt_reserve,
+            ..Meta::auto(&stake_pubkey)
+        };
+        let stake = Stake {
+            delegation: Delegation {
+                voter_pubkey: vote_pubkey,
+                stake: stake_lamports,
+                activation_epoch: 0,
+                ..Delegation::default()
+            },
+            ..Stake::default()
+        };
+        let stake_account = Account::new_ref_data_with_space(
+            rent_exempt_reserve + stake_lamports + undelegated_lamports,
+            &StakeState::Stake(meta, stake),
+            std::mem::size_of::<StakeState>(),
+            &id(),
+        )
+        .expect("stake_account");
+        let stake_keyed_account = KeyedAccount::new(&stake_pubkey, true, &stake_account);
+
+        let new_stake_pubkey = solana_sdk::pubkey::new_rand();
+        let new_stake_account = Account::new_ref_data_with_space(
+            0,
+            &StakeState::Uninitialized,
+            std::mem::size_of::<StakeState>(),
+            &id(),
+        )
+        .expect("stake_account");
+        let new_stake_keyed_account =
+            KeyedAccount::new(&new_stake_pubkey, true, &new_stake_account);
+
+        // unsigned
+        assert_eq!(
+            stake_keyed_account.redelegate(
+                &new_stake_keyed_account,
+                &new_vote_keyed_account,
+                &clock,
+                &stake_history,
+                &config,
+                &HashSet::default(),
+            ),
+            Err(InstructionError::MissingRequiredSignature)
+        );
+
+        let signers = vec![stake_pubkey].into_iter().collect();
+
+        // same vote account
+        assert_eq!(
+            stake_keyed_account.redelegate(
+                &new_stake_keyed_account,
+                &vote_keyed_account,
+                &clock,
+                &stake_history,
+                &config,
+                &signers,
+            ),
+            Err(StakeError::RedelegateToSameVoteAccount.into())
+        );
+
+        // stake that is still activating can't be redelegated
+        assert_eq!(
+            stake_keyed_account.redelegate(
+                &new_stake_keyed_account,
+                &new_vote_keyed_account,
+                &Clock::default(),
+                &stake_history,
+                &config,
+                &signers,
+            ),
+            Err(StakeError::RedelegateTransientOrInactiveStake.into())
+        );
+
+        assert_eq!(
+            stake_keyed_account.redelegate(
+                &new_stake_keyed_account,
+                &new_vote_keyed_account,
+                &clock,
+                &stake_history,
+                &config,
+                &signers,
+            ),
+            Ok(())
+        );
+
+        // the source keeps its reserve and undelegated lamports, and cools down
+        assert_eq!(
+            stake_keyed_account.lamports().unwrap(),
+            rent_exempt_reserve + undelegated_lamports
+        );
+        assert_eq!(
+            stake_keyed_account.state(),
+            Ok(StakeState::Stake(
+                meta,
+                Stake {
+                    delegation: Delegation {
+                        deactivation_epoch: clock.epoch,
+                        ..stake.delegation
+                    },
+                    ..stake
+                }
+            ))
+        );
+
+        // the new account warms up on the new vote account
+        assert_eq!(new_stake_keyed_account.lamports().unwrap(), stake_lamports);
+        assert_eq!(
+            new_stake_keyed_account.state(),
+            Ok(StakeState::Stake(
+                meta,
+                Stake {
+                    delegation: Delegation {
+                        voter_pubkey: new_vote_pubkey,
+                        stake: stake_lamports - rent_exempt_reserve,
+                        activation_epoch: clock.epoch,
+                        ..Delegation::default()
+                    },
+                    ..Stake::default()
+                }
+            ))
+        );
+
+        // neither account is fully active any more
+        let another_stake_account = Account::new_ref_data_with_space(
+            0,
+            &StakeState::Uninitialized,
+            std::mem::size_of::<StakeState>(),
+            &id(),
+        )
+        .expect("stake_account");
+        let another_stake_keyed_account = KeyedAccount::new(
+            &solana_sdk::pubkey::new_rand(),
+            true,
+            &another_stake_account,
+        );
+        for keyed_account in &[&stake_keyed_account, &new_stake_keyed_account] {
+            assert_eq!(
+                keyed_account.redelegate(
+                    &another_stake_keyed_account,
+                    &vote_keyed_account,
+                    &clock,
+                    &stake_history,
+                    &config,
+                    &signers,
+                ),
+                Err(StakeError::RedelegateTransientOrInactiveStake.into())
+            );
+        }
+
+        // the uninitialized account must really be uninitialized
+        stake_keyed_account
+            .set_state(&StakeState::Stake(meta, stake))
+            .unwrap();
+        assert_eq!(
+            stake_keyed_account.redelegate(
+                &new_stake_keyed_account,
+                &vote_keyed_account,
+                &clock,
+                &stake_history,
+                &config,
+                &signers,
+            ),
+            Err(InstructionError::InvalidAccountData)
+        );
+    }
+
     fn create_stake_history_from_delegations(
         bootstrap: Option<u64>,
         epochs: std::ops::Range<Epoch>,
@@ -0,0 +1,8 @@
+#![allow(clippy::integer_arithmetic)]
+pub mod vaccount_instruction;
+pub mod vaccount_processor;
+pub mod vaccount_state;
+
+pub use vaccount_state::{Operational, OperationalState, VAccountInfo, VAccountStorage, Whitelist};
+
+solana_sdk::declare_id!("VAcccHVjpknkW5N5R9sfRppQxYJrJYVV7QJGKchkQj5");
@@ -0,0 +1,283 @@
+use crate::{
+    id,
+    vaccount_state::{Operational, VAccountInfo},
+};
+use num_derive::FromPrimitive;
+use serde_derive::{Deserialize, Serialize};
+use solana_sdk::{
+    decode_error::DecodeError,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::{Pubkey, PubkeyError},
+    system_instruction,
+};
+use thiserror::Error;
+
+/// Seed used to derive vaccount address from its genesis key.
+pub const VACCOUNT_SEED: &str = "vaccount";
+
+#[derive(Error, Debug, Clone, PartialEq, FromPrimitive)]
+pub enum VAccountError {
+    #[error("vaccount address is not derived from genesis key")]
+    InvalidVAccountAddress,
+
+    #[error("storage doesn't belong to vaccount")]
+    InvalidStorage,
+
+    #[error("signer is not allowed to run this instruction")]
+    Unauthorized,
+
+    #[error("vaccount should have at least one owner")]
+    LastOwner,
+
+    #[error("key is already registered in vaccount")]
+    AlreadyRegistered,
+
+    #[error("key is not registered in vaccount")]
+    NotRegistered,
+}
+
+impl From<VAccountError> for InstructionError {
+    fn from(e: VAccountError) -> Self {
+        InstructionError::Custom(e as u32)
+    }
+}
+
+impl<T> DecodeError<T> for VAccountError {
+    fn type_of() -> &'static str {
+        "VAccountError"
+    }
+}
+
+/// Instructions of vaccount program.
+/// Discriminant of instruction is used as a scope of operational keys.
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub enum VAccountInstruction {
+    /// Initialize vaccount and its storage. Genesis key becomes the first owner.
+    ///
+    /// # Account references
+    ///   0. [WRITE] VAccount, created with `VACCOUNT_SEED` from genesis key
+    ///   1. [WRITE] Uninitialized storage account
+    ///   2. [SIGNER] Genesis seed key
+    Initialize,
+
+    /// Add owners to vaccount.
+    ///
+    /// # Account references
+    ///   0. [] VAccount
+    ///   1. [WRITE] VAccount storage
+    ///   2. [SIGNER] Owner
+    AddOwners(Vec<Pubkey>),
+
+    /// Remove owner from vaccount, the last owner can't be removed.
+    ///
+    /// # Account references
+    ///   0. [] VAccount
+    ///   1. [WRITE] VAccount storage
+    ///   2. [SIGNER] Owner
+    RemoveOwner(Pubkey),
+
+    /// Register new operational key with its scopes.
+    /// Operational keys can grant only their own scopes and whitelisted programs,
+    /// master keys are registered by owners only.
+    ///
+    /// # Account references
+    ///   0. [] VAccount
+    ///   1. [WRITE] VAccount storage
+    ///   2. [SIGNER] Owner or authorized operational key
+    AddOperational(Operational),
+
+    /// Remove operational key, master keys are removed by owners only.
+    ///
+    /// # Account references
+    ///   0. [] VAccount
+    ///   1. [WRITE] VAccount storage
+    ///   2. [SIGNER] Owner or authorized operational key
+    RemoveOperational(Pubkey),
+
+    /// Move storage into a new, usually bigger, account.
+    /// Lamports of the old storage are moved to the new one.
+    ///
+    /// # Account references
+    ///   0. [WRITE] VAccount
+    ///   1. [WRITE] Current VAccount storage
+    ///   2. [WRITE] Uninitialized new storage account
+    ///   3. [SIGNER] Owner or authorized operational key
+    ReplenishStorage,
+
+    /// Transfer lamports from vaccount, lets owners and operational keys sign native transfers.
+    ///
+    /// # Account references
+    ///   0. [WRITE] VAccount
+    ///   1. [] VAccount storage
+    ///   2. [SIGNER] Owner or authorized operational key
+    ///   3. [WRITE] Recipient
+    Transfer(u64),
+}
+
+impl VAccountInstruction {
+    /// Scope that operational key should have, to run this instruction.
+    pub fn scope(&self) -> u8 {
+        match self {
+            VAccountInstruction::Initialize => 0,
+            VAccountInstruction::AddOwners(_) => 1,
+            VAccountInstruction::RemoveOwner(_) => 2,
+            VAccountInstruction::AddOperational(_) => 3,
+            VAccountInstruction::RemoveOperational(_) => 4,
+            VAccountInstruction::ReplenishStorage => 5,
+            VAccountInstruction::Transfer(_) => 6,
+        }
+    }
+}
+
+/// Address of vaccount, generated by genesis key.
+pub fn vaccount_address(genesis_seed_key: &Pubkey) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_with_seed(genesis_seed_key, VACCOUNT_SEED, &id())
+}
+
+/// Create vaccount from genesis key, and storage account of `storage_space` bytes.
+pub fn create_account(
+    payer: &Pubkey,
+    genesis_seed_key: &Pubkey,
+    storage: &Pubkey,
+    lamports: u64,
+    storage_lamports: u64,
+    storage_space: u64,
+) -> Vec<Instruction> {
+    let vaccount = vaccount_address(genesis_seed_key).expect("Seed is shorter than limit");
+    vec![
+        system_instruction::create_account_with_seed(
+            payer,
+            &vaccount,
+            genesis_seed_key,
+            VACCOUNT_SEED,
+            lamports,
+            VAccountInfo::LEN as u64,
+            &id(),
+        ),
+        system_instruction::create_account(payer, storage, storage_lamports, storage_space, &id()),
+        initialize(&vaccount, storage, genesis_seed_key),
+    ]
+}
+
+pub fn initialize(vaccount: &Pubkey, storage: &Pubkey, genesis_seed_key: &Pubkey) -> Instruction {
+    let account_metas = vec![
+        AccountMeta::new(*vaccount, false),
+        AccountMeta::new(*storage, false),
+        AccountMeta::new_readonly(*genesis_seed_key, true),
+    ];
+    Instruction::new(id(), &VAccountInstruction::Initialize, account_metas)
+}
+
+fn update_storage(
+    vaccount: &Pubkey,
+    storage: &Pubkey,
+    signer: &Pubkey,
+    instruction: VAccountInstruction,
+) -> Instruction {
+    let account_metas = vec![
+        AccountMeta::new_readonly(*vaccount, false),
+        AccountMeta::new(*storage, false),
+        AccountMeta::new_readonly(*signer, true),
+    ];
+    Instruction::new(id(), &instruction, account_metas)
+}
+
+pub fn add_owners(
+    vaccount: &Pubkey,
+    storage: &Pubkey,
+    signer: &Pubkey,
+    owners: Vec<Pubkey>,
+) -> Instruction {
+    update_storage(
+        vaccount,
+        storage,
+        signer,
+        VAccountInstruction::AddOwners(owners),
+    )
+}
+
+pub fn remove_owner(
+    vaccount: &Pubkey,
+    storage: &Pubkey,
+    signer: &Pubkey,
+    owner: &Pubkey,
+) -> Instruction {
+    update_storage(
+        vaccount,
+        storage,
+        signer,
+        VAccountInstruction::RemoveOwner(*owner),
+    )
+}
+
+pub fn add_operational(
+    vaccount: &Pubkey,
+    storage: &Pubkey,
+    signer: &Pubkey,
+    operational: Operational,
+) -> Instruction {
+    update_storage(
+        vaccount,
+        storage,
+        signer,
+        VAccountInstruction::AddOperational(operational),
+    )
+}
+
+pub fn remove_operational(
+    vaccount: &Pubkey,
+    storage: &Pubkey,
+    signer: &Pubkey,
+    operational: &Pubkey,
+) -> Instruction {
+    update_storage(
+        vaccount,
+        storage,
+        signer,
+        VAccountInstruction::RemoveOperational(*operational),
+    )
+}
+
+/// Create new storage account of `storage_space` bytes, and move vaccount storage into it.
+/// `lamports` are added to the lamports of the current storage.
+pub fn replenish_storage(
+    payer: &Pubkey,
+    vaccount: &Pubkey,
+    storage: &Pubkey,
+    new_storage: &Pubkey,
+    signer: &Pubkey,
+    lamports: u64,
+    storage_space: u64,
+) -> Vec<Instruction> {
+    let account_metas = vec![
+        AccountMeta::new(*vaccount, false),
+        AccountMeta::new(*storage, false),
+        AccountMeta::new(*new_storage, false),
+        AccountMeta::new_readonly(*signer, true),
+    ];
+    vec![
+        system_instruction::create_account(payer, new_storage, lamports, storage_space, &id()),
+        Instruction::new(id(), &VAccountInstruction::ReplenishStorage, account_metas),
+    ]
+}
+
+/// Transfer `lamports` from vaccount to `recipient`, signed by owner or operational key.
+pub fn transfer(
+    vaccount: &Pubkey,
+    storage: &Pubkey,
+    signer: &Pubkey,
+    recipient: &Pubkey,
+    lamports: u64,
+) -> Instruction {
+    let account_metas = vec![
+        AccountMeta::new(*vaccount, false),
+        AccountMeta::new_readonly(*storage, false),
+        AccountMeta::new_readonly(*signer, true),
+        AccountMeta::new(*recipient, false),
+    ];
+    Instruction::new(
+        id(),
+        &VAccountInstruction::Transfer(lamports),
+        account_metas,
+    )
+}
@@ -0,0 +1,734 @@
+//! Velas account program
+
+use crate::{
+    vaccount_instruction::{vaccount_address, VAccountError, VAccountInstruction},
+    vaccount_state::{VAccountInfo, VAccountStorage},
+};
+use solana_sdk::{
+    instruction::InstructionError,
+    keyed_account::{next_keyed_account, KeyedAccount},
+    process_instruction::InvokeContext,
+    program_utils::limited_deserialize,
+    pubkey::Pubkey,
+};
+
+fn check_owner(keyed_account: &KeyedAccount) -> Result<(), InstructionError> {
+    if keyed_account.owner()? != crate::id() {
+        return Err(InstructionError::InvalidAccountOwner);
+    }
+    Ok(())
+}
+
+/// Read vaccount info, and check that `storage` is the current storage of vaccount.
+fn verify_vaccount(
+    vaccount: &KeyedAccount,
+    storage: &KeyedAccount,
+) -> Result<VAccountInfo, InstructionError> {
+    check_owner(vaccount)?;
+    check_owner(storage)?;
+    let info = VAccountInfo::unpack(&vaccount.try_account_ref()?.data)?
+        .ok_or(InstructionError::UninitializedAccount)?;
+    if info.storage != *storage.unsigned_key() {
+        return Err(VAccountError::InvalidStorage.into());
+    }
+    Ok(info)
+}
+
+/// Check that signer is allowed to run `instruction` on vaccount with `storage`.
+fn verify_signer<'a>(
+    storage: &VAccountStorage,
+    signer: &'a KeyedAccount,
+    instruction: &VAccountInstruction,
+) -> Result<&'a Pubkey, InstructionError> {
+    let signer_key = signer
+        .signer_key()
+        .ok_or(InstructionError::MissingRequiredSignature)?;
+    if !storage.is_vaccount_scope_allowed(signer_key, instruction.scope()) {
+        return Err(VAccountError::Unauthorized.into());
+    }
+    Ok(signer_key)
+}
+
+/// Check that storage update doesn't give signer more privileges than it has.
+/// Only owners can manage owners and master keys,
+/// operational keys can register keys only with a part of their own privileges.
+fn verify_privileges(
+    storage: &VAccountStorage,
+    signer_key: &Pubkey,
+    instruction: &VAccountInstruction,
+) -> Result<(), InstructionError> {
+    if storage.is_owner(signer_key) {
+        return Ok(());
+    }
+    let allowed = match instruction {
+        VAccountInstruction::AddOwners(_) | VAccountInstruction::RemoveOwner(_) => false,
+        VAccountInstruction::AddOperational(operational) => storage
+            .find_operational(signer_key)
+            .map_or(false, |signer| signer.covers(operational)),
+        VAccountInstruction::RemoveOperational(pubkey) => storage
+            .find_operational(pubkey)
+            .map_or(true, |operational| !operational.is_master_key),
+        _ => true,
+    };
+    if !allowed {
+        return Err(VAccountError::Unauthorized.into());
+    }
+    Ok(())
+}
+
+/// Check that `signer` is allowed to sign for `vaccount` in instructions of `program_id` with `scope`.
+/// Used by other programs, to accept operational keys of vaccount in place of its signature.
+pub fn verify_operational_signer(
+    vaccount: &KeyedAccount,
+    storage: &KeyedAccount,
+    signer: &KeyedAccount,
+    program_id: &Pubkey,
+    scope: Option<u8>,
+) -> Result<(), InstructionError> {
+    verify_vaccount(vaccount, storage)?;
+    let signer_key = signer
+        .signer_key()
+        .ok_or(InstructionError::MissingRequiredSignature)?;
+    let vaccount_storage = VAccountStorage::unpack(&storage.try_account_ref()?.data)?;
+    if !vaccount_storage.is_program_scope_allowed(signer_key, program_id, scope) {
+        return Err(VAccountError::Unauthorized.into());
+    }
+    Ok(())
+}
+
+fn initialize(
+    vaccount: &KeyedAccount,
+    storage: &KeyedAccount,
+    genesis_seed_key: &KeyedAccount,
+) -> Result<(), InstructionError> {
+    let genesis_key = genesis_seed_key
+        .signer_key()
+        .ok_or(InstructionError::MissingRequiredSignature)?;
+    if vaccount_address(genesis_key).ok() != Some(*vaccount.unsigned_key()) {
+        return Err(VAccountError::InvalidVAccountAddress.into());
+    }
+    check_owner(vaccount)?;
+    check_owner(storage)?;
+
+    let mut vaccount_account = vaccount.try_account_ref_mut()?;
+    if VAccountInfo::unpack(&vaccount_account.data)?.is_some() {
+        return Err(InstructionError::AccountAlreadyInitialized);
+    }
+    let mut storage_account = storage.try_account_ref_mut()?;
+    if VAccountStorage::unpack(&storage_account.data)?.is_initialized() {
+        return Err(InstructionError::AccountAlreadyInitialized);
+    }
+
+    let vaccount_storage = VAccountStorage {
+        owners: vec![*genesis_key],
+        operationals: vec![],
+    };
+    vaccount_storage.pack(&mut storage_account.data)?;
+    VAccountInfo::new(*genesis_key, *storage.unsigned_key()).pack(&mut vaccount_account.data)
+}
+
+fn update_storage(
+    storage: &mut VAccountStorage,
+    instruction: VAccountInstruction,
+) -> Result<(), InstructionError> {
+    match instruction {
+        VAccountInstruction::AddOwners(owners) => {
+            for owner in owners {
+                if storage.is_owner(&owner) {
+                    return Err(VAccountError::AlreadyRegistered.into());
+                }
+                storage.owners.push(owner);
+            }
+        }
+        VAccountInstruction::RemoveOwner(owner) => {
+            if !storage.is_owner(&owner) {
+                return Err(VAccountError::NotRegistered.into());
+            }
+            if storage.owners.len() == 1 {
+                return Err(VAccountError::LastOwner.into());
+            }
+            storage.owners.retain(|key| *key != owner);
+        }
+        VAccountInstruction::AddOperational(operational) => {
+            if storage.find_operational(&operational.pubkey).is_some() {
+                return Err(VAccountError::AlreadyRegistered.into());
+            }
+            storage.operationals.push(operational);
+        }
+        VAccountInstruction::RemoveOperational(pubkey) => {
+            if storage.find_operational(&pubkey).is_none() {
+                return Err(VAccountError::NotRegistered.into());
+            }
+            storage
+                .operationals
+                .retain(|operational| operational.pubkey != pubkey);
+        }
+        VAccountInstruction::Initialize
+        | VAccountInstruction::ReplenishStorage
+        | VAccountInstruction::Transfer(_) => return Err(InstructionError::InvalidInstructionData),
+    }
+    Ok(())
+}
+
+fn replenish_storage(
+    vaccount: &KeyedAccount,
+    storage: &KeyedAccount,
+    new_storage: &KeyedAccount,
+    signer: &KeyedAccount,
+) -> Result<(), InstructionError> {
+    let mut info = verify_vaccount(vaccount, storage)?;
+    check_owner(new_storage)?;
+    if storage.unsigned_key() == new_storage.unsigned_key() {
+        return Err(InstructionError::InvalidArgument);
+    }
+
+    let vaccount_storage = VAccountStorage::unpack(&storage.try_account_ref()?.data)?;
+    verify_signer(
+        &vaccount_storage,
+        signer,
+        &VAccountInstruction::ReplenishStorage,
+    )?;
+
+    let mut new_storage_account = new_storage.try_account_ref_mut()?;
+    if VAccountStorage::unpack(&new_storage_account.data)?.is_initialized() {
+        return Err(InstructionError::AccountAlreadyInitialized);
+    }
+    vaccount_storage.pack(&mut new_storage_account.data)?;
+
+    let mut storage_account = storage.try_account_ref_mut()?;
+    new_storage_account.lamports = new_storage_account
+        .lamports
+        .checked_add(storage_account.lamports)
+        .ok_or(InstructionError::InsufficientFunds)?;
+    storage_account.lamports = 0;
+    for byte in storage_account.data.iter_mut() {
+        *byte = 0;
+    }
+
+    info.storage = *new_storage.unsigned_key();
+    info.storage_version = info.storage_version.wrapping_add(1);
+    info.pack(&mut vaccount.try_account_ref_mut()?.data)
+}
+
+fn transfer(
+    vaccount: &KeyedAccount,
+    storage: &KeyedAccount,
+    signer: &KeyedAccount,
+    recipient: &KeyedAccount,
+    lamports: u64,
+) -> Result<(), InstructionError> {
+    verify_vaccount(vaccount, storage)?;
+    let vaccount_storage = VAccountStorage::unpack(&storage.try_account_ref()?.data)?;
+    verify_signer(
+        &vaccount_storage,
+        signer,
+        &VAccountInstruction::Transfer(lamports),
+    )?;
+
+    let mut vaccount_account = vaccount.try_account_ref_mut()?;
+    vaccount_account.lamports = vaccount_account
+        .lamports
+        .checked_sub(lamports)
+        .ok_or(InstructionError::InsufficientFunds)?;
+    let mut recipient_account = recipient.try_account_ref_mut()?;
+    recipient_account.lamports = recipient_account
+        .lamports
+        .checked_add(lamports)
+        .ok_or(InstructionError::InsufficientFunds)?;
+    Ok(())
+}
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    keyed_accounts: &[KeyedAccount],
+    data: &[u8],
+    _invoke_context: &mut dyn InvokeContext,
+) -> Result<(), InstructionError> {
+    let instruction: VAccountInstruction = limited_deserialize(data)?;
+    let keyed_accounts_iter = &mut keyed_accounts.iter();
+    let vaccount = next_keyed_account(keyed_accounts_iter)?;
+    let storage = next_keyed_account(keyed_accounts_iter)?;
+
+    match instruction {
+        VAccountInstruction::Initialize => {
+            let genesis_seed_key = next_keyed_account(keyed_accounts_iter)?;
+            initialize(vaccount, storage, genesis_seed_key)
+        }
+        VAccountInstruction::ReplenishStorage => {
+            let new_storage = next_keyed_account(keyed_accounts_iter)?;
+            let signer = next_keyed_account(keyed_accounts_iter)?;
+            replenish_storage(vaccount, storage, new_storage, signer)
+        }
+        VAccountInstruction::Transfer(lamports) => {
+            let signer = next_keyed_account(keyed_accounts_iter)?;
+            let recipient = next_keyed_account(keyed_accounts_iter)?;
+            transfer(vaccount, storage, signer, recipient, lamports)
+        }
+        instruction => {
+            let signer = next_keyed_account(keyed_accounts_iter)?;
+            verify_vaccount(vaccount, storage)?;
+
+            let mut storage_account = storage.try_account_ref_mut()?;
+            let mut vaccount_storage = VAccountStorage::unpack(&storage_account.data)?;
+            let signer_key = verify_signer(&vaccount_storage, signer, &instruction)?;
+            verify_privileges(&vaccount_storage, signer_key, &instruction)?;
+            update_storage(&mut vaccount_storage, instruction)?;
+            vaccount_storage.pack(&mut storage_account.data)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        vaccount_instruction,
+        vaccount_state::{Operational, OperationalState, Whitelist},
+    };
+    use solana_runtime::{bank::Bank, bank_client::BankClient};
+    use solana_sdk::{
+        client::SyncClient,
+        genesis_config::create_genesis_config,
+        instruction::Instruction,
+        message::Message,
+        signature::{Keypair, Signer},
+        system_instruction,
+        transaction::TransactionError,
+        transport::{Result, TransportError},
+    };
+
+    const STORAGE_SPACE: u64 = 128;
+
+    fn create_bank_client(lamports: u64) -> (BankClient, Keypair) {
+        let (genesis_config, mint_keypair) = create_genesis_config(lamports);
+        let mut bank = Bank::new(&genesis_config);
+        bank.add_builtin("velas_account_program", crate::id(), process_instruction);
+        (BankClient::new(bank), mint_keypair)
+    }
+
+    fn send(
+        bank_client: &BankClient,
+        signers: &[&Keypair],
+        instructions: &[Instruction],
+    ) -> Result<()> {
+        let message = Message::new(instructions, Some(&signers[0].pubkey()));
+        bank_client.send_and_confirm_message(signers, message)?;
+        Ok(())
+    }
+
+    fn create_vaccount(
+        bank_client: &BankClient,
+        payer: &Keypair,
+        genesis: &Keypair,
+        storage: &Keypair,
+    ) -> Pubkey {
+        let instructions = vaccount_instruction::create_account(
+            &payer.pubkey(),
+            &genesis.pubkey(),
+            &storage.pubkey(),
+            1,
+            1,
+            STORAGE_SPACE,
+        );
+        send(bank_client, &[payer, genesis, storage], &instructions).unwrap();
+        vaccount_instruction::vaccount_address(&genesis.pubkey()).unwrap()
+    }
+
+    fn get_storage(bank_client: &BankClient, storage: &Pubkey) -> VAccountStorage {
+        let data = bank_client.get_account_data(storage).unwrap().unwrap();
+        VAccountStorage::unpack(&data).unwrap()
+    }
+
+    fn custom_error(err: TransportError, index: u8) -> Option<InstructionError> {
+        match err.unwrap() {
+            TransactionError::InstructionError(i, err) if i == index => Some(err),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_vaccount_initialize() {
+        let (bank_client, payer) = create_bank_client(10_000);
+        let genesis = Keypair::new();
+        let storage = Keypair::new();
+        let vaccount = create_vaccount(&bank_client, &payer, &genesis, &storage);
+
+        let data = bank_client.get_account_data(&vaccount).unwrap().unwrap();
+        let info = VAccountInfo::unpack(&data).unwrap().unwrap();
+        assert_eq!(info, VAccountInfo::new(genesis.pubkey(), storage.pubkey()));
+        assert_eq!(
+            get_storage(&bank_client, &storage.pubkey()).owners,
+            vec![genesis.pubkey()]
+        );
+
+        // Second initialize is rejected
+        let instruction =
+            vaccount_instruction::initialize(&vaccount, &storage.pubkey(), &genesis.pubkey());
+        let err = send(&bank_client, &[&payer, &genesis], &[instruction]).unwrap_err();
+        assert_eq!(
+            custom_error(err, 0),
+            Some(InstructionError::AccountAlreadyInitialized)
+        );
+    }
+
+    #[test]
+    fn test_vaccount_owners() {
+        let (bank_client, payer) = create_bank_client(10_000);
+        let genesis = Keypair::new();
+        let storage = Keypair::new();
+        let vaccount = create_vaccount(&bank_client, &payer, &genesis, &storage);
+        let owner = Keypair::new();
+
+        let instruction = vaccount_instruction::add_owners(
+            &vaccount,
+            &storage.pubkey(),
+            &genesis.pubkey(),
+            vec![owner.pubkey()],
+        );
+        send(&bank_client, &[&payer, &genesis], &[instruction]).unwrap();
+        assert!(get_storage(&bank_client, &storage.pubkey()).is_owner(&owner.pubkey()));
+
+        // New owner can remove genesis key, but not the last owner
+        let instruction = vaccount_instruction::remove_owner(
+            &vaccount,
+            &storage.pubkey(),
+            &owner.pubkey(),
+            &genesis.pubkey(),
+        );
+        send(&bank_client, &[&payer, &owner], &[instruction]).unwrap();
+        let instruction = vaccount_instruction::remove_owner(
+            &vaccount,
+            &storage.pubkey(),
+            &owner.pubkey(),
+            &owner.pubkey(),
+        );
+        let err = send(&bank_client, &[&payer, &owner], &[instruction]).unwrap_err();
+        assert_eq!(custom_error(err, 0), Some(VAccountError::LastOwner.into()));
+
+        // Removed owner has no access
+        let instruction = vaccount_instruction::add_owners(
+            &vaccount,
+            &storage.pubkey(),
+            &genesis.pubkey(),
+            vec![Pubkey::new_unique()],
+        );
+        let err = send(&bank_client, &[&payer, &genesis], &[instruction]).unwrap_err();
+        assert_eq!(
+            custom_error(err, 0),
+            Some(VAccountError::Unauthorized.into())
+        );
+    }
+
+    #[test]
+    fn test_vaccount_operational_scopes() {
+        let (bank_client, payer) = create_bank_client(10_000);
+        let genesis = Keypair::new();
+        let storage = Keypair::new();
+        let vaccount = create_vaccount(&bank_client, &payer, &genesis, &storage);
+        let operational = Keypair::new();
+
+        let instruction = vaccount_instruction::add_operational(
+            &vaccount,
+            &storage.pubkey(),
+            &genesis.pubkey(),
+            Operational {
+                pubkey: operational.pubkey(),
+                state: OperationalState::Initialized,
+                scopes: vec![VAccountInstruction::AddOwners(vec![]).scope()],
+                ..Operational::default()
+            },
+        );
+        send(&bank_client, &[&payer, &genesis], &[instruction]).unwrap();
+
+        // Owners are managed only by owners, even if operational key has the scope
+        let instruction = vaccount_instruction::add_owners(
+            &vaccount,
+            &storage.pubkey(),
+            &operational.pubkey(),
+            vec![Pubkey::new_unique()],
+        );
+        let err = send(&bank_client, &[&payer, &operational], &[instruction]).unwrap_err();
+        assert_eq!(
+            custom_error(err, 0),
+            Some(VAccountError::Unauthorized.into())
+        );
+
+        let instruction = vaccount_instruction::remove_owner(
+            &vaccount,
+            &storage.pubkey(),
+            &operational.pubkey(),
+            &genesis.pubkey(),
+        );
+        let err = send(&bank_client, &[&payer, &operational], &[instruction]).unwrap_err();
+        assert_eq!(
+            custom_error(err, 0),
+            Some(VAccountError::Unauthorized.into())
+        );
+
+        let instruction = vaccount_instruction::remove_operational(
+            &vaccount,
+            &storage.pubkey(),
+            &genesis.pubkey(),
+            &operational.pubkey(),
+        );
+        send(&bank_client, &[&payer, &genesis], &[instruction]).unwrap();
+        assert!(get_storage(&bank_client, &storage.pubkey())
+            .operationals
+            .is_empty());
+    }
+
+    #[test]
+    fn test_vaccount_operational_privilege_escalation() {
+        let (bank_client, payer) = create_bank_client(10_000);
+        let genesis = Keypair::new();
+        let storage = Keypair::new();
+        let vaccount = create_vaccount(&bank_client, &payer, &genesis, &storage);
+        let operational = Keypair::new();
+        let master = Keypair::new();
+        let program_id = Pubkey::new_unique();
+        let add_operational_scope =
+            VAccountInstruction::AddOperational(Operational::default()).scope();
+        let transfer_scope = VAccountInstruction::Transfer(0).scope();
+
+        let instructions = vec![
+            vaccount_instruction::add_operational(
+                &vaccount,
+                &storage.pubkey(),
+                &genesis.pubkey(),
+                Operational {
+                    pubkey: operational.pubkey(),
+                    state: OperationalState::Initialized,
+                    scopes: vec![add_operational_scope, transfer_scope],
+                    whitelist_programs: vec![Whitelist {
+                        program_id,
+                        scopes: vec![7],
+                    }],
+                    ..Operational::default()
+                },
+            ),
+            vaccount_instruction::add_operational(
+                &vaccount,
+                &storage.pubkey(),
+                &genesis.pubkey(),
+                Operational {
+                    pubkey: master.pubkey(),
+                    state: OperationalState::Initialized,
+                    is_master_key: true,
+                    ..Operational::default()
+                },
+            ),
+        ];
+        send(&bank_client, &[&payer, &genesis], &instructions).unwrap();
+
+        let add_operational = |signer: &Keypair, operational: Operational| {
+            let instruction = vaccount_instruction::add_operational(
+                &vaccount,
+                &storage.pubkey(),
+                &signer.pubkey(),
+                Operational {
+                    pubkey: Pubkey::new_unique(),
+                    state: OperationalState::Initialized,
+                    ..operational
+                },
+            );
+            send(&bank_client, &[&payer, signer], &[instruction])
+        };
+        let assert_unauthorized = |result: Result<()>| {
+            assert_eq!(
+                custom_error(result.unwrap_err(), 0),
+                Some(VAccountError::Unauthorized.into())
+            );
+        };
+
+        // Operational key can share its own privileges
+        add_operational(
+            &operational,
+            Operational {
+                scopes: vec![transfer_scope],
+                whitelist_programs: vec![Whitelist {
+                    program_id,
+                    scopes: vec![7],
+                }],
+                ..Operational::default()
+            },
+        )
+        .unwrap();
+
+        // But not grant scopes, programs or program instructions it doesn't have
+        assert_unauthorized(add_operational(
+            &operational,
+            Operational {
+                scopes: vec![VAccountInstruction::ReplenishStorage.scope()],
+                ..Operational::default()
+            },
+        ));
+        assert_unauthorized(add_operational(
+            &operational,
+            Operational {
+                whitelist_programs: vec![Whitelist {
+                    program_id: Pubkey::new_unique(),
+                    scopes: vec![],
+                }],
+                ..Operational::default()
+            },
+        ));
+        assert_unauthorized(add_operational(
+            &operational,
+            Operational {
+                whitelist_programs: vec![Whitelist {
+                    program_id,
+                    scopes: vec![],
+                }],
+                ..Operational::default()
+            },
+        ));
+
+        // Master keys are registered only by owners
+        for signer in &[&operational, &master] {
+            assert_unauthorized(add_operational(
+                signer,
+                Operational {
+                    is_master_key: true,
+                    ..Operational::default()
+                },
+            ));
+        }
+
+        // Master key can't manage owners or other master keys
+        let instruction = vaccount_instruction::add_owners(
+            &vaccount,
+            &storage.pubkey(),
+            &master.pubkey(),
+            vec![master.pubkey()],
+        );
+        assert_unauthorized(send(&bank_client, &[&payer, &master], &[instruction]));
+        let instruction = vaccount_instruction::remove_owner(
+            &vaccount,
+            &storage.pubkey(),
+            &master.pubkey(),
+            &genesis.pubkey(),
+        );
+        assert_unauthorized(send(&bank_client, &[&payer, &master], &[instruction]));
+        let instruction = vaccount_instruction::remove_operational(
+            &vaccount,
+            &storage.pubkey(),
+            &master.pubkey(),
+            &master.pubkey(),
+        );
+        assert_unauthorized(send(&bank_client, &[&payer, &master], &[instruction]));
+
+        let storage = get_storage(&bank_client, &storage.pubkey());
+        assert_eq!(storage.owners, vec![genesis.pubkey()]);
+        assert_eq!(storage.operationals.len(), 3);
+    }
+
+    #[test]
+    fn test_vaccount_operational_transfer() {
+        let (bank_client, payer) = create_bank_client(10_000);
+        let genesis = Keypair::new();
+        let storage = Keypair::new();
+        let vaccount = create_vaccount(&bank_client, &payer, &genesis, &storage);
+        let operational = Keypair::new();
+        let recipient = Pubkey::new_unique();
+
+        let instructions = vec![
+            system_instruction::transfer(&payer.pubkey(), &vaccount, 100),
+            vaccount_instruction::add_operational(
+                &vaccount,
+                &storage.pubkey(),
+                &genesis.pubkey(),
+                Operational {
+                    pubkey: operational.pubkey(),
+                    state: OperationalState::Initialized,
+                    scopes: vec![VAccountInstruction::Transfer(0).scope()],
+                    ..Operational::default()
+                },
+            ),
+        ];
+        send(&bank_client, &[&payer, &genesis], &instructions).unwrap();
+
+        let instruction = vaccount_instruction::transfer(
+            &vaccount,
+            &storage.pubkey(),
+            &operational.pubkey(),
+            &recipient,
+            40,
+        );
+        send(&bank_client, &[&payer, &operational], &[instruction]).unwrap();
+        assert_eq!(bank_client.get_balance(&vaccount).unwrap(), 61);
+        assert_eq!(bank_client.get_balance(&recipient).unwrap(), 40);
+
+        // Unregistered key can't sign for vaccount
+        let stranger = Keypair::new();
+        let instruction = vaccount_instruction::transfer(
+            &vaccount,
+            &storage.pubkey(),
+            &stranger.pubkey(),
+            &recipient,
+            40,
+        );
+        let err = send(&bank_client, &[&payer, &stranger], &[instruction]).unwrap_err();
+        assert_eq!(
+            custom_error(err, 0),
+            Some(VAccountError::Unauthorized.into())
+        );
+
+        let instruction = vaccount_instruction::transfer(
+            &vaccount,
+            &storage.pubkey(),
+            &operational.pubkey(),
+            &recipient,
+            100,
+        );
+        let err = send(&bank_client, &[&payer, &operational], &[instruction]).unwrap_err();
+        assert_eq!(
+            custom_error(err, 0),
+            Some(InstructionError::InsufficientFunds)
+        );
+    }
+
+    #[test]
+    fn test_vaccount_replenish_storage() {
+        let (bank_client, payer) = create_bank_client(10_000);
+        let genesis = Keypair::new();
+        let storage = Keypair::new();
+        let vaccount = create_vaccount(&bank_client, &payer, &genesis, &storage);
+        let new_storage = Keypair::new();
+
+        let instructions = vaccount_instruction::replenish_storage(
+            &payer.pubkey(),
+            &vaccount,
+            &storage.pubkey(),
+            &new_storage.pubkey(),
+            &genesis.pubkey(),
+            1,
+            STORAGE_SPACE * 2,
+        );
+        send(
+            &bank_client,
+            &[&payer, &new_storage, &genesis],
+            &instructions,
+        )
+        .unwrap();
+
+        let data = bank_client.get_account_data(&vaccount).unwrap().unwrap();
+        let info = VAccountInfo::unpack(&data).unwrap().unwrap();
+        assert_eq!(info.storage, new_storage.pubkey());
+        assert_eq!(info.storage_version, 1);
+        assert_eq!(
+            get_storage(&bank_client, &new_storage.pubkey()).owners,
+            vec![genesis.pubkey()]
+        );
+        assert_eq!(bank_client.get_balance(&new_storage.pubkey()).unwrap(), 2);
+        assert_eq!(bank_client.get_balance(&storage.pubkey()).unwrap(), 0);
+
+        // Old storage can't be used anymore
+        let instruction = vaccount_instruction::add_owners(
+            &vaccount,
+            &storage.pubkey(),
+            &genesis.pubkey(),
+            vec![Pubkey::new_unique()],
+        );
+        assert!(send(&bank_client, &[&payer, &genesis], &[instruction]).is_err());
+    }
+}
@@ -0,0 +1,355 @@
+//! Velas account state
+use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use serde_derive::{Deserialize, Serialize};
+use solana_sdk::{instruction::InstructionError, pubkey::Pubkey};
+
+/// Current version of `VAccountInfo` layout
+pub const VACCOUNT_VERSION: u8 = 1;
+
+/// Program states.
+#[repr(C)]
+#[derive(
+    BorshSerialize, BorshDeserialize, BorshSchema, PartialEq, Debug, Clone, Serialize, Deserialize,
+)]
+pub struct VAccountInfo {
+    /// Vaccount version
+    pub version: u8,
+    /// Genegis owner key that generate Vaccount address
+    pub genesis_seed_key: Pubkey,
+    /// Storage version
+    pub storage_version: u16,
+    /// Storage address
+    pub storage: Pubkey,
+}
+
+impl VAccountInfo {
+    /// Serialized size of `VAccountInfo`
+    pub const LEN: usize = 1 + 32 + 2 + 32;
+
+    pub fn new(genesis_seed_key: Pubkey, storage: Pubkey) -> Self {
+        Self {
+            version: VACCOUNT_VERSION,
+            genesis_seed_key,
+            storage_version: 0,
+            storage,
+        }
+    }
+
+    /// Returns `None` if account was not initialized yet.
+    pub fn unpack(data: &[u8]) -> Result<Option<Self>, InstructionError> {
+        if data.len() != Self::LEN {
+            return Err(InstructionError::InvalidAccountData);
+        }
+        if data.iter().all(|b| *b == 0) {
+            return Ok(None);
+        }
+        Self::try_from_slice(data)
+            .map(Some)
+            .map_err(|_| InstructionError::InvalidAccountData)
+    }
+
+    pub fn pack(&self, data: &mut [u8]) -> Result<(), InstructionError> {
+        let bytes = self
+            .try_to_vec()
+            .map_err(|_| InstructionError::InvalidAccountData)?;
+        if data.len() != bytes.len() {
+            return Err(InstructionError::InvalidAccountData);
+        }
+        data.copy_from_slice(&bytes);
+        Ok(())
+    }
+}
+
+/// Storage of the basic Vaccount information.
+#[repr(C)]
+#[derive(
+    BorshSerialize,
+    BorshDeserialize,
+    BorshSchema,
+    PartialEq,
+    Debug,
+    Default,
+    Clone,
+    Serialize,
+    Deserialize,
+)]
+pub struct VAccountStorage {
+    /// Owner key in not extended VAccount
+    pub owners: Vec<Pubkey>,
+    /// Operational in not extended VAccount
+    pub operationals: Vec<Operational>,
+}
+
+impl VAccountStorage {
+    /// Storage is allocated by client, so its data can be longer than serialized state.
+    /// Zeroed data is decoded as storage without owners, which means storage is not initialized.
+    pub fn unpack(data: &[u8]) -> Result<Self, InstructionError> {
+        Self::deserialize(&mut &data[..]).map_err(|_| InstructionError::InvalidAccountData)
+    }
+
+    pub fn pack(&self, data: &mut [u8]) -> Result<(), InstructionError> {
+        let bytes = self
+            .try_to_vec()
+            .map_err(|_| InstructionError::InvalidAccountData)?;
+        if data.len() < bytes.len() {
+            return Err(InstructionError::AccountDataTooSmall);
+        }
+        data[..bytes.len()].copy_from_slice(&bytes);
+        for byte in &mut data[bytes.len()..] {
+            *byte = 0;
+        }
+        Ok(())
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        !self.owners.is_empty()
+    }
+
+    pub fn is_owner(&self, key: &Pubkey) -> bool {
+        self.owners.contains(key)
+    }
+
+    pub fn find_operational(&self, key: &Pubkey) -> Option<&Operational> {
+        self.operationals
+            .iter()
+            .find(|operational| operational.pubkey == *key)
+    }
+
+    /// Check that `key` is allowed to run vaccount instruction with `scope`.
+    /// Owners can run any instruction, operational keys - only instructions from their scopes.
+    pub fn is_vaccount_scope_allowed(&self, key: &Pubkey, scope: u8) -> bool {
+        self.is_owner(key)
+            || self
+                .find_operational(key)
+                .map(|operational| {
+                    operational.is_active()
+                        && (operational.is_master_key || operational.scopes.contains(&scope))
+                })
+                .unwrap_or(false)
+    }
+
+    /// Check that `key` is allowed to sign for vaccount in calls to `program_id`.
+    /// Empty scopes of whitelist entry allow any instruction of the program.
+    pub fn is_program_scope_allowed(
+        &self,
+        key: &Pubkey,
+        program_id: &Pubkey,
+        scope: Option<u8>,
+    ) -> bool {
+        self.is_owner(key)
+            || self
+                .find_operational(key)
+                .map(|operational| {
+                    operational.is_active()
+                        && operational.whitelist_programs.iter().any(|whitelist| {
+                            whitelist.program_id == *program_id
+                                && (whitelist.scopes.is_empty()
+                                    || scope
+                                        .map_or(false, |scope| whitelist.scopes.contains(&scope)))
+                        })
+                })
+                .unwrap_or(false)
+    }
+}
+
+/// Operational key state.
+#[repr(C)]
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    BorshDeserialize,
+    BorshSerialize,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct Operational {
+    /// Operational key
+    pub pubkey: Pubkey,
+    /// Operational key state
+    pub state: OperationalState,
+    /// Type of the agent session associated with an operational key
+    pub agent_type: Vec<u8>,
+    /// Allowed instruction for operational key
+    pub scopes: Vec<u8>,
+    /// Allowed programs to call
+    pub whitelist_programs: Vec<Whitelist>,
+    /// Master key is allowed to call any instruction in Vaccount
+    pub is_master_key: bool,
+}
+
+impl Operational {
+    pub fn is_active(&self) -> bool {
+        self.state == OperationalState::Initialized
+    }
+
+    /// Check that `other` grants nothing beyond this key privileges.
+    /// Master key can't be granted by operational keys.
+    pub fn covers(&self, other: &Operational) -> bool {
+        !other.is_master_key
+            && (self.is_master_key || other.scopes.iter().all(|scope| self.scopes.contains(scope)))
+            && other.whitelist_programs.iter().all(|whitelist| {
+                self.whitelist_programs
+                    .iter()
+                    .any(|own| own.covers(whitelist))
+            })
+    }
+}
+
+/// Operational key state.
+#[repr(C)]
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    BorshDeserialize,
+    BorshSerialize,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct Whitelist {
+    /// Allowed to call program code id
+    pub program_id: Pubkey,
+    /// Allowed to call instruction inside program
+    pub scopes: Vec<u8>,
+}
+
+impl Whitelist {
+    /// Check that `other` allows only calls, that are allowed by this entry.
+    pub fn covers(&self, other: &Whitelist) -> bool {
+        self.program_id == other.program_id
+            && (self.scopes.is_empty()
+                || (!other.scopes.is_empty()
+                    && other.scopes.iter().all(|scope| self.scopes.contains(scope))))
+    }
+}
+
+/// Operational key state.
+#[repr(u8)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    BorshDeserialize,
+    BorshSerialize,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub enum OperationalState {
+    /// Operational key is not yet initialized
+    Uninitialized,
+    /// Operational key is initialized
+    Initialized,
+    /// Operational has been frozen by the owner/operational freeze authority.
+    Frozen,
+}
+
+impl Default for OperationalState {
+    fn default() -> Self {
+        OperationalState::Uninitialized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vaccount_info_len() {
+        let info = VAccountInfo::new(Pubkey::new_unique(), Pubkey::new_unique());
+        let mut data = vec![0; VAccountInfo::LEN];
+        assert_eq!(VAccountInfo::unpack(&data).unwrap(), None);
+        info.pack(&mut data).unwrap();
+        assert_eq!(VAccountInfo::unpack(&data).unwrap(), Some(info));
+    }
+
+    #[test]
+    fn test_storage_pack_unpack() {
+        let mut data = vec![0; 256];
+        let storage = VAccountStorage::unpack(&data).unwrap();
+        assert!(!storage.is_initialized());
+
+        let storage = VAccountStorage {
+            owners: vec![Pubkey::new_unique()],
+            operationals: vec![Operational {
+                pubkey: Pubkey::new_unique(),
+                state: OperationalState::Initialized,
+                ..Operational::default()
+            }],
+        };
+        storage.pack(&mut data).unwrap();
+        assert_eq!(VAccountStorage::unpack(&data).unwrap(), storage);
+        assert_eq!(
+            storage.pack(&mut [0; 8]),
+            Err(InstructionError::AccountDataTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_storage_scopes() {
+        let owner = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+        let operational = Operational {
+            pubkey: Pubkey::new_unique(),
+            state: OperationalState::Initialized,
+            scopes: vec![1],
+            whitelist_programs: vec![Whitelist {
+                program_id,
+                scopes: vec![7],
+            }],
+            ..Operational::default()
+        };
+        let mut storage = VAccountStorage {
+            owners: vec![owner],
+            operationals: vec![operational.clone()],
+        };
+
+        assert!(storage.is_vaccount_scope_allowed(&owner, 3));
+        assert!(storage.is_vaccount_scope_allowed(&operational.pubkey, 1));
+        assert!(!storage.is_vaccount_scope_allowed(&operational.pubkey, 3));
+        assert!(storage.is_program_scope_allowed(&operational.pubkey, &program_id, Some(7)));
+        assert!(!storage.is_program_scope_allowed(&operational.pubkey, &program_id, Some(8)));
+        assert!(!storage.is_program_scope_allowed(
+            &operational.pubkey,
+            &Pubkey::new_unique(),
+            None
+        ));
+
+        let granted = Operational {
+            scopes: vec![1],
+            whitelist_programs: vec![Whitelist {
+                program_id,
+                scopes: vec![7],
+            }],
+            ..Operational::default()
+        };
+        assert!(operational.covers(&granted));
+        assert!(!operational.covers(&Operational {
+            scopes: vec![1, 3],
+            ..granted.clone()
+        }));
+        assert!(!operational.covers(&Operational {
+            is_master_key: true,
+            ..granted.clone()
+        }));
+        assert!(!operational.covers(&Operational {
+            whitelist_programs: vec![Whitelist {
+                program_id,
+                scopes: vec![],
+            }],
+            ..granted
+        }));
+
+        storage.operationals[0].state = OperationalState::Frozen;
+        assert!(!storage.is_vaccount_scope_allowed(&operational.pubkey, 1));
+        assert!(!storage.is_program_scope_allowed(&operational.pubkey, &program_id, Some(7)));
+    }
+}
@@ -1,5 +1,4 @@
-use crate::{id, vest_state::VestState};
-use bincode::serialized_size;
+use crate::{id, vest_schedule::VestSchedule, vest_state::VestState};
 use chrono::prelude::{Date, DateTime, Utc};
 use num_derive::FromPrimitive;
 use serde_derive::{Deserialize, Serialize};
@@ -18,6 +17,9 @@ pub enum VestError {
 
     #[error("unauthorized")]
     Unauthorized,
+
+    #[error("invalid vesting schedule")]
+    InvalidSchedule,
 }
 
 impl From<VestError> for InstructionError {
@@ -64,6 +66,16 @@ pub enum VestInstruction {
 
     /// Mark all available tokens as redeemable, regardless of the date.
     VestAll,
+
+    /// Declare and instantiate a vesting contract that follows the given schedule
+    InitializeAccountWithSchedule {
+        terminator_pubkey: Pubkey, // The address authorized to terminate this contract with a signed Terminate instruction
+        payee_pubkey: Pubkey,      // The address authorized to redeem vested tokens
+        start_date_time: DateTime<Utc>, // The day from which the vesting contract begins
+        date_pubkey: Pubkey, // Address of an account containing a trusted date, used to drive the vesting schedule
+        total_lamports: u64, // The number of lamports to send the payee if the schedule completes
+        schedule: VestSchedule, // How the total lamports vest over time
+    },
 }
 
 fn initialize_account(
@@ -88,6 +100,30 @@ fn initialize_account(
     )
 }
 
+fn initialize_account_with_schedule(
+    terminator_pubkey: &Pubkey,
+    payee_pubkey: &Pubkey,
+    contract_pubkey: &Pubkey,
+    start_date: Date<Utc>,
+    date_pubkey: &Pubkey,
+    total_lamports: u64,
+    schedule: VestSchedule,
+) -> Instruction {
+    let keys = vec![AccountMeta::new(*contract_pubkey, false)];
+    Instruction::new(
+        id(),
+        &VestInstruction::InitializeAccountWithSchedule {
+            terminator_pubkey: *terminator_pubkey,
+            payee_pubkey: *payee_pubkey,
+            start_date_time: start_date.and_hms(0, 0, 0),
+            date_pubkey: *date_pubkey,
+            total_lamports,
+            schedule,
+        },
+        keys,
+    )
+}
+
 pub fn create_account(
     payer_pubkey: &Pubkey,
     terminator_pubkey: &Pubkey,
@@ -97,7 +133,7 @@ pub fn create_account(
     date_pubkey: &Pubkey,
     lamports: u64,
 ) -> Vec<Instruction> {
-    let space = serialized_size(&VestState::default()).unwrap();
+    let space = VestState::size_of(&VestSchedule::Standard);
     vec![
         system_instruction::create_account(&payer_pubkey, contract_pubkey, lamports, space, &id()),
         initialize_account(
@@ -111,6 +147,32 @@ pub fn create_account(
     ]
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn create_account_with_schedule(
+    payer_pubkey: &Pubkey,
+    terminator_pubkey: &Pubkey,
+    contract_pubkey: &Pubkey,
+    payee_pubkey: &Pubkey,
+    start_date: Date<Utc>,
+    date_pubkey: &Pubkey,
+    lamports: u64,
+    schedule: VestSchedule,
+) -> Vec<Instruction> {
+    let space = VestState::size_of(&schedule);
+    vec![
+        system_instruction::create_account(&payer_pubkey, contract_pubkey, lamports, space, &id()),
+        initialize_account_with_schedule(
+            terminator_pubkey,
+            payee_pubkey,
+            contract_pubkey,
+            start_date,
+            date_pubkey,
+            lamports,
+            schedule,
+        ),
+    ]
+}
+
 pub fn set_terminator(contract: &Pubkey, old_pubkey: &Pubkey, new_pubkey: &Pubkey) -> Instruction {
     let account_metas = vec![
         AccountMeta::new(*contract, false),
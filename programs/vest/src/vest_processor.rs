@@ -73,28 +73,46 @@ pub fn process_instruction(
 
     let instruction = limited_deserialize(data)?;
 
-    let mut vest_state = if let VestInstruction::InitializeAccount {
-        terminator_pubkey,
-        payee_pubkey,
-        start_date_time,
-        date_pubkey,
-        total_lamports,
-    } = instruction
-    {
-        VestState {
+    let mut vest_state = match instruction {
+        VestInstruction::InitializeAccount {
+            terminator_pubkey,
+            payee_pubkey,
+            start_date_time,
+            date_pubkey,
+            total_lamports,
+        } => VestState {
             terminator_pubkey,
             payee_pubkey,
             start_date_time,
             date_pubkey,
             total_lamports,
             ..VestState::default()
+        },
+        VestInstruction::InitializeAccountWithSchedule {
+            terminator_pubkey,
+            payee_pubkey,
+            start_date_time,
+            date_pubkey,
+            total_lamports,
+            ref schedule,
+        } => {
+            schedule.validate(start_date_time.date(), total_lamports)?;
+            VestState {
+                terminator_pubkey,
+                payee_pubkey,
+                start_date_time,
+                date_pubkey,
+                total_lamports,
+                schedule: schedule.clone(),
+                ..VestState::default()
+            }
         }
-    } else {
-        VestState::deserialize(&contract_account.data)?
+        _ => VestState::deserialize(&contract_account.data)?,
     };
 
     match instruction {
-        VestInstruction::InitializeAccount { .. } => {}
+        VestInstruction::InitializeAccount { .. }
+        | VestInstruction::InitializeAccountWithSchedule { .. } => {}
         VestInstruction::SetTerminator(new_pubkey) => {
             verify_signed_account(
                 next_keyed_account(keyed_accounts_iter)?,
@@ -155,6 +173,7 @@ mod tests {
     use super::*;
     use crate::id;
     use crate::vest_instruction;
+    use crate::vest_schedule::{VestSchedule, VestTranche};
     use solana_config_program::date_instruction;
     use solana_runtime::bank::Bank;
     use solana_runtime::bank_client::BankClient;
@@ -561,6 +580,82 @@ mod tests {
         assert_eq!(bank_client.get_balance(&bob_pubkey).unwrap(), 13);
     }
 
+    #[test]
+    fn test_redeem_tokens_with_schedule() {
+        let (bank_client, alice_keypair) = create_bank_client(38);
+        let alice_pubkey = alice_keypair.pubkey();
+
+        let date_keypair = Keypair::new();
+        let date_pubkey = date_keypair.pubkey();
+        let current_date = Utc.ymd(2018, 7, 1);
+        create_date_account(&bank_client, &date_keypair, &alice_keypair, current_date).unwrap();
+
+        let contract_keypair = Keypair::new();
+        let contract_pubkey = contract_keypair.pubkey();
+        let bob_pubkey = solana_sdk::pubkey::new_rand();
+
+        // Quarterly over a year with a 6 month cliff
+        let instructions = vest_instruction::create_account_with_schedule(
+            &alice_pubkey,
+            &alice_pubkey,
+            &contract_pubkey,
+            &bob_pubkey,
+            Utc.ymd(2018, 1, 1),
+            &date_pubkey,
+            36,
+            VestSchedule::Periodic {
+                cliff_months: 6,
+                period_months: 3,
+                periods: 4,
+            },
+        );
+        let message = Message::new(&instructions, Some(&alice_pubkey));
+        bank_client
+            .send_and_confirm_message(&[&alice_keypair, &contract_keypair], message)
+            .unwrap();
+
+        send_redeem_tokens(
+            &bank_client,
+            &contract_pubkey,
+            &alice_keypair,
+            &bob_pubkey,
+            &date_pubkey,
+        )
+        .unwrap();
+        assert_eq!(bank_client.get_balance(&contract_pubkey).unwrap(), 18);
+        assert_eq!(bank_client.get_balance(&bob_pubkey).unwrap(), 18);
+    }
+
+    #[test]
+    fn test_initialize_invalid_schedule() {
+        let (bank_client, alice_keypair) = create_bank_client(38);
+        let alice_pubkey = alice_keypair.pubkey();
+        let contract_keypair = Keypair::new();
+
+        // Tranches don't add up to the contract's lamports
+        let instructions = vest_instruction::create_account_with_schedule(
+            &alice_pubkey,
+            &alice_pubkey,
+            &contract_keypair.pubkey(),
+            &solana_sdk::pubkey::new_rand(),
+            Utc.ymd(2018, 1, 1),
+            &solana_sdk::pubkey::new_rand(),
+            36,
+            VestSchedule::Tranches(vec![VestTranche {
+                date_time: Utc.ymd(2019, 1, 1).and_hms(0, 0, 0),
+                lamports: 35,
+            }]),
+        );
+        let message = Message::new(&instructions, Some(&alice_pubkey));
+        assert_eq!(
+            bank_client
+                .send_and_confirm_message(&[&alice_keypair, &contract_keypair], message)
+                .unwrap_err()
+                .unwrap(),
+            TransactionError::InstructionError(1, VestError::InvalidSchedule.into())
+        );
+    }
+
     #[test]
     fn test_terminate_and_refund() {
         let (bank_client, alice_keypair) = create_bank_client(3);
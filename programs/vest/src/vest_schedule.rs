@@ -1,6 +1,112 @@
 //! A library for creating vesting schedules
 
-use chrono::prelude::*;
+use crate::vest_instruction::VestError;
+use chrono::{prelude::*, serde::ts_seconds};
+use serde_derive::{Deserialize, Serialize};
+use std::cmp::max;
+
+/// Upper bound on how far into the future a periodic schedule may reach
+pub const MAX_VEST_MONTHS: u32 = 1200;
+
+/// An explicit amount of lamports that vests on a given date
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct VestTranche {
+    #[serde(with = "ts_seconds")]
+    pub date_time: DateTime<Utc>,
+    pub lamports: u64,
+}
+
+/// Policy that determines how the total lamports of a vesting contract unlock over time
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum VestSchedule {
+    /// One third vests 12 months after the start date, the rest vests monthly
+    /// over the following 24 months
+    Standard,
+
+    /// The lamports vest in `periods` equal payments, one every `period_months`
+    /// from the start date. Payments that fall before the cliff, `cliff_months`
+    /// after the start date, vest together on the cliff date.
+    Periodic {
+        cliff_months: u32,
+        period_months: u32,
+        periods: u32,
+    },
+
+    /// The lamports vest in the given tranches, ordered by date
+    Tranches(Vec<VestTranche>),
+}
+
+impl Default for VestSchedule {
+    fn default() -> Self {
+        VestSchedule::Standard
+    }
+}
+
+impl VestSchedule {
+    /// Check that this schedule can vest exactly `total_lamports` after `start_date`.
+    pub fn validate(&self, start_date: Date<Utc>, total_lamports: u64) -> Result<(), VestError> {
+        match self {
+            VestSchedule::Standard => Ok(()),
+            VestSchedule::Periodic {
+                cliff_months,
+                period_months,
+                periods,
+            } => {
+                let last_month = period_months
+                    .checked_mul(*periods)
+                    .ok_or(VestError::InvalidSchedule)?;
+                if *period_months == 0
+                    || *periods == 0
+                    || last_month > MAX_VEST_MONTHS
+                    || *cliff_months > MAX_VEST_MONTHS
+                {
+                    return Err(VestError::InvalidSchedule);
+                }
+                Ok(())
+            }
+            VestSchedule::Tranches(tranches) => {
+                if tranches.is_empty() || tranches[0].date_time.date() < start_date {
+                    return Err(VestError::InvalidSchedule);
+                }
+                if tranches
+                    .windows(2)
+                    .any(|pair| pair[0].date_time >= pair[1].date_time)
+                {
+                    return Err(VestError::InvalidSchedule);
+                }
+                let lamports = tranches
+                    .iter()
+                    .try_fold(0u64, |sum, tranche| sum.checked_add(tranche.lamports));
+                if lamports != Some(total_lamports) {
+                    return Err(VestError::InvalidSchedule);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Return a list of vesting-date/lamports pairs for `lamports` starting at `start_date`.
+    pub fn vesting_dates(&self, start_date: Date<Utc>, lamports: u64) -> Vec<(Date<Utc>, u64)> {
+        match self {
+            VestSchedule::Standard => create_vesting_schedule(start_date, lamports),
+            VestSchedule::Periodic {
+                cliff_months,
+                period_months,
+                periods,
+            } => create_periodic_vesting_schedule(
+                start_date,
+                lamports,
+                *cliff_months,
+                *period_months,
+                *periods,
+            ),
+            VestSchedule::Tranches(tranches) => tranches
+                .iter()
+                .map(|tranche| (tranche.date_time.date(), tranche.lamports))
+                .collect(),
+        }
+    }
+}
 
 /// Return the date that is 'n' months from 'start'.
 fn get_month(start: Date<Utc>, n: u32) -> Date<Utc> {
@@ -54,6 +160,36 @@ pub fn create_vesting_schedule(start_date: Date<Utc>, mut lamports: u64) -> Vec<
     schedule
 }
 
+/// Return a list of vesting-date/lamports pairs for `lamports` paid out in `periods`
+/// equal payments every `period_months`, none of them before `cliff_months`.
+pub fn create_periodic_vesting_schedule(
+    start_date: Date<Utc>,
+    mut lamports: u64,
+    cliff_months: u32,
+    period_months: u32,
+    periods: u32,
+) -> Vec<(Date<Utc>, u64)> {
+    let mut schedule: Vec<(Date<Utc>, u64)> = vec![];
+    let cliff_date = get_month(start_date, cliff_months);
+
+    let (stipend, remainder) = div(lamports, u64::from(periods));
+    for n in 0..periods {
+        let mut stipend = stipend;
+        if u64::from(n) < remainder {
+            stipend += 1;
+        }
+        let dt = max(get_month(start_date, (n + 1) * period_months), cliff_date);
+        match schedule.last_mut() {
+            Some((last_dt, last_stipend)) if *last_dt == dt => *last_stipend += stipend,
+            _ => schedule.push((dt, stipend)),
+        }
+        lamports -= stipend;
+    }
+    assert_eq!(lamports, 0);
+
+    schedule
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -163,4 +299,95 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_create_periodic_vesting_schedule() {
+        // Quarterly over two years with a 6 month cliff
+        assert_eq!(
+            create_periodic_vesting_schedule(Utc.ymd(2018, 1, 31), 8_001, 6, 3, 8),
+            vec![
+                (Utc.ymd(2018, 7, 31), 2001),
+                (Utc.ymd(2018, 10, 31), 1000),
+                (Utc.ymd(2019, 1, 31), 1000),
+                (Utc.ymd(2019, 4, 30), 1000),
+                (Utc.ymd(2019, 7, 31), 1000),
+                (Utc.ymd(2019, 10, 31), 1000),
+                (Utc.ymd(2020, 1, 31), 1000),
+            ]
+        );
+
+        // Everything at a 24 month cliff
+        assert_eq!(
+            create_periodic_vesting_schedule(Utc.ymd(2018, 1, 1), 36_000, 24, 24, 1),
+            vec![(Utc.ymd(2020, 1, 1), 36_000)]
+        );
+
+        // A cliff past the last payment
+        assert_eq!(
+            create_periodic_vesting_schedule(Utc.ymd(2018, 1, 1), 3, 12, 1, 3),
+            vec![(Utc.ymd(2019, 1, 1), 3)]
+        );
+    }
+
+    #[test]
+    fn test_validate_vest_schedule() {
+        let start_date = Utc.ymd(2018, 1, 1);
+        assert_eq!(VestSchedule::Standard.validate(start_date, 1), Ok(()));
+
+        let periodic = |cliff_months, period_months, periods| VestSchedule::Periodic {
+            cliff_months,
+            period_months,
+            periods,
+        };
+        assert_eq!(periodic(6, 3, 8).validate(start_date, 1), Ok(()));
+        assert_eq!(
+            periodic(6, 0, 8).validate(start_date, 1),
+            Err(VestError::InvalidSchedule)
+        );
+        assert_eq!(
+            periodic(6, 3, 0).validate(start_date, 1),
+            Err(VestError::InvalidSchedule)
+        );
+        assert_eq!(
+            periodic(0, u32::MAX, 2).validate(start_date, 1),
+            Err(VestError::InvalidSchedule)
+        );
+        assert_eq!(
+            periodic(MAX_VEST_MONTHS + 1, 1, 1).validate(start_date, 1),
+            Err(VestError::InvalidSchedule)
+        );
+
+        let tranche = |year, lamports| VestTranche {
+            date_time: Utc.ymd(year, 1, 1).and_hms(0, 0, 0),
+            lamports,
+        };
+        let tranches = VestSchedule::Tranches(vec![tranche(2019, 1), tranche(2020, 2)]);
+        assert_eq!(tranches.validate(start_date, 3), Ok(()));
+        assert_eq!(
+            tranches.validate(start_date, 4),
+            Err(VestError::InvalidSchedule)
+        );
+        assert_eq!(
+            tranches.vesting_dates(start_date, 3),
+            vec![(Utc.ymd(2019, 1, 1), 1), (Utc.ymd(2020, 1, 1), 2)]
+        );
+        assert_eq!(
+            VestSchedule::Tranches(vec![]).validate(start_date, 0),
+            Err(VestError::InvalidSchedule)
+        );
+        assert_eq!(
+            VestSchedule::Tranches(vec![tranche(2020, 1), tranche(2019, 2)])
+                .validate(start_date, 3),
+            Err(VestError::InvalidSchedule)
+        );
+        assert_eq!(
+            VestSchedule::Tranches(vec![tranche(2017, 3)]).validate(start_date, 3),
+            Err(VestError::InvalidSchedule)
+        );
+        assert_eq!(
+            VestSchedule::Tranches(vec![tranche(2019, u64::MAX), tranche(2020, 1)])
+                .validate(start_date, 0),
+            Err(VestError::InvalidSchedule)
+        );
+    }
 }
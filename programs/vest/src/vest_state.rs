@@ -1,6 +1,6 @@
 //! vest state
-use crate::vest_schedule::create_vesting_schedule;
-use bincode::{self, deserialize, serialize_into};
+use crate::vest_schedule::VestSchedule;
+use bincode::{self, deserialize, serialize_into, serialized_size};
 use chrono::prelude::*;
 use chrono::{
     prelude::{DateTime, TimeZone, Utc},
@@ -36,6 +36,55 @@ pub struct VestState {
 
     /// True if the terminator has declared this contract fully vested.
     pub is_fully_vested: bool,
+
+    /// How `total_lamports` vest over time
+    pub schedule: VestSchedule,
+}
+
+/// Layout of `VestState` before vesting schedules became configurable. Contracts
+/// created back then always follow `VestSchedule::Standard`.
+#[derive(Serialize, Deserialize)]
+struct LegacyVestState {
+    terminator_pubkey: Pubkey,
+    payee_pubkey: Pubkey,
+    #[serde(with = "ts_seconds")]
+    start_date_time: DateTime<Utc>,
+    date_pubkey: Pubkey,
+    total_lamports: u64,
+    redeemed_lamports: u64,
+    reneged_lamports: u64,
+    is_fully_vested: bool,
+}
+
+impl From<LegacyVestState> for VestState {
+    fn from(state: LegacyVestState) -> Self {
+        Self {
+            terminator_pubkey: state.terminator_pubkey,
+            payee_pubkey: state.payee_pubkey,
+            start_date_time: state.start_date_time,
+            date_pubkey: state.date_pubkey,
+            total_lamports: state.total_lamports,
+            redeemed_lamports: state.redeemed_lamports,
+            reneged_lamports: state.reneged_lamports,
+            is_fully_vested: state.is_fully_vested,
+            schedule: VestSchedule::Standard,
+        }
+    }
+}
+
+impl From<&VestState> for LegacyVestState {
+    fn from(state: &VestState) -> Self {
+        Self {
+            terminator_pubkey: state.terminator_pubkey,
+            payee_pubkey: state.payee_pubkey,
+            start_date_time: state.start_date_time,
+            date_pubkey: state.date_pubkey,
+            total_lamports: state.total_lamports,
+            redeemed_lamports: state.redeemed_lamports,
+            reneged_lamports: state.reneged_lamports,
+            is_fully_vested: state.is_fully_vested,
+        }
+    }
 }
 
 impl Default for VestState {
@@ -49,26 +98,49 @@ impl Default for VestState {
             redeemed_lamports: 0,
             reneged_lamports: 0,
             is_fully_vested: false,
+            schedule: VestSchedule::Standard,
         }
     }
 }
 
 impl VestState {
+    /// Number of bytes needed to store a contract that follows `schedule`
+    pub fn size_of(schedule: &VestSchedule) -> u64 {
+        serialized_size(&VestState {
+            schedule: schedule.clone(),
+            ..VestState::default()
+        })
+        .unwrap()
+    }
+
     pub fn serialize(&self, output: &mut [u8]) -> Result<(), InstructionError> {
+        // Contracts created before schedules became configurable have no room to
+        // store one, keep them in the layout they were created with
+        if self.schedule == VestSchedule::Standard
+            && (output.len() as u64) < Self::size_of(&self.schedule)
+        {
+            return serialize_into(output, &LegacyVestState::from(self))
+                .map_err(|_| InstructionError::AccountDataTooSmall);
+        }
         serialize_into(output, self).map_err(|_| InstructionError::AccountDataTooSmall)
     }
 
     pub fn deserialize(input: &[u8]) -> Result<Self, InstructionError> {
-        deserialize(input).map_err(|_| InstructionError::InvalidAccountData)
+        deserialize(input)
+            .or_else(|_| deserialize::<LegacyVestState>(input).map(VestState::from))
+            .map_err(|_| InstructionError::InvalidAccountData)
     }
 
-    fn calc_vested_lamports(&self, current_date: Date<Utc>) -> u64 {
+    /// Return the number of lamports vested by `current_date`
+    pub fn calc_vested_lamports(&self, current_date: Date<Utc>) -> u64 {
         let total_lamports_after_reneged = self.total_lamports - self.reneged_lamports;
         if self.is_fully_vested {
             return total_lamports_after_reneged;
         }
 
-        let schedule = create_vesting_schedule(self.start_date_time.date(), self.total_lamports);
+        let schedule = self
+            .schedule
+            .vesting_dates(self.start_date_time.date(), self.total_lamports);
 
         let vested_lamports = schedule
             .into_iter()
@@ -141,6 +213,33 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_serializer_legacy_layout() {
+        let legacy_size = serialized_size(&LegacyVestState::from(&VestState::default())).unwrap();
+        let mut a = Account::new(0, legacy_size as usize, &id());
+        let b = VestState {
+            total_lamports: 3,
+            redeemed_lamports: 1,
+            ..VestState::default()
+        };
+        b.serialize(&mut a.data).unwrap();
+        assert_eq!(VestState::deserialize(&a.data).unwrap(), b);
+
+        // A configured schedule does not fit into the legacy layout
+        let c = VestState {
+            schedule: VestSchedule::Periodic {
+                cliff_months: 0,
+                period_months: 1,
+                periods: 3,
+            },
+            ..b
+        };
+        assert_eq!(
+            c.serialize(&mut a.data),
+            Err(InstructionError::AccountDataTooSmall)
+        );
+    }
+
     #[test]
     fn test_schedule_after_renege() {
         let total_lamports = 3;
@@ -12,6 +12,7 @@ use log::*;
 use results::Results;
 use solana_client::rpc_client::RpcClient;
 use solana_metrics::datapoint_info;
+use solana_notifier::Notifier;
 use solana_sdk::{genesis_config::GenesisConfig, signature::read_keypair_file};
 use solana_stake_program::config::{id as stake_config_id, Config as StakeConfig};
 use std::{
@@ -54,7 +55,7 @@ fn gift_for_round(tps_round: u32, initial_balance: u64) -> u64 {
 fn main() {
     solana_logger::setup_with_default("solana=debug");
     solana_metrics::set_panic_hook("ramp-tps");
-    let mut notifier = solana_notifier::Notifier::default();
+    let mut notifier = Notifier::default().with_source("ramp-tps");
 
     let matches = App::new(crate_name!())
         .about(crate_description!())
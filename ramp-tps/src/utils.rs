@@ -2,7 +2,7 @@ use bzip2::bufread::BzDecoder;
 use log::*;
 use solana_client::rpc_client::RpcClient;
 use solana_net_utils::parse_host;
-use solana_notifier::Notifier;
+use solana_notifier::{Alert, Notifier, Severity};
 use solana_sdk::{
     clock::{Epoch, Slot},
     genesis_config::GenesisConfig,
@@ -90,7 +90,7 @@ pub fn is_host(string: String) -> Result<(), String> {
 }
 
 pub fn bail(notifier: &Notifier, msg: &str) -> ! {
-    notifier.send(msg);
+    notifier.notify(Alert::firing("ramp-tps-bail", Severity::Critical, msg));
     sleep(Duration::from_secs(30)); // Wait for notifications to send
     std::process::exit(1);
 }
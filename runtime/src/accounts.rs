@@ -373,6 +373,8 @@ impl Accounts {
         let fee_config = FeeConfig {
             secp256k1_program_enabled: feature_set
                 .is_active(&feature_set::secp256k1_program_enabled::id()),
+            compute_budget_program_enabled: feature_set
+                .is_active(&feature_set::compute_budget_requests::id()),
         };
         OrderedIterator::new(txs, txs_iteration_order)
             .zip(lock_results.into_iter())
@@ -6,9 +6,10 @@ use crate::{
     bank::{Bank, BankSlotDelta, DropCallback},
     bank_forks::{BankForks, SnapshotConfig},
     snapshot_package::AccountsPackageSender,
-    snapshot_utils,
+    snapshot_utils::{self, IncrementalSnapshotBase},
 };
 use crossbeam_channel::{Receiver, SendError, Sender};
+use evm_state::AccountProvider;
 use log::*;
 use rand::{thread_rng, Rng};
 use solana_measure::measure::Measure;
@@ -18,7 +19,7 @@ use std::{
     fmt::{Debug, Formatter},
     sync::{
         atomic::{AtomicBool, Ordering},
-        Arc, RwLock,
+        Arc, Mutex, RwLock,
     },
     thread::{self, sleep, Builder, JoinHandle},
     time::{Duration, Instant},
@@ -80,9 +81,84 @@ pub struct SnapshotRequestHandler {
     pub snapshot_config: SnapshotConfig,
     pub snapshot_request_receiver: SnapshotRequestReceiver,
     pub accounts_package_sender: AccountsPackageSender,
+    // Last full snapshot taken by this node, incremental snapshots are built on top of it
+    pub last_full_snapshot: Mutex<Option<IncrementalSnapshotBase>>,
 }
 
 impl SnapshotRequestHandler {
+    pub fn new(
+        snapshot_config: SnapshotConfig,
+        snapshot_request_receiver: SnapshotRequestReceiver,
+        accounts_package_sender: AccountsPackageSender,
+    ) -> Self {
+        // Continue building incremental snapshots on top of the full snapshot,
+        // taken before the node restart
+        let last_full_snapshot = IncrementalSnapshotBase::load(
+            &snapshot_config.snapshot_path,
+            &snapshot_config.snapshot_package_output_path,
+        );
+        if let Some(base) = &last_full_snapshot {
+            info!("Recovered last full snapshot: {:?}", base);
+        }
+        Self {
+            snapshot_config,
+            snapshot_request_receiver,
+            accounts_package_sender,
+            last_full_snapshot: Mutex::new(last_full_snapshot),
+        }
+    }
+
+    fn is_full_snapshot(&self, block_height: u64) -> bool {
+        let interval = self.snapshot_config.snapshot_interval_slots;
+        interval != 0 && block_height % interval == 0
+    }
+
+    // Returns the base full snapshot, if an incremental snapshot should be taken
+    // of this bank
+    fn incremental_snapshot_base(&self, bank: &Bank) -> Option<IncrementalSnapshotBase> {
+        let block_height = bank.block_height();
+        if !self.snapshot_config.incremental_snapshots_enabled()
+            || block_height % self.snapshot_config.incremental_snapshot_interval_slots != 0
+            || self.is_full_snapshot(block_height)
+        {
+            return None;
+        }
+        let base = (*self.last_full_snapshot.lock().unwrap())?;
+        // Base recovered after restart can be already purged from the EVM state
+        if base.slot >= bank.slot()
+            || !bank
+                .evm_state
+                .read()
+                .unwrap()
+                .check_state_root_exist(base.evm_root)
+        {
+            warn!(
+                "Skipping incremental snapshot of slot {}, its base {:?} is unavailable",
+                bank.slot(),
+                base
+            );
+            return None;
+        }
+        Some(base)
+    }
+
+    // Make accounts cleaning of `bank` keep the zero-lamport accounts, that are newer
+    // than the last full snapshot, so incremental snapshots still contain them.
+    pub fn set_accounts_db_last_full_snapshot_slot(&self, bank: &Bank) {
+        if !self.snapshot_config.incremental_snapshots_enabled() {
+            return;
+        }
+        if let Some(base) = *self.last_full_snapshot.lock().unwrap() {
+            *bank
+                .rc
+                .accounts
+                .accounts_db
+                .last_full_snapshot_slot
+                .write()
+                .unwrap() = Some(base.slot);
+        }
+    }
+
     // Returns the latest requested snapshot slot, if one exists
     pub fn handle_snapshot_requests(
         &self,
@@ -128,16 +204,21 @@ impl SnapshotRequestHandler {
                 }
                 flush_accounts_cache_time.stop();
 
+                let block_height = snapshot_root_bank.block_height();
+                let incremental_snapshot_base = self.incremental_snapshot_base(&snapshot_root_bank);
+
                 let mut hash_time = Measure::start("hash_time");
                 snapshot_root_bank.update_accounts_hash_with_index_option(
                     use_index_hash_calculation,
                     test_hash_calculation,
                 );
-                let hash_for_testing = if test_hash_calculation {
-                    Some(snapshot_root_bank.get_accounts_hash())
-                } else {
-                    None
-                };
+                // Incremental package doesn't contain all the storages to recalculate the hash
+                let hash_for_testing =
+                    if test_hash_calculation && incremental_snapshot_base.is_none() {
+                        Some(snapshot_root_bank.get_accounts_hash())
+                    } else {
+                        None
+                    };
                 hash_time.stop();
 
                 let mut clean_time = Measure::start("clean_time");
@@ -165,6 +246,7 @@ impl SnapshotRequestHandler {
                     self.snapshot_config.snapshot_version,
                     &self.snapshot_config.archive_format,
                     hash_for_testing,
+                    incremental_snapshot_base,
                 );
                 if r.is_err() {
                     warn!(
@@ -172,6 +254,9 @@ impl SnapshotRequestHandler {
                         snapshot_root_bank.slot(),
                         r
                     );
+                } else if incremental_snapshot_base.is_none() {
+                    // Full snapshot was taken, next incremental ones are built on top of it
+                    self.set_last_full_snapshot(&snapshot_root_bank);
                 }
                 snapshot_time.stop();
 
@@ -197,9 +282,24 @@ impl SnapshotRequestHandler {
                         i64
                     ),
                 );
-                snapshot_root_bank.block_height()
+                block_height
             })
     }
+
+    fn set_last_full_snapshot(&self, snapshot_root_bank: &Bank) {
+        let base = IncrementalSnapshotBase {
+            slot: snapshot_root_bank.slot(),
+            evm_root: snapshot_root_bank.evm_state.read().unwrap().last_root(),
+        };
+        if let Err(err) = base.save(&self.snapshot_config.snapshot_path) {
+            warn!(
+                "Unable to save incremental snapshot base {:?}: {}",
+                base, err
+            );
+        }
+        *self.last_full_snapshot.lock().unwrap() = Some(base);
+        self.set_accounts_db_last_full_snapshot_slot(snapshot_root_bank);
+    }
 }
 
 #[derive(Default)]
@@ -279,6 +379,10 @@ impl AccountsBackgroundService {
         use_index_hash_calculation: bool,
     ) -> Self {
         info!("AccountsBackgroundService active");
+        if let Some(snapshot_request_handler) = &request_handler.snapshot_request_handler {
+            snapshot_request_handler
+                .set_accounts_db_last_full_snapshot_slot(&bank_forks.read().unwrap().root_bank());
+        }
         let exit = exit.clone();
         let mut consumed_budget = 0;
         let mut last_cleaned_block_height = 0;
@@ -24,6 +24,7 @@ use crate::{
         AccountIndex, AccountsIndex, AccountsIndexRootsStats, Ancestors, IndexKey, IsCached,
         SlotList, SlotSlice, ZeroLamport,
     },
+    accounts_update_notifier_interface::AccountsUpdateNotifier,
     append_vec::{AppendVec, StoredAccountMeta, StoredMeta},
     contains::Contains,
 };
@@ -774,6 +775,14 @@ pub struct AccountsDb {
     /// to drive clean_accounts
     /// Generated by get_accounts_delta_hash
     uncleaned_pubkeys: DashMap<Slot, Vec<Pubkey>>,
+
+    /// Slot of the last full snapshot, incremental snapshots are built on top of it.
+    /// Zero-lamport accounts updated after this slot are not purged by clean,
+    /// otherwise they would be resurrected from the full snapshot on restore.
+    pub last_full_snapshot_slot: RwLock<Option<Slot>>,
+
+    /// Streams account writes out of the validator, set once plugins are loaded
+    accounts_update_notifier: RwLock<Option<AccountsUpdateNotifier>>,
 }
 
 #[derive(Debug, Default)]
@@ -1087,6 +1096,8 @@ impl Default for AccountsDb {
             accounts_cache: AccountsCache::default(),
             recycle_stores: RwLock::new(RecycleStores::default()),
             uncleaned_pubkeys: DashMap::new(),
+            last_full_snapshot_slot: RwLock::new(None),
+            accounts_update_notifier: RwLock::new(None),
             next_id: AtomicUsize::new(0),
             shrink_candidate_slots_v1: Mutex::new(Vec::new()),
             shrink_candidate_slots: Mutex::new(HashMap::new()),
@@ -1165,6 +1176,43 @@ impl AccountsDb {
         *shrink_paths = Some(paths);
     }
 
+    pub fn set_accounts_update_notifier(&self, notifier: Option<AccountsUpdateNotifier>) {
+        *self.accounts_update_notifier.write().unwrap() = notifier;
+    }
+
+    /// Sends every account visible from `ancestors` to the accounts update notifier,
+    /// so that it starts from the complete state restored from a snapshot
+    pub fn notify_account_restore_from_snapshot(&self, ancestors: &Ancestors) {
+        let notifier = match &*self.accounts_update_notifier.read().unwrap() {
+            Some(notifier) => notifier.clone(),
+            None => return,
+        };
+        let mut measure = Measure::start("accountsdb-plugin-notifying-accounts");
+        let notified: usize = self.scan_accounts(ancestors, |count: &mut usize, account| {
+            if let Some((pubkey, account, slot)) = account {
+                notifier
+                    .read()
+                    .unwrap()
+                    .notify_account_restore_from_snapshot(slot, pubkey, &account);
+                *count += 1;
+            }
+        });
+        measure.stop();
+        info!(
+            "Notified {} accounts restored from snapshot, {}",
+            notified, measure
+        );
+    }
+
+    fn notify_account_updates(&self, slot: Slot, accounts: &[(&Pubkey, &Account)]) {
+        if let Some(notifier) = &*self.accounts_update_notifier.read().unwrap() {
+            let notifier = notifier.read().unwrap();
+            for (pubkey, account) in accounts {
+                notifier.notify_account_update(slot, pubkey, account);
+            }
+        }
+    }
+
     pub fn file_size(&self) -> u64 {
         self.file_size
     }
@@ -1442,6 +1490,7 @@ impl AccountsDb {
 
         let mut key_timings = CleanKeyTimings::default();
         let pubkeys = self.construct_candidate_clean_keys(max_clean_root, &mut key_timings);
+        let last_full_snapshot_slot = *self.last_full_snapshot_slot.read().unwrap();
 
         let total_keys_count = pubkeys.len();
         let mut accounts_scan = Measure::start("accounts_scan");
@@ -1459,7 +1508,10 @@ impl AccountsDb {
                             {
                                 let slot_list = locked_entry.slot_list();
                                 let (slot, account_info) = &slot_list[index];
-                                if account_info.lamports == 0 {
+                                if account_info.lamports == 0
+                                    && last_full_snapshot_slot
+                                        .map_or(true, |full_slot| *slot <= full_slot)
+                                {
                                     purges.insert(
                                         *pubkey,
                                         self.accounts_index
@@ -4441,6 +4493,7 @@ impl AccountsDb {
 
     pub fn store_cached(&self, slot: Slot, accounts: &[(&Pubkey, &Account)]) {
         self.store(slot, accounts, self.caching_enabled);
+        self.notify_account_updates(slot, accounts);
     }
 
     /// Store the account update.
@@ -6703,6 +6756,88 @@ pub mod tests {
         assert!(accounts.accounts_index.get(&pubkey, None, None).is_none());
     }
 
+    #[test]
+    fn test_clean_zero_lamport_account_after_last_full_snapshot() {
+        solana_logger::setup();
+
+        let accounts = AccountsDb::new(Vec::new(), &ClusterType::Development);
+        let pubkey = solana_sdk::pubkey::new_rand();
+        let account = Account::new(1, 0, &Account::default().owner);
+        let zero_account = Account::new(0, 0, &Account::default().owner);
+
+        accounts.store_uncached(0, &[(&pubkey, &account)]);
+        accounts.store_uncached(1, &[(&pubkey, &zero_account)]);
+        accounts.add_root(0);
+        accounts.add_root(1);
+
+        // Full snapshot was taken at slot 0, zero lamport account from slot 1
+        // must survive for the incremental snapshots
+        *accounts.last_full_snapshot_slot.write().unwrap() = Some(0);
+        accounts.clean_accounts(Some(1));
+        assert_eq!(accounts.alive_account_count_in_slot(1), 1);
+        assert!(accounts.accounts_index.get(&pubkey, None, None).is_some());
+
+        // Once the full snapshot covers slot 1, the account can be cleaned up
+        *accounts.last_full_snapshot_slot.write().unwrap() = Some(1);
+        accounts.clean_accounts(Some(1));
+        assert_eq!(accounts.alive_account_count_in_slot(1), 0);
+        assert!(accounts.accounts_index.get(&pubkey, None, None).is_none());
+    }
+
+    #[derive(Debug, Default)]
+    struct TestAccountsUpdateNotifier {
+        updates: Mutex<Vec<(Slot, Pubkey, u64)>>,
+        restored: Mutex<Vec<(Slot, Pubkey, u64)>>,
+    }
+
+    impl crate::accounts_update_notifier_interface::AccountsUpdateNotifierInterface
+        for TestAccountsUpdateNotifier
+    {
+        fn notify_account_update(&self, slot: Slot, pubkey: &Pubkey, account: &Account) {
+            self.updates
+                .lock()
+                .unwrap()
+                .push((slot, *pubkey, account.lamports));
+        }
+
+        fn notify_account_restore_from_snapshot(
+            &self,
+            slot: Slot,
+            pubkey: &Pubkey,
+            account: &Account,
+        ) {
+            self.restored
+                .lock()
+                .unwrap()
+                .push((slot, *pubkey, account.lamports));
+        }
+    }
+
+    #[test]
+    fn test_accounts_update_notifier() {
+        let accounts = AccountsDb::new(Vec::new(), &ClusterType::Development);
+        let pubkey = solana_sdk::pubkey::new_rand();
+        let account = Account::new(1, 0, &Account::default().owner);
+        accounts.store_cached(0, &[(&pubkey, &account)]);
+
+        let notifier = Arc::new(RwLock::new(TestAccountsUpdateNotifier::default()));
+        accounts.set_accounts_update_notifier(Some(notifier.clone()));
+
+        let account = Account::new(2, 0, &Account::default().owner);
+        accounts.store_cached(1, &[(&pubkey, &account)]);
+        assert_eq!(
+            *notifier.read().unwrap().updates.lock().unwrap(),
+            vec![(1, pubkey, 2)]
+        );
+
+        let ancestors = vec![(0, 0)].into_iter().collect();
+        accounts.notify_account_restore_from_snapshot(&ancestors);
+        assert_eq!(
+            *notifier.read().unwrap().restored.lock().unwrap(),
+            vec![(0, pubkey, 1)]
+        );
+    }
+
     #[test]
     fn test_uncleaned_roots_with_account() {
         solana_logger::setup();
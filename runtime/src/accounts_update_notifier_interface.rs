@@ -0,0 +1,16 @@
+use {
+    solana_sdk::{account::Account, clock::Slot, pubkey::Pubkey},
+    std::sync::{Arc, RwLock},
+};
+
+/// Receives every account written to the accounts cache, used to stream
+/// account updates out of the validator (e.g. to accountsdb plugins).
+pub trait AccountsUpdateNotifierInterface: std::fmt::Debug {
+    /// Notified when an account is updated at runtime, due to transaction activities
+    fn notify_account_update(&self, slot: Slot, pubkey: &Pubkey, account: &Account);
+
+    /// Notified for every account loaded from a snapshot when the validator starts
+    fn notify_account_restore_from_snapshot(&self, slot: Slot, pubkey: &Pubkey, account: &Account);
+}
+
+pub type AccountsUpdateNotifier = Arc<RwLock<dyn AccountsUpdateNotifierInterface + Sync + Send>>;
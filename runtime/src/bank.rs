@@ -30,6 +30,8 @@ use evm_state::FromKey;
 use itertools::Itertools;
 use log::*;
 use rayon::ThreadPool;
+use solana_config_program::create_config_account;
+use solana_evm_loader_program::evm_params::{self, EvmParams};
 use solana_measure::measure::Measure;
 use solana_metrics::{datapoint_debug, inc_new_counter_debug, inc_new_counter_info};
 use solana_sdk::{
@@ -54,7 +56,9 @@ use solana_sdk::{
     native_loader,
     native_token::sol_to_lamports,
     nonce, nonce_account,
-    process_instruction::{BpfComputeBudget, Executor, ProcessInstructionWithContext},
+    process_instruction::{
+        BpfComputeBudget, Executor, ProcessInstructionWithContext, TransactionReturnData,
+    },
     program_utils::limited_deserialize,
     pubkey::Pubkey,
     recent_blockhashes_account, recent_evm_blockhashes_account,
@@ -74,7 +78,7 @@ use solana_sdk::{
 use solana_stake_program::stake_state::{
     self, Delegation, InflationPointCalculationEvent, PointValue,
 };
-use solana_vote_program::vote_instruction::VoteInstruction;
+use solana_vote_program::{vote_instruction::VoteInstruction, vote_state::VoteState};
 use std::{
     cell::RefCell,
     collections::{HashMap, HashSet},
@@ -100,6 +104,7 @@ pub struct ExecuteTimings {
     pub load_us: u64,
     pub execute_us: u64,
     pub store_us: u64,
+    pub units_consumed: u64,
 }
 
 impl ExecuteTimings {
@@ -107,11 +112,26 @@ impl ExecuteTimings {
         self.load_us += other.load_us;
         self.execute_us += other.execute_us;
         self.store_us += other.store_us;
+        self.units_consumed += other.units_consumed;
     }
 }
 
+/// Outcome of a transaction run against a frozen bank without committing its results
+#[derive(Debug)]
+pub struct TransactionSimulationResult {
+    pub result: Result<()>,
+    pub logs: TransactionLogMessages,
+    /// Accounts of the transaction message, as they would be stored after execution
+    pub post_simulation_accounts: Vec<(Pubkey, Account)>,
+    pub units_consumed: u64,
+    /// Receipts and return data of evm transactions executed by the simulated transaction
+    pub evm_receipts: Vec<(evm_state::TransactionReceipt, Vec<u8>)>,
+    /// Data returned by the last instruction of the simulated transaction
+    pub return_data: Option<TransactionReturnData>,
+}
+
 type BankStatusCache = StatusCache<Result<()>>;
-#[frozen_abi(digest = "3ZaEt781qwhfQSE4DZPBHhng2S6MuimchRjkR9ZWzDFs")]
+#[frozen_abi(digest = "w7wboziPkN5Y7JgkKBigPrVHpseZMMrUWNjhEFtbcpg")]
 pub type BankSlotDelta = SlotDelta<Result<()>>;
 type TransactionAccountRefCells = Vec<Rc<RefCell<Account>>>;
 type TransactionAccountDepRefCells = Vec<(Pubkey, RefCell<Account>)>;
@@ -715,8 +735,9 @@ pub trait DropCallback: fmt::Debug {
 #[derive(Debug, PartialEq, Serialize, Deserialize, AbiExample, Clone, Copy)]
 pub struct RewardInfo {
     pub reward_type: RewardType,
-    pub lamports: i64,     // Reward amount
-    pub post_balance: u64, // Account balance in lamports after `lamports` was applied
+    pub lamports: i64,          // Reward amount
+    pub post_balance: u64,      // Account balance in lamports after `lamports` was applied
+    pub commission: Option<u8>, // Vote account commission when the reward was credited, only present for voting and staking rewards
 }
 
 #[derive(Debug, Default)]
@@ -748,6 +769,9 @@ pub struct Bank {
     pub evm_chain_id: u64,
     pub evm_state: RwLock<evm_state::EvmState>,
 
+    /// Evm params in effect for the current epoch
+    evm_params: EvmParams,
+
     /// Hash of this Bank's state. Only meaningful after freezing.
     hash: RwLock<Hash>,
 
@@ -1042,6 +1066,7 @@ impl Bank {
             epoch,
             evm_chain_id: parent.evm_chain_id,
             evm_state: RwLock::new(evm_state),
+            evm_params: parent.evm_params,
             blockhash_queue: RwLock::new(parent.blockhash_queue.read().unwrap().clone()),
             evm_blockhashes: RwLock::new(parent.evm_blockhashes.read().unwrap().clone()),
 
@@ -1117,6 +1142,7 @@ impl Bank {
         let parent_epoch = parent.epoch();
         if parent_epoch < new.epoch() {
             new.apply_feature_activations(false);
+            new.update_evm_params();
         }
 
         let cloned = new
@@ -1197,6 +1223,7 @@ impl Bank {
             src: new(),
             evm_chain_id: genesis_config.evm_chain_id,
             evm_state: RwLock::new(evm_state),
+            evm_params: new(),
             blockhash_queue: RwLock::new(fields.blockhash_queue),
             evm_blockhashes: RwLock::new(fields.evm_blockhashes),
             ancestors: fields.ancestors,
@@ -1251,6 +1278,10 @@ impl Bank {
             freeze_started: AtomicBool::new(fields.hash != Hash::default()),
         };
         bank.finish_init(genesis_config, additional_builtins);
+        bank.evm_params = bank
+            .get_account(&evm_params::active::id())
+            .and_then(|account| EvmParams::from(&account))
+            .unwrap_or_default();
 
         // Sanity assertions between bank snapshot and genesis config
         // Consider removing from serializable bank state
@@ -1411,6 +1442,20 @@ impl Bank {
             .unwrap_or_default()
     }
 
+    /// Data of the sysvars programs can read through syscalls
+    fn sysvars_for_syscalls(&self) -> Vec<(Pubkey, Vec<u8>)> {
+        [
+            sysvar::clock::id(),
+            sysvar::epoch_schedule::id(),
+            sysvar::fees::id(),
+            sysvar::rent::id(),
+            sysvar::recent_evm_blockhashes::id(),
+        ]
+        .iter()
+        .filter_map(|id| self.get_account(id).map(|account| (*id, account.data)))
+        .collect()
+    }
+
     fn update_clock(&self, parent_epoch: Option<Epoch>) {
         let mut unix_timestamp = self.clock().unix_timestamp;
         let warp_timestamp_again = self
@@ -1566,6 +1611,36 @@ impl Bank {
         });
     }
 
+    // Params from the governance config account are applied only at epoch boundaries,
+    // the copy in effect is kept in a bank maintained account, so it survives snapshot restore.
+    fn update_evm_params(&mut self) {
+        if !self
+            .feature_set
+            .is_active(&feature_set::evm_params_config::id())
+        {
+            return;
+        }
+        let evm_params = self
+            .get_account(&evm_params::id())
+            .and_then(|account| EvmParams::from_governance_account(&account))
+            .filter(EvmParams::is_valid)
+            .unwrap_or_default();
+        if evm_params != self.evm_params {
+            self.update_sysvar_account(&evm_params::active::id(), |account| {
+                create_config_account(
+                    vec![],
+                    &evm_params,
+                    self.inherit_specially_retained_account_balance(account),
+                )
+            });
+            self.evm_params = evm_params;
+        }
+    }
+
+    pub fn evm_params(&self) -> EvmParams {
+        self.evm_params
+    }
+
     fn update_epoch_schedule(&self) {
         self.update_sysvar_account(&sysvar::epoch_schedule::id(), |account| {
             create_account(
@@ -1873,6 +1948,7 @@ impl Bank {
         for (vote_pubkey, (stake_group, vote_account)) in stake_delegation_accounts.iter_mut() {
             let mut vote_account_changed = false;
             let voters_account_pre_balance = vote_account.lamports;
+            let commission = VoteState::from(vote_account).map(|vote_state| vote_state.commission);
 
             for (stake_pubkey, stake_account) in stake_group.iter_mut() {
                 // curry closure to add the contextual stake_pubkey
@@ -1903,6 +1979,7 @@ impl Bank {
                                 reward_type: RewardType::Staking,
                                 lamports: stakers_reward as i64,
                                 post_balance: stake_account.lamports,
+                                commission,
                             },
                         ));
                     }
@@ -1924,6 +2001,7 @@ impl Bank {
                             reward_type: RewardType::Voting,
                             lamports,
                             post_balance,
+                            commission,
                         },
                     ));
                 }
@@ -2049,6 +2127,7 @@ impl Bank {
                         reward_type: RewardType::Fee,
                         lamports: unburned as i64,
                         post_balance,
+                        commission: None,
                     },
                 ));
             }
@@ -2056,6 +2135,39 @@ impl Bank {
         }
     }
 
+    // Parts of evm transaction fees, that were not refunded to the senders, stay on the evm state
+    // account until the bank is frozen: burned part is removed from capitalization, and leader
+    // part is paid to collector_id.
+    fn settle_evm_fees(&self) {
+        let (burned, leader) = self
+            .evm_state
+            .read()
+            .expect("evm state was poisoned")
+            .withheld_fees();
+        if burned + leader == 0 {
+            return;
+        }
+        if let Err(err) = self.withdraw(&solana_sdk::evm_state::id(), burned + leader) {
+            warn!("Unable to settle withheld evm fees: {:?}", err);
+            return;
+        }
+        debug!("settled evm fees, burned: {}, leader: {}", burned, leader);
+
+        if leader != 0 {
+            let post_balance = self.deposit(&self.collector_id, leader);
+            self.rewards.write().unwrap().push((
+                self.collector_id,
+                RewardInfo {
+                    reward_type: RewardType::Fee,
+                    lamports: leader as i64,
+                    post_balance,
+                    commission: None,
+                },
+            ));
+        }
+        self.capitalization.fetch_sub(burned, Relaxed);
+    }
+
     pub fn commit_evm(&self) {
         let apply_start = std::time::Instant::now();
         let hash = self
@@ -2109,6 +2221,7 @@ impl Bank {
             // finish up any deferred changes to account state
             self.collect_rent_eagerly();
             self.collect_fees();
+            self.settle_evm_fees();
             self.distribute_rent();
             self.update_slot_history();
             self.run_incinerator();
@@ -2536,36 +2649,44 @@ impl Bank {
     pub fn take_evm_state_form_simulation(
         &self,
     ) -> Option<evm_state::EvmBackend<evm_state::Incomming>> {
-        Some(
-            match &*self.evm_state.read().expect("bank evm state was poisoned") {
-                evm_state::EvmState::Incomming(i) => i.clone(),
-                evm_state::EvmState::Committed(c) => {
-                    debug!("Creating cloned evm state for simulation");
-                    c.next_incomming(self.clock().unix_timestamp as u64)
-                }
-            },
-        )
+        let mut evm_backend = match &*self.evm_state.read().expect("bank evm state was poisoned") {
+            evm_state::EvmState::Incomming(i) => i.clone(),
+            evm_state::EvmState::Committed(c) => {
+                debug!("Creating cloned evm state for simulation");
+                c.next_incomming(self.clock().unix_timestamp as u64)
+            }
+        };
+        // Return data is shown only in simulation results.
+        evm_backend.collect_transactions_output();
+        Some(evm_backend)
     }
 
     /// Run transactions against a frozen bank without committing the results
-    pub fn simulate_transaction(
-        &self,
-        transaction: Transaction,
-    ) -> (Result<()>, TransactionLogMessages) {
+    pub fn simulate_transaction(&self, transaction: Transaction) -> TransactionSimulationResult {
         assert!(self.is_frozen(), "simulation bank must be frozen");
 
+        let account_keys = transaction.message.account_keys.clone();
         let txs = &[transaction];
         let batch = self.prepare_simulation_batch(txs);
 
+        // Receipts already present in bank evm state, are not part of this simulation.
+        let executed_evm_transactions = match &*self.evm_state.read().expect("evm state poisoned") {
+            evm_state::EvmState::Incomming(i) => i.get_executed_transactions().len(),
+            evm_state::EvmState::Committed(_) => 0,
+        };
+
+        let mut timings = ExecuteTimings::default();
+
         let (
-            _loaded_accounts,
+            loaded_accounts,
             executed,
             _inner_instructions,
             log_messages,
+            mut return_data,
             _retryable_transactions,
             _transaction_count,
             _signature_count,
-            _patch,
+            evm_patch,
         ) = self.load_and_execute_transactions(
             &batch,
             // After simulation, transactions will need to be forwarded to the leader
@@ -2574,16 +2695,51 @@ impl Bank {
             MAX_PROCESSING_AGE - MAX_TRANSACTION_FORWARDING_DELAY,
             false,
             true,
-            &mut ExecuteTimings::default(),
+            &mut timings,
             Self::take_evm_state_form_simulation,
         );
 
-        let transaction_result = executed[0].0.clone().map(|_| ());
-        let log_messages = log_messages
+        let post_simulation_accounts = loaded_accounts
+            .into_iter()
+            .next()
+            .and_then(|(loaded_transaction, _nonce_rollback)| loaded_transaction.ok())
+            .map(|(accounts, _account_deps, _loaders, _rents)| {
+                account_keys.into_iter().zip(accounts).collect()
+            })
+            .unwrap_or_default();
+
+        let evm_receipts = evm_patch
+            .map(|evm_patch| {
+                evm_patch
+                    .get_executed_transactions()
+                    .into_iter()
+                    .skip(executed_evm_transactions)
+                    .filter_map(|hash| {
+                        let receipt = evm_patch.find_transaction_receipt(hash)?.clone();
+                        let output = evm_patch
+                            .find_transaction_output(hash)
+                            .map(<[u8]>::to_vec)
+                            .unwrap_or_default();
+                        Some((receipt, output))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let result = executed[0].0.clone().map(|_| ());
+        let logs = log_messages
             .get(0)
             .map_or(vec![], |messages| messages.to_vec());
+        let return_data = return_data.pop().flatten();
 
-        (transaction_result, log_messages)
+        TransactionSimulationResult {
+            result,
+            logs,
+            post_simulation_accounts,
+            units_consumed: timings.units_consumed,
+            evm_receipts,
+            return_data,
+        }
     }
 
     pub fn unlock_accounts(&self, batch: &mut TransactionBatch) {
@@ -3001,6 +3157,7 @@ impl Bank {
         Vec<TransactionExecutionResult>,
         Vec<Option<InnerInstructionsList>>,
         Vec<TransactionLogMessages>,
+        Vec<Option<TransactionReturnData>>,
         Vec<usize>,
         u64,
         u64,
@@ -3048,15 +3205,27 @@ impl Bank {
         let mut inner_instructions: Vec<Option<InnerInstructionsList>> =
             Vec::with_capacity(txs.len());
         let mut transaction_log_messages = Vec::with_capacity(txs.len());
+        let mut transaction_return_data = Vec::with_capacity(txs.len());
         let bpf_compute_budget = self
             .bpf_compute_budget
             .unwrap_or_else(|| BpfComputeBudget::new(&self.feature_set));
+        let sysvars = if self
+            .feature_set
+            .is_active(&feature_set::sysvar_getter_syscalls::id())
+        {
+            self.sysvars_for_syscalls()
+        } else {
+            vec![]
+        };
 
         let executed: Vec<TransactionExecutionResult> = loaded_accounts
             .iter_mut()
             .zip(OrderedIterator::new(txs, batch.iteration_order()))
             .map(|(accs, (_, tx))| match accs {
-                (Err(e), _nonce_rollback) => (Err(e.clone()), None),
+                (Err(e), _nonce_rollback) => {
+                    transaction_return_data.push(None);
+                    (Err(e.clone()), None)
+                }
                 (Ok((accounts, account_deps, loaders, _rents)), nonce_rollback) => {
                     signature_count += u64::from(tx.message().header.num_required_signatures);
 
@@ -3089,7 +3258,7 @@ impl Bank {
                             let evm_executor = evm_state::Executor::with_config(
                                 state,
                                 evm_state::ChainContext::new(last_hashes),
-                                evm_state::EvmConfig::new(self.evm_chain_id),
+                                self.evm_params.evm_config(self.evm_chain_id),
                             );
                             Some(evm_executor)
                         } else {
@@ -3100,11 +3269,14 @@ impl Bank {
                         None
                     };
 
+                    let mut compute_units_consumed = 0;
+                    let mut return_data = None;
                     let process_result = self.message_processor.process_message(
                         tx.message(),
                         &loader_refcells,
                         &account_refcells,
                         &account_dep_refcells,
+                        &sysvars,
                         &self.rent_collector,
                         log_collector.clone(),
                         executors.clone(),
@@ -3112,7 +3284,11 @@ impl Bank {
                         self.feature_set.clone(),
                         bpf_compute_budget,
                         evm_executor.as_mut(),
+                        &mut compute_units_consumed,
+                        &mut return_data,
                     );
+                    timings.units_consumed += compute_units_consumed;
+                    transaction_return_data.push(return_data);
 
                     if let Some(evm_executor) = evm_executor {
                         evm_patch = Some(evm_executor.deconstruct());
@@ -3251,6 +3427,7 @@ impl Bank {
             executed,
             inner_instructions,
             transaction_log_messages,
+            transaction_return_data,
             retryable_txs,
             tx_count,
             signature_count,
@@ -3269,6 +3446,9 @@ impl Bank {
 
         let fee_config = FeeConfig {
             secp256k1_program_enabled: self.secp256k1_program_enabled(),
+            compute_budget_program_enabled: self
+                .feature_set
+                .is_active(&feature_set::compute_budget_requests::id()),
         };
 
         let results = OrderedIterator::new(txs, iteration_order)
@@ -3488,6 +3668,7 @@ impl Bank {
                             reward_type: RewardType::Rent,
                             lamports: rent_to_be_paid as i64,
                             post_balance: account.lamports,
+                            commission: None,
                         },
                     ));
                 }
@@ -3937,6 +4118,7 @@ impl Bank {
         TransactionBalancesSet,
         Vec<Option<InnerInstructionsList>>,
         Vec<TransactionLogMessages>,
+        Vec<Option<TransactionReturnData>>,
     ) {
         let pre_balances = if collect_balances {
             self.collect_balances(batch)
@@ -3948,6 +4130,7 @@ impl Bank {
             executed,
             inner_instructions,
             transaction_logs,
+            transaction_return_data,
             _,
             tx_count,
             signature_count,
@@ -3981,6 +4164,7 @@ impl Bank {
             TransactionBalancesSet::new(pre_balances, post_balances),
             inner_instructions,
             transaction_logs,
+            transaction_return_data,
         )
     }
 
@@ -4518,7 +4702,22 @@ impl Bank {
             self.shrink_all_slots();
         }
         // Order and short-circuiting is significant; verify_hash requires a valid bank hash
-        self.verify_bank_hash() && self.verify_hash()
+        self.verify_bank_hash() && self.verify_evm_state() && self.verify_hash()
+    }
+
+    /// Check that the evm state root, which is part of the bank hash, is present in evm storage.
+    fn verify_evm_state(&self) -> bool {
+        let evm_state = self.evm_state.read().expect("evm state poisoned");
+        if evm_state.check_root_exist() {
+            true
+        } else {
+            warn!(
+                "verify failed: slot: {}, evm state root {:?} not found in storage",
+                self.slot(),
+                evm_state.last_root()
+            );
+            false
+        }
     }
 
     /// Return the number of hashes per tick
@@ -5166,7 +5365,7 @@ pub fn goto_end_of_slot(bank: &mut Bank) {
     }
 }
 
-fn is_simple_vote_transaction(transaction: &Transaction) -> bool {
+pub fn is_simple_vote_transaction(transaction: &Transaction) -> bool {
     if transaction.message.instructions.len() == 1 {
         let instruction = &transaction.message.instructions[0];
         let program_pubkey =
@@ -7195,6 +7394,7 @@ pub(crate) mod tests {
                     reward_type: RewardType::Staking,
                     lamports: (rewards.validator_point_value * validator_points as f64) as i64,
                     post_balance: bank1.get_balance(&stake_id),
+                    commission: Some(VoteState::from(&vote_account).unwrap().commission),
                 }
             )]
         );
@@ -7688,6 +7888,7 @@ pub(crate) mod tests {
                     reward_type: RewardType::Fee,
                     lamports: expected_fee_collected as i64,
                     post_balance: initial_balance + expected_fee_collected,
+                    commission: None,
                 }
             )]
         );
@@ -7723,6 +7924,7 @@ pub(crate) mod tests {
                     reward_type: RewardType::Fee,
                     lamports: expected_fee_collected as i64,
                     post_balance: initial_balance + 2 * expected_fee_collected,
+                    commission: None,
                 }
             )]
         );
@@ -7968,6 +8170,69 @@ pub(crate) mod tests {
         assert!(bank.transfer(2, &mint_keypair, &bob.pubkey()).is_ok());
     }
 
+    #[test]
+    fn test_evm_params_updated_at_epoch_boundary() {
+        let (mut genesis_config, _mint_keypair) = create_genesis_config(500);
+        activate_all_features(&mut genesis_config);
+        let bank0 = Arc::new(Bank::new(&genesis_config));
+        assert_eq!(bank0.evm_params(), EvmParams::default());
+
+        let params = EvmParams {
+            min_gas_price: 1,
+            block_gas_limit: 1_000_000,
+            fee_burn_percent: 50,
+            fee_leader_percent: 50,
+        };
+        let account = create_config_account(vec![(evm_params::governance::id(), true)], &params, 1);
+        bank0.store_account(&evm_params::id(), &account);
+
+        // params are not applied in the middle of an epoch
+        let bank1 = Arc::new(Bank::new_from_parent(&bank0, &Pubkey::default(), 1));
+        assert_eq!(bank1.evm_params(), EvmParams::default());
+        assert!(bank1.get_account(&evm_params::active::id()).is_none());
+
+        let slot = bank1.epoch_schedule().get_first_slot_in_epoch(1);
+        let bank2 = Bank::new_from_parent(&bank1, &Pubkey::default(), slot);
+        assert_eq!(bank2.evm_params(), params);
+        assert_eq!(
+            bank2
+                .get_account(&evm_params::active::id())
+                .and_then(|account| EvmParams::from(&account)),
+            Some(params)
+        );
+    }
+
+    #[test]
+    fn test_settle_evm_fees() {
+        let (genesis_config, _mint_keypair) = create_genesis_config(500);
+        let bank = Bank::new(&genesis_config);
+        bank.deposit(&solana_sdk::evm_state::id(), 100);
+        match &mut *bank.evm_state.write().unwrap() {
+            evm_state::EvmState::Incomming(incomming) => incomming.withhold_fee(30, 20),
+            evm_state::EvmState::Committed(_) => unreachable!(),
+        }
+        let evm_balance = bank.get_balance(&solana_sdk::evm_state::id());
+        let collector_balance = bank.get_balance(bank.collector_id());
+
+        bank.freeze();
+        assert_eq!(
+            bank.get_balance(&solana_sdk::evm_state::id()),
+            evm_balance - 50
+        );
+        assert_eq!(
+            bank.get_balance(bank.collector_id()),
+            collector_balance + 20
+        );
+        assert!(bank
+            .rewards
+            .read()
+            .unwrap()
+            .iter()
+            .any(|(pubkey, reward)| pubkey == bank.collector_id()
+                && reward.reward_type == RewardType::Fee
+                && reward.lamports == 20));
+    }
+
     #[test]
     fn test_interleaving_locks_evm_tx() {
         solana_logger::setup();
@@ -8048,6 +8313,56 @@ pub(crate) mod tests {
         bank.process_transaction(&tx).unwrap();
     }
 
+    #[test]
+    fn test_evm_state_available_only_to_write_locking_transactions() {
+        let (genesis_config, mint_keypair) = create_genesis_config(500);
+        let mut bank = Bank::new(&genesis_config);
+
+        fn mock_process_instruction(
+            _program_id: &Pubkey,
+            _keyed_accounts: &[KeyedAccount],
+            data: &[u8],
+            invoke_context: &mut dyn InvokeContext,
+        ) -> result::Result<(), InstructionError> {
+            let expect_evm_state = data[0] != 0;
+            if invoke_context.get_evm_state().is_some() != expect_evm_state {
+                return Err(InstructionError::InvalidArgument);
+            }
+            Ok(())
+        }
+
+        let mock_program_id = Pubkey::new(&[2u8; 32]);
+        bank.add_builtin("mock_program", mock_program_id, mock_process_instruction);
+
+        let process_instruction = |expect_evm_state: bool, evm_state_meta: AccountMeta| {
+            let instruction = Instruction::new(
+                mock_program_id,
+                &(expect_evm_state as u8),
+                vec![evm_state_meta],
+            );
+            let tx = Transaction::new_signed_with_payer(
+                &[instruction],
+                Some(&mint_keypair.pubkey()),
+                &[&mint_keypair],
+                bank.last_blockhash(),
+            );
+            bank.process_transaction(&tx)
+        };
+
+        // read-only transactions don't get an evm executor
+        assert_eq!(
+            process_instruction(
+                false,
+                AccountMeta::new_readonly(solana_sdk::evm_state::id(), false)
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            process_instruction(true, AccountMeta::new(solana_sdk::evm_state::id(), false)),
+            Ok(())
+        );
+    }
+
     /// Process two batches, one with some slow routine, and second with evm state modification.
     /// Both batches are without conflicts, expect that with any size of sleep, evm batch will modify state root.
     #[test]
@@ -10164,15 +10479,20 @@ pub(crate) mod tests {
         let txs = vec![tx0, tx1, tx2];
 
         let lock_result = bank0.prepare_batch(&txs, None);
-        let (transaction_results, transaction_balances_set, inner_instructions, transaction_logs) =
-            bank0.load_execute_and_commit_transactions(
-                &lock_result,
-                MAX_PROCESSING_AGE,
-                true,
-                false,
-                false,
-                &mut ExecuteTimings::default(),
-            );
+        let (
+            transaction_results,
+            transaction_balances_set,
+            inner_instructions,
+            transaction_logs,
+            _transaction_return_data,
+        ) = bank0.load_execute_and_commit_transactions(
+            &lock_result,
+            MAX_PROCESSING_AGE,
+            true,
+            false,
+            false,
+            &mut ExecuteTimings::default(),
+        );
 
         assert!(inner_instructions[0].iter().all(|ix| ix.is_empty()));
         assert_eq!(transaction_logs.len(), 0);
@@ -1,5 +1,8 @@
 use crate::bank::Bank;
-use evm_state::AccountProvider;
+use evm_rpc::{
+    BlockId, RPCBlock, RPCLog, RPCLogFilter, RPCReceipt, RPCTopicFilter, RPCTransaction,
+};
+use evm_state::{AccountProvider, LogFilter, LogWithLocation};
 use solana_sdk::{
     account::Account,
     client::{AsyncClient, Client, SyncClient},
@@ -17,6 +20,7 @@ use solana_sdk::{
     transport::{Result, TransportError},
 };
 use std::{
+    convert::TryInto,
     io,
     sync::{
         mpsc::{channel, Receiver, Sender},
@@ -293,9 +297,185 @@ impl SyncClient for BankClient {
 
         Ok(account.balance)
     }
+
+    fn get_evm_block_number(&self) -> Result<u64> {
+        Ok(self.bank.evm_state.read().unwrap().block_number())
+    }
+
+    fn get_evm_transaction_count(&self, address: &evm_state::Address) -> Result<evm_state::U256> {
+        let account = self
+            .bank
+            .evm_state
+            .read()
+            .unwrap()
+            .get_account_state(*address)
+            .unwrap_or_default();
+
+        Ok(account.nonce)
+    }
+
+    fn get_evm_code(&self, address: &evm_state::Address) -> Result<Vec<u8>> {
+        let account = self
+            .bank
+            .evm_state
+            .read()
+            .unwrap()
+            .get_account_state(*address)
+            .unwrap_or_default();
+
+        Ok(account.code.into())
+    }
+
+    fn get_evm_storage_at(
+        &self,
+        address: &evm_state::Address,
+        index: &evm_state::H256,
+    ) -> Result<evm_state::H256> {
+        Ok(self
+            .bank
+            .evm_state
+            .read()
+            .unwrap()
+            .get_storage(*address, *index)
+            .unwrap_or_default())
+    }
+
+    /// Only the block committed by this bank is available.
+    fn get_evm_block_by_number(&self, block: BlockId, full: bool) -> Result<Option<RPCBlock>> {
+        let committed_block = self.bank.evm_state.read().unwrap().get_block();
+        Ok(committed_block
+            .filter(|committed_block| match block {
+                BlockId::Num(num) => committed_block.header.block_number == num,
+                BlockId::Latest | BlockId::Pending => true,
+                BlockId::Earliest => false,
+            })
+            .map(|committed_block| RPCBlock::new_from_block(committed_block, true, full)))
+    }
+
+    /// Only transactions of the block committed by this bank are available.
+    fn get_evm_transaction_receipt(&self, hash: &evm_state::H256) -> Result<Option<RPCReceipt>> {
+        let committed_block = match self.bank.evm_state.read().unwrap().get_block() {
+            Some(committed_block) => committed_block,
+            None => return Ok(None),
+        };
+        let block_hash = committed_block.header.hash();
+        committed_block
+            .transactions
+            .into_iter()
+            .find(|(transaction_hash, _)| transaction_hash == hash)
+            .map(|(_, receipt)| RPCReceipt::new_from_receipt(receipt, block_hash))
+            .transpose()
+            .map_err(|err| TransportError::Custom(err.to_string()))
+    }
+
+    /// Only logs of the block committed by this bank are available.
+    fn get_evm_logs(&self, filter: &RPCLogFilter) -> Result<Vec<RPCLog>> {
+        let committed_block = match self.bank.evm_state.read().unwrap().get_block() {
+            Some(committed_block) => committed_block,
+            None => return Ok(vec![]),
+        };
+        let block_num = committed_block.header.block_number;
+        let filter = LogFilter {
+            address: filter.address.map(|address| address.0),
+            topics: filter
+                .topics
+                .clone()
+                .into_iter()
+                .flatten()
+                .map(RPCTopicFilter::into_topics)
+                .collect(),
+            from_block: block_tag_to_num(filter.from_block.as_ref(), block_num)?,
+            to_block: block_tag_to_num(filter.to_block.as_ref(), block_num)?,
+        };
+        if block_num < filter.from_block || block_num > filter.to_block {
+            return Ok(vec![]);
+        }
+
+        let mut logs = vec![];
+        for (id, (transaction_hash, receipt)) in
+            committed_block.transactions.into_iter().enumerate()
+        {
+            for log in receipt.logs {
+                if filter.is_log_match(&log) {
+                    logs.push(
+                        LogWithLocation {
+                            transaction_hash,
+                            transaction_id: id as u64,
+                            block_num,
+                            address: log.address,
+                            data: log.data,
+                            topics: log.topics,
+                        }
+                        .into(),
+                    );
+                }
+            }
+        }
+        Ok(logs)
+    }
+
+    fn evm_call(&self, tx: &RPCTransaction) -> Result<Vec<u8>> {
+        let (_exit_reason, output, _used_gas) = self.evm_execute(tx)?;
+        Ok(output)
+    }
+
+    fn evm_estimate_gas(&self, tx: &RPCTransaction) -> Result<evm_state::Gas> {
+        let (_exit_reason, _output, used_gas) = self.evm_execute(tx)?;
+        Ok(used_gas.into())
+    }
+}
+
+// Resolve block tag of the logs filter, `None` is the latest block.
+fn block_tag_to_num(block: Option<&String>, latest: u64) -> Result<u64> {
+    let block = match block {
+        Some(block) => block
+            .parse()
+            .map_err(|err: evm_rpc::Error| TransportError::Custom(err.to_string()))?,
+        None => BlockId::Latest,
+    };
+    Ok(match block {
+        BlockId::Num(num) => num,
+        BlockId::Earliest => 0,
+        BlockId::Latest | BlockId::Pending => latest,
+    })
 }
 
 impl BankClient {
+    /// Execute `tx` on top of the bank EVM state, without committing it.
+    /// Returns exit reason, output and used gas.
+    fn evm_execute(&self, tx: &RPCTransaction) -> Result<(evm_state::ExitReason, Vec<u8>, u64)> {
+        let caller = tx.from.map(|a| a.0).unwrap_or_default();
+        let value = tx.value.map(|a| a.0).unwrap_or_else(|| 0.into());
+        let input = tx.data.clone().map(|a| a.0).unwrap_or_else(Vec::new);
+        let gas_limit = tx.gas.map(|a| a.0).unwrap_or_else(|| 300000000.into());
+        let gas_limit: u64 = gas_limit
+            .try_into()
+            .map_err(|err: &str| TransportError::Custom(err.to_string()))?;
+
+        let evm_state = match self.bank.evm_state.read().unwrap().new_from_parent(0) {
+            evm_state::EvmState::Incomming(i) => i,
+            evm_state::EvmState::Committed(_) => unreachable!(),
+        };
+        let estimate_config = evm_state::EvmConfig {
+            estimate: true,
+            ..self.bank.evm_params().evm_config(self.bank.evm_chain_id)
+        };
+        let mut executor = evm_state::Executor::with_config(
+            evm_state,
+            evm_state::ChainContext::new(self.bank.evm_hashes()),
+            estimate_config,
+        );
+
+        let (exit_reason, output) = if let Some(address) = tx.to {
+            executor.with_executor(|e| e.transact_call(caller, address.0, value, input, gas_limit))
+        } else {
+            executor.with_executor(|e| (e.transact_create(caller, value, input, gas_limit), vec![]))
+        };
+
+        let used_gas = executor.deconstruct().state.used_gas;
+        Ok((exit_reason, output, used_gas))
+    }
+
     fn run(bank: &Bank, transaction_receiver: Receiver<Transaction>) {
         while let Ok(tx) = transaction_receiver.recv() {
             let mut transactions = vec![tx];
@@ -357,4 +537,43 @@ mod tests {
             .unwrap();
         assert_eq!(bank_client.get_balance(&bob_pubkey).unwrap(), 42);
     }
+
+    #[test]
+    fn test_bank_client_evm_requests() {
+        let (genesis_config, _) = create_genesis_config(10_000);
+        let bank_client = BankClient::new(Bank::new(&genesis_config));
+        let address = evm_state::Address::from_low_u64_be(1);
+
+        assert_eq!(
+            bank_client.get_evm_transaction_count(&address).unwrap(),
+            evm_state::U256::zero()
+        );
+        assert!(bank_client.get_evm_code(&address).unwrap().is_empty());
+        assert_eq!(
+            bank_client
+                .get_evm_storage_at(&address, &evm_state::H256::zero())
+                .unwrap(),
+            evm_state::H256::zero()
+        );
+        assert!(bank_client
+            .get_evm_transaction_receipt(&evm_state::H256::zero())
+            .unwrap()
+            .is_none());
+        assert!(bank_client
+            .get_evm_logs(&RPCLogFilter {
+                from_block: None,
+                to_block: None,
+                address: None,
+                topics: None,
+            })
+            .unwrap()
+            .is_empty());
+
+        let tx = RPCTransaction {
+            to: Some(evm_rpc::Hex(address)),
+            ..RPCTransaction::default()
+        };
+        assert!(bank_client.evm_call(&tx).unwrap().is_empty());
+        assert!(!bank_client.evm_estimate_gas(&tx).unwrap().is_zero());
+    }
 }
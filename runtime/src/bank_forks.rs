@@ -40,6 +40,16 @@ pub struct SnapshotConfig {
 
     // Snapshot version to generate
     pub snapshot_version: SnapshotVersion,
+
+    // Generate a new incremental snapshot, on top of the last full one, every this many slots
+    pub incremental_snapshot_interval_slots: u64,
+}
+
+impl SnapshotConfig {
+    pub fn incremental_snapshots_enabled(&self) -> bool {
+        self.incremental_snapshot_interval_slots != 0
+            && self.incremental_snapshot_interval_slots != std::u64::MAX
+    }
 }
 
 pub struct BankForks {
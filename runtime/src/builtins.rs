@@ -3,7 +3,7 @@ use crate::{
     system_instruction_processor,
 };
 use solana_sdk::{
-    feature_set,
+    compute_budget, feature_set,
     instruction::InstructionError,
     keyed_account::KeyedAccount,
     process_instruction::{stable_log, InvokeContext, ProcessInstructionWithContext},
@@ -47,6 +47,17 @@ macro_rules! with_program_logging {
     };
 }
 
+/// Compute budget instructions are applied by the message processor before the message is
+/// executed, there is nothing left to do when they are invoked
+fn process_compute_budget_instruction(
+    _program_id: &Pubkey,
+    _keyed_accounts: &[KeyedAccount],
+    _instruction_data: &[u8],
+    _invoke_context: &mut dyn InvokeContext,
+) -> Result<(), InstructionError> {
+    Ok(())
+}
+
 /// Builtin programs that are always available
 fn genesis_builtins() -> Vec<Builtin> {
     vec![
@@ -88,15 +99,35 @@ pub enum ActivationType {
 /// normal child Bank creation.
 /// https://github.com/solana-labs/solana/blob/84b139cc94b5be7c9e0c18c2ad91743231b85a0d/runtime/src/bank.rs#L1723
 fn feature_builtins() -> Vec<(Builtin, Pubkey, ActivationType)> {
-    vec![(
-        Builtin::new(
-            "secp256k1_program",
-            solana_sdk::secp256k1_program::id(),
-            solana_secp256k1_program::process_instruction,
+    vec![
+        (
+            Builtin::new(
+                "secp256k1_program",
+                solana_sdk::secp256k1_program::id(),
+                solana_secp256k1_program::process_instruction,
+            ),
+            feature_set::secp256k1_program_enabled::id(),
+            ActivationType::NewProgram,
+        ),
+        (
+            Builtin::new(
+                "velas_account_program",
+                solana_velas_account_program::id(),
+                solana_velas_account_program::vaccount_processor::process_instruction,
+            ),
+            feature_set::velas_accounts_program::id(),
+            ActivationType::NewProgram,
         ),
-        feature_set::secp256k1_program_enabled::id(),
-        ActivationType::NewProgram,
-    )]
+        (
+            Builtin::new(
+                "compute_budget_program",
+                compute_budget::id(),
+                process_compute_budget_instruction,
+            ),
+            feature_set::compute_budget_requests::id(),
+            ActivationType::NewProgram,
+        ),
+    ]
 }
 
 pub(crate) fn get() -> Builtins {
@@ -5,6 +5,7 @@ pub mod accounts_background_service;
 pub mod accounts_cache;
 pub mod accounts_db;
 pub mod accounts_index;
+pub mod accounts_update_notifier_interface;
 pub mod append_vec;
 pub mod bank;
 pub mod bank_client;
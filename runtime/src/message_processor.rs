@@ -2,6 +2,7 @@ use crate::{
     instruction_recorder::InstructionRecorder, log_collector::LogCollector,
     native_loader::NativeLoader, rent_collector::RentCollector,
 };
+use evm_state::AccountProvider;
 use log::*;
 use serde::{Deserialize, Serialize};
 use solana_evm_loader_program::EvmProcessor;
@@ -9,7 +10,10 @@ use solana_sdk::{
     account::Account,
     account_utils::StateMut,
     bpf_loader_upgradeable::{self, UpgradeableLoaderState},
-    feature_set::{instructions_sysvar_enabled, track_writable_deescalation, FeatureSet},
+    feature_set::{
+        compute_budget_requests, instructions_sysvar_enabled, track_writable_deescalation,
+        FeatureSet,
+    },
     ic_msg,
     instruction::{CompiledInstruction, Instruction, InstructionError},
     keyed_account::{create_keyed_readonly_accounts, KeyedAccount},
@@ -17,7 +21,7 @@ use solana_sdk::{
     native_loader,
     process_instruction::{
         BpfComputeBudget, ComputeMeter, Executor, InvokeContext, Logger,
-        ProcessInstructionWithContext,
+        ProcessInstructionWithContext, TransactionReturnData,
     },
     pubkey::Pubkey,
     rent::Rent,
@@ -216,6 +220,7 @@ pub struct ThisInvokeContext<'a> {
     rent: Rent,
     pre_accounts: Vec<PreAccount>,
     account_deps: &'a [(Pubkey, RefCell<Account>)],
+    sysvars: &'a [(Pubkey, Vec<u8>)],
     programs: &'a [(Pubkey, ProcessInstructionWithContext)],
     logger: Rc<RefCell<dyn Logger>>,
     bpf_compute_budget: BpfComputeBudget,
@@ -223,6 +228,8 @@ pub struct ThisInvokeContext<'a> {
     executors: Rc<RefCell<Executors>>,
     instruction_recorder: Option<InstructionRecorder>,
     feature_set: Arc<FeatureSet>,
+    evm_state: Option<&'a dyn AccountProvider>,
+    return_data: (Pubkey, Vec<u8>),
 }
 impl<'a> ThisInvokeContext<'a> {
     #[allow(clippy::too_many_arguments)]
@@ -231,12 +238,14 @@ impl<'a> ThisInvokeContext<'a> {
         rent: Rent,
         pre_accounts: Vec<PreAccount>,
         account_deps: &'a [(Pubkey, RefCell<Account>)],
+        sysvars: &'a [(Pubkey, Vec<u8>)],
         programs: &'a [(Pubkey, ProcessInstructionWithContext)],
         log_collector: Option<Rc<LogCollector>>,
         bpf_compute_budget: BpfComputeBudget,
         executors: Rc<RefCell<Executors>>,
         instruction_recorder: Option<InstructionRecorder>,
         feature_set: Arc<FeatureSet>,
+        evm_state: Option<&'a dyn AccountProvider>,
     ) -> Self {
         let mut program_ids = Vec::with_capacity(bpf_compute_budget.max_invoke_depth);
         program_ids.push(*program_id);
@@ -245,6 +254,7 @@ impl<'a> ThisInvokeContext<'a> {
             rent,
             pre_accounts,
             account_deps,
+            sysvars,
             programs,
             logger: Rc::new(RefCell::new(ThisLogger { log_collector })),
             bpf_compute_budget,
@@ -254,6 +264,18 @@ impl<'a> ThisInvokeContext<'a> {
             executors,
             instruction_recorder,
             feature_set,
+            evm_state,
+            return_data: (Pubkey::default(), Vec::new()),
+        }
+    }
+
+    /// Take the return data left by the instruction, if any
+    pub fn take_return_data(&mut self) -> Option<TransactionReturnData> {
+        let (program_id, data) = std::mem::take(&mut self.return_data);
+        if data.is_empty() {
+            None
+        } else {
+            Some(TransactionReturnData { program_id, data })
         }
     }
 }
@@ -347,6 +369,19 @@ impl<'a> InvokeContext for ThisInvokeContext<'a> {
             }
         })
     }
+    fn get_evm_state(&self) -> Option<&dyn AccountProvider> {
+        self.evm_state
+    }
+    fn set_return_data(&mut self, data: Vec<u8>) -> Result<(), InstructionError> {
+        self.return_data = (*self.get_caller()?, data);
+        Ok(())
+    }
+    fn get_return_data(&self) -> (Pubkey, &[u8]) {
+        (self.return_data.0, &self.return_data.1)
+    }
+    fn get_sysvars(&self) -> &[(Pubkey, Vec<u8>)] {
+        self.sysvars
+    }
 }
 pub struct ThisLogger {
     log_collector: Option<Rc<LogCollector>>,
@@ -785,7 +820,8 @@ impl MessageProcessor {
             let keyed_accounts =
                 Self::create_keyed_accounts(message, instruction, executable_accounts, accounts);
 
-            // Invoke callee
+            // Invoke callee, it starts without return data
+            invoke_context.set_return_data(Vec::new())?;
             invoke_context.push(program_id)?;
 
             let mut message_processor = MessageProcessor::default();
@@ -955,6 +991,7 @@ impl MessageProcessor {
         executable_accounts: &[(Pubkey, RefCell<Account>)],
         accounts: &[Rc<RefCell<Account>>],
         account_deps: &[(Pubkey, RefCell<Account>)],
+        sysvars: &[(Pubkey, Vec<u8>)],
         rent_collector: &RentCollector,
         log_collector: Option<Rc<LogCollector>>,
         executors: Rc<RefCell<Executors>>,
@@ -963,6 +1000,8 @@ impl MessageProcessor {
         feature_set: Arc<FeatureSet>,
         bpf_compute_budget: BpfComputeBudget,
         evm_executor: Option<&mut evm_state::Executor>,
+        compute_units_consumed: &mut u64,
+        return_data: &mut Option<TransactionReturnData>,
     ) -> Result<(), InstructionError> {
         // Fixup the special instructions key if present
         // before the account pre-values are taken care of
@@ -981,27 +1020,42 @@ impl MessageProcessor {
 
         let pre_accounts = Self::create_pre_accounts(message, instruction, accounts);
         let program_id = instruction.program_id(&message.account_keys);
+        // Only the evm loader can modify the evm state, other programs can only read it
+        let (evm_executor, evm_state) = if solana_sdk::evm_loader::check_id(program_id) {
+            (evm_executor, None)
+        } else {
+            let evm_state =
+                evm_executor.map(|evm_executor| &evm_executor.evm_backend as &dyn AccountProvider);
+            (None, evm_state)
+        };
         let mut invoke_context = ThisInvokeContext::new(
             program_id,
             rent_collector.rent,
             pre_accounts,
             account_deps,
+            sysvars,
             &self.programs,
             log_collector,
             bpf_compute_budget,
             executors,
             instruction_recorder,
             feature_set,
+            evm_state,
         );
         let keyed_accounts =
             Self::create_keyed_accounts(message, instruction, executable_accounts, accounts);
-        self.process_instruction(
+        let result = self.process_instruction(
             program_id,
             &keyed_accounts,
             &instruction.data,
             &mut invoke_context,
             evm_executor,
-        )?;
+        );
+        *compute_units_consumed += bpf_compute_budget
+            .max_units
+            .saturating_sub(invoke_context.get_compute_meter().borrow().get_remaining());
+        *return_data = invoke_context.take_return_data();
+        result?;
         Self::verify(
             message,
             instruction,
@@ -1016,6 +1070,13 @@ impl MessageProcessor {
     /// Process a message.
     /// This method calls each instruction in the message over the set of loaded Accounts
     /// The accounts are committed back to the bank only if every instruction succeeds
+    /// Compute units used by the executed instructions are added to `compute_units_consumed`,
+    /// including the units of a failed instruction
+    /// `return_data` is set to the data returned by the last executed instruction
+    /// `sysvars` holds the sysvar accounts data programs can read through syscalls
+    /// With compute budget requests enabled, the message's requests are applied to
+    /// `bpf_compute_budget`, and if the message requests compute units they limit
+    /// the whole message instead of each instruction
     #[allow(clippy::too_many_arguments)]
     pub fn process_message(
         &self,
@@ -1023,32 +1084,52 @@ impl MessageProcessor {
         loaders: &[Vec<(Pubkey, RefCell<Account>)>],
         accounts: &[Rc<RefCell<Account>>],
         account_deps: &[(Pubkey, RefCell<Account>)],
+        sysvars: &[(Pubkey, Vec<u8>)],
         rent_collector: &RentCollector,
         log_collector: Option<Rc<LogCollector>>,
         executors: Rc<RefCell<Executors>>,
         instruction_recorders: Option<&[InstructionRecorder]>,
         feature_set: Arc<FeatureSet>,
-        bpf_compute_budget: BpfComputeBudget,
+        mut bpf_compute_budget: BpfComputeBudget,
         mut evm_executor: Option<&mut evm_state::Executor>,
+        compute_units_consumed: &mut u64,
+        return_data: &mut Option<TransactionReturnData>,
     ) -> Result<(), TransactionError> {
+        let transaction_wide_compute_cap = feature_set.is_active(&compute_budget_requests::id())
+            && bpf_compute_budget.process_message(message)?;
+        let initial_compute_units_consumed = *compute_units_consumed;
         for (instruction_index, instruction) in message.instructions.iter().enumerate() {
             let instruction_recorder = instruction_recorders
                 .as_ref()
                 .map(|recorders| recorders[instruction_index].clone());
+            let instruction_compute_budget = if transaction_wide_compute_cap {
+                // Each instruction gets the units left over by the previous ones
+                BpfComputeBudget {
+                    max_units: bpf_compute_budget.max_units.saturating_sub(
+                        compute_units_consumed.saturating_sub(initial_compute_units_consumed),
+                    ),
+                    ..bpf_compute_budget
+                }
+            } else {
+                bpf_compute_budget
+            };
             self.execute_instruction(
                 message,
                 instruction,
                 &loaders[instruction_index],
                 accounts,
                 account_deps,
+                sysvars,
                 rent_collector,
                 log_collector.clone(),
                 executors.clone(),
                 instruction_recorder,
                 instruction_index,
                 feature_set.clone(),
-                bpf_compute_budget,
+                instruction_compute_budget,
                 evm_executor.as_deref_mut(),
+                compute_units_consumed,
+                return_data,
             )
             .map_err(|err| TransactionError::InstructionError(instruction_index as u8, err))?;
         }
@@ -1060,6 +1141,7 @@ impl MessageProcessor {
 mod tests {
     use super::*;
     use solana_sdk::{
+        compute_budget::{self, ComputeBudgetInstruction},
         instruction::{AccountMeta, Instruction, InstructionError},
         message::Message,
         native_loader::create_loadable_account,
@@ -1093,11 +1175,13 @@ mod tests {
             pre_accounts,
             &[],
             &[],
+            &[],
             None,
             BpfComputeBudget::default(),
             Rc::new(RefCell::new(Executors::default())),
             None,
             Arc::new(FeatureSet::all_enabled()),
+            None,
         );
 
         // Check call depth increases and has a limit
@@ -1662,6 +1746,7 @@ mod tests {
             &loaders,
             &accounts,
             &[],
+            &[],
             &rent_collector,
             None,
             executors.clone(),
@@ -1669,6 +1754,8 @@ mod tests {
             Arc::new(FeatureSet::all_enabled()),
             BpfComputeBudget::new(&FeatureSet::all_enabled()),
             None,
+            &mut 0,
+            &mut None,
         );
         assert_eq!(result, Ok(()));
         assert_eq!(accounts[0].borrow().lamports, 100);
@@ -1688,6 +1775,7 @@ mod tests {
             &loaders,
             &accounts,
             &[],
+            &[],
             &rent_collector,
             None,
             executors.clone(),
@@ -1695,6 +1783,8 @@ mod tests {
             Arc::new(FeatureSet::all_enabled()),
             BpfComputeBudget::new(&FeatureSet::all_enabled()),
             None,
+            &mut 0,
+            &mut None,
         );
         assert_eq!(
             result,
@@ -1718,6 +1808,7 @@ mod tests {
             &loaders,
             &accounts,
             &[],
+            &[],
             &rent_collector,
             None,
             executors,
@@ -1725,6 +1816,8 @@ mod tests {
             Arc::new(FeatureSet::all_enabled()),
             BpfComputeBudget::new(&FeatureSet::all_enabled()),
             None,
+            &mut 0,
+            &mut None,
         );
         assert_eq!(
             result,
@@ -1832,6 +1925,7 @@ mod tests {
             &loaders,
             &accounts,
             &[],
+            &[],
             &rent_collector,
             None,
             executors.clone(),
@@ -1839,6 +1933,8 @@ mod tests {
             Arc::new(FeatureSet::all_enabled()),
             BpfComputeBudget::new(&FeatureSet::all_enabled()),
             None,
+            &mut 0,
+            &mut None,
         );
         assert_eq!(
             result,
@@ -1862,6 +1958,7 @@ mod tests {
             &loaders,
             &accounts,
             &[],
+            &[],
             &rent_collector,
             None,
             executors.clone(),
@@ -1869,6 +1966,8 @@ mod tests {
             Arc::new(FeatureSet::all_enabled()),
             BpfComputeBudget::new(&FeatureSet::all_enabled()),
             None,
+            &mut 0,
+            &mut None,
         );
         assert_eq!(result, Ok(()));
 
@@ -1889,6 +1988,7 @@ mod tests {
             &loaders,
             &accounts,
             &[],
+            &[],
             &rent_collector,
             None,
             executors,
@@ -1896,6 +1996,8 @@ mod tests {
             Arc::new(FeatureSet::all_enabled()),
             BpfComputeBudget::new(&FeatureSet::all_enabled()),
             None,
+            &mut 0,
+            &mut None,
         );
         assert_eq!(result, Ok(()));
         assert_eq!(accounts[0].borrow().lamports, 80);
@@ -1903,6 +2005,302 @@ mod tests {
         assert_eq!(accounts[0].borrow().data, vec![42]);
     }
 
+    #[test]
+    fn test_process_message_evm_state_read_access() {
+        fn mock_process_instruction(
+            _program_id: &Pubkey,
+            _keyed_accounts: &[KeyedAccount],
+            data: &[u8],
+            invoke_context: &mut dyn InvokeContext,
+        ) -> Result<(), InstructionError> {
+            let evm_state = invoke_context
+                .get_evm_state()
+                .ok_or(InstructionError::MissingAccount)?;
+            let balance = evm_state
+                .get_account_state(evm_state::H160::repeat_byte(data[0]))
+                .map(|state| state.balance)
+                .unwrap_or_default();
+            if balance != evm_state::U256::from(42) {
+                return Err(InstructionError::InvalidArgument);
+            }
+            Ok(())
+        }
+
+        let mock_program_id = Pubkey::new(&[2u8; 32]);
+        let rent_collector = RentCollector::default();
+        let mut message_processor = MessageProcessor::default();
+        message_processor.add_program(mock_program_id, mock_process_instruction);
+
+        let mut loaders: Vec<Vec<(Pubkey, RefCell<Account>)>> = Vec::new();
+        let account = RefCell::new(create_loadable_account("mock_program", 1));
+        loaders.push(vec![(mock_program_id, account)]);
+
+        let from_pubkey = solana_sdk::pubkey::new_rand();
+        let accounts = vec![Account::new_ref(100, 1, &mock_program_id)];
+        let message = Message::new(
+            &[Instruction::new(
+                mock_program_id,
+                &1u8,
+                vec![AccountMeta::new(from_pubkey, true)],
+            )],
+            Some(&from_pubkey),
+        );
+
+        let mut evm_executor = evm_state::Executor::testing();
+        evm_executor.evm_backend.set_account_state(
+            evm_state::H160::repeat_byte(1),
+            evm_state::AccountState {
+                balance: 42.into(),
+                ..Default::default()
+            },
+        );
+
+        let result = message_processor.process_message(
+            &message,
+            &loaders,
+            &accounts,
+            &[],
+            &[],
+            &rent_collector,
+            None,
+            Rc::new(RefCell::new(Executors::default())),
+            None,
+            Arc::new(FeatureSet::all_enabled()),
+            BpfComputeBudget::new(&FeatureSet::all_enabled()),
+            Some(&mut evm_executor),
+            &mut 0,
+            &mut None,
+        );
+        assert_eq!(result, Ok(()));
+
+        // Evm state is not available if the transaction didn't lock it
+        let result = message_processor.process_message(
+            &message,
+            &loaders,
+            &accounts,
+            &[],
+            &[],
+            &rent_collector,
+            None,
+            Rc::new(RefCell::new(Executors::default())),
+            None,
+            Arc::new(FeatureSet::all_enabled()),
+            BpfComputeBudget::new(&FeatureSet::all_enabled()),
+            None,
+            &mut 0,
+            &mut None,
+        );
+        assert_eq!(
+            result,
+            Err(TransactionError::InstructionError(
+                0,
+                InstructionError::MissingAccount
+            ))
+        );
+    }
+
+    #[test]
+    fn test_process_message_return_data() {
+        fn mock_process_instruction(
+            _program_id: &Pubkey,
+            _keyed_accounts: &[KeyedAccount],
+            data: &[u8],
+            invoke_context: &mut dyn InvokeContext,
+        ) -> Result<(), InstructionError> {
+            assert!(invoke_context.get_return_data().1.is_empty());
+            if data[0] != 0 {
+                invoke_context.set_return_data(vec![data[0]; 3])?;
+            }
+            Ok(())
+        }
+
+        let mock_program_id = Pubkey::new(&[2u8; 32]);
+        let rent_collector = RentCollector::default();
+        let mut message_processor = MessageProcessor::default();
+        message_processor.add_program(mock_program_id, mock_process_instruction);
+
+        let mut loaders: Vec<Vec<(Pubkey, RefCell<Account>)>> = Vec::new();
+        let account = RefCell::new(create_loadable_account("mock_program", 1));
+        loaders.push(vec![(mock_program_id, account)]);
+
+        let from_pubkey = solana_sdk::pubkey::new_rand();
+        let accounts = vec![Account::new_ref(100, 1, &mock_program_id)];
+        let metas = vec![AccountMeta::new(from_pubkey, true)];
+        let process_message = |instructions: &[Instruction]| {
+            let message = Message::new(instructions, Some(&from_pubkey));
+            let mut return_data = None;
+            let result = message_processor.process_message(
+                &message,
+                &loaders,
+                &accounts,
+                &[],
+                &[],
+                &rent_collector,
+                None,
+                Rc::new(RefCell::new(Executors::default())),
+                None,
+                Arc::new(FeatureSet::all_enabled()),
+                BpfComputeBudget::new(&FeatureSet::all_enabled()),
+                None,
+                &mut 0,
+                &mut return_data,
+            );
+            assert_eq!(result, Ok(()));
+            return_data
+        };
+
+        // The return data of the last instruction is the return data of the transaction
+        assert_eq!(
+            process_message(&[
+                Instruction::new(mock_program_id, &1u8, metas.clone()),
+                Instruction::new(mock_program_id, &2u8, metas.clone()),
+            ]),
+            Some(TransactionReturnData {
+                program_id: mock_program_id,
+                data: vec![2; 3],
+            })
+        );
+        assert_eq!(
+            process_message(&[
+                Instruction::new(mock_program_id, &1u8, metas.clone()),
+                Instruction::new(mock_program_id, &0u8, metas),
+            ]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_process_message_compute_budget_requests() {
+        fn mock_process_instruction(
+            _program_id: &Pubkey,
+            _keyed_accounts: &[KeyedAccount],
+            data: &[u8],
+            invoke_context: &mut dyn InvokeContext,
+        ) -> Result<(), InstructionError> {
+            assert_eq!(
+                invoke_context.get_bpf_compute_budget().heap_size,
+                Some(64 * 1024)
+            );
+            invoke_context
+                .get_compute_meter()
+                .borrow_mut()
+                .consume(u64::from(data[0]) * 1_000)
+        }
+        fn noop_process_instruction(
+            _program_id: &Pubkey,
+            _keyed_accounts: &[KeyedAccount],
+            _data: &[u8],
+            _invoke_context: &mut dyn InvokeContext,
+        ) -> Result<(), InstructionError> {
+            Ok(())
+        }
+
+        let mock_program_id = Pubkey::new(&[2u8; 32]);
+        let rent_collector = RentCollector::default();
+        let mut message_processor = MessageProcessor::default();
+        message_processor.add_program(mock_program_id, mock_process_instruction);
+        message_processor.add_program(compute_budget::id(), noop_process_instruction);
+
+        let from_pubkey = solana_sdk::pubkey::new_rand();
+        let accounts = vec![Account::new_ref(100, 1, &mock_program_id)];
+        let metas = vec![AccountMeta::new(from_pubkey, true)];
+        let process_message = |instructions: &[Instruction]| {
+            let loaders: Vec<Vec<(Pubkey, RefCell<Account>)>> = instructions
+                .iter()
+                .map(|instruction| {
+                    vec![(
+                        instruction.program_id,
+                        RefCell::new(create_loadable_account("program", 1)),
+                    )]
+                })
+                .collect();
+            let mut compute_units_consumed = 0;
+            let result = message_processor.process_message(
+                &Message::new(instructions, Some(&from_pubkey)),
+                &loaders,
+                &accounts,
+                &[],
+                &[],
+                &rent_collector,
+                None,
+                Rc::new(RefCell::new(Executors::default())),
+                None,
+                Arc::new(FeatureSet::all_enabled()),
+                BpfComputeBudget::new(&FeatureSet::all_enabled()),
+                None,
+                &mut compute_units_consumed,
+                &mut None,
+            );
+            (result, compute_units_consumed)
+        };
+
+        // Requested units limit the whole transaction
+        assert_eq!(
+            process_message(&[
+                ComputeBudgetInstruction::request_units(10_000),
+                ComputeBudgetInstruction::request_heap_frame(64 * 1024),
+                Instruction::new(mock_program_id, &6u8, metas.clone()),
+                Instruction::new(mock_program_id, &4u8, metas.clone()),
+            ]),
+            (Ok(()), 10_000)
+        );
+        assert_eq!(
+            process_message(&[
+                ComputeBudgetInstruction::request_units(10_000),
+                ComputeBudgetInstruction::request_heap_frame(64 * 1024),
+                Instruction::new(mock_program_id, &6u8, metas.clone()),
+                Instruction::new(mock_program_id, &6u8, metas.clone()),
+            ]),
+            (
+                Err(TransactionError::InstructionError(
+                    3,
+                    InstructionError::ComputationalBudgetExceeded
+                )),
+                10_000
+            )
+        );
+
+        // Without requested units each instruction keeps its own budget
+        assert_eq!(
+            process_message(&[
+                ComputeBudgetInstruction::request_heap_frame(64 * 1024),
+                Instruction::new(mock_program_id, &150u8, metas.clone()),
+                Instruction::new(mock_program_id, &150u8, metas.clone()),
+            ]),
+            (Ok(()), 300_000)
+        );
+        assert_eq!(
+            process_message(&[
+                ComputeBudgetInstruction::request_heap_frame(64 * 1024),
+                Instruction::new(mock_program_id, &150u8, metas.clone()),
+                Instruction::new(mock_program_id, &250u8, metas.clone()),
+            ]),
+            (
+                Err(TransactionError::InstructionError(
+                    2,
+                    InstructionError::ComputationalBudgetExceeded
+                )),
+                350_000
+            )
+        );
+
+        // Invalid requests fail the transaction before any instruction is executed
+        assert_eq!(
+            process_message(&[
+                ComputeBudgetInstruction::request_heap_frame(64 * 1024),
+                Instruction::new(mock_program_id, &1u8, metas),
+                ComputeBudgetInstruction::request_heap_frame(1),
+            ]),
+            (
+                Err(TransactionError::InstructionError(
+                    2,
+                    InstructionError::InvalidArgument
+                )),
+                0
+            )
+        );
+    }
+
     #[test]
     fn test_process_cross_program() {
         #[derive(Debug, Serialize, Deserialize)]
@@ -1975,12 +2373,14 @@ mod tests {
                 executable_preaccount,
             ],
             &[],
+            &[],
             programs.as_slice(),
             None,
             BpfComputeBudget::default(),
             Rc::new(RefCell::new(Executors::default())),
             None,
             Arc::new(FeatureSet::all_enabled()),
+            None,
         );
         let metas = vec![
             AccountMeta::new(owned_key, false),
@@ -67,6 +67,17 @@ const MAX_STREAM_SIZE: u64 = 32 * 1024 * 1024 * 1024;
 #[derive(Clone, Debug, Default, Deserialize, Serialize, AbiExample)]
 struct AccountsDbFields<T>(HashMap<Slot, Vec<T>>, u64, Slot, BankHashInfo);
 
+impl<T> AccountsDbFields<T> {
+    // Incremental snapshot only contains storages of the slots after its base full snapshot,
+    // the rest of the storages are taken from the full snapshot.
+    fn with_incremental(self, incremental: Self) -> Self {
+        let AccountsDbFields(mut storage, ..) = self;
+        let AccountsDbFields(incremental_storage, version, slot, bank_hash_info) = incremental;
+        storage.extend(incremental_storage);
+        AccountsDbFields(storage, version, slot, bank_hash_info)
+    }
+}
+
 trait TypeContext<'a> {
     type SerializableAccountStorageEntry: Serialize
         + DeserializeOwned
@@ -122,6 +133,7 @@ where
 pub(crate) fn bank_from_stream<R, P>(
     serde_style: SerdeStyle,
     stream: &mut BufReader<R>,
+    incremental_stream: Option<&mut BufReader<R>>,
     append_vecs_path: P,
     evm_state_path: &Path,
     account_paths: &[PathBuf],
@@ -139,6 +151,17 @@ where
     macro_rules! INTO {
         ($x:ident) => {{
             let (bank_fields, accounts_db_fields) = $x::deserialize_bank_fields(stream)?;
+            let (bank_fields, accounts_db_fields) = match incremental_stream {
+                Some(incremental_stream) => {
+                    let (incremental_bank_fields, incremental_accounts_db_fields) =
+                        $x::deserialize_bank_fields(incremental_stream)?;
+                    (
+                        incremental_bank_fields,
+                        accounts_db_fields.with_incremental(incremental_accounts_db_fields),
+                    )
+                }
+                None => (bank_fields, accounts_db_fields),
+            };
 
             let bank = reconstruct_bank_from_fields(
                 bank_fields,
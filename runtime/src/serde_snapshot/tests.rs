@@ -212,6 +212,7 @@ fn test_bank_serialize_style(serde_style: SerdeStyle) {
     let mut dbank = crate::serde_snapshot::bank_from_stream(
         serde_style,
         &mut reader,
+        None,
         copied_accounts.path(),
         &evm_state_dir.path(),
         &dbank_paths,
@@ -230,6 +231,79 @@ fn test_bank_serialize_style(serde_style: SerdeStyle) {
     assert!(bank2 == dbank);
 }
 
+#[cfg(test)]
+fn test_bank_serialize_incremental_style(serde_style: SerdeStyle) {
+    solana_logger::setup();
+    let (genesis_config, _) = create_genesis_config(500);
+    let bank0 = Arc::new(Bank::new(&genesis_config));
+    let key1 = Keypair::new();
+    bank0.deposit(&key1.pubkey(), 5);
+    bank0.freeze();
+    bank0.squash();
+    bank0.force_flush_accounts_cache();
+
+    let full_snapshot_storages = bank0.get_snapshot_storages();
+    let mut full_buf = vec![];
+    crate::serde_snapshot::bank_to_stream(
+        serde_style,
+        &mut std::io::BufWriter::new(Cursor::new(&mut full_buf)),
+        &bank0,
+        &full_snapshot_storages,
+    )
+    .unwrap();
+
+    let bank1 = Bank::new_from_parent(&bank0, &Pubkey::default(), 1);
+    let key2 = Keypair::new();
+    bank1.deposit(&key1.pubkey(), 1);
+    bank1.deposit(&key2.pubkey(), 10);
+    bank1.freeze();
+    bank1.squash();
+    bank1.force_flush_accounts_cache();
+
+    // Incremental snapshot only carries storages of the slots after the full snapshot
+    let incremental_snapshot_storages: Vec<_> = bank1
+        .get_snapshot_storages()
+        .into_iter()
+        .filter(|storage| storage.first().map_or(false, |entry| entry.slot() > 0))
+        .collect();
+    assert!(!incremental_snapshot_storages.is_empty());
+    let mut incremental_buf = vec![];
+    crate::serde_snapshot::bank_to_stream(
+        serde_style,
+        &mut std::io::BufWriter::new(Cursor::new(&mut incremental_buf)),
+        &bank1,
+        &incremental_snapshot_storages,
+    )
+    .unwrap();
+
+    let evm_state_dir = TempDir::new().unwrap();
+    let (_accounts_dir, dbank_paths) = get_temp_accounts_paths(4).unwrap();
+    let copied_accounts = TempDir::new().unwrap();
+    copy_append_vecs(&bank1.rc.accounts.accounts_db, copied_accounts.path()).unwrap();
+    let dbank = crate::serde_snapshot::bank_from_stream(
+        serde_style,
+        &mut BufReader::new(&full_buf[..]),
+        Some(&mut BufReader::new(&incremental_buf[..])),
+        copied_accounts.path(),
+        &evm_state_dir.path(),
+        &dbank_paths,
+        &genesis_config,
+        &[],
+        None,
+        None,
+        HashSet::new(),
+        false,
+    )
+    .unwrap();
+    assert_eq!(dbank.slot(), 1);
+    assert_eq!(dbank.get_balance(&key1.pubkey()), 6);
+    assert_eq!(dbank.get_balance(&key2.pubkey()), 10);
+    assert_eq!(
+        dbank.rc.accounts.accounts_db.storage.0.len(),
+        full_snapshot_storages.len() + incremental_snapshot_storages.len()
+    );
+}
+
 #[cfg(test)]
 pub(crate) fn reconstruct_accounts_db_via_serialization(
     accounts: &AccountsDb,
@@ -264,6 +338,11 @@ fn test_bank_serialize_newer() {
     test_bank_serialize_style(SerdeStyle::Newer)
 }
 
+#[test]
+fn test_bank_serialize_incremental_newer() {
+    test_bank_serialize_incremental_style(SerdeStyle::Newer)
+}
+
 #[cfg(all(test, RUSTC_WITH_SPECIALIZATION))]
 mod test_bank_serialize {
     use super::*;
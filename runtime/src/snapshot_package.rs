@@ -27,6 +27,8 @@ pub struct AccountsPackagePre {
     pub expected_capitalization: u64,
     pub hash_for_testing: Option<Hash>,
     pub simple_capitalization_testing: bool,
+    // Slot of the full snapshot this package is built on top of, if it's an incremental one
+    pub incremental_snapshot_base_slot: Option<Slot>,
 }
 
 impl AccountsPackagePre {
@@ -44,6 +46,7 @@ impl AccountsPackagePre {
         expected_capitalization: u64,
         hash_for_testing: Option<Hash>,
         simple_capitalization_testing: bool,
+        incremental_snapshot_base_slot: Option<Slot>,
     ) -> Self {
         Self {
             slot,
@@ -58,8 +61,13 @@ impl AccountsPackagePre {
             expected_capitalization,
             hash_for_testing,
             simple_capitalization_testing,
+            incremental_snapshot_base_slot,
         }
     }
+
+    pub fn is_incremental(&self) -> bool {
+        self.incremental_snapshot_base_slot.is_some()
+    }
 }
 
 pub struct AccountsPackage {
@@ -72,9 +80,11 @@ pub struct AccountsPackage {
     pub hash: Hash,
     pub archive_format: ArchiveFormat,
     pub snapshot_version: SnapshotVersion,
+    pub incremental_snapshot_base_slot: Option<Slot>,
 }
 
 impl AccountsPackage {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         slot: Slot,
         block_height: u64,
@@ -85,6 +95,7 @@ impl AccountsPackage {
         hash: Hash,
         archive_format: ArchiveFormat,
         snapshot_version: SnapshotVersion,
+        incremental_snapshot_base_slot: Option<Slot>,
     ) -> Self {
         Self {
             slot,
@@ -96,6 +107,11 @@ impl AccountsPackage {
             hash,
             archive_format,
             snapshot_version,
+            incremental_snapshot_base_slot,
         }
     }
+
+    pub fn is_incremental(&self) -> bool {
+        self.incremental_snapshot_base_slot.is_some()
+    }
 }
@@ -19,7 +19,7 @@ use rayon::ThreadPool;
 use regex::Regex;
 use solana_measure::measure::Measure;
 use solana_sdk::{clock::Slot, genesis_config::GenesisConfig, hash::Hash, pubkey::Pubkey};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::{
     cmp::Ordering,
@@ -39,7 +39,9 @@ pub const TAR_ACCOUNTS_DIR: &str = "accounts";
 pub const TAR_VERSION_FILE: &str = "version";
 
 pub const MAX_SNAPSHOTS: usize = 8; // Save some snapshots but not too many
+pub const MAX_INCREMENTAL_SNAPSHOT_ARCHIVES: usize = 2;
 const EVM_STATE_DIR: &str = "evm-state";
+const INCREMENTAL_SNAPSHOT_BASE_FILE_NAME: &str = "incremental-snapshot-base";
 const MAX_SNAPSHOT_DATA_FILE_SIZE: u64 = 32 * 1024 * 1024 * 1024; // 32 GiB
 const VERSION_STRING_V1_2_0: &str = "1.2.0";
 const VERSION_STRING_V1_3_0: &str = "1.3.0";
@@ -104,6 +106,56 @@ impl SnapshotVersion {
     }
 }
 
+/// Full snapshot, that incremental snapshots are built on top of.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct IncrementalSnapshotBase {
+    pub slot: Slot,
+    pub evm_root: evm_state::H256,
+}
+
+impl IncrementalSnapshotBase {
+    const SERIALIZED_SIZE: usize = 8 + 32;
+
+    /// Save the base into `snapshot_path`, so it survives the node restart
+    pub fn save(&self, snapshot_path: &Path) -> Result<()> {
+        let mut data = Vec::with_capacity(Self::SERIALIZED_SIZE);
+        data.extend_from_slice(&self.slot.to_le_bytes());
+        data.extend_from_slice(self.evm_root.as_bytes());
+        fs::write(
+            snapshot_path.join(INCREMENTAL_SNAPSHOT_BASE_FILE_NAME),
+            data,
+        )?;
+        Ok(())
+    }
+
+    /// Load the base saved into `snapshot_path`, if the full snapshot archive
+    /// of its slot is still in `snapshot_output_dir`
+    pub fn load(snapshot_path: &Path, snapshot_output_dir: &Path) -> Option<Self> {
+        let data = fs::read(snapshot_path.join(INCREMENTAL_SNAPSHOT_BASE_FILE_NAME)).ok()?;
+        if data.len() != Self::SERIALIZED_SIZE {
+            warn!(
+                "Invalid incremental snapshot base file in {:?}",
+                snapshot_path
+            );
+            return None;
+        }
+        let mut slot = [0; 8];
+        slot.copy_from_slice(&data[..8]);
+        let base = Self {
+            slot: Slot::from_le_bytes(slot),
+            evm_root: evm_state::H256::from_slice(&data[8..]),
+        };
+        let has_archive = get_snapshot_archives(snapshot_output_dir)
+            .iter()
+            .any(|(_, (slot, ..))| *slot == base.slot);
+        if has_archive {
+            Some(base)
+        } else {
+            None
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Debug)]
 pub struct SlotSnapshotPaths {
     pub slot: Slot,
@@ -155,6 +207,7 @@ pub fn package_snapshot<P: AsRef<Path>, Q: AsRef<Path>>(
     archive_format: ArchiveFormat,
     snapshot_version: SnapshotVersion,
     hash_for_testing: Option<Hash>,
+    incremental_snapshot_base_slot: Option<Slot>,
 ) -> Result<AccountsPackagePre> {
     // Hard link all the snapshots we need for this package
     let snapshot_tmpdir = tempfile::Builder::new()
@@ -198,6 +251,7 @@ pub fn package_snapshot<P: AsRef<Path>, Q: AsRef<Path>>(
         bank.capitalization(),
         hash_for_testing,
         bank.simple_capitalization_enabled(),
+        incremental_snapshot_base_slot,
     );
 
     Ok(package)
@@ -516,6 +570,24 @@ pub fn add_snapshot<P: AsRef<Path>>(
     bank: &Bank,
     snapshot_storages: &[SnapshotStorage],
     snapshot_version: SnapshotVersion,
+) -> Result<SlotSnapshotPaths> {
+    add_snapshot_with_evm_base(
+        snapshot_path,
+        bank,
+        snapshot_storages,
+        snapshot_version,
+        None,
+    )
+}
+
+// If `evm_base_root` is set, only EVM trie nodes changed since that root are saved,
+// this is the EVM part of an incremental snapshot.
+fn add_snapshot_with_evm_base<P: AsRef<Path>>(
+    snapshot_path: P,
+    bank: &Bank,
+    snapshot_storages: &[SnapshotStorage],
+    snapshot_version: SnapshotVersion,
+    evm_base_root: Option<evm_state::H256>,
 ) -> Result<SlotSnapshotPaths> {
     let slot = bank.slot();
     // snapshot_path/slot
@@ -557,34 +629,25 @@ pub fn add_snapshot<P: AsRef<Path>>(
     );
     let evm_state_backup_dir = slot_snapshot_dir.join(EVM_STATE_DIR);
 
-    let mut wl_acquire = Measure::start("evm_state_write_lock_acquire_time");
-    let evm_state = bank.evm_state.write().unwrap();
-    wl_acquire.stop();
-    debug!("EVM state write acquire time lock {}", wl_acquire);
-
-    let mut squash_evm_state_time = Measure::start("squash_evm_state_time");
-    // TODO(hrls): evm_state.gc();
-    squash_evm_state_time.stop();
-    debug!("EVM state squash time {}", squash_evm_state_time);
-
-    inc_new_counter_info!(
-        "squash_evm_state_time",
-        squash_evm_state_time.as_ms() as usize
-    );
-
-    let mut evm_state_backup = Measure::start("evm-state-backup-ms");
-    let backup_path = evm_state
-        .make_backup()
-        .expect("Unable to save EVM storage data in new place");
-    evm_state_backup.stop();
-    info!("EVM state backup done in {:?}", backup_path);
-
-    symlink::symlink_dir(&backup_path, &evm_state_backup_dir)?;
+    let mut rl_acquire = Measure::start("evm_state_read_lock_acquire_time");
+    let evm_state = bank.evm_state.read().unwrap();
+    rl_acquire.stop();
+    debug!("EVM state read acquire time lock {}", rl_acquire);
+
+    // Only nodes reachable from the bank's state root are saved, so snapshot
+    // doesn't carry any historical trie nodes.
+    let mut evm_state_copy = Measure::start("evm-state-copy-ms");
+    let evm_root = match evm_base_root {
+        Some(evm_base_root) => evm_state.make_state_diff_copy(evm_base_root, &evm_state_backup_dir),
+        None => evm_state.make_state_copy(&evm_state_backup_dir),
+    }
+    .map_err(|e| get_io_error(&format!("Unable to copy EVM state: {}", e)))?;
+    evm_state_copy.stop();
 
-    inc_new_counter_info!("evm-state-backup-ms", evm_state_backup.as_ms() as usize);
+    inc_new_counter_info!("evm-state-copy-ms", evm_state_copy.as_ms() as usize);
     info!(
-        "EVM state backup {} for slot {} at {:?}",
-        evm_state_backup, slot, evm_state_backup_dir
+        "EVM state copy {} of root {:?} for slot {} at {:?}",
+        evm_state_copy, evm_root, slot, evm_state_backup_dir
     );
 
     Ok(SlotSnapshotPaths {
@@ -635,6 +698,7 @@ pub fn bank_from_archive<P: AsRef<Path>>(
     snapshot_path: &Path,
     snapshot_tar: P,
     archive_format: ArchiveFormat,
+    incremental_snapshot_archive: Option<(&Path, ArchiveFormat)>,
     genesis_config: &GenesisConfig,
     debug_keys: Option<Arc<HashSet<Pubkey>>>,
     additional_builtins: Option<&Builtins>,
@@ -647,20 +711,49 @@ pub fn bank_from_archive<P: AsRef<Path>>(
         .tempdir_in(snapshot_path)?;
     untar_snapshot_in(&snapshot_tar, &unpack_dir, archive_format)?;
 
-    let mut measure = Measure::start("bank rebuild from snapshot");
     let unpacked_accounts_dir = unpack_dir.as_ref().join(TAR_ACCOUNTS_DIR);
     let unpacked_snapshots_dir = unpack_dir.as_ref().join(TAR_SNAPSHOTS_DIR);
-    let unpacked_version_file = unpack_dir.as_ref().join(TAR_VERSION_FILE);
-
-    let mut snapshot_version = String::new();
-    File::open(unpacked_version_file).and_then(|mut f| f.read_to_string(&mut snapshot_version))?;
+    let snapshot_version = read_snapshot_version(&unpack_dir)?;
+
+    // Untar the incremental snapshot next to the full one, and move its account storages
+    // (which are all newer than the full snapshot slot) together with the full snapshot ones
+    let incremental_unpack_dir = match incremental_snapshot_archive {
+        Some((incremental_snapshot_tar, incremental_archive_format)) => {
+            let incremental_unpack_dir = tempfile::Builder::new()
+                .prefix(TMP_SNAPSHOT_PREFIX)
+                .tempdir_in(snapshot_path)?;
+            untar_snapshot_in(
+                incremental_snapshot_tar,
+                &incremental_unpack_dir,
+                incremental_archive_format,
+            )?;
+            let incremental_snapshot_version = read_snapshot_version(&incremental_unpack_dir)?;
+            if incremental_snapshot_version != snapshot_version {
+                return Err(get_io_error(&format!(
+                    "incremental snapshot version {} doesn't match full snapshot version {}",
+                    incremental_snapshot_version, snapshot_version
+                )));
+            }
+            for entry in fs::read_dir(incremental_unpack_dir.as_ref().join(TAR_ACCOUNTS_DIR))? {
+                let entry = entry?;
+                fs::rename(entry.path(), unpacked_accounts_dir.join(entry.file_name()))?;
+            }
+            Some(incremental_unpack_dir)
+        }
+        None => None,
+    };
+    let incremental_unpacked_snapshots_dir = incremental_unpack_dir
+        .as_ref()
+        .map(|dir| dir.as_ref().join(TAR_SNAPSHOTS_DIR));
 
+    let mut measure = Measure::start("bank rebuild from snapshot");
     let bank = rebuild_bank_from_snapshots(
-        snapshot_version.trim(),
+        &snapshot_version,
         evm_state_path,
         account_paths,
         frozen_account_pubkeys,
         &unpacked_snapshots_dir,
+        incremental_unpacked_snapshots_dir.as_deref(),
         unpacked_accounts_dir,
         genesis_config,
         debug_keys,
@@ -678,6 +771,13 @@ pub fn bank_from_archive<P: AsRef<Path>>(
     Ok(bank)
 }
 
+fn read_snapshot_version(unpack_dir: impl AsRef<Path>) -> Result<String> {
+    let mut snapshot_version = String::new();
+    File::open(unpack_dir.as_ref().join(TAR_VERSION_FILE))
+        .and_then(|mut f| f.read_to_string(&mut snapshot_version))?;
+    Ok(snapshot_version.trim().to_string())
+}
+
 pub fn get_snapshot_archive_path(
     snapshot_output_dir: PathBuf,
     snapshot_hash: &(Slot, Hash),
@@ -691,6 +791,21 @@ pub fn get_snapshot_archive_path(
     ))
 }
 
+pub fn get_incremental_snapshot_archive_path(
+    snapshot_output_dir: PathBuf,
+    base_slot: Slot,
+    snapshot_hash: &(Slot, Hash),
+    archive_format: ArchiveFormat,
+) -> PathBuf {
+    snapshot_output_dir.join(format!(
+        "incremental-snapshot-{}-{}-{}{}",
+        base_slot,
+        snapshot_hash.0,
+        snapshot_hash.1,
+        get_archive_ext(archive_format),
+    ))
+}
+
 fn archive_format_from_str(archive_format: &str) -> Option<ArchiveFormat> {
     match archive_format {
         "tar.bz2" => Some(ArchiveFormat::TarBzip2),
@@ -703,7 +818,7 @@ fn archive_format_from_str(archive_format: &str) -> Option<ArchiveFormat> {
 
 fn snapshot_hash_of(archive_filename: &str) -> Option<(Slot, Hash, ArchiveFormat)> {
     let snapshot_filename_regex =
-        Regex::new(r"snapshot-(\d+)-([[:alnum:]]+)\.(tar|tar\.bz2|tar\.zst|tar\.gz)$").unwrap();
+        Regex::new(r"^snapshot-(\d+)-([[:alnum:]]+)\.(tar|tar\.bz2|tar\.zst|tar\.gz)$").unwrap();
 
     if let Some(captures) = snapshot_filename_regex.captures(archive_filename) {
         let slot_str = captures.get(1).unwrap().as_str();
@@ -721,37 +836,79 @@ fn snapshot_hash_of(archive_filename: &str) -> Option<(Slot, Hash, ArchiveFormat
     None
 }
 
-pub fn get_snapshot_archives<P: AsRef<Path>>(
-    snapshot_output_dir: P,
-) -> Vec<(PathBuf, (Slot, Hash, ArchiveFormat))> {
+/// Returns (base slot, slot, hash, archive format) of the incremental snapshot archive
+pub fn incremental_snapshot_hash_of(
+    archive_filename: &str,
+) -> Option<(Slot, Slot, Hash, ArchiveFormat)> {
+    let incremental_snapshot_filename_regex = Regex::new(
+        r"^incremental-snapshot-(\d+)-(\d+)-([[:alnum:]]+)\.(tar|tar\.bz2|tar\.zst|tar\.gz)$",
+    )
+    .unwrap();
+
+    if let Some(captures) = incremental_snapshot_filename_regex.captures(archive_filename) {
+        let base_slot_str = captures.get(1).unwrap().as_str();
+        let slot_str = captures.get(2).unwrap().as_str();
+        let hash_str = captures.get(3).unwrap().as_str();
+        let ext = captures.get(4).unwrap().as_str();
+
+        if let (Ok(base_slot), Ok(slot), Ok(hash), Some(archive_format)) = (
+            base_slot_str.parse::<Slot>(),
+            slot_str.parse::<Slot>(),
+            hash_str.parse::<Hash>(),
+            archive_format_from_str(ext),
+        ) {
+            if base_slot < slot {
+                return Some((base_slot, slot, hash, archive_format));
+            }
+        }
+    }
+    None
+}
+
+fn get_archives<P, T, F>(snapshot_output_dir: P, archive_hash_of: F) -> Vec<(PathBuf, T)>
+where
+    P: AsRef<Path>,
+    F: Fn(&str) -> Option<T>,
+{
     match fs::read_dir(&snapshot_output_dir) {
         Err(err) => {
             info!("Unable to read snapshot directory: {}", err);
             vec![]
         }
-        Ok(files) => {
-            let mut archives: Vec<_> = files
-                .filter_map(|entry| {
-                    if let Ok(entry) = entry {
-                        let path = entry.path();
-                        if path.is_file() {
-                            if let Some(snapshot_hash) =
-                                snapshot_hash_of(path.file_name().unwrap().to_str().unwrap())
-                            {
-                                return Some((path, snapshot_hash));
-                            }
+        Ok(files) => files
+            .filter_map(|entry| {
+                if let Ok(entry) = entry {
+                    let path = entry.path();
+                    if path.is_file() {
+                        if let Some(archive_hash) =
+                            archive_hash_of(path.file_name().unwrap().to_str().unwrap())
+                        {
+                            return Some((path, archive_hash));
                         }
                     }
-                    None
-                })
-                .collect();
-
-            archives.sort_by(|a, b| (b.1).0.cmp(&(a.1).0)); // reverse sort by slot
-            archives
-        }
+                }
+                None
+            })
+            .collect(),
     }
 }
 
+pub fn get_snapshot_archives<P: AsRef<Path>>(
+    snapshot_output_dir: P,
+) -> Vec<(PathBuf, (Slot, Hash, ArchiveFormat))> {
+    let mut archives = get_archives(snapshot_output_dir, snapshot_hash_of);
+    archives.sort_by(|a, b| (b.1).0.cmp(&(a.1).0)); // reverse sort by slot
+    archives
+}
+
+pub fn get_incremental_snapshot_archives<P: AsRef<Path>>(
+    snapshot_output_dir: P,
+) -> Vec<(PathBuf, (Slot, Slot, Hash, ArchiveFormat))> {
+    let mut archives = get_archives(snapshot_output_dir, incremental_snapshot_hash_of);
+    archives.sort_by(|a, b| (b.1).1.cmp(&(a.1).1)); // reverse sort by slot
+    archives
+}
+
 pub fn get_highest_snapshot_archive_path<P: AsRef<Path>>(
     snapshot_output_dir: P,
 ) -> Option<(PathBuf, (Slot, Hash, ArchiveFormat))> {
@@ -759,14 +916,40 @@ pub fn get_highest_snapshot_archive_path<P: AsRef<Path>>(
     archives.into_iter().next()
 }
 
+/// Returns the highest incremental snapshot archive, that is built on top of
+/// the full snapshot at `full_snapshot_slot`.
+pub fn get_highest_incremental_snapshot_archive_path<P: AsRef<Path>>(
+    snapshot_output_dir: P,
+    full_snapshot_slot: Slot,
+) -> Option<(PathBuf, (Slot, Slot, Hash, ArchiveFormat))> {
+    get_incremental_snapshot_archives(snapshot_output_dir)
+        .into_iter()
+        .find(|(_, (base_slot, ..))| *base_slot == full_snapshot_slot)
+}
+
 pub fn purge_old_snapshot_archives<P: AsRef<Path>>(snapshot_output_dir: P) {
-    let mut archives = get_snapshot_archives(snapshot_output_dir);
+    let mut archives = get_snapshot_archives(&snapshot_output_dir);
     // Keep the oldest snapshot so we can always play the ledger from it.
     archives.pop();
     for old_archive in archives.into_iter().skip(2) {
         fs::remove_file(old_archive.0)
             .unwrap_or_else(|err| info!("Failed to remove old snapshot: {:}", err));
     }
+
+    // Incremental snapshots are only useful on top of the retained full snapshots
+    let full_snapshot_slots: HashSet<Slot> = get_snapshot_archives(&snapshot_output_dir)
+        .into_iter()
+        .map(|(_, (slot, ..))| slot)
+        .collect();
+    let mut incremental_archives_per_base: HashMap<Slot, usize> = HashMap::new();
+    for (path, (base_slot, ..)) in get_incremental_snapshot_archives(&snapshot_output_dir) {
+        let count = incremental_archives_per_base.entry(base_slot).or_default();
+        *count += 1;
+        if !full_snapshot_slots.contains(&base_slot) || *count > MAX_INCREMENTAL_SNAPSHOT_ARCHIVES {
+            fs::remove_file(path)
+                .unwrap_or_else(|err| info!("Failed to remove old incremental snapshot: {:}", err));
+        }
+    }
 }
 
 pub fn untar_snapshot_in<P: AsRef<Path>, Q: AsRef<Path>>(
@@ -810,6 +993,7 @@ fn rebuild_bank_from_snapshots<P>(
     account_paths: &[PathBuf],
     frozen_account_pubkeys: &[Pubkey],
     unpacked_snapshots_dir: &Path,
+    incremental_unpacked_snapshots_dir: Option<&Path>,
     append_vecs_path: P,
     genesis_config: &GenesisConfig,
     debug_keys: Option<Arc<HashSet<Pubkey>>>,
@@ -829,15 +1013,12 @@ where
                 snapshot_version
             ))
         })?;
-    let mut snapshot_paths = get_snapshot_paths(&unpacked_snapshots_dir);
-    if snapshot_paths.len() > 1 {
-        return Err(get_io_error("invalid snapshot format"));
-    }
-    let root_paths = snapshot_paths
-        .pop()
-        .ok_or_else(|| get_io_error("No snapshots found in snapshots directory"))?;
+    let root_paths = get_unpacked_snapshot_paths(unpacked_snapshots_dir)?;
+    let incremental_root_paths = incremental_unpacked_snapshots_dir
+        .map(get_unpacked_snapshot_paths)
+        .transpose()?;
     info!(
-        "restoring database from storage backup: {:?}",
+        "restoring database from storage copy: {:?}",
         root_paths.evm_state_backup_path
     );
     let mut measure = Measure::start("evm state database restore");
@@ -848,8 +1029,19 @@ where
         );
         fs::remove_dir_all(&evm_state_path)?;
     }
-    evm_state::Storage::restore_from(root_paths.evm_state_backup_path, &evm_state_path)
-        .expect("Unable to restore EVM state underlying database from storage backup");
+    evm_state::Storage::restore_from_copy(&root_paths.evm_state_backup_path, &evm_state_path)
+        .map_err(|e| get_io_error(&format!("Unable to restore EVM state: {}", e)))?;
+    if let Some(incremental_root_paths) = &incremental_root_paths {
+        info!(
+            "applying incremental storage copy: {:?}",
+            incremental_root_paths.evm_state_backup_path
+        );
+        evm_state::Storage::restore_diff_from_copy(
+            &incremental_root_paths.evm_state_backup_path,
+            &evm_state_path,
+        )
+        .map_err(|e| get_io_error(&format!("Unable to apply EVM state diff: {}", e)))?;
+    }
     measure.stop();
     info!("{}", measure);
 
@@ -857,12 +1049,15 @@ where
         "Loading bank from {}",
         &root_paths.snapshot_file_path.display()
     );
-    let bank = deserialize_snapshot_data_file(&root_paths.snapshot_file_path, |mut stream| {
+    let bank_from_streams = |stream: &mut BufReader<File>,
+                             incremental_stream: Option<&mut BufReader<File>>|
+     -> Result<Bank> {
         Ok(match snapshot_version_enum {
             SnapshotVersion::V1_2_0 => unimplemented!(),
             SnapshotVersion::V1_3_0 => bank_from_stream(
                 SerdeStyle::Newer,
-                &mut stream,
+                stream,
+                incremental_stream,
                 &append_vecs_path,
                 &evm_state_path,
                 account_paths,
@@ -874,9 +1069,27 @@ where
                 accounts_db_caching_enabled,
             ),
         }?)
+    };
+    let bank = deserialize_snapshot_data_file(&root_paths.snapshot_file_path, |stream| {
+        match &incremental_root_paths {
+            Some(incremental_root_paths) => {
+                info!(
+                    "Applying incremental bank from {}",
+                    &incremental_root_paths.snapshot_file_path.display()
+                );
+                deserialize_snapshot_data_file(
+                    &incremental_root_paths.snapshot_file_path,
+                    |incremental_stream| bank_from_streams(stream, Some(incremental_stream)),
+                )
+            }
+            None => bank_from_streams(stream, None),
+        }
     })?;
 
-    let status_cache_path = unpacked_snapshots_dir.join(SNAPSHOT_STATUS_CACHE_FILE_NAME);
+    // Status cache of the incremental snapshot is the most recent one
+    let status_cache_path = incremental_unpacked_snapshots_dir
+        .unwrap_or(unpacked_snapshots_dir)
+        .join(SNAPSHOT_STATUS_CACHE_FILE_NAME);
     let slot_deltas = deserialize_snapshot_data_file(&status_cache_path, |stream| {
         info!(
             "Rebuilding status cache from {}",
@@ -896,6 +1109,16 @@ where
     Ok(bank)
 }
 
+fn get_unpacked_snapshot_paths(unpacked_snapshots_dir: &Path) -> Result<SlotSnapshotPaths> {
+    let mut snapshot_paths = get_snapshot_paths(unpacked_snapshots_dir);
+    if snapshot_paths.len() > 1 {
+        return Err(get_io_error("invalid snapshot format"));
+    }
+    snapshot_paths
+        .pop()
+        .ok_or_else(|| get_io_error("No snapshots found in snapshots directory"))
+}
+
 fn get_snapshot_file_name(slot: Slot) -> String {
     slot.to_string()
 }
@@ -925,8 +1148,6 @@ pub fn verify_snapshot_archive<P, Q, R>(
 
     // Check snapshots are the same
     let unpacked_snapshots = unpack_dir.join(&TAR_SNAPSHOTS_DIR);
-    // TODO: Make evm-state snapshot more consistent, currently rocksdb incremental backup is
-    // not consistent (because it support more than one backup at a time)
     assert!(!dir_diff::is_different(&snapshots_to_verify, unpacked_snapshots).unwrap());
 
     // Check the account entries are the same
@@ -946,7 +1167,10 @@ pub fn purge_old_snapshots(snapshot_path: &Path) {
     }
 }
 
-// Gather the necessary elements for a snapshot of the given `root_bank`
+// Gather the necessary elements for a snapshot of the given `root_bank`.
+// If `incremental_snapshot_base` is set, only accounts and EVM state changed
+// since the base full snapshot are packaged.
+#[allow(clippy::too_many_arguments)]
 pub fn snapshot_bank(
     root_bank: &Bank,
     status_cache_slot_deltas: Vec<BankSlotDelta>,
@@ -956,10 +1180,24 @@ pub fn snapshot_bank(
     snapshot_version: SnapshotVersion,
     archive_format: &ArchiveFormat,
     hash_for_testing: Option<Hash>,
+    incremental_snapshot_base: Option<IncrementalSnapshotBase>,
 ) -> Result<()> {
-    let storages: Vec<_> = root_bank.get_snapshot_storages();
+    let mut storages: Vec<_> = root_bank.get_snapshot_storages();
+    if let Some(base) = incremental_snapshot_base {
+        storages.retain(|storage| {
+            storage
+                .first()
+                .map_or(false, |entry| entry.slot() > base.slot)
+        });
+    }
     let mut add_snapshot_time = Measure::start("add-snapshot-ms");
-    add_snapshot(snapshot_path, &root_bank, &storages, snapshot_version)?;
+    add_snapshot_with_evm_base(
+        snapshot_path,
+        &root_bank,
+        &storages,
+        snapshot_version,
+        incremental_snapshot_base.map(|base| base.evm_root),
+    )?;
     add_snapshot_time.stop();
     inc_new_counter_info!("add-snapshot-ms", add_snapshot_time.as_ms() as usize);
 
@@ -979,6 +1217,7 @@ pub fn snapshot_bank(
         *archive_format,
         snapshot_version,
         hash_for_testing,
+        incremental_snapshot_base.map(|base| base.slot),
     )?;
 
     accounts_package_sender.send(package)?;
@@ -1019,6 +1258,7 @@ pub fn bank_to_snapshot_archive<P: AsRef<Path>, Q: AsRef<Path>>(
         archive_format,
         snapshot_version,
         None,
+        None,
     )?;
 
     let package = process_accounts_package_pre(package, thread_pool);
@@ -1052,11 +1292,19 @@ pub fn process_accounts_package_pre(
         ("calculate_hash", time.as_us(), i64),
     );
 
-    let tar_output_file = get_snapshot_archive_path(
-        accounts_package.snapshot_output_dir,
-        &(accounts_package.slot, hash),
-        accounts_package.archive_format,
-    );
+    let tar_output_file = match accounts_package.incremental_snapshot_base_slot {
+        Some(base_slot) => get_incremental_snapshot_archive_path(
+            accounts_package.snapshot_output_dir,
+            base_slot,
+            &(accounts_package.slot, hash),
+            accounts_package.archive_format,
+        ),
+        None => get_snapshot_archive_path(
+            accounts_package.snapshot_output_dir,
+            &(accounts_package.slot, hash),
+            accounts_package.archive_format,
+        ),
+    };
 
     AccountsPackage::new(
         accounts_package.slot,
@@ -1068,6 +1316,7 @@ pub fn process_accounts_package_pre(
         hash,
         accounts_package.archive_format,
         accounts_package.snapshot_version,
+        accounts_package.incremental_snapshot_base_slot,
     )
 }
 
@@ -1199,5 +1448,89 @@ mod tests {
         );
 
         assert!(snapshot_hash_of("invalid").is_none());
+        assert!(snapshot_hash_of(&format!(
+            "incremental-snapshot-42-43-{}.tar.bz2",
+            Hash::default()
+        ))
+        .is_none());
+    }
+
+    #[test]
+    fn test_incremental_snapshot_hash_of() {
+        assert_eq!(
+            incremental_snapshot_hash_of(&format!(
+                "incremental-snapshot-42-100-{}.tar.bz2",
+                Hash::default()
+            )),
+            Some((42, 100, Hash::default(), ArchiveFormat::TarBzip2))
+        );
+        assert_eq!(
+            incremental_snapshot_hash_of(&format!(
+                "incremental-snapshot-42-43-{}.tar.zst",
+                Hash::default()
+            )),
+            Some((42, 43, Hash::default(), ArchiveFormat::TarZstd))
+        );
+        // Base slot must be lower than the incremental snapshot slot
+        assert!(incremental_snapshot_hash_of(&format!(
+            "incremental-snapshot-42-42-{}.tar",
+            Hash::default()
+        ))
+        .is_none());
+
+        assert!(
+            incremental_snapshot_hash_of(&format!("snapshot-42-{}.tar", Hash::default())).is_none()
+        );
+        assert!(incremental_snapshot_hash_of("invalid").is_none());
+    }
+
+    #[test]
+    fn test_get_highest_incremental_snapshot_archive_path() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let hash = Hash::default();
+        for (base_slot, slot) in &[(100, 110), (100, 120), (200, 210), (200, 205)] {
+            let path = get_incremental_snapshot_archive_path(
+                temp_dir.path().to_path_buf(),
+                *base_slot,
+                &(*slot, hash),
+                ArchiveFormat::Tar,
+            );
+            File::create(path).unwrap();
+        }
+
+        let (_, (base_slot, slot, ..)) =
+            get_highest_incremental_snapshot_archive_path(temp_dir.path(), 100).unwrap();
+        assert_eq!((base_slot, slot), (100, 120));
+        let (_, (base_slot, slot, ..)) =
+            get_highest_incremental_snapshot_archive_path(temp_dir.path(), 200).unwrap();
+        assert_eq!((base_slot, slot), (200, 210));
+        assert!(get_highest_incremental_snapshot_archive_path(temp_dir.path(), 300).is_none());
+        assert!(get_highest_snapshot_archive_path(temp_dir.path()).is_none());
+    }
+
+    #[test]
+    fn test_incremental_snapshot_base_save_load() {
+        let snapshot_dir = tempfile::TempDir::new().unwrap();
+        let output_dir = tempfile::TempDir::new().unwrap();
+        let base = IncrementalSnapshotBase {
+            slot: 100,
+            evm_root: evm_state::H256::repeat_byte(1),
+        };
+        assert!(IncrementalSnapshotBase::load(snapshot_dir.path(), output_dir.path()).is_none());
+
+        base.save(snapshot_dir.path()).unwrap();
+        // Full snapshot archive of the base is missing
+        assert!(IncrementalSnapshotBase::load(snapshot_dir.path(), output_dir.path()).is_none());
+
+        let archive_path = get_snapshot_archive_path(
+            output_dir.path().to_path_buf(),
+            &(base.slot, Hash::default()),
+            ArchiveFormat::Tar,
+        );
+        File::create(archive_path).unwrap();
+        assert_eq!(
+            IncrementalSnapshotBase::load(snapshot_dir.path(), output_dir.path()),
+            Some(base)
+        );
     }
 }
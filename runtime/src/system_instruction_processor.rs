@@ -2,7 +2,7 @@ use log::*;
 use solana_sdk::{
     account::Account,
     account_utils::StateMut,
-    ic_msg,
+    feature_set, ic_msg,
     instruction::InstructionError,
     keyed_account::{from_keyed_account, get_signers, next_keyed_account, KeyedAccount},
     nonce,
@@ -374,6 +374,18 @@ pub fn process_instruction(
             let me = &mut next_keyed_account(keyed_accounts_iter)?;
             me.authorize_nonce_account(&nonce_authority, &signers, invoke_context)
         }
+        SystemInstruction::AuthorizeNonceAccountChecked => {
+            if !invoke_context.is_feature_active(&feature_set::authorize_checked_instructions::id())
+            {
+                return Err(InstructionError::InvalidInstructionData);
+            }
+            let me = &mut next_keyed_account(keyed_accounts_iter)?;
+            let _current_authority = next_keyed_account(keyed_accounts_iter)?;
+            let nonce_authority = next_keyed_account(keyed_accounts_iter)?
+                .signer_key()
+                .ok_or(InstructionError::MissingRequiredSignature)?;
+            me.authorize_nonce_account(nonce_authority, &signers, invoke_context)
+        }
         SystemInstruction::Allocate { space } => {
             let keyed_account = next_keyed_account(keyed_accounts_iter)?;
             let mut account = keyed_account.try_account_ref_mut()?;
@@ -1738,6 +1750,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_process_authorize_checked_ix() {
+        let nonce_acc = nonce_account::create_account(1_000_000);
+        process_instruction(
+            &Pubkey::default(),
+            &[
+                KeyedAccount::new(&Pubkey::default(), true, &nonce_acc),
+                KeyedAccount::new(
+                    &sysvar::recent_blockhashes::id(),
+                    false,
+                    &create_default_recent_blockhashes_account(),
+                ),
+                KeyedAccount::new(&sysvar::rent::id(), false, &create_default_rent_account()),
+            ],
+            &serialize(&SystemInstruction::InitializeNonceAccount(Pubkey::default())).unwrap(),
+        )
+        .unwrap();
+        let new_authority = solana_sdk::pubkey::new_rand();
+        let new_authority_account = RefCell::new(Account::default());
+        assert_eq!(
+            process_instruction(
+                &Pubkey::default(),
+                &[
+                    KeyedAccount::new(&Pubkey::default(), true, &nonce_acc),
+                    KeyedAccount::new(&Pubkey::default(), true, &nonce_acc),
+                    KeyedAccount::new(&new_authority, false, &new_authority_account),
+                ],
+                &serialize(&SystemInstruction::AuthorizeNonceAccountChecked).unwrap(),
+            ),
+            Err(InstructionError::MissingRequiredSignature),
+        );
+        assert_eq!(
+            process_instruction(
+                &Pubkey::default(),
+                &[
+                    KeyedAccount::new(&Pubkey::default(), true, &nonce_acc),
+                    KeyedAccount::new(&Pubkey::default(), true, &nonce_acc),
+                    KeyedAccount::new(&new_authority, true, &new_authority_account),
+                ],
+                &serialize(&SystemInstruction::AuthorizeNonceAccountChecked).unwrap(),
+            ),
+            Ok(()),
+        );
+        let versions: nonce::state::Versions = nonce_acc.borrow().state().unwrap();
+        match versions.convert_to_current() {
+            nonce::State::Initialized(data) => assert_eq!(data.authority, new_authority),
+            _ => panic!("expected an initialized nonce account"),
+        }
+    }
+
     #[test]
     fn test_process_authorize_bad_account_data_fail() {
         assert_eq!(
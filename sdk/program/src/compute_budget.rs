@@ -0,0 +1,183 @@
+//! The compute budget native program, its instructions let a transaction request
+//! a larger compute budget or program heap than the cluster default.
+
+use crate::{
+    entrypoint::HEAP_LENGTH,
+    instruction::{Instruction, InstructionError},
+    message::Message,
+};
+
+crate::declare_id!("ComputeBudget111111111111111111111111111111");
+
+/// Maximum number of compute units a transaction can request
+pub const MAX_UNITS: u32 = 1_400_000;
+/// Requested compute units are charged one signature fee for every started
+/// `UNITS_PER_SIGNATURE_FEE` units above this amount
+pub const FREE_UNITS: u32 = 200_000;
+/// Number of requested compute units charged as one signature fee
+pub const UNITS_PER_SIGNATURE_FEE: u32 = 200_000;
+/// Smallest program heap frame a transaction can request, the default heap size
+pub const MIN_HEAP_FRAME_BYTES: u32 = HEAP_LENGTH as u32;
+/// Largest program heap frame a transaction can request
+pub const MAX_HEAP_FRAME_BYTES: u32 = 256 * 1024;
+
+/// Compute budget instructions
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComputeBudgetInstruction {
+    /// Request a transaction-wide limit of compute units, bounded by `MAX_UNITS`.
+    /// Units above `FREE_UNITS` are paid on top of the signature fees.
+    RequestUnits(u32),
+
+    /// Request a program heap frame of the given size in bytes, the size must be a
+    /// multiple of 1024 between `MIN_HEAP_FRAME_BYTES` and `MAX_HEAP_FRAME_BYTES`.
+    /// Programs need a custom allocator to make use of the larger heap.
+    RequestHeapFrame(u32),
+}
+
+impl ComputeBudgetInstruction {
+    pub fn request_units(units: u32) -> Instruction {
+        Instruction::new(id(), &Self::RequestUnits(units), vec![])
+    }
+
+    pub fn request_heap_frame(bytes: u32) -> Instruction {
+        Instruction::new(id(), &Self::RequestHeapFrame(bytes), vec![])
+    }
+}
+
+/// Compute budget requests made by the instructions of a message
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ComputeBudgetRequests {
+    pub units: Option<u32>,
+    pub heap_frame: Option<u32>,
+}
+
+impl ComputeBudgetRequests {
+    /// Fee for the requested compute units, on top of the signature fees
+    pub fn additional_fee(&self, lamports_per_signature: u64) -> u64 {
+        let paid_units = self.units.unwrap_or(0).saturating_sub(FREE_UNITS);
+        let signatures = (paid_units + UNITS_PER_SIGNATURE_FEE - 1) / UNITS_PER_SIGNATURE_FEE;
+        lamports_per_signature * u64::from(signatures)
+    }
+
+    /// Collect the compute budget requests of `message`, fails with the index of the first
+    /// compute budget instruction that can't be deserialized, is out of bounds or is a duplicate
+    pub fn from_message(message: &Message) -> Result<Self, (u8, InstructionError)> {
+        let mut requests = Self::default();
+        for (index, instruction) in message.instructions.iter().enumerate() {
+            // Message may not be sanitized here
+            let is_compute_budget_instruction = message
+                .account_keys
+                .get(instruction.program_id_index as usize)
+                .map_or(false, check_id);
+            if !is_compute_budget_instruction {
+                continue;
+            }
+
+            let invalid_data = (index as u8, InstructionError::InvalidInstructionData);
+            match bincode::deserialize(&instruction.data).map_err(|_| invalid_data.clone())? {
+                ComputeBudgetInstruction::RequestUnits(units) => {
+                    if requests.units.is_some() {
+                        return Err(invalid_data);
+                    }
+                    requests.units = Some(units.min(MAX_UNITS));
+                }
+                ComputeBudgetInstruction::RequestHeapFrame(bytes) => {
+                    if requests.heap_frame.is_some() {
+                        return Err(invalid_data);
+                    }
+                    if bytes < MIN_HEAP_FRAME_BYTES
+                        || bytes > MAX_HEAP_FRAME_BYTES
+                        || bytes % 1024 != 0
+                    {
+                        return Err((index as u8, InstructionError::InvalidArgument));
+                    }
+                    requests.heap_frame = Some(bytes);
+                }
+            }
+        }
+        Ok(requests)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{pubkey::Pubkey, system_instruction};
+
+    fn requests(
+        instructions: &[Instruction],
+    ) -> Result<ComputeBudgetRequests, (u8, InstructionError)> {
+        let payer = Pubkey::new_unique();
+        ComputeBudgetRequests::from_message(&Message::new(instructions, Some(&payer)))
+    }
+
+    #[test]
+    fn test_compute_budget_requests() {
+        let transfer =
+            system_instruction::transfer(&Pubkey::new_unique(), &Pubkey::new_unique(), 1);
+        assert_eq!(
+            requests(&[transfer.clone()]),
+            Ok(ComputeBudgetRequests::default())
+        );
+        assert_eq!(
+            requests(&[
+                transfer.clone(),
+                ComputeBudgetInstruction::request_units(500_000),
+                ComputeBudgetInstruction::request_heap_frame(64 * 1024),
+            ]),
+            Ok(ComputeBudgetRequests {
+                units: Some(500_000),
+                heap_frame: Some(64 * 1024),
+            })
+        );
+
+        // Requested units are capped
+        assert_eq!(
+            requests(&[ComputeBudgetInstruction::request_units(u32::MAX)])
+                .unwrap()
+                .units,
+            Some(MAX_UNITS)
+        );
+
+        assert_eq!(
+            requests(&[
+                ComputeBudgetInstruction::request_units(1),
+                ComputeBudgetInstruction::request_units(2),
+            ]),
+            Err((1, InstructionError::InvalidInstructionData))
+        );
+        assert_eq!(
+            requests(&[
+                transfer,
+                ComputeBudgetInstruction::request_heap_frame(MAX_HEAP_FRAME_BYTES + 1024),
+            ]),
+            Err((1, InstructionError::InvalidArgument))
+        );
+        assert_eq!(
+            requests(&[ComputeBudgetInstruction::request_heap_frame(
+                MIN_HEAP_FRAME_BYTES + 1
+            )]),
+            Err((0, InstructionError::InvalidArgument))
+        );
+        assert_eq!(
+            requests(&[Instruction::new(id(), &[0u8; 2], vec![])]),
+            Err((0, InstructionError::InvalidInstructionData))
+        );
+    }
+
+    #[test]
+    fn test_compute_budget_requests_additional_fee() {
+        let fee = |units| {
+            ComputeBudgetRequests {
+                units,
+                heap_frame: None,
+            }
+            .additional_fee(10)
+        };
+        assert_eq!(fee(None), 0);
+        assert_eq!(fee(Some(FREE_UNITS)), 0);
+        assert_eq!(fee(Some(FREE_UNITS + 1)), 10);
+        assert_eq!(fee(Some(FREE_UNITS + UNITS_PER_SIGNATURE_FEE)), 10);
+        assert_eq!(fee(Some(MAX_UNITS)), 60);
+    }
+}
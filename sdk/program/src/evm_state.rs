@@ -1 +1,75 @@
+//! The `evm_state` account and read-only access to the evm state from programs.
+//!  The state can be read only by transactions that lock the evm state account as writable,
+//!  and reflects all evm transactions executed earlier in the same block.
+
 crate::declare_id!("EvmState11111111111111111111111111111111111");
+
+/// Size of an evm address
+pub const EVM_ADDRESS_BYTES: usize = 20;
+/// Size of an evm word (balance, nonce, hash or storage value)
+pub const EVM_WORD_BYTES: usize = 32;
+
+/// Returned by the evm state syscalls if the evm state account was not locked as writable
+///  by the transaction, read-only access to the account doesn't make the evm state available
+pub const EVM_STATE_NOT_AVAILABLE: u64 = 1;
+
+/// Evm account as seen by programs, all values are big-endian.
+/// Accounts that don't exist are returned zeroed, including `code_hash`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EvmAccount {
+    pub balance: [u8; EVM_WORD_BYTES],
+    pub nonce: [u8; EVM_WORD_BYTES],
+    pub code_hash: [u8; EVM_WORD_BYTES],
+}
+
+/// Read the balance, nonce and code hash of an evm account.
+/// The transaction has to lock the evm state account as writable,
+/// otherwise the evm state is not available and `None` is returned.
+pub fn get_account(address: &[u8; EVM_ADDRESS_BYTES]) -> Option<EvmAccount> {
+    #[cfg(target_arch = "bpf")]
+    {
+        let mut account = EvmAccount::default();
+        let result =
+            unsafe { sol_evm_get_account(address.as_ptr(), &mut account as *mut _ as *mut u8) };
+        match result {
+            crate::entrypoint::SUCCESS => Some(account),
+            _ => None,
+        }
+    }
+
+    #[cfg(not(target_arch = "bpf"))]
+    crate::program_stubs::sol_evm_get_account(address)
+}
+
+/// Read a storage slot of an evm account, missing slots are returned zeroed.
+/// The transaction has to lock the evm state account as writable,
+/// otherwise the evm state is not available and `None` is returned.
+pub fn get_storage(
+    address: &[u8; EVM_ADDRESS_BYTES],
+    index: &[u8; EVM_WORD_BYTES],
+) -> Option<[u8; EVM_WORD_BYTES]> {
+    #[cfg(target_arch = "bpf")]
+    {
+        let mut value = [0; EVM_WORD_BYTES];
+        let result =
+            unsafe { sol_evm_get_storage(address.as_ptr(), index.as_ptr(), value.as_mut_ptr()) };
+        match result {
+            crate::entrypoint::SUCCESS => Some(value),
+            _ => None,
+        }
+    }
+
+    #[cfg(not(target_arch = "bpf"))]
+    crate::program_stubs::sol_evm_get_storage(address, index)
+}
+
+#[cfg(target_arch = "bpf")]
+extern "C" {
+    fn sol_evm_get_account(address_addr: *const u8, account_addr: *mut u8) -> u64;
+    fn sol_evm_get_storage(
+        address_addr: *const u8,
+        index_addr: *const u8,
+        value_addr: *mut u8,
+    ) -> u64;
+}
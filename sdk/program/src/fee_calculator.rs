@@ -1,8 +1,10 @@
 use crate::clock::{DEFAULT_TICKS_PER_SECOND, DEFAULT_TICKS_PER_SLOT};
+use crate::compute_budget::ComputeBudgetRequests;
 use crate::message::Message;
 use crate::secp256k1_program;
 use log::*;
 
+#[repr(C)]
 #[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Debug, AbiExample)]
 #[serde(rename_all = "camelCase")]
 pub struct FeeCalculator {
@@ -21,12 +23,14 @@ impl Default for FeeCalculator {
 
 pub struct FeeConfig {
     pub secp256k1_program_enabled: bool,
+    pub compute_budget_program_enabled: bool,
 }
 
 impl Default for FeeConfig {
     fn default() -> Self {
         Self {
             secp256k1_program_enabled: true,
+            compute_budget_program_enabled: true,
         }
     }
 }
@@ -57,8 +61,18 @@ impl FeeCalculator {
             }
         }
 
+        // Invalid requests fail the transaction before execution, without the additional fee
+        let additional_fee = if fee_config.compute_budget_program_enabled {
+            ComputeBudgetRequests::from_message(message)
+                .map(|requests| requests.additional_fee(self.lamports_per_signature))
+                .unwrap_or(0)
+        } else {
+            0
+        };
+
         self.lamports_per_signature
             * (u64::from(message.header.num_required_signatures) + num_secp256k1_signatures)
+            + additional_fee
     }
 }
 
@@ -263,7 +277,8 @@ mod tests {
             FeeCalculator::new(1).calculate_fee_with_config(
                 &message,
                 &FeeConfig {
-                    secp256k1_program_enabled: false
+                    secp256k1_program_enabled: false,
+                    ..FeeConfig::default()
                 }
             ),
             1
@@ -275,6 +290,29 @@ mod tests {
         assert_eq!(FeeCalculator::new(1).calculate_fee(&message), 11);
     }
 
+    #[test]
+    fn test_fee_calculator_calculate_fee_compute_budget() {
+        use crate::compute_budget::ComputeBudgetInstruction;
+        let pubkey0 = Pubkey::new(&[0; 32]);
+        let pubkey1 = Pubkey::new(&[1; 32]);
+        let ix0 = system_instruction::transfer(&pubkey0, &pubkey1, 1);
+        let message = Message::new(
+            &[ix0, ComputeBudgetInstruction::request_units(300_000)],
+            Some(&pubkey0),
+        );
+        assert_eq!(FeeCalculator::new(2).calculate_fee(&message), 4);
+        assert_eq!(
+            FeeCalculator::new(2).calculate_fee_with_config(
+                &message,
+                &FeeConfig {
+                    compute_budget_program_enabled: false,
+                    ..FeeConfig::default()
+                }
+            ),
+            2
+        );
+    }
+
     #[test]
     fn test_fee_rate_governor_derived_default() {
         solana_logger::setup();
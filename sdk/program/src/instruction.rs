@@ -198,6 +198,9 @@ pub enum InstructionError {
 
     #[error("Invalid account owner")]
     InvalidAccountOwner,
+
+    #[error("Unsupported sysvar")]
+    UnsupportedSysvar,
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
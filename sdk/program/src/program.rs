@@ -1,4 +1,9 @@
-use crate::{account_info::AccountInfo, entrypoint::ProgramResult, instruction::Instruction};
+use crate::{
+    account_info::AccountInfo, entrypoint::ProgramResult, instruction::Instruction, pubkey::Pubkey,
+};
+
+/// Maximum size of the data a program can return to its caller
+pub const MAX_RETURN_DATA: usize = 1024;
 
 /// Invoke a cross-program instruction
 ///
@@ -54,8 +59,48 @@ pub fn invoke_signed(
     crate::program_stubs::sol_invoke_signed(instruction, account_infos, signers_seeds)
 }
 
+/// Set the data returned to the caller of the currently executing program
+///
+/// The return data is cleared when an instruction or a cross-program invocation
+/// starts, the return data of the last instruction is recorded in the transaction
+/// status.  `data` must not be longer than `MAX_RETURN_DATA`.
+pub fn set_return_data(data: &[u8]) {
+    #[cfg(target_arch = "bpf")]
+    unsafe {
+        sol_set_return_data(data.as_ptr(), data.len() as u64)
+    };
+
+    #[cfg(not(target_arch = "bpf"))]
+    crate::program_stubs::sol_set_return_data(data)
+}
+
+/// Get the data returned by the last invoked program, along with the id of the
+/// program that set it.  Returns `None` if no data was returned.
+pub fn get_return_data() -> Option<(Pubkey, Vec<u8>)> {
+    #[cfg(target_arch = "bpf")]
+    {
+        let mut buf = [0u8; MAX_RETURN_DATA];
+        let mut program_id = Pubkey::default();
+
+        let size =
+            unsafe { sol_get_return_data(buf.as_mut_ptr(), buf.len() as u64, &mut program_id) };
+
+        if size == 0 {
+            None
+        } else {
+            let size = std::cmp::min(size as usize, MAX_RETURN_DATA);
+            Some((program_id, buf[..size].to_vec()))
+        }
+    }
+
+    #[cfg(not(target_arch = "bpf"))]
+    crate::program_stubs::sol_get_return_data()
+}
+
 #[cfg(target_arch = "bpf")]
 extern "C" {
+    fn sol_set_return_data(data: *const u8, length: u64);
+    fn sol_get_return_data(data: *mut u8, length: u64, program_id: *mut Pubkey) -> u64;
     fn sol_invoke_signed_rust(
         instruction_addr: *const u8,
         account_infos_addr: *const u8,
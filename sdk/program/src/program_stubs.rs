@@ -2,7 +2,14 @@
 
 #![cfg(not(target_arch = "bpf"))]
 
-use crate::{account_info::AccountInfo, entrypoint::ProgramResult, instruction::Instruction};
+use crate::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    evm_state::{EvmAccount, EVM_ADDRESS_BYTES, EVM_WORD_BYTES},
+    instruction::Instruction,
+    program_error::UNSUPPORTED_SYSVAR,
+    pubkey::Pubkey,
+};
 use std::sync::{Arc, RwLock};
 
 lazy_static::lazy_static! {
@@ -31,6 +38,39 @@ pub trait SyscallStubs: Sync + Send {
         sol_log("SyscallStubs: sol_invoke_signed() not available");
         Ok(())
     }
+    fn sol_set_return_data(&self, _data: &[u8]) {
+        sol_log("SyscallStubs: sol_set_return_data() not available");
+    }
+    fn sol_get_return_data(&self) -> Option<(Pubkey, Vec<u8>)> {
+        None
+    }
+    fn sol_evm_get_account(&self, _address: &[u8; EVM_ADDRESS_BYTES]) -> Option<EvmAccount> {
+        sol_log("SyscallStubs: sol_evm_get_account() not available");
+        None
+    }
+    fn sol_evm_get_storage(
+        &self,
+        _address: &[u8; EVM_ADDRESS_BYTES],
+        _index: &[u8; EVM_WORD_BYTES],
+    ) -> Option<[u8; EVM_WORD_BYTES]> {
+        sol_log("SyscallStubs: sol_evm_get_storage() not available");
+        None
+    }
+    fn sol_get_clock_sysvar(&self, _var_addr: *mut u8) -> u64 {
+        UNSUPPORTED_SYSVAR
+    }
+    fn sol_get_epoch_schedule_sysvar(&self, _var_addr: *mut u8) -> u64 {
+        UNSUPPORTED_SYSVAR
+    }
+    fn sol_get_fees_sysvar(&self, _var_addr: *mut u8) -> u64 {
+        UNSUPPORTED_SYSVAR
+    }
+    fn sol_get_rent_sysvar(&self, _var_addr: *mut u8) -> u64 {
+        UNSUPPORTED_SYSVAR
+    }
+    fn sol_get_recent_evm_blockhashes_sysvar(&self, _var_addr: *mut u8) -> u64 {
+        UNSUPPORTED_SYSVAR
+    }
 }
 
 struct DefaultSyscallStubs {}
@@ -61,3 +101,51 @@ pub(crate) fn sol_invoke_signed(
         .unwrap()
         .sol_invoke_signed(instruction, account_infos, signers_seeds)
 }
+
+pub(crate) fn sol_set_return_data(data: &[u8]) {
+    SYSCALL_STUBS.read().unwrap().sol_set_return_data(data)
+}
+
+pub(crate) fn sol_get_return_data() -> Option<(Pubkey, Vec<u8>)> {
+    SYSCALL_STUBS.read().unwrap().sol_get_return_data()
+}
+
+pub(crate) fn sol_evm_get_account(address: &[u8; EVM_ADDRESS_BYTES]) -> Option<EvmAccount> {
+    SYSCALL_STUBS.read().unwrap().sol_evm_get_account(address)
+}
+
+pub(crate) fn sol_evm_get_storage(
+    address: &[u8; EVM_ADDRESS_BYTES],
+    index: &[u8; EVM_WORD_BYTES],
+) -> Option<[u8; EVM_WORD_BYTES]> {
+    SYSCALL_STUBS
+        .read()
+        .unwrap()
+        .sol_evm_get_storage(address, index)
+}
+
+pub(crate) fn sol_get_clock_sysvar(var_addr: *mut u8) -> u64 {
+    SYSCALL_STUBS.read().unwrap().sol_get_clock_sysvar(var_addr)
+}
+
+pub(crate) fn sol_get_epoch_schedule_sysvar(var_addr: *mut u8) -> u64 {
+    SYSCALL_STUBS
+        .read()
+        .unwrap()
+        .sol_get_epoch_schedule_sysvar(var_addr)
+}
+
+pub(crate) fn sol_get_fees_sysvar(var_addr: *mut u8) -> u64 {
+    SYSCALL_STUBS.read().unwrap().sol_get_fees_sysvar(var_addr)
+}
+
+pub(crate) fn sol_get_rent_sysvar(var_addr: *mut u8) -> u64 {
+    SYSCALL_STUBS.read().unwrap().sol_get_rent_sysvar(var_addr)
+}
+
+pub(crate) fn sol_get_recent_evm_blockhashes_sysvar(var_addr: *mut u8) -> u64 {
+    SYSCALL_STUBS
+        .read()
+        .unwrap()
+        .sol_get_recent_evm_blockhashes_sysvar(var_addr)
+}
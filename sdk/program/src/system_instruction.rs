@@ -217,6 +217,17 @@ pub enum SystemInstruction {
         /// Owner to use to derive the funding account address
         from_owner: Pubkey,
     },
+
+    /// Change the entity authorized to execute nonce instructions on the account
+    ///
+    /// This instruction behaves like `AuthorizeNonceAccount` with the additional
+    /// requirement that the new nonce authority must also be a signer.
+    ///
+    /// # Account references
+    ///   0. [WRITE] Nonce account
+    ///   1. [SIGNER] Nonce authority
+    ///   2. [SIGNER] New nonce authority
+    AuthorizeNonceAccountChecked,
 }
 
 pub fn create_account(
@@ -482,6 +493,23 @@ pub fn authorize_nonce_account(
     )
 }
 
+pub fn authorize_nonce_account_checked(
+    nonce_pubkey: &Pubkey,
+    authorized_pubkey: &Pubkey,
+    new_authority: &Pubkey,
+) -> Instruction {
+    let account_metas = vec![
+        AccountMeta::new(*nonce_pubkey, false),
+        AccountMeta::new_readonly(*authorized_pubkey, true),
+        AccountMeta::new_readonly(*new_authority, true),
+    ];
+    Instruction::new(
+        system_program::id(),
+        &SystemInstruction::AuthorizeNonceAccountChecked,
+        account_metas,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,6 +1,6 @@
 //! This account contains the current cluster fees
 //!
-use crate::{fee_calculator::FeeCalculator, sysvar::Sysvar};
+use crate::{fee_calculator::FeeCalculator, impl_sysvar_get, sysvar::Sysvar};
 
 crate::declare_sysvar_id!("SysvarFees111111111111111111111111111111111", Fees);
 
@@ -17,4 +17,6 @@ impl Fees {
     }
 }
 
-impl Sysvar for Fees {}
+impl Sysvar for Fees {
+    impl_sysvar_get!(sol_get_fees_sysvar);
+}
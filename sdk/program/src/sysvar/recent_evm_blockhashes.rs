@@ -1,4 +1,4 @@
-use crate::{declare_sysvar_id, hash::Hash, sysvar::Sysvar};
+use crate::{declare_sysvar_id, hash::Hash, program_error::ProgramError, sysvar::Sysvar};
 
 use serde::{
     de::{SeqAccess, Visitor},
@@ -26,6 +26,37 @@ impl Default for RecentBlockhashes {
 }
 
 impl RecentBlockhashes {
+    /// Read the sysvar without passing its account to the program.
+    /// The sysvar takes 8KiB, more than a 4KiB BPF stack frame, so it is read into the heap,
+    /// and `Sysvar::get()` is not supported for it.
+    pub fn get_boxed() -> Result<Box<Self>, ProgramError> {
+        let layout = std::alloc::Layout::new::<Self>();
+        // Zeroed memory is a valid value of default hashes
+        let mut var = unsafe {
+            let ptr = std::alloc::alloc_zeroed(layout) as *mut Self;
+            if ptr.is_null() {
+                std::alloc::handle_alloc_error(layout);
+            }
+            Box::from_raw(ptr)
+        };
+        let var_addr = &mut *var as *mut _ as *mut u8;
+
+        #[cfg(target_arch = "bpf")]
+        let result = unsafe {
+            extern "C" {
+                fn sol_get_recent_evm_blockhashes_sysvar(var_addr: *mut u8) -> u64;
+            }
+            sol_get_recent_evm_blockhashes_sysvar(var_addr)
+        };
+        #[cfg(not(target_arch = "bpf"))]
+        let result = crate::program_stubs::sol_get_recent_evm_blockhashes_sysvar(var_addr);
+
+        match result {
+            crate::entrypoint::SUCCESS => Ok(var),
+            error => Err(error.into()),
+        }
+    }
+
     fn deserialize<'de, D>(deserializer: D) -> Result<[Hash; MAX_ENTRIES], D::Error>
     where
         D: Deserializer<'de>,
@@ -93,4 +124,17 @@ mod test {
             MAX_ENTRIES * 32
         )
     }
+
+    #[test]
+    fn test_get_evm_blockhashes_unsupported() {
+        // Default syscall stubs don't provide the sysvar
+        assert_eq!(
+            RecentBlockhashes::get_boxed(),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+        assert_eq!(
+            <RecentBlockhashes as Sysvar>::get(),
+            Err(ProgramError::UnsupportedSysvar)
+        );
+    }
 }
@@ -143,6 +143,44 @@ pub trait SyncClient {
 
     /// Get account balance or 0 if not found.
     fn get_evm_balance(&self, pubkey: &evm_state::Address) -> Result<evm_state::U256>;
+
+    /// Get number of the last EVM block
+    fn get_evm_block_number(&self) -> Result<u64>;
+
+    /// Get account nonce or 0 if not found.
+    fn get_evm_transaction_count(&self, address: &evm_state::Address) -> Result<evm_state::U256>;
+
+    /// Get account code or empty code if not found.
+    fn get_evm_code(&self, address: &evm_state::Address) -> Result<Vec<u8>>;
+
+    /// Get account storage value at `index` or zero if not found.
+    fn get_evm_storage_at(
+        &self,
+        address: &evm_state::Address,
+        index: &evm_state::H256,
+    ) -> Result<evm_state::H256>;
+
+    /// Get EVM block or None if not found. Includes full transactions if `full` is set.
+    fn get_evm_block_by_number(
+        &self,
+        block: evm_rpc::BlockId,
+        full: bool,
+    ) -> Result<Option<evm_rpc::RPCBlock>>;
+
+    /// Get EVM transaction receipt or None if not found.
+    fn get_evm_transaction_receipt(
+        &self,
+        hash: &evm_state::H256,
+    ) -> Result<Option<evm_rpc::RPCReceipt>>;
+
+    /// Get EVM logs matching `filter`
+    fn get_evm_logs(&self, filter: &evm_rpc::RPCLogFilter) -> Result<Vec<evm_rpc::RPCLog>>;
+
+    /// Execute `tx` against the last EVM state without committing it, and return its output.
+    fn evm_call(&self, tx: &evm_rpc::RPCTransaction) -> Result<Vec<u8>>;
+
+    /// Estimate gas needed to execute `tx` against the last EVM state.
+    fn evm_estimate_gas(&self, tx: &evm_rpc::RPCTransaction) -> Result<evm_state::Gas>;
 }
 
 pub trait AsyncClient {
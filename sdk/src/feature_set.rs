@@ -175,6 +175,42 @@ pub mod check_program_owner {
     solana_sdk::declare_id!("5XnbR5Es9YXEARRuP6mdvoxiW3hx5atNNeBmwVd8P3QD");
 }
 
+pub mod velas_accounts_program {
+    solana_sdk::declare_id!("5GVNThf7XBYTay57zCHEDLqV7MrsomPmbBem3WBGT7H");
+}
+
+pub mod stake_redelegate_instruction {
+    solana_sdk::declare_id!("CAQsbzFgs59aEWRGNZBEJ1xietoNZEFHCdHQ99HgBvzX");
+}
+
+pub mod authorize_checked_instructions {
+    solana_sdk::declare_id!("An6wYtGgus2Z7f7rxHgxCvLQ4tCpdccfJbUfBgrKexEy");
+}
+
+pub mod evm_params_config {
+    solana_sdk::declare_id!("749fc3ANcgSaZbRWCzGq1GfZdSJdpE3qywav299bmwEV");
+}
+
+pub mod keccak256_secp256k1_recover_syscalls {
+    solana_sdk::declare_id!("FSbM36JD15SVkp42XDeMYdWdM8fMWhLU2g2uHhBjDYtN");
+}
+
+pub mod evm_state_read_syscalls {
+    solana_sdk::declare_id!("9nfBRHbn37okAewnW83LtdU29GjEJNye8fJaT3Y7F3t2");
+}
+
+pub mod cpi_return_data_syscalls {
+    solana_sdk::declare_id!("2zawnty6Gw8VGgiFgHSeVve5LqhRWRSCnsASAKeWGWkp");
+}
+
+pub mod compute_budget_requests {
+    solana_sdk::declare_id!("5HtiWgu4spHpzHsAfa5ATjhvKWK4zG3UyUGw5GJPHaFc");
+}
+
+pub mod sysvar_getter_syscalls {
+    solana_sdk::declare_id!("CDy3HnGtZFWXNZ1t9Qg3exR3wZR85WozJf6Y2CqjVR94");
+}
+
 pub mod test_features {
     solana_sdk::declare_id!("11111111111111111111111111111111");
 }
@@ -231,7 +267,16 @@ lazy_static! {
     pub static ref FEATURE_NAMES: HashMap<Pubkey, &'static str> = FEATURE_NAMES_BEFORE_MAINNET.iter().map(|(k,v)| (*k, *v))
     .chain(
         [
-            (test_features::id(), "Test feature used as example how to implement features.")
+            (test_features::id(), "Test feature used as example how to implement features."),
+            (velas_accounts_program::id(), "enable velas accounts program"),
+            (stake_redelegate_instruction::id(), "enable the stake program Redelegate instruction"),
+            (authorize_checked_instructions::id(), "enable AuthorizeChecked instructions in the stake, vote and system programs"),
+            (evm_params_config::id(), "read evm gas price, block gas limit and fee split from the evm params config account"),
+            (keccak256_secp256k1_recover_syscalls::id(), "enable sol_keccak256 and sol_secp256k1_recover syscalls"),
+            (evm_state_read_syscalls::id(), "enable sol_evm_get_account and sol_evm_get_storage syscalls"),
+            (cpi_return_data_syscalls::id(), "enable sol_set_return_data and sol_get_return_data syscalls"),
+            (compute_budget_requests::id(), "compute budget program, transaction-wide compute limit and requestable heap size"),
+            (sysvar_getter_syscalls::id(), "sysvar getter syscalls")
             /*************** ADD NEW FEATURES HERE ***************/
         ]
         .iter()
@@ -1,5 +1,7 @@
+use evm_state::{AccountProvider, EvmBackend, Incomming};
 use solana_sdk::{
     account::Account,
+    compute_budget::ComputeBudgetRequests,
     feature_set::{
         bpf_compute_budget_balancing, max_cpi_instruction_size_ipv6_mtu, max_invoke_depth_4,
         max_program_call_depth_64, pubkey_log_syscall_enabled, FeatureSet,
@@ -8,6 +10,8 @@ use solana_sdk::{
     keyed_account::KeyedAccount,
     message::Message,
     pubkey::Pubkey,
+    sysvar::Sysvar,
+    transaction::TransactionError,
 };
 use std::{cell::RefCell, fmt::Debug, rc::Rc, sync::Arc};
 
@@ -27,6 +31,14 @@ pub type LoaderEntrypoint = unsafe extern "C" fn(
 pub type ProcessInstructionWithContext =
     fn(&Pubkey, &[KeyedAccount], &[u8], &mut dyn InvokeContext) -> Result<(), InstructionError>;
 
+/// Data returned by the last instruction of a transaction, and the id of the
+/// program that set it
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TransactionReturnData {
+    pub program_id: Pubkey,
+    pub data: Vec<u8>,
+}
+
 /// Invocation context passed to loaders
 pub trait InvokeContext {
     /// Push a program ID on to the invocation stack
@@ -64,6 +76,15 @@ pub trait InvokeContext {
     fn is_feature_active(&self, feature_id: &Pubkey) -> bool;
     /// Get an account from a pre-account
     fn get_account(&self, pubkey: &Pubkey) -> Option<RefCell<Account>>;
+    /// Get the incoming evm state of the block, available only if the transaction
+    /// locked the evm state account as writable
+    fn get_evm_state(&self) -> Option<&dyn AccountProvider>;
+    /// Set the return data of the currently executing program
+    fn set_return_data(&mut self, data: Vec<u8>) -> Result<(), InstructionError>;
+    /// Get the last return data and the id of the program that set it
+    fn get_return_data(&self) -> (Pubkey, &[u8]);
+    /// Get the sysvars readable without passing their accounts
+    fn get_sysvars(&self) -> &[(Pubkey, Vec<u8>)];
 }
 
 /// Convenience macro to log a message with an `Rc<RefCell<dyn Logger>>`
@@ -96,6 +117,27 @@ macro_rules! ic_msg {
     };
 }
 
+/// Deserialize a sysvar readable without passing its account
+pub fn get_sysvar<T: Sysvar>(
+    invoke_context: &dyn InvokeContext,
+    id: &Pubkey,
+) -> Result<T, InstructionError> {
+    invoke_context
+        .get_sysvars()
+        .iter()
+        .find_map(|(key, data)| {
+            if id == key {
+                bincode::deserialize(data).ok()
+            } else {
+                None
+            }
+        })
+        .ok_or_else(|| {
+            ic_msg!(invoke_context, "Unable to get sysvar {}", id);
+            InstructionError::UnsupportedSysvar
+        })
+}
+
 #[derive(Clone, Copy, Debug, AbiExample)]
 pub struct BpfComputeBudget {
     /// Number of compute units that an instruction is allowed.  Compute units
@@ -116,6 +158,20 @@ pub struct BpfComputeBudget {
     pub sha256_base_cost: u64,
     /// Incremental number of units consumed by SHA256 (based on bytes)
     pub sha256_byte_cost: u64,
+    /// Base number of compute units consumed to call Keccak256
+    pub keccak256_base_cost: u64,
+    /// Incremental number of units consumed by Keccak256 (based on bytes)
+    pub keccak256_byte_cost: u64,
+    /// Number of compute units consumed to call secp256k1_recover
+    pub secp256k1_recover_cost: u64,
+    /// Number of compute units consumed by an evm account or storage read
+    pub evm_state_read_units: u64,
+    /// Base number of compute units consumed to set or get return data
+    pub return_data_units: u64,
+    /// Number of return data bytes per additional compute unit
+    pub return_data_bytes_per_unit: u64,
+    /// Number of compute units consumed to get a sysvar, plus one unit per byte of the sysvar
+    pub sysvar_base_cost: u64,
     /// Maximum BPF to BPF call depth
     pub max_call_depth: usize,
     /// Size of a stack frame in bytes, must match the size specified in the LLVM BPF backend
@@ -124,6 +180,8 @@ pub struct BpfComputeBudget {
     pub log_pubkey_units: u64,
     /// Maximum cross-program invocation instruction size
     pub max_cpi_instruction_size: usize,
+    /// Program heap region size, the loader default is used if `None`
+    pub heap_size: Option<usize>,
 }
 impl Default for BpfComputeBudget {
     fn default() -> Self {
@@ -143,10 +201,18 @@ impl BpfComputeBudget {
             max_invoke_depth: 1,
             sha256_base_cost: 85,
             sha256_byte_cost: 1,
+            keccak256_base_cost: 85,
+            keccak256_byte_cost: 1,
+            secp256k1_recover_cost: 25_000,
+            evm_state_read_units: 1_000,
+            return_data_units: 100,
+            return_data_bytes_per_unit: 250,
+            sysvar_base_cost: 100,
             max_call_depth: 20,
             stack_frame_size: 4_096,
             log_pubkey_units: 0,
             max_cpi_instruction_size: std::usize::MAX,
+            heap_size: None,
         };
 
         if feature_set.is_active(&bpf_compute_budget_balancing::id()) {
@@ -186,6 +252,20 @@ impl BpfComputeBudget {
         }
         bpf_compute_budget
     }
+
+    /// Apply the compute budget requests of `message`, requested units replace `max_units`.
+    /// Returns `true` if the message requested compute units.
+    pub fn process_message(&mut self, message: &Message) -> Result<bool, TransactionError> {
+        let requests = ComputeBudgetRequests::from_message(message)
+            .map_err(|(index, err)| TransactionError::InstructionError(index, err))?;
+        if let Some(units) = requests.units {
+            self.max_units = u64::from(units);
+        }
+        if let Some(bytes) = requests.heap_frame {
+            self.heap_size = Some(bytes as usize);
+        }
+        Ok(requests.units.is_some())
+    }
 }
 
 /// Compute meter
@@ -310,6 +390,9 @@ pub struct MockInvokeContext {
     pub compute_meter: MockComputeMeter,
     pub programs: Vec<(Pubkey, ProcessInstructionWithContext)>,
     pub invoke_depth: usize,
+    pub evm_state: Option<EvmBackend<Incomming>>,
+    pub return_data: (Pubkey, Vec<u8>),
+    pub sysvars: Vec<(Pubkey, Vec<u8>)>,
 }
 impl Default for MockInvokeContext {
     fn default() -> Self {
@@ -322,6 +405,9 @@ impl Default for MockInvokeContext {
             },
             programs: vec![],
             invoke_depth: 0,
+            evm_state: None,
+            return_data: (Pubkey::default(), Vec::new()),
+            sysvars: vec![],
         }
     }
 }
@@ -371,4 +457,19 @@ impl InvokeContext for MockInvokeContext {
     fn get_account(&self, _pubkey: &Pubkey) -> Option<RefCell<Account>> {
         None
     }
+    fn get_evm_state(&self) -> Option<&dyn AccountProvider> {
+        self.evm_state
+            .as_ref()
+            .map(|evm_state| evm_state as &dyn AccountProvider)
+    }
+    fn set_return_data(&mut self, data: Vec<u8>) -> Result<(), InstructionError> {
+        self.return_data = (self.key, data);
+        Ok(())
+    }
+    fn get_return_data(&self) -> (Pubkey, &[u8]) {
+        (self.return_data.0, &self.return_data.1)
+    }
+    fn get_sysvars(&self) -> &[(Pubkey, Vec<u8>)] {
+        &self.sysvars
+    }
 }
@@ -25,6 +25,8 @@ pub enum AccountOperation {
     SystemAccountEnroll,
     FailedToMaintainMinimumBalance,
     MergeSource,
+    RedelegateSource,
+    RedelegateDestination,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -137,6 +139,8 @@ fn process_transaction(
                     }
                     StakeInstruction::Authorize(_, _)
                     | StakeInstruction::AuthorizeWithSeed(_)
+                    | StakeInstruction::AuthorizeChecked(_)
+                    | StakeInstruction::AuthorizeCheckedWithSeed(_)
                     | StakeInstruction::DelegateStake
                     | StakeInstruction::Deactivate => {
                         // These instructions are always permitted
@@ -200,6 +204,45 @@ fn process_transaction(
                             }
                         }
                     }
+                    StakeInstruction::Redelegate => {
+                        // Redelegate moves the active stake into a new account, like Split,
+                        // and propagates compliance to it
+                        let source_stake_account_index = instruction.accounts[0] as usize;
+                        let redelegated_stake_account_index = instruction.accounts[1] as usize;
+
+                        let source_stake_pubkey =
+                            message.account_keys[source_stake_account_index].to_string();
+                        let redelegated_stake_pubkey =
+                            message.account_keys[redelegated_stake_account_index].to_string();
+
+                        if let Some(mut source_account_info) =
+                            accounts.get_mut(&source_stake_pubkey)
+                        {
+                            if source_account_info.compliant_since.is_some() {
+                                let lamports = meta.pre_balances[source_stake_account_index]
+                                    .saturating_sub(meta.post_balances[source_stake_account_index]);
+                                source_account_info
+                                    .transactions
+                                    .push(AccountTransactionInfo {
+                                        op: AccountOperation::RedelegateSource,
+                                        slot,
+                                        signature: signature.clone(),
+                                    });
+                                source_account_info.lamports -= lamports;
+
+                                let redelegated_account_info = AccountInfo {
+                                    compliant_since: source_account_info.compliant_since,
+                                    lamports,
+                                    transactions: vec![AccountTransactionInfo {
+                                        op: AccountOperation::RedelegateDestination,
+                                        slot,
+                                        signature: signature.clone(),
+                                    }],
+                                };
+                                accounts.insert(redelegated_stake_pubkey, redelegated_account_info);
+                            }
+                        }
+                    }
                     StakeInstruction::Withdraw(_) => {
                         // Withdrawing is not permitted
 
@@ -7,14 +7,19 @@ use solana_clap_utils::{
 };
 use solana_cli_output::display::format_labeled_address;
 use solana_client::{
-    client_error, rpc_client::RpcClient, rpc_config::RpcSimulateTransactionConfig,
-    rpc_request::MAX_GET_SIGNATURE_STATUSES_QUERY_ITEMS, rpc_response::RpcVoteAccountInfo,
+    client_error,
+    rpc_client::RpcClient,
+    rpc_config::{
+        RpcBlockProductionConfig, RpcBlockProductionConfigRange, RpcSimulateTransactionConfig,
+    },
+    rpc_request::MAX_GET_SIGNATURE_STATUSES_QUERY_ITEMS,
+    rpc_response::RpcVoteAccountInfo,
 };
 use solana_metrics::datapoint_info;
-use solana_notifier::Notifier;
+use solana_notifier::{Alert, Notifier, Severity};
 use solana_sdk::{
     account_utils::StateMut,
-    clock::{Epoch, Slot},
+    clock::Epoch,
     commitment_config::CommitmentConfig,
     message::Message,
     native_token::*,
@@ -281,7 +286,12 @@ fn classify_block_producers(
     let last_slot_in_epoch = epoch_schedule.get_last_slot_in_epoch(epoch);
 
     let minimum_ledger_slot = rpc_client.minimum_ledger_slot()?;
-    if minimum_ledger_slot >= last_slot_in_epoch {
+    let first_slot = if minimum_ledger_slot > first_slot_in_epoch {
+        minimum_ledger_slot
+    } else {
+        first_slot_in_epoch
+    };
+    if first_slot > last_slot_in_epoch {
         return Err(format!(
             "Minimum ledger slot is newer than the last epoch: {} > {}",
             minimum_ledger_slot, last_slot_in_epoch
@@ -289,31 +299,21 @@ fn classify_block_producers(
         .into());
     }
 
-    let first_slot = if minimum_ledger_slot > first_slot_in_epoch {
-        minimum_ledger_slot
-    } else {
-        first_slot_in_epoch
-    };
-
-    let confirmed_blocks = rpc_client.get_confirmed_blocks(first_slot, Some(last_slot_in_epoch))?;
-    let confirmed_blocks: HashSet<Slot> = confirmed_blocks.into_iter().collect();
+    let block_production = rpc_client
+        .get_block_production_with_config(RpcBlockProductionConfig {
+            identity: None,
+            range: Some(RpcBlockProductionConfigRange {
+                first_slot,
+                last_slot: Some(last_slot_in_epoch),
+            }),
+            commitment: Some(CommitmentConfig::finalized()),
+        })?
+        .value;
 
     let mut poor_block_producers = HashSet::new();
     let mut quality_block_producers = HashSet::new();
 
-    let leader_schedule = rpc_client.get_leader_schedule(Some(first_slot))?.unwrap();
-    for (validator_identity, relative_slots) in leader_schedule {
-        let mut validator_blocks = 0;
-        let mut validator_slots = 0;
-        for relative_slot in relative_slots {
-            let slot = first_slot_in_epoch + relative_slot as Slot;
-            if slot >= first_slot {
-                validator_slots += 1;
-                if confirmed_blocks.contains(&slot) {
-                    validator_blocks += 1;
-                }
-            }
-        }
+    for (validator_identity, (validator_slots, validator_blocks)) in block_production.by_identity {
         trace!(
             "Validator {} produced {} blocks in {} slots",
             validator_identity,
@@ -563,7 +563,7 @@ fn main() -> Result<(), Box<dyn error::Error>> {
     solana_logger::setup_with_default("solana=info");
     let config = get_config();
 
-    let notifier = Notifier::default();
+    let notifier = Notifier::default().with_source("stake-o-matic");
     let rpc_client = RpcClient::new(config.json_rpc_url.clone());
 
     let source_stake_balance = validate_source_stake_account(&rpc_client, &config)?;
@@ -882,7 +882,11 @@ fn main() -> Result<(), Box<dyn error::Error>> {
         );
         warn!("{}", message);
         if !config.dry_run {
-            notifier.send(&message);
+            notifier.notify(Alert::firing(
+                "stake-o-matic-poor-block-producers",
+                Severity::Warning,
+                &message,
+            ));
         }
     }
 
@@ -689,6 +689,7 @@ mod tests {
                 log_messages: Some(vec![]),
                 pre_token_balances: Some(vec![]),
                 post_token_balances: Some(vec![]),
+                return_data: None,
             }),
         };
         let block = ConfirmedBlock {
@@ -65,6 +65,8 @@ pub struct TransactionStatusMeta {
     pub pre_token_balances: ::std::vec::Vec<TokenBalance>,
     #[prost(message, repeated, tag = "8")]
     pub post_token_balances: ::std::vec::Vec<TokenBalance>,
+    #[prost(message, optional, tag = "9")]
+    pub return_data: ::std::option::Option<ReturnData>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TransactionError {
@@ -97,6 +99,13 @@ pub struct TokenBalance {
     pub ui_token_amount: ::std::option::Option<UiTokenAmount>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReturnData {
+    #[prost(bytes, tag = "1")]
+    pub program_id: std::vec::Vec<u8>,
+    #[prost(bytes, tag = "2")]
+    pub data: std::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UiTokenAmount {
     #[prost(double, tag = "1")]
     pub ui_amount: f64,
@@ -117,6 +126,8 @@ pub struct Reward {
     pub post_balance: u64,
     #[prost(enumeration = "RewardType", tag = "4")]
     pub reward_type: i32,
+    #[prost(string, tag = "5")]
+    pub commission: std::string::String,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Rewards {
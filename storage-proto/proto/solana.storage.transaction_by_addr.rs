@@ -117,4 +117,5 @@ pub enum InstructionErrorType {
     BorshIoError = 44,
     AccountNotRentExempt = 45,
     InvalidAccountOwner = 46,
+    UnsupportedSysvar = 47,
 }
@@ -5,7 +5,8 @@ use solana_sdk::{
     instruction::CompiledInstruction,
     instruction::InstructionError,
     message::{Message, MessageHeader},
-    pubkey::Pubkey,
+    process_instruction::TransactionReturnData,
+    pubkey::{Pubkey, PUBKEY_BYTES},
     signature::Signature,
     transaction::Transaction,
     transaction::TransactionError,
@@ -87,6 +88,7 @@ impl From<Reward> for generated::Reward {
                 Some(RewardType::Staking) => generated::RewardType::Staking,
                 Some(RewardType::Voting) => generated::RewardType::Voting,
             } as i32,
+            commission: reward.commission.map(|c| c.to_string()).unwrap_or_default(),
         }
     }
 }
@@ -105,6 +107,7 @@ impl From<generated::Reward> for Reward {
                 4 => Some(RewardType::Voting),
                 _ => None,
             },
+            commission: reward.commission.parse::<u8>().ok(),
         }
     }
 }
@@ -275,6 +278,7 @@ impl From<TransactionStatusMeta> for generated::TransactionStatusMeta {
             log_messages,
             pre_token_balances,
             post_token_balances,
+            return_data,
         } = value;
         let err = match status {
             Ok(()) => None,
@@ -298,6 +302,7 @@ impl From<TransactionStatusMeta> for generated::TransactionStatusMeta {
             .into_iter()
             .map(|balance| balance.into())
             .collect();
+        let return_data = return_data.map(|return_data| return_data.into());
 
         Self {
             err,
@@ -308,6 +313,7 @@ impl From<TransactionStatusMeta> for generated::TransactionStatusMeta {
             log_messages,
             pre_token_balances,
             post_token_balances,
+            return_data,
         }
     }
 }
@@ -332,6 +338,7 @@ impl TryFrom<generated::TransactionStatusMeta> for TransactionStatusMeta {
             log_messages,
             pre_token_balances,
             post_token_balances,
+            return_data,
         } = value;
         let status = match &err {
             None => Ok(()),
@@ -356,6 +363,20 @@ impl TryFrom<generated::TransactionStatusMeta> for TransactionStatusMeta {
                 .map(|balance| balance.into())
                 .collect(),
         );
+        let return_data = return_data
+            .map(|return_data| {
+                if return_data.program_id.len() != PUBKEY_BYTES {
+                    return Err(Box::new(bincode::ErrorKind::Custom(format!(
+                        "Invalid return data program id length: {}",
+                        return_data.program_id.len()
+                    ))));
+                }
+                Ok(TransactionReturnData {
+                    program_id: Pubkey::new(&return_data.program_id),
+                    data: return_data.data,
+                })
+            })
+            .transpose()?;
         Ok(Self {
             status,
             fee,
@@ -365,6 +386,7 @@ impl TryFrom<generated::TransactionStatusMeta> for TransactionStatusMeta {
             log_messages,
             pre_token_balances,
             post_token_balances,
+            return_data,
         })
     }
 }
@@ -387,6 +409,15 @@ impl From<generated::InnerInstructions> for InnerInstructions {
     }
 }
 
+impl From<TransactionReturnData> for generated::ReturnData {
+    fn from(value: TransactionReturnData) -> Self {
+        Self {
+            program_id: value.program_id.to_bytes().into(),
+            data: value.data,
+        }
+    }
+}
+
 impl From<TransactionTokenBalance> for generated::TokenBalance {
     fn from(value: TransactionTokenBalance) -> Self {
         Self {
@@ -509,6 +540,7 @@ impl TryFrom<tx_by_addr::TransactionError> for TransactionError {
                     44 => InstructionError::BorshIoError(String::new()),
                     45 => InstructionError::AccountNotRentExempt,
                     46 => InstructionError::InvalidAccountOwner,
+                    47 => InstructionError::UnsupportedSysvar,
                     _ => return Err("Invalid InstructionError"),
                 };
 
@@ -735,6 +767,9 @@ impl From<TransactionError> for tx_by_addr::TransactionError {
                             InstructionError::InvalidAccountOwner => {
                                 tx_by_addr::InstructionErrorType::InvalidAccountOwner
                             }
+                            InstructionError::UnsupportedSysvar => {
+                                tx_by_addr::InstructionErrorType::UnsupportedSysvar
+                            }
                         } as i32,
                         custom: match instruction_error {
                             InstructionError::Custom(custom) => {
@@ -822,6 +857,7 @@ mod test {
             lamports: 123,
             post_balance: 321,
             reward_type: None,
+            commission: None,
         };
         let gen_reward: generated::Reward = reward.clone().into();
         assert_eq!(reward, gen_reward.into());
@@ -841,6 +877,37 @@ mod test {
         reward.reward_type = Some(RewardType::Staking);
         let gen_reward: generated::Reward = reward.clone().into();
         assert_eq!(reward, gen_reward.into());
+
+        reward.commission = Some(10);
+        let gen_reward: generated::Reward = reward.clone().into();
+        assert_eq!(reward, gen_reward.into());
+    }
+
+    #[test]
+    fn test_transaction_status_meta_return_data() {
+        let program_id = solana_sdk::pubkey::new_rand();
+        let meta = generated::TransactionStatusMeta {
+            return_data: Some(generated::ReturnData {
+                program_id: program_id.to_bytes().to_vec(),
+                data: vec![1, 2, 3],
+            }),
+            ..generated::TransactionStatusMeta::default()
+        };
+        let return_data = TransactionStatusMeta::try_from(meta)
+            .unwrap()
+            .return_data
+            .unwrap();
+        assert_eq!(return_data.program_id, program_id);
+        assert_eq!(return_data.data, vec![1, 2, 3]);
+
+        let meta = generated::TransactionStatusMeta {
+            return_data: Some(generated::ReturnData {
+                program_id: vec![1; 31],
+                data: vec![],
+            }),
+            ..generated::TransactionStatusMeta::default()
+        };
+        assert!(TransactionStatusMeta::try_from(meta).is_err());
     }
 
     #[test]
@@ -3,7 +3,10 @@ use solana_account_decoder::{
     parse_token::{real_number_string_trimmed, UiTokenAmount},
     StringAmount,
 };
-use solana_sdk::{deserialize_utils::default_on_eof, transaction::Result};
+use solana_sdk::{
+    deserialize_utils::default_on_eof, process_instruction::TransactionReturnData,
+    transaction::Result,
+};
 use solana_transaction_status::{
     InnerInstructions, Reward, RewardType, TransactionStatusMeta, TransactionTokenBalance,
 };
@@ -21,6 +24,8 @@ pub struct StoredExtendedReward {
     post_balance: u64,
     #[serde(deserialize_with = "default_on_eof")]
     reward_type: Option<RewardType>,
+    #[serde(deserialize_with = "default_on_eof")]
+    commission: Option<u8>,
 }
 
 impl From<StoredExtendedReward> for Reward {
@@ -30,12 +35,14 @@ impl From<StoredExtendedReward> for Reward {
             lamports,
             post_balance,
             reward_type,
+            commission,
         } = value;
         Self {
             pubkey,
             lamports,
             post_balance,
             reward_type,
+            commission,
         }
     }
 }
@@ -47,13 +54,14 @@ impl From<Reward> for StoredExtendedReward {
             lamports,
             post_balance,
             reward_type,
-            ..
+            commission,
         } = value;
         Self {
             pubkey,
             lamports,
             post_balance,
             reward_type,
+            commission,
         }
     }
 }
@@ -150,6 +158,8 @@ pub struct StoredTransactionStatusMeta {
     pub pre_token_balances: Option<Vec<StoredTransactionTokenBalance>>,
     #[serde(deserialize_with = "default_on_eof")]
     pub post_token_balances: Option<Vec<StoredTransactionTokenBalance>>,
+    #[serde(deserialize_with = "default_on_eof")]
+    pub return_data: Option<TransactionReturnData>,
 }
 
 impl From<StoredTransactionStatusMeta> for TransactionStatusMeta {
@@ -163,6 +173,7 @@ impl From<StoredTransactionStatusMeta> for TransactionStatusMeta {
             log_messages,
             pre_token_balances,
             post_token_balances,
+            return_data,
         } = value;
         Self {
             status,
@@ -175,6 +186,7 @@ impl From<StoredTransactionStatusMeta> for TransactionStatusMeta {
                 .map(|balances| balances.into_iter().map(|balance| balance.into()).collect()),
             post_token_balances: post_token_balances
                 .map(|balances| balances.into_iter().map(|balance| balance.into()).collect()),
+            return_data,
         }
     }
 }
@@ -190,6 +202,7 @@ impl From<TransactionStatusMeta> for StoredTransactionStatusMeta {
             log_messages,
             pre_token_balances,
             post_token_balances,
+            return_data,
         } = value;
         Self {
             status,
@@ -202,6 +215,7 @@ impl From<TransactionStatusMeta> for StoredTransactionStatusMeta {
                 .map(|balances| balances.into_iter().map(|balance| balance.into()).collect()),
             post_token_balances: post_token_balances
                 .map(|balances| balances.into_iter().map(|balance| balance.into()).collect()),
+            return_data,
         }
     }
 }
@@ -185,6 +185,65 @@ pub fn parse_stake(
                 info: value,
             })
         }
+        StakeInstruction::Redelegate => {
+            check_num_stake_accounts(&instruction.accounts, 7)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: "redelegate".to_string(),
+                info: json!({
+                    "stakeAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                    "newStakeAccount": account_keys[instruction.accounts[1] as usize].to_string(),
+                    "voteAccount": account_keys[instruction.accounts[2] as usize].to_string(),
+                    "clockSysvar": account_keys[instruction.accounts[3] as usize].to_string(),
+                    "stakeHistorySysvar": account_keys[instruction.accounts[4] as usize].to_string(),
+                    "stakeConfigAccount": account_keys[instruction.accounts[5] as usize].to_string(),
+                    "stakeAuthority": account_keys[instruction.accounts[6] as usize].to_string(),
+                }),
+            })
+        }
+        StakeInstruction::AuthorizeChecked(authority_type) => {
+            check_num_stake_accounts(&instruction.accounts, 4)?;
+            let mut value = json!({
+                "stakeAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                "clockSysvar": account_keys[instruction.accounts[1] as usize].to_string(),
+                "authority": account_keys[instruction.accounts[2] as usize].to_string(),
+                "newAuthority": account_keys[instruction.accounts[3] as usize].to_string(),
+                "authorityType": authority_type,
+            });
+            let map = value.as_object_mut().unwrap();
+            if instruction.accounts.len() >= 5 {
+                map.insert(
+                    "custodian".to_string(),
+                    json!(account_keys[instruction.accounts[4] as usize].to_string()),
+                );
+            }
+            Ok(ParsedInstructionEnum {
+                instruction_type: "authorizeChecked".to_string(),
+                info: value,
+            })
+        }
+        StakeInstruction::AuthorizeCheckedWithSeed(args) => {
+            check_num_stake_accounts(&instruction.accounts, 4)?;
+            let mut value = json!({
+                "stakeAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                "authorityBase": account_keys[instruction.accounts[1] as usize].to_string(),
+                "clockSysvar": account_keys[instruction.accounts[2] as usize].to_string(),
+                "newAuthorized": account_keys[instruction.accounts[3] as usize].to_string(),
+                "authorityType": args.stake_authorize,
+                "authoritySeed": args.authority_seed,
+                "authorityOwner": args.authority_owner.to_string(),
+            });
+            let map = value.as_object_mut().unwrap();
+            if instruction.accounts.len() >= 5 {
+                map.insert(
+                    "custodian".to_string(),
+                    json!(account_keys[instruction.accounts[4] as usize].to_string()),
+                );
+            }
+            Ok(ParsedInstructionEnum {
+                instruction_type: "authorizeCheckedWithSeed".to_string(),
+                info: value,
+            })
+        }
     }
 }
 
@@ -205,7 +264,7 @@ mod test {
     #[allow(clippy::same_item_push)]
     fn test_parse_stake_instruction() {
         let mut keys: Vec<Pubkey> = vec![];
-        for _ in 0..6 {
+        for _ in 0..7 {
             keys.push(Pubkey::new_unique());
         }
 
@@ -393,6 +452,25 @@ mod test {
         );
         assert!(parse_stake(&message.instructions[0], &keys[0..4]).is_err());
 
+        let instructions = stake_instruction::redelegate(&keys[2], &keys[0], &keys[3], &keys[1]);
+        let message = Message::new(&instructions, None);
+        assert_eq!(
+            parse_stake(&message.instructions[1], &keys[0..7]).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "redelegate".to_string(),
+                info: json!({
+                    "stakeAccount": keys[2].to_string(),
+                    "newStakeAccount": keys[1].to_string(),
+                    "voteAccount": keys[3].to_string(),
+                    "clockSysvar": keys[4].to_string(),
+                    "stakeHistorySysvar": keys[5].to_string(),
+                    "stakeConfigAccount": keys[6].to_string(),
+                    "stakeAuthority": keys[0].to_string(),
+                }),
+            }
+        );
+        assert!(parse_stake(&message.instructions[1], &keys[0..6]).is_err());
+
         let seed = "test_seed";
         let instruction = stake_instruction::authorize_with_seed(
             &keys[1],
@@ -448,6 +526,80 @@ mod test {
             }
         );
         assert!(parse_stake(&message.instructions[0], &keys[0..1]).is_err());
+
+        let instruction = stake_instruction::authorize_checked(
+            &keys[2],
+            &keys[0],
+            &keys[1],
+            StakeAuthorize::Staker,
+            None,
+        );
+        let message = Message::new(&[instruction], None);
+        assert_eq!(
+            parse_stake(&message.instructions[0], &keys[0..4]).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "authorizeChecked".to_string(),
+                info: json!({
+                    "stakeAccount": keys[2].to_string(),
+                    "clockSysvar": keys[3].to_string(),
+                    "authority": keys[0].to_string(),
+                    "newAuthority": keys[1].to_string(),
+                    "authorityType": StakeAuthorize::Staker,
+                }),
+            }
+        );
+        assert!(parse_stake(&message.instructions[0], &keys[0..3]).is_err());
+
+        let instruction = stake_instruction::authorize_checked(
+            &keys[3],
+            &keys[0],
+            &keys[1],
+            StakeAuthorize::Withdrawer,
+            Some(&keys[2]),
+        );
+        let message = Message::new(&[instruction], None);
+        assert_eq!(
+            parse_stake(&message.instructions[0], &keys[0..5]).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "authorizeChecked".to_string(),
+                info: json!({
+                    "stakeAccount": keys[3].to_string(),
+                    "clockSysvar": keys[4].to_string(),
+                    "authority": keys[0].to_string(),
+                    "newAuthority": keys[1].to_string(),
+                    "authorityType": StakeAuthorize::Withdrawer,
+                    "custodian": keys[2].to_string(),
+                }),
+            }
+        );
+        assert!(parse_stake(&message.instructions[0], &keys[0..4]).is_err());
+
+        let instruction = stake_instruction::authorize_checked_with_seed(
+            &keys[2],
+            &keys[0],
+            seed.to_string(),
+            &keys[5],
+            &keys[1],
+            StakeAuthorize::Staker,
+            None,
+        );
+        let message = Message::new(&[instruction], None);
+        assert_eq!(
+            parse_stake(&message.instructions[0], &keys[0..4]).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "authorizeCheckedWithSeed".to_string(),
+                info: json!({
+                    "stakeAccount": keys[2].to_string(),
+                    "authorityBase": keys[0].to_string(),
+                    "clockSysvar": keys[3].to_string(),
+                    "newAuthorized": keys[1].to_string(),
+                    "authorityType": StakeAuthorize::Staker,
+                    "authoritySeed": seed,
+                    "authorityOwner": keys[5].to_string(),
+                }),
+            }
+        );
+        assert!(parse_stake(&message.instructions[0], &keys[0..3]).is_err());
     }
 
     #[test]
@@ -130,6 +130,17 @@ pub fn parse_system(
                 }),
             })
         }
+        SystemInstruction::AuthorizeNonceAccountChecked => {
+            check_num_system_accounts(&instruction.accounts, 3)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: "authorizeNonceChecked".to_string(),
+                info: json!({
+                    "nonceAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                    "nonceAuthority": account_keys[instruction.accounts[1] as usize].to_string(),
+                    "newAuthorized": account_keys[instruction.accounts[2] as usize].to_string(),
+                }),
+            })
+        }
         SystemInstruction::Allocate { space } => {
             check_num_system_accounts(&instruction.accounts, 1)?;
             Ok(ParsedInstructionEnum {
@@ -449,5 +460,21 @@ mod test {
             }
         );
         assert!(parse_system(&message.instructions[0], &keys[0..1]).is_err());
+
+        let instruction =
+            system_instruction::authorize_nonce_account_checked(&keys[2], &keys[0], &keys[1]);
+        let message = Message::new(&[instruction], None);
+        assert_eq!(
+            parse_system(&message.instructions[0], &keys[0..3]).unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "authorizeNonceChecked".to_string(),
+                info: json!({
+                    "nonceAccount": keys[2].to_string(),
+                    "nonceAuthority": keys[0].to_string(),
+                    "newAuthorized": keys[1].to_string(),
+                }),
+            }
+        );
+        assert!(parse_system(&message.instructions[0], &keys[0..2]).is_err());
     }
 }
@@ -26,7 +26,9 @@ use solana_core::{
     tpu::DEFAULT_TPU_COALESCE_MS,
     validator::{is_snapshot_config_invalid, Validator, ValidatorConfig},
 };
-use solana_download_utils::{download_genesis_if_missing, download_snapshot};
+use solana_download_utils::{
+    download_genesis_if_missing, download_incremental_snapshot, download_snapshot,
+};
 use solana_ledger::blockstore_db::BlockstoreRecoveryMode;
 use solana_perf::recycler::enable_recycler_warming;
 use solana_runtime::{
@@ -715,7 +717,19 @@ fn rpc_bootstrap(
                                 &ledger_path,
                                 snapshot_hash,
                                 use_progress_bar,
-                            );
+                            )
+                            .map(|()| {
+                                // Incremental snapshot is optional, the node can always
+                                // catch up from the full snapshot alone
+                                if let Err(err) = download_incremental_snapshot(
+                                    &rpc_contact_info.rpc,
+                                    &ledger_path,
+                                    snapshot_hash.0,
+                                    use_progress_bar,
+                                ) {
+                                    warn!("Failed to download incremental snapshot: {}", err);
+                                }
+                            });
                             gossip_service.join().unwrap();
                             ret
                         })
@@ -1072,6 +1086,15 @@ pub fn main() {
                 .help("Number of slots between generating snapshots, \
                       0 to disable snapshots"),
         )
+        .arg(
+            Arg::with_name("incremental_snapshot_interval_slots")
+                .long("incremental-snapshot-interval-slots")
+                .value_name("INCREMENTAL_SNAPSHOT_INTERVAL_SLOTS")
+                .takes_value(true)
+                .default_value("0")
+                .help("Number of slots between generating incremental snapshots \
+                      on top of the last full snapshot, 0 to disable incremental snapshots"),
+        )
         .arg(
             Arg::with_name("contact_debug_interval")
                 .long("contact-debug-interval")
@@ -1273,6 +1296,16 @@ pub fn main() {
                 .validator(solana_net_utils::is_host)
                 .help("IP address to bind the RPC port [default: use --bind-address]"),
         )
+        .arg(
+            Arg::with_name("metrics_prometheus_address")
+                .long("metrics-prometheus-address")
+                .value_name("HOST:PORT")
+                .takes_value(true)
+                .validator(solana_net_utils::is_host_port)
+                .help("Serve metrics in the Prometheus text format on this address \
+                       instead of submitting them to InfluxDB \
+                       [default: $SOLANA_METRICS_PROMETHEUS_ADDRESS]"),
+        )
         .arg(
             Arg::with_name("rpc_threads")
                 .long("rpc-threads")
@@ -1291,6 +1324,13 @@ pub fn main() {
                 .default_value("30")
                 .help("Number of seconds before timing out RPC requests backed by BigTable"),
         )
+        .arg(
+            Arg::with_name("rpc_pubsub_enable_block_subscription")
+                .long("rpc-pubsub-enable-block-subscription")
+                .requires("enable_rpc_transaction_history")
+                .takes_value(false)
+                .help("Enable the unstable RPC PubSub `blockSubscribe` subscription"),
+        )
         .arg(
             Arg::with_name("rpc_pubsub_enable_vote_subscription")
                 .long("rpc-pubsub-enable-vote-subscription")
@@ -1467,6 +1507,14 @@ pub fn main() {
                 .conflicts_with("no_accounts_db_caching")
                 .hidden(true)
         )
+        .arg(
+            Arg::with_name("accountsdb_plugin_config")
+                .long("accountsdb-plugin-config")
+                .value_name("FILE")
+                .takes_value(true)
+                .multiple(true)
+                .help("Specify the configuration file for the AccountsDb plugin."),
+        )
         .arg(
             Arg::with_name("no_duplicate_instance_check")
                 .long("no-duplicate-instance-check")
@@ -1520,6 +1568,17 @@ pub fn main() {
         value_t!(matches, "rocksdb_max_compaction_jitter", u64).ok();
     let tpu_coalesce_ms =
         value_t!(matches, "tpu_coalesce_ms", u64).unwrap_or(DEFAULT_TPU_COALESCE_MS);
+
+    let accountsdb_plugin_config_files = if matches.is_present("accountsdb_plugin_config") {
+        Some(
+            values_t_or_exit!(matches, "accountsdb_plugin_config", String)
+                .into_iter()
+                .map(PathBuf::from)
+                .collect(),
+        )
+    } else {
+        None
+    };
     let wal_recovery_mode = matches
         .value_of("wal_recovery_mode")
         .map(BlockstoreRecoveryMode::from);
@@ -1633,6 +1692,7 @@ pub fn main() {
             )
         }),
         pubsub_config: PubSubConfig {
+            enable_block_subscription: matches.is_present("rpc_pubsub_enable_block_subscription"),
             enable_vote_subscription: matches.is_present("rpc_pubsub_enable_vote_subscription"),
             max_connections: value_t_or_exit!(matches, "rpc_pubsub_max_connections", usize),
             max_fragment_size: value_t_or_exit!(matches, "rpc_pubsub_max_fragment_size", usize),
@@ -1677,6 +1737,7 @@ pub fn main() {
         accounts_db_test_hash_calculation: matches.is_present("accounts_db_test_hash_calculation"),
         accounts_db_use_index_hash_calculation: !matches.is_present("no_accounts_db_index_hashing"),
         tpu_coalesce_ms,
+        accountsdb_plugin_config_files,
         ..ValidatorConfig::default()
     };
 
@@ -1745,6 +1806,8 @@ pub fn main() {
     });
 
     let snapshot_interval_slots = value_t_or_exit!(matches, "snapshot_interval_slots", u64);
+    let incremental_snapshot_interval_slots =
+        value_t_or_exit!(matches, "incremental_snapshot_interval_slots", u64);
     let maximum_local_snapshot_age = value_t_or_exit!(matches, "maximum_local_snapshot_age", u64);
     let snapshot_path = ledger_path.join("snapshot");
     fs::create_dir_all(&snapshot_path).unwrap_or_else(|err| {
@@ -1785,6 +1848,11 @@ pub fn main() {
         snapshot_package_output_path: ledger_path.clone(),
         archive_format,
         snapshot_version,
+        incremental_snapshot_interval_slots: if incremental_snapshot_interval_slots > 0 {
+            incremental_snapshot_interval_slots
+        } else {
+            std::u64::MAX
+        },
     });
 
     validator_config.accounts_hash_interval_slots =
@@ -1803,6 +1871,16 @@ pub fn main() {
         );
         exit(1);
     }
+    if is_snapshot_config_invalid(
+        incremental_snapshot_interval_slots,
+        validator_config.accounts_hash_interval_slots,
+    ) {
+        eprintln!("Invalid incremental snapshot interval provided ({}), must be a multiple of accounts_hash_interval_slots ({})",
+            incremental_snapshot_interval_slots,
+            validator_config.accounts_hash_interval_slots,
+        );
+        exit(1);
+    }
 
     if matches.is_present("limit_ledger_size") {
         let limit_ledger_size = match matches.value_of("limit_ledger_size") {
@@ -1951,6 +2029,11 @@ pub fn main() {
         }
     }
 
+    if let Some(address) = matches.value_of("metrics_prometheus_address") {
+        solana_metrics::set_prometheus_address(
+            solana_net_utils::parse_host_port(address).expect("invalid prometheus address"),
+        );
+    }
     solana_metrics::set_host_id(identity_keypair.pubkey().to_string());
     solana_metrics::set_panic_hook("validator");
 
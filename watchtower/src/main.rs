@@ -3,16 +3,24 @@
 
 use {
     clap::{crate_description, crate_name, value_t, value_t_or_exit, App, Arg},
+    evm_rpc::BlockId,
+    evm_state::H256,
     log::*,
     solana_clap_utils::{
         input_parsers::pubkeys_of,
         input_validators::{is_parsable, is_pubkey_or_keypair, is_url},
     },
     solana_cli_output::display::format_labeled_address,
-    solana_client::{client_error, rpc_client::RpcClient, rpc_response::RpcVoteAccountStatus},
+    solana_client::{
+        client_error,
+        rpc_client::RpcClient,
+        rpc_request::RpcRequest,
+        rpc_response::{RpcBlockProduction, RpcVoteAccountStatus},
+    },
     solana_metrics::{datapoint_error, datapoint_info},
-    solana_notifier::Notifier,
+    solana_notifier::{Alert, Notifier, Severity},
     solana_sdk::{
+        clock::Slot,
         hash::Hash,
         native_token::{sol_to_lamports, Sol},
         pubkey::Pubkey,
@@ -20,23 +28,38 @@ use {
     std::{
         collections::HashMap,
         error,
+        ffi::OsString,
         thread::sleep,
-        time::{Duration, Instant},
+        time::{Duration, Instant, SystemTime, UNIX_EPOCH},
     },
 };
 
 struct Config {
     address_labels: HashMap<String, String>,
+    evm_bridge_url: Option<String>,
     ignore_http_bad_gateway: bool,
     interval: Duration,
     json_rpc_url: String,
+    maximum_evm_block_age: Duration,
+    maximum_evm_bridge_lag: u64,
+    maximum_evm_state_root_age: Option<Duration>,
+    maximum_skip_rate: Option<f64>,
     minimum_validator_identity_balance: u64,
     monitor_active_stake: bool,
+    monitor_evm: bool,
     unhealthy_threshold: usize,
     validator_identity_pubkeys: Vec<Pubkey>,
 }
 
 fn get_config() -> Config {
+    get_config_from(std::env::args_os())
+}
+
+fn get_config_from<I, T>(args: I) -> Config
+where
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone,
+{
     let matches = App::new(crate_name!())
         .about(crate_description!())
         .version(solana_version::version!())
@@ -56,7 +79,13 @@ fn get_config() -> Config {
         and a sending number owned by that account,
         define environment variable before running `solana-watchtower`:
 
-        export TWILIO_CONFIG='ACCOUNT=<account>,TOKEN=<securityToken>,TO=<receivingNumber>,FROM=<sendingNumber>'")
+        export TWILIO_CONFIG='ACCOUNT=<account>,TOKEN=<securityToken>,TO=<receivingNumber>,FROM=<sendingNumber>'
+
+        To post alerts as JSON to a webhook and/or to PagerDuty,
+        define environment variables before running `solana-watchtower`:
+
+        export ALERT_WEBHOOK=...
+        export PAGERDUTY_ROUTING_KEY=...")
         .arg({
             let arg = Arg::with_name("config_file")
                 .short("C")
@@ -113,6 +142,15 @@ fn get_config() -> Config {
                 .validator(is_parsable::<f64>)
                 .help("Alert when the validator identity balance is less than this amount of VLX")
         )
+        .arg(
+            Arg::with_name("maximum_skip_rate")
+                .long("maximum-skip-rate")
+                .value_name("PERCENT")
+                .takes_value(true)
+                .validator(is_parsable::<f64>)
+                .help("Alert when a monitored validator skips more than this percentage \
+                    of its leader slots in the current epoch")
+        )
         .arg(
             // Deprecated parameter, now always enabled
             Arg::with_name("no_duplicate_notifications")
@@ -125,6 +163,52 @@ fn get_config() -> Config {
                 .takes_value(false)
                 .help("Alert when the current stake for the cluster drops below 80%"),
         )
+        .arg(
+            Arg::with_name("monitor_evm")
+                .long("monitor-evm")
+                .takes_value(false)
+                .help("Alert when the EVM block height stops advancing with the native slot, \
+                    or the latest EVM block is too old"),
+        )
+        .arg(
+            Arg::with_name("maximum_evm_block_age")
+                .long("maximum-evm-block-age")
+                .value_name("SECONDS")
+                .takes_value(true)
+                .default_value("120")
+                .validator(is_parsable::<u64>)
+                .help("Alert when the timestamp of the latest EVM block lags \
+                    wall-clock time by more than this many seconds")
+        )
+        .arg(
+            Arg::with_name("maximum_evm_state_root_age")
+                .long("maximum-evm-state-root-age")
+                .value_name("SECONDS")
+                .takes_value(true)
+                .validator(is_parsable::<u64>)
+                .requires("monitor_evm")
+                .help("Alert when the EVM state root has not changed for more than \
+                    this many seconds")
+        )
+        .arg(
+            Arg::with_name("evm_bridge_url")
+                .long("evm-bridge-url")
+                .value_name("URL")
+                .takes_value(true)
+                .validator(is_url)
+                .requires("monitor_evm")
+                .help("Also probe this evm-bridge JSON RPC URL")
+        )
+        .arg(
+            Arg::with_name("maximum_evm_bridge_lag")
+                .long("maximum-evm-bridge-lag")
+                .value_name("BLOCKS")
+                .takes_value(true)
+                .default_value("10")
+                .validator(is_parsable::<u64>)
+                .help("Alert when the evm-bridge is more than this many EVM blocks \
+                    behind the JSON RPC URL")
+        )
         .arg(
             Arg::with_name("ignore_http_bad_gateway")
                 .long("ignore-http-bad-gateway")
@@ -134,7 +218,7 @@ fn get_config() -> Config {
                     no alerting should a Bad Gateway error be a side effect of \
                     the real problem")
         )
-        .get_matches();
+        .get_matches_from(args);
 
     let config = if let Some(config_file) = matches.value_of("config_file") {
         solana_cli_config::Config::load(config_file).unwrap_or_default()
@@ -149,6 +233,7 @@ fn get_config() -> Config {
         "minimum_validator_identity_balance",
         f64
     ));
+    let maximum_skip_rate = value_t!(matches, "maximum_skip_rate", f64).ok();
     let json_rpc_url =
         value_t!(matches, "json_rpc_url", String).unwrap_or_else(|_| config.json_rpc_url.clone());
     let validator_identity_pubkeys: Vec<_> = pubkeys_of(&matches, "validator_identities")
@@ -159,18 +244,36 @@ fn get_config() -> Config {
     let monitor_active_stake = matches.is_present("monitor_active_stake");
     let ignore_http_bad_gateway = matches.is_present("ignore_http_bad_gateway");
 
+    let monitor_evm = matches.is_present("monitor_evm");
+    let maximum_evm_block_age =
+        Duration::from_secs(value_t_or_exit!(matches, "maximum_evm_block_age", u64));
+    let maximum_evm_state_root_age = value_t!(matches, "maximum_evm_state_root_age", u64)
+        .ok()
+        .map(Duration::from_secs);
+    let evm_bridge_url = value_t!(matches, "evm_bridge_url", String).ok();
+    let maximum_evm_bridge_lag = value_t_or_exit!(matches, "maximum_evm_bridge_lag", u64);
+
     let config = Config {
         address_labels: config.address_labels,
+        evm_bridge_url,
         ignore_http_bad_gateway,
         interval,
         json_rpc_url,
+        maximum_evm_block_age,
+        maximum_evm_bridge_lag,
+        maximum_evm_state_root_age,
+        maximum_skip_rate,
         minimum_validator_identity_balance,
         monitor_active_stake,
+        monitor_evm,
         unhealthy_threshold,
         validator_identity_pubkeys,
     };
 
     info!("RPC URL: {}", config.json_rpc_url);
+    if let Some(evm_bridge_url) = &config.evm_bridge_url {
+        info!("EVM bridge URL: {}", evm_bridge_url);
+    }
     info!(
         "Monitored validators: {:?}",
         config.validator_identity_pubkeys
@@ -178,10 +281,57 @@ fn get_config() -> Config {
     config
 }
 
-fn get_cluster_info(
-    config: &Config,
-    rpc_client: &RpcClient,
-) -> client_error::Result<(u64, Hash, RpcVoteAccountStatus, HashMap<Pubkey, u64>)> {
+struct ClusterInfo {
+    transaction_count: u64,
+    recent_blockhash: Hash,
+    vote_accounts: RpcVoteAccountStatus,
+    validator_balances: HashMap<Pubkey, u64>,
+    block_production: Option<RpcBlockProduction>,
+    evm_info: Option<EvmInfo>,
+}
+
+struct EvmInfo {
+    slot: Slot,
+    block_number: u64,
+    block_timestamp: u64,
+    state_root: H256,
+}
+
+fn get_evm_info(rpc_client: &RpcClient) -> client_error::Result<EvmInfo> {
+    // Fetch the slot first, so that a block recorded in between can only make
+    // the EVM side look further ahead, never behind
+    let slot = rpc_client.get_slot()?;
+    let block_number = rpc_client.get_evm_block_number()?;
+    let block = rpc_client
+        .get_evm_block_by_number(BlockId::Num(block_number), false)?
+        .ok_or_else(|| {
+            client_error::ClientError::new_with_request(
+                client_error::ClientErrorKind::Custom(format!(
+                    "EVM block {} not found",
+                    block_number
+                )),
+                RpcRequest::EthGetBlockByNumber,
+            )
+        })?;
+
+    Ok(EvmInfo {
+        slot,
+        block_number,
+        block_timestamp: block.timestamp.0,
+        state_root: block.state_root.0,
+    })
+}
+
+/// How far the given unix timestamp lags behind wall-clock time
+fn block_age(block_timestamp: u64) -> Duration {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    now.checked_sub(Duration::from_secs(block_timestamp))
+        .unwrap_or_default()
+}
+
+fn get_cluster_info(config: &Config, rpc_client: &RpcClient) -> client_error::Result<ClusterInfo> {
     let transaction_count = rpc_client.get_transaction_count()?;
     let recent_blockhash = rpc_client.get_recent_blockhash()?.0;
     let vote_accounts = rpc_client.get_vote_accounts()?;
@@ -194,12 +344,35 @@ fn get_cluster_info(
         );
     }
 
-    Ok((
+    let block_production =
+        if config.maximum_skip_rate.is_some() && !config.validator_identity_pubkeys.is_empty() {
+            Some(rpc_client.get_block_production()?.value)
+        } else {
+            None
+        };
+
+    let evm_info = if config.monitor_evm {
+        Some(get_evm_info(rpc_client)?)
+    } else {
+        None
+    };
+
+    Ok(ClusterInfo {
         transaction_count,
         recent_blockhash,
         vote_accounts,
         validator_balances,
-    ))
+        block_production,
+        evm_info,
+    })
+}
+
+/// Percentage of leader slots, where no block was produced
+fn skip_rate(leader_slots: usize, blocks_produced: usize) -> f64 {
+    if leader_slots == 0 {
+        return 0.;
+    }
+    leader_slots.saturating_sub(blocks_produced) as f64 * 100. / leader_slots as f64
 }
 
 fn main() -> Result<(), Box<dyn error::Error>> {
@@ -209,16 +382,26 @@ fn main() -> Result<(), Box<dyn error::Error>> {
     let config = get_config();
 
     let rpc_client = RpcClient::new(config.json_rpc_url.clone());
-    let notifier = Notifier::default();
+    let evm_bridge_client = config.evm_bridge_url.clone().map(RpcClient::new);
+    let notifier = Notifier::default().with_source("solana-watchtower");
     let mut last_transaction_count = 0;
     let mut last_recent_blockhash = Hash::default();
-    let mut last_notification_msg = "".into();
+    let mut last_evm_info: Option<EvmInfo> = None;
+    let mut last_evm_state_root_change = Instant::now();
+    let mut alerting = false;
     let mut num_consecutive_failures = 0;
     let mut last_success = Instant::now();
 
     loop {
         let failure = match get_cluster_info(&config, &rpc_client) {
-            Ok((transaction_count, recent_blockhash, vote_accounts, validator_balances)) => {
+            Ok(ClusterInfo {
+                transaction_count,
+                recent_blockhash,
+                vote_accounts,
+                validator_balances,
+                block_production,
+                evm_info,
+            }) => {
                 info!("Current transaction count: {}", transaction_count);
                 info!("Recent blockhash: {}", recent_blockhash);
                 info!("Current validator count: {}", vote_accounts.current.len());
@@ -299,6 +482,33 @@ fn main() -> Result<(), Box<dyn error::Error>> {
                         validator_errors.push(format!("{} missing", formatted_validator_identity));
                     }
 
+                    if let (Some(maximum_skip_rate), Some(block_production)) =
+                        (config.maximum_skip_rate, &block_production)
+                    {
+                        if let Some((leader_slots, blocks_produced)) = block_production
+                            .by_identity
+                            .get(&validator_identity.to_string())
+                        {
+                            let skip_rate = skip_rate(*leader_slots, *blocks_produced);
+                            info!(
+                                "{} produced {} blocks in {} leader slots, skip rate {:.2}%",
+                                formatted_validator_identity,
+                                blocks_produced,
+                                leader_slots,
+                                skip_rate
+                            );
+                            if skip_rate > maximum_skip_rate {
+                                failures.push((
+                                    "skip-rate",
+                                    format!(
+                                        "{} skipped {:.2}% of leader slots",
+                                        formatted_validator_identity, skip_rate
+                                    ),
+                                ));
+                            }
+                        }
+                    }
+
                     if let Some(balance) = validator_balances.get(&validator_identity) {
                         if *balance < config.minimum_validator_identity_balance {
                             failures.push((
@@ -313,6 +523,87 @@ fn main() -> Result<(), Box<dyn error::Error>> {
                     failures.push(("delinquent", validator_errors.join(",")));
                 }
 
+                if let Some(evm_info) = evm_info {
+                    info!(
+                        "EVM block height: {}, slot: {}, state root: {:?}",
+                        evm_info.block_number, evm_info.slot, evm_info.state_root
+                    );
+
+                    if let Some(last_evm_info) = &last_evm_info {
+                        if evm_info.slot > last_evm_info.slot
+                            && evm_info.block_number <= last_evm_info.block_number
+                        {
+                            failures.push((
+                                "evm-block-height",
+                                format!(
+                                    "EVM block height is not advancing: {} <= {} while slot advanced {} -> {}",
+                                    evm_info.block_number,
+                                    last_evm_info.block_number,
+                                    last_evm_info.slot,
+                                    evm_info.slot
+                                ),
+                            ));
+                        }
+                        if evm_info.state_root != last_evm_info.state_root {
+                            last_evm_state_root_change = Instant::now();
+                        }
+                    }
+
+                    let evm_block_age = block_age(evm_info.block_timestamp);
+                    if evm_block_age > config.maximum_evm_block_age {
+                        failures.push((
+                            "evm-block-age",
+                            format!(
+                                "EVM block {} is {} old",
+                                evm_info.block_number,
+                                humantime::format_duration(Duration::from_secs(
+                                    evm_block_age.as_secs()
+                                ))
+                            ),
+                        ));
+                    }
+
+                    if let Some(maximum_evm_state_root_age) = config.maximum_evm_state_root_age {
+                        let state_root_age = last_evm_state_root_change.elapsed();
+                        if state_root_age > maximum_evm_state_root_age {
+                            failures.push((
+                                "evm-state-root",
+                                format!(
+                                    "EVM state root {:?} unchanged for {}",
+                                    evm_info.state_root,
+                                    humantime::format_duration(Duration::from_secs(
+                                        state_root_age.as_secs()
+                                    ))
+                                ),
+                            ));
+                        }
+                    }
+
+                    if let Some(evm_bridge_client) = &evm_bridge_client {
+                        match evm_bridge_client.get_evm_block_number() {
+                            Ok(bridge_block_number) => {
+                                info!("EVM bridge block height: {}", bridge_block_number);
+                                if bridge_block_number + config.maximum_evm_bridge_lag
+                                    < evm_info.block_number
+                                {
+                                    failures.push((
+                                        "evm-bridge",
+                                        format!(
+                                            "EVM bridge is behind: {} < {}",
+                                            bridge_block_number, evm_info.block_number
+                                        ),
+                                    ));
+                                }
+                            }
+                            Err(err) => {
+                                failures.push(("evm-bridge", err.to_string()));
+                            }
+                        }
+                    }
+
+                    last_evm_info = Some(evm_info);
+                }
+
                 for failure in failures.iter() {
                     error!("{} sanity failure: {}", failure.0, failure.1);
                 }
@@ -336,22 +627,22 @@ fn main() -> Result<(), Box<dyn error::Error>> {
         };
 
         if let Some((failure_test_name, failure_error_message)) = &failure {
-            let notification_msg = format!(
-                "solana-watchtower: Error: {}: {}",
-                failure_test_name, failure_error_message
-            );
+            let notification_msg = format!("{}: {}", failure_test_name, failure_error_message);
             num_consecutive_failures += 1;
             if num_consecutive_failures > config.unhealthy_threshold {
                 datapoint_info!("watchtower-sanity", ("ok", false, bool));
-                if last_notification_msg != notification_msg {
-                    notifier.send(&notification_msg);
-                }
+                // The notifier suppresses repeats of the same failure
+                notifier.notify(Alert::firing(
+                    "watchtower-sanity",
+                    Severity::Error,
+                    &notification_msg,
+                ));
                 datapoint_error!(
                     "watchtower-sanity-failure",
                     ("test", failure_test_name, String),
                     ("err", failure_error_message, String)
                 );
-                last_notification_msg = notification_msg;
+                alerting = true;
             } else {
                 info!(
                     "Failure {} of {}: {}",
@@ -360,7 +651,7 @@ fn main() -> Result<(), Box<dyn error::Error>> {
             }
         } else {
             datapoint_info!("watchtower-sanity", ("ok", true, bool));
-            if !last_notification_msg.is_empty() {
+            if alerting {
                 let alarm_duration = Instant::now().duration_since(last_success);
                 let alarm_duration = alarm_duration - config.interval; // Subtract the period before the first error
                 let alarm_duration = Duration::from_secs(alarm_duration.as_secs()); // Drop milliseconds in message
@@ -370,12 +661,48 @@ fn main() -> Result<(), Box<dyn error::Error>> {
                     humantime::format_duration(alarm_duration)
                 );
                 info!("{}", all_clear_msg);
-                notifier.send(&format!("solana-watchtower: {}", all_clear_msg));
+                notifier.notify(Alert::resolved("watchtower-sanity", &all_clear_msg));
             }
-            last_notification_msg = "".into();
+            alerting = false;
             last_success = Instant::now();
             num_consecutive_failures = 0;
         }
         sleep(config.interval);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_config_without_evm_flags() {
+        let config = get_config_from(vec!["solana-watchtower", "--url", "http://127.0.0.1:8899"]);
+        assert!(!config.monitor_evm);
+        assert_eq!(config.maximum_evm_block_age, Duration::from_secs(120));
+        assert_eq!(config.maximum_evm_bridge_lag, 10);
+        assert_eq!(config.maximum_evm_state_root_age, None);
+        assert_eq!(config.evm_bridge_url, None);
+    }
+
+    #[test]
+    fn test_get_config_with_evm_flags() {
+        let config = get_config_from(vec![
+            "solana-watchtower",
+            "--monitor-evm",
+            "--maximum-evm-block-age",
+            "30",
+            "--evm-bridge-url",
+            "http://127.0.0.1:8545",
+            "--maximum-evm-bridge-lag",
+            "3",
+        ]);
+        assert!(config.monitor_evm);
+        assert_eq!(config.maximum_evm_block_age, Duration::from_secs(30));
+        assert_eq!(
+            config.evm_bridge_url.as_deref(),
+            Some("http://127.0.0.1:8545")
+        );
+        assert_eq!(config.maximum_evm_bridge_lag, 3);
+    }
+}